@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use object_store::{MultipartUpload, PutPayload, PutResult, Result, UploadPart};
+
+use crate::runtime::get_runtime;
+
+/// Wraps `upload` so it is aborted if dropped before completing, e.g. when a part fails, the
+/// deadline expires or the calling task is cancelled. Otherwise its parts stay stored, and billed,
+/// until a bucket lifecycle rule removes them.
+pub fn on_drop(upload: Box<dyn MultipartUpload>) -> Box<dyn MultipartUpload> {
+    Box::new(AbortOnDrop { inner: Some(upload) })
+}
+
+/// The upload is `None` once it completed or was aborted.
+#[derive(Debug)]
+struct AbortOnDrop {
+    inner: Option<Box<dyn MultipartUpload>>,
+}
+
+fn finished() -> object_store::Error {
+    object_store::Error::Generic {
+        store: "multipart_upload",
+        source: "Multipart upload already completed or aborted".into(),
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for AbortOnDrop {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        match self.inner.as_mut() {
+            Some(inner) => inner.put_part(data),
+            None => Box::pin(async { Err(finished()) }),
+        }
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        let result = self.inner.as_mut().ok_or_else(finished)?.complete().await;
+        if result.is_ok() {
+            self.inner = None;
+        }
+        result
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        match self.inner.take() {
+            Some(mut inner) => inner.abort().await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        if let Some(mut upload) = self.inner.take() {
+            get_runtime().spawn(async move {
+                let _ = upload.abort().await;
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct RecordingUpload {
+        aborted: mpsc::Sender<()>,
+    }
+
+    #[async_trait]
+    impl MultipartUpload for RecordingUpload {
+        fn put_part(&mut self, _data: PutPayload) -> UploadPart {
+            Box::pin(async { Ok(()) })
+        }
+
+        async fn complete(&mut self) -> Result<PutResult> {
+            Ok(PutResult {
+                e_tag: None,
+                version: None,
+            })
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            let _ = self.aborted.send(());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aborts_dropped_upload() {
+        let (tx, rx) = mpsc::channel();
+        let mut upload = on_drop(Box::new(RecordingUpload { aborted: tx }));
+        upload.put_part(PutPayload::from_static(b"part")).await.unwrap();
+        drop(upload);
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+
+        let (tx, rx) = mpsc::channel();
+        let mut upload = on_drop(Box::new(RecordingUpload { aborted: tx }));
+        upload.put_part(PutPayload::from_static(b"part")).await.unwrap();
+        upload.complete().await.unwrap();
+        assert!(upload.put_part(PutPayload::from_static(b"late")).await.is_err());
+        drop(upload);
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+        let (tx, rx) = mpsc::channel();
+        let mut upload = on_drop(Box::new(RecordingUpload { aborted: tx }));
+        upload.abort().await.unwrap();
+        drop(upload);
+        assert!(rx.recv().is_ok());
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+}
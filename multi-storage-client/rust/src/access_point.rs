@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::StorageError;
+
+/// An S3 Access Point given as the `bucket`, e.g. `arn:aws:s3:us-east-1:123456789012:accesspoint/my-ap`.
+#[derive(Debug, PartialEq)]
+pub struct AccessPointArn {
+    pub partition: String,
+    pub region: String,
+    pub account: String,
+    pub name: String,
+}
+
+fn invalid(arn: &str, reason: &str, components: &[&str]) -> StorageError {
+    StorageError::ConfigError(format!(
+        "Invalid S3 access point ARN '{}': {} (partition={:?}, service={:?}, region={:?}, account={:?}, resource={:?})",
+        arn,
+        reason,
+        components.get(1).copied().unwrap_or_default(),
+        components.get(2).copied().unwrap_or_default(),
+        components.get(3).copied().unwrap_or_default(),
+        components.get(4).copied().unwrap_or_default(),
+        components.get(5).copied().unwrap_or_default(),
+    ))
+}
+
+/// Returns the partition that owns `region`.
+fn region_partition(region: &str) -> &'static str {
+    if region.starts_with("cn-") {
+        "aws-cn"
+    } else if region.starts_with("us-gov-") {
+        "aws-us-gov"
+    } else {
+        "aws"
+    }
+}
+
+impl AccessPointArn {
+    /// Parses `bucket` as an access point ARN, or returns `None` if it isn't an ARN.
+    ///
+    /// `region_name`, when configured, must be the access point's region. Multi-Region Access
+    /// Points are rejected because they need SigV4A signing, which the S3 client doesn't support.
+    pub fn parse(bucket: &str, region_name: Option<&str>) -> Result<Option<Self>, StorageError> {
+        if !bucket.starts_with("arn:") {
+            return Ok(None);
+        }
+        let components: Vec<&str> = bucket.splitn(6, ':').collect();
+        let [_, partition, service, region, account, resource] = components[..] else {
+            return Err(invalid(bucket, "expected arn:partition:s3:region:account:accesspoint/name", &components));
+        };
+        if !matches!(partition, "aws" | "aws-cn" | "aws-us-gov") {
+            return Err(invalid(bucket, "unknown partition", &components));
+        }
+        if service != "s3" {
+            return Err(invalid(bucket, "only s3 access points are supported", &components));
+        }
+        let name = match resource.split_once(['/', ':']) {
+            Some(("accesspoint", name)) if !name.is_empty() && !name.contains(['/', ':']) => name,
+            _ => return Err(invalid(bucket, "resource must be accesspoint/<name>", &components)),
+        };
+        if region.is_empty() || name.ends_with(".mrap") {
+            return Err(invalid(
+                bucket,
+                "Multi-Region Access Points require SigV4A signing, which is not supported; use the ARN of a regional access point",
+                &components,
+            ));
+        }
+        if account.len() != 12 || !account.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid(bucket, "account must be a 12-digit AWS account id", &components));
+        }
+        if region_partition(region) != partition {
+            return Err(invalid(bucket, "region is not in the ARN's partition", &components));
+        }
+        if let Some(region_name) = region_name.filter(|region_name| *region_name != region) {
+            return Err(invalid(
+                bucket,
+                &format!("region_name '{}' does not match the access point's region", region_name),
+                &components,
+            ));
+        }
+        Ok(Some(AccessPointArn {
+            partition: partition.to_string(),
+            region: region.to_string(),
+            account: account.to_string(),
+            name: name.to_string(),
+        }))
+    }
+
+    /// The access point's virtual-hosted endpoint, which requests are signed for in its region.
+    pub fn endpoint(&self) -> String {
+        let domain = match self.partition.as_str() {
+            "aws-cn" => "amazonaws.com.cn",
+            _ => "amazonaws.com",
+        };
+        format!("https://{}-{}.s3-accesspoint.{}.{}", self.name, self.account, self.region, domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_access_point_arn() {
+        assert_eq!(AccessPointArn::parse("my-bucket", None).unwrap(), None);
+
+        let arn = "arn:aws:s3:us-east-1:123456789012:accesspoint/my-ap";
+        let access_point = AccessPointArn::parse(arn, Some("us-east-1")).unwrap().unwrap();
+        assert_eq!(access_point.name, "my-ap");
+        assert_eq!(
+            access_point.endpoint(),
+            "https://my-ap-123456789012.s3-accesspoint.us-east-1.amazonaws.com"
+        );
+
+        let china = AccessPointArn::parse("arn:aws-cn:s3:cn-north-1:123456789012:accesspoint:my-ap", None);
+        assert_eq!(
+            china.unwrap().unwrap().endpoint(),
+            "https://my-ap-123456789012.s3-accesspoint.cn-north-1.amazonaws.com.cn"
+        );
+
+        for (bad, reason) in [
+            ("arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap", "SigV4A"),
+            ("arn:aws-cn:s3:us-east-1:123456789012:accesspoint/my-ap", "partition"),
+            ("arn:aws:s3:us-east-1:123456789012:bucket/my-ap", "accesspoint/<name>"),
+            ("arn:aws:s3:us-east-1:1234:accesspoint/my-ap", "12-digit"),
+            ("arn:aws:s3:us-east-1", "expected"),
+            ("arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap", "region_name"),
+        ] {
+            match AccessPointArn::parse(bad, Some("us-east-1")).unwrap_err() {
+                StorageError::ConfigError(msg) => assert!(msg.contains(reason), "{}: {}", bad, msg),
+                other => panic!("Expected ConfigError for {}, got {:?}", bad, other),
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use object_store::path::Path;
+use object_store::{ObjectStore, PutMode, PutOptions, PutPayload, UpdateVersion};
+use std::sync::Arc;
+
+use crate::StorageError;
+
+/// Largest object `rewrite` reads back by default, from the `max_append_rewrite_size` config.
+pub const DEFAULT_MAX_REWRITE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Appends `data` to the object at `location`, creating it if needed, on stores without appends:
+/// reads the object and writes it back followed by `data`, on condition that its ETag hasn't
+/// changed or that it still doesn't exist. Objects larger than `max_size` are refused. Returns the
+/// new size of the object.
+///
+/// A concurrent write in between fails the put, reported as `PreconditionFailed`, instead of one
+/// of the writes being lost.
+pub async fn rewrite(
+    store: &Arc<dyn ObjectStore>,
+    location: &Path,
+    data: Bytes,
+    max_size: u64,
+) -> Result<u64, StorageError> {
+    let (existing, options) = match store.get(location).await {
+        Ok(result) => {
+            if result.meta.size > max_size {
+                return Err(StorageError::ConfigError(format!(
+                    "Appending to {} would rewrite its {} bytes, more than max_append_rewrite_size ({} bytes)",
+                    location, result.meta.size, max_size
+                )));
+            }
+            let Some(e_tag) = result.meta.e_tag.clone() else {
+                return Err(StorageError::ObjectStoreError(format!(
+                    "Can't append to {}: the store reports no ETag to guard the rewrite with",
+                    location
+                )));
+            };
+            let options = PutOptions {
+                mode: PutMode::Update(UpdateVersion {
+                    e_tag: Some(e_tag),
+                    version: result.meta.version.clone(),
+                }),
+                attributes: result.attributes.clone(),
+                ..Default::default()
+            };
+            (result.bytes().await?, options)
+        }
+        Err(object_store::Error::NotFound { .. }) => (Bytes::new(), PutMode::Create.into()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let size = (existing.len() + data.len()) as u64;
+    let payload: PutPayload = [existing, data].into_iter().collect();
+    match store.put_opts(location, payload, options).await {
+        Ok(_) => Ok(size),
+        Err(object_store::Error::Precondition { .. } | object_store::Error::AlreadyExists { .. }) => {
+            Err(StorageError::PreconditionFailed(format!(
+                "{} was written by someone else while appending to it; the append wasn't applied",
+                location
+            )))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use futures::stream::BoxStream;
+    use object_store::memory::InMemory;
+    use object_store::{
+        GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, PutMultipartOpts, PutResult, Result,
+    };
+    use std::fmt;
+
+    /// Store that overwrites each object it is asked for right after reading it, as a concurrent
+    /// writer would between the get and the put of a rewrite.
+    #[derive(Debug)]
+    struct Interloper {
+        inner: InMemory,
+    }
+
+    impl fmt::Display for Interloper {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "Interloper({})", self.inner)
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for Interloper {
+        async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOpts,
+        ) -> Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+            let result = self.inner.get_opts(location, options).await;
+            self.inner.put(location, "interloper\n".into()).await?;
+            result
+        }
+
+        async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+            self.inner.head(location).await
+        }
+
+        async fn delete(&self, location: &Path) -> Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+            self.inner.list(prefix)
+        }
+
+        fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+            self.inner.list_with_offset(prefix, offset)
+        }
+
+        async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+
+        async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.rename(from, to).await
+        }
+
+        async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+            self.inner.rename_if_not_exists(from, to).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rewrite() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let location = Path::from("log.txt");
+
+        assert_eq!(rewrite(&store, &location, "one\n".into(), 8).await.unwrap(), 4);
+        assert_eq!(rewrite(&store, &location, "two\n".into(), 8).await.unwrap(), 8);
+        let content = store.get(&location).await.unwrap().bytes().await.unwrap();
+        assert_eq!(content.as_ref(), b"one\ntwo\n");
+
+        match rewrite(&store, &location, "three\n".into(), 4).await {
+            Err(StorageError::ConfigError(msg)) => assert!(msg.contains("max_append_rewrite_size")),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+
+        let store: Arc<dyn ObjectStore> = Arc::new(Interloper { inner: InMemory::new() });
+        // Created by someone else after the get found nothing, then changed after the get read it.
+        for _ in 0..2 {
+            match rewrite(&store, &location, "mine\n".into(), 1024).await {
+                Err(StorageError::PreconditionFailed(msg)) => assert!(msg.contains("log.txt")),
+                other => panic!("Expected PreconditionFailed, got {:?}", other),
+            }
+            let content = store.get(&location).await.unwrap().bytes().await.unwrap();
+            assert_eq!(content.as_ref(), b"interloper\n");
+        }
+    }
+}
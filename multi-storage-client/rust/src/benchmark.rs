@@ -0,0 +1,425 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::{StreamExt, TryStreamExt};
+use object_store::{path::Path, Attributes, ObjectStore};
+use regex::Regex;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::types::{BenchmarkPhaseStats, BenchmarkReport};
+use crate::retry::RetryPolicy;
+use crate::{download_bytes_multipart, parse_path, upload_bytes_multipart, StorageError, WriteMode};
+
+// Benchmarks write and delete objects, so by default they only run under a prefix
+// containing an explicit "msc-benchmark" path segment.
+pub const DEFAULT_BENCHMARK_PREFIX_PATTERN: &str = r"(^|/)msc-benchmark(/|$)";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BenchmarkDirection {
+    Write,
+    Read,
+    Both,
+}
+
+impl BenchmarkDirection {
+    pub fn parse(direction: &str) -> Result<Self, StorageError> {
+        match direction {
+            "write" => Ok(Self::Write),
+            "read" => Ok(Self::Read),
+            "both" => Ok(Self::Both),
+            _ => Err(StorageError::ConfigError(format!(
+                "Invalid benchmark direction '{}'. Supported directions are: write, read, both",
+                direction
+            ))),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Write => "write",
+            Self::Read => "read",
+            Self::Both => "both",
+        }
+    }
+}
+
+pub struct BenchmarkOptions {
+    pub prefix: String,
+    pub object_size: u64,
+    pub num_objects: usize,
+    pub concurrency: usize,
+    pub direction: BenchmarkDirection,
+    pub read_only: bool,
+    pub multipart_chunksize: usize,
+    pub max_concurrency: usize,
+}
+
+/// Refuses to write synthetic objects under a prefix that doesn't match the safety pattern.
+pub fn check_prefix_allowed(prefix: &str, pattern: &Regex) -> Result<(), StorageError> {
+    if pattern.is_match(prefix) {
+        Ok(())
+    } else {
+        Err(StorageError::ConfigError(format!(
+            "Refusing to run a write benchmark under prefix '{}': it does not match the benchmark safety pattern '{}'. \
+             Use a scratch prefix that matches the pattern, or read_only=True against existing objects.",
+            prefix,
+            pattern.as_str()
+        )))
+    }
+}
+
+/// Runs the benchmark described by `options` and returns the aggregated report.
+///
+/// Objects written by the benchmark are deleted before returning, whether or not the
+/// benchmark itself succeeded.
+pub async fn run_benchmark(
+    store: Arc<dyn ObjectStore>,
+    options: BenchmarkOptions,
+) -> Result<BenchmarkReport, StorageError> {
+    if options.num_objects == 0 || options.concurrency == 0 {
+        return Err(StorageError::ConfigError(
+            "Benchmark num_objects and concurrency must be greater than zero".to_string(),
+        ));
+    }
+
+    let mut report = BenchmarkReport {
+        prefix: options.prefix.clone(),
+        direction: options.direction.as_str().to_string(),
+        read_only: options.read_only,
+        object_size: options.object_size,
+        concurrency: options.concurrency,
+        write: None,
+        read: None,
+    };
+
+    if options.read_only {
+        if options.direction == BenchmarkDirection::Write {
+            return Err(StorageError::ConfigError(
+                "A read_only benchmark cannot use direction 'write'".to_string(),
+            ));
+        }
+        report.direction = BenchmarkDirection::Read.as_str().to_string();
+        report.read = Some(run_read_only(&store, &options).await?);
+        return Ok(report);
+    }
+
+    if options.object_size == 0 {
+        return Err(StorageError::ConfigError(
+            "Benchmark object_size must be greater than zero".to_string(),
+        ));
+    }
+
+    let run_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let keys = (0..options.num_objects)
+        .map(|i| parse_path(&format!("{}/{}-{:06}", options.prefix.trim_end_matches('/'), run_id, i)))
+        .collect::<Result<Vec<Path>, StorageError>>()?;
+    let payload = synthetic_payload(options.object_size as usize);
+
+    let result = async {
+        let chunksize = options.multipart_chunksize;
+        let max_concurrency = options.max_concurrency;
+
+        let write_stats = run_phase(&keys, options.concurrency, |key| {
+            let store = Arc::clone(&store);
+            let payload = payload.clone();
+            async move {
+                let result = upload_bytes_multipart(&store, &key, payload, chunksize, max_concurrency, WriteMode::Overwrite, &Attributes::new(), None).await?;
+                Ok(result.bytes_transferred)
+            }
+        })
+        .await?;
+        if options.direction != BenchmarkDirection::Read {
+            report.write = Some(write_stats);
+        }
+
+        if options.direction != BenchmarkDirection::Write {
+            let object_size = options.object_size;
+            let read_stats = run_phase(&keys, options.concurrency, |key| {
+                let store = Arc::clone(&store);
+                async move {
+                    let data = download_bytes_multipart(&store, &key, None, Some((0, object_size)), chunksize, max_concurrency, &RetryPolicy::default(), None, None).await?;
+                    Ok(data.len() as u64)
+                }
+            })
+            .await?;
+            report.read = Some(read_stats);
+        }
+
+        Ok::<(), StorageError>(())
+    }
+    .await;
+
+    let cleanup = delete_objects(&store, &keys, options.concurrency).await;
+
+    result?;
+    cleanup?;
+    Ok(report)
+}
+
+async fn run_read_only(
+    store: &Arc<dyn ObjectStore>,
+    options: &BenchmarkOptions,
+) -> Result<BenchmarkPhaseStats, StorageError> {
+    let prefix = parse_path(&options.prefix)?;
+    let objects: Vec<(Path, u64)> = store
+        .list(Some(&prefix))
+        .try_filter(|meta| futures::future::ready(meta.size > 0))
+        .map_ok(|meta| (meta.location, meta.size))
+        .take(options.num_objects)
+        .try_collect()
+        .await
+        .map_err(StorageError::from)?;
+
+    if objects.is_empty() {
+        return Err(StorageError::ConfigError(format!(
+            "No non-empty objects found under prefix '{}' for a read_only benchmark",
+            options.prefix
+        )));
+    }
+
+    let sizes: std::collections::HashMap<Path, u64> = objects.iter().cloned().collect();
+    let keys: Vec<Path> = objects.into_iter().map(|(key, _)| key).collect();
+    let chunksize = options.multipart_chunksize;
+    let max_concurrency = options.max_concurrency;
+
+    run_phase(&keys, options.concurrency, |key| {
+        let store = Arc::clone(store);
+        let size = sizes[&key];
+        async move {
+            let data = download_bytes_multipart(&store, &key, None, Some((0, size)), chunksize, max_concurrency, &RetryPolicy::default(), None, None).await?;
+            Ok(data.len() as u64)
+        }
+    })
+    .await
+}
+
+/// Runs `op` for every key with at most `concurrency` objects in flight, timing each one.
+async fn run_phase<F, Fut>(
+    keys: &[Path],
+    concurrency: usize,
+    op: F,
+) -> Result<BenchmarkPhaseStats, StorageError>
+where
+    F: Fn(Path) -> Fut,
+    Fut: Future<Output = Result<u64, StorageError>> + Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut join_set = JoinSet::new();
+    let started = Instant::now();
+
+    for key in keys {
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let operation = op(key.clone());
+        join_set.spawn(async move {
+            let object_started = Instant::now();
+            let result = operation.await;
+            drop(permit);
+            result.map(|bytes| (bytes, object_started.elapsed()))
+        });
+    }
+
+    let mut samples = Vec::with_capacity(keys.len());
+    while let Some(joined) = join_set.join_next().await {
+        let sample = joined.map_err(|e| {
+            StorageError::ObjectStoreError(format!("Failed to join benchmark task: {:?}", e))
+        })??;
+        samples.push(sample);
+    }
+
+    Ok(summarize_phase(&samples, started.elapsed()))
+}
+
+/// Deletes every benchmark object, reporting the first failure after attempting all of them.
+async fn delete_objects(
+    store: &Arc<dyn ObjectStore>,
+    keys: &[Path],
+    concurrency: usize,
+) -> Result<(), StorageError> {
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut join_set = JoinSet::new();
+
+    for key in keys {
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let store = Arc::clone(store);
+        let key = key.clone();
+        join_set.spawn(async move {
+            let result = match store.delete(&key).await {
+                Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+                Err(e) => Err(StorageError::from(e)),
+            };
+            drop(permit);
+            result
+        });
+    }
+
+    let mut first_error = None;
+    while let Some(joined) = join_set.join_next().await {
+        let result = joined.map_err(|e| {
+            StorageError::ObjectStoreError(format!("Failed to join benchmark cleanup task: {:?}", e))
+        });
+        if let Err(e) = result.and_then(|r| r) {
+            first_error.get_or_insert(e);
+        }
+    }
+
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Builds a non-repeating payload so transparent compression on the path doesn't skew results.
+fn synthetic_payload(size: usize) -> bytes::Bytes {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut data = Vec::with_capacity(size + 8);
+    while data.len() < size {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        data.extend_from_slice(&state.to_le_bytes());
+    }
+    data.truncate(size);
+    bytes::Bytes::from(data)
+}
+
+fn summarize_phase(samples: &[(u64, Duration)], elapsed: Duration) -> BenchmarkPhaseStats {
+    const MB: f64 = 1024.0 * 1024.0;
+
+    let total_bytes: u64 = samples.iter().map(|(bytes, _)| bytes).sum();
+    let elapsed_secs = elapsed.as_secs_f64();
+    let mut latencies_ms: Vec<f64> = samples.iter().map(|(_, latency)| latency.as_secs_f64() * 1000.0).collect();
+    latencies_ms.sort_by(|a, b| a.total_cmp(b));
+
+    let per_object: Vec<f64> = samples
+        .iter()
+        .filter(|(_, latency)| !latency.is_zero())
+        .map(|(bytes, latency)| *bytes as f64 / MB / latency.as_secs_f64())
+        .collect();
+    let mean_object_throughput = if per_object.is_empty() {
+        0.0
+    } else {
+        per_object.iter().sum::<f64>() / per_object.len() as f64
+    };
+
+    let rate = |value: f64| if elapsed_secs > 0.0 { value / elapsed_secs } else { 0.0 };
+
+    BenchmarkPhaseStats {
+        num_objects: samples.len(),
+        total_bytes,
+        elapsed_secs,
+        throughput_mb_per_sec: rate(total_bytes as f64 / MB),
+        objects_per_sec: rate(samples.len() as f64),
+        mean_object_throughput_mb_per_sec: mean_object_throughput,
+        latency_p50_ms: percentile(&latencies_ms, 50.0),
+        latency_p90_ms: percentile(&latencies_ms, 90.0),
+        latency_p99_ms: percentile(&latencies_ms, 99.0),
+        latency_max_ms: latencies_ms.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Nearest-rank percentile over an ascending slice.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    fn options(prefix: &str, direction: BenchmarkDirection, read_only: bool) -> BenchmarkOptions {
+        BenchmarkOptions {
+            prefix: prefix.to_string(),
+            object_size: 1024,
+            num_objects: 8,
+            concurrency: 3,
+            direction,
+            read_only,
+            multipart_chunksize: 256,
+            max_concurrency: 2,
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values: Vec<f64> = (1..=100).map(|v| v as f64).collect();
+        assert_eq!(percentile(&values, 50.0), 50.0);
+        assert_eq!(percentile(&values, 99.0), 99.0);
+        assert_eq!(percentile(&values, 100.0), 100.0);
+        assert_eq!(percentile(&[7.0], 90.0), 7.0);
+        assert_eq!(percentile(&[], 90.0), 0.0);
+    }
+
+    #[test]
+    fn test_check_prefix_allowed() {
+        let pattern = Regex::new(DEFAULT_BENCHMARK_PREFIX_PATTERN).unwrap();
+        assert!(check_prefix_allowed("msc-benchmark", &pattern).is_ok());
+        assert!(check_prefix_allowed("team/msc-benchmark/run1", &pattern).is_ok());
+        assert!(check_prefix_allowed("checkpoints", &pattern).is_err());
+        assert!(check_prefix_allowed("team/msc-benchmarks-prod", &pattern).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_benchmark_cleans_up() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+
+        let report = run_benchmark(Arc::clone(&store), options("msc-benchmark", BenchmarkDirection::Both, false))
+            .await
+            .unwrap();
+        let write = report.write.unwrap();
+        let read = report.read.unwrap();
+        assert_eq!(write.num_objects, 8);
+        assert_eq!(write.total_bytes, 8 * 1024);
+        assert_eq!(read.total_bytes, 8 * 1024);
+        assert!(read.latency_p50_ms <= read.latency_max_ms);
+
+        let remaining: Vec<_> = store.list(None).try_collect().await.unwrap();
+        assert!(remaining.is_empty(), "benchmark left objects behind: {:?}", remaining);
+    }
+
+    #[tokio::test]
+    async fn test_run_benchmark_read_only() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        for i in 0..4 {
+            store
+                .put(&Path::from(format!("data/shard-{}", i)), synthetic_payload(512).into())
+                .await
+                .unwrap();
+        }
+
+        let report = run_benchmark(Arc::clone(&store), options("data", BenchmarkDirection::Both, true))
+            .await
+            .unwrap();
+        assert!(report.write.is_none());
+        let read = report.read.unwrap();
+        assert_eq!(read.num_objects, 4);
+        assert_eq!(read.total_bytes, 4 * 512);
+
+        // Read-only benchmarks leave pre-existing objects untouched.
+        let remaining: Vec<_> = store.list(None).try_collect().await.unwrap();
+        assert_eq!(remaining.len(), 4);
+    }
+}
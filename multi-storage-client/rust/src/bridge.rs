@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::FutureExt;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::{oneshot, watch};
+
+use crate::checksum;
+use crate::failed_response;
+use crate::runtime::get_runtime;
+use crate::telemetry;
+
+/// How often each event loop with operations in flight is checked for having been closed.
+const LOOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Closed signal of each watched event loop, keyed by the loop object's address.
+static WATCHERS: LazyLock<Mutex<HashMap<usize, watch::Sender<bool>>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Returns a receiver that turns `true` once `event_loop` is closed, starting a watcher for the
+/// loop if it has none.
+fn closed_signal(event_loop: &Bound<'_, PyAny>) -> watch::Receiver<bool> {
+    let key = event_loop.as_ptr() as usize;
+    let mut watchers = WATCHERS.lock().unwrap();
+    if let Some(closed) = watchers.get(&key) {
+        return closed.subscribe();
+    }
+    let (closed, receiver) = watch::channel(false);
+    watchers.insert(key, closed.clone());
+    get_runtime().spawn(watch_loop(key, event_loop.clone().unbind(), closed));
+    receiver
+}
+
+/// Polls `event_loop` until it is closed or no operation is waiting on it any more.
+async fn watch_loop(key: usize, event_loop: Py<PyAny>, closed: watch::Sender<bool>) {
+    loop {
+        tokio::time::sleep(LOOP_POLL_INTERVAL).await;
+        {
+            let mut watchers = WATCHERS.lock().unwrap();
+            if closed.receiver_count() == 0 {
+                watchers.remove(&key);
+                return;
+            }
+        }
+        // An interpreter that is shutting down won't run the loop again either.
+        let is_closed = Python::try_attach(|py| is_closed(event_loop.bind(py))).unwrap_or(true);
+        if is_closed {
+            WATCHERS.lock().unwrap().remove(&key);
+            closed.send_replace(true);
+            return;
+        }
+    }
+}
+
+fn is_closed(event_loop: &Bound<'_, PyAny>) -> bool {
+    event_loop
+        .call_method0("is_closed")
+        .and_then(|closed| closed.extract::<bool>())
+        .unwrap_or(true)
+}
+
+/// Done callback that stops the Rust side of an operation once its Python future is done, which
+/// before the operation finishes means it was cancelled.
+#[pyclass]
+struct CancelOnDone {
+    cancel: Option<oneshot::Sender<()>>,
+}
+
+#[pymethods]
+impl CancelOnDone {
+    fn __call__(&mut self, _future: &Bound<'_, PyAny>) {
+        if let Some(cancel) = self.cancel.take() {
+            let _ = cancel.send(());
+        }
+    }
+}
+
+/// Sets the outcome of a future on its loop's thread, unless it was cancelled in the meantime.
+#[pyclass(frozen)]
+struct Resolve;
+
+#[pymethods]
+impl Resolve {
+    fn __call__(&self, future: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>, failed: bool) -> PyResult<()> {
+        if future.call_method0("done")?.extract::<bool>()? {
+            return Ok(());
+        }
+        future.call_method1(if failed { "set_exception" } else { "set_result" }, (value,))?;
+        Ok(())
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+fn deliver(py: Python<'_>, event_loop: &Bound<'_, PyAny>, future: &Bound<'_, PyAny>, result: PyResult<Py<PyAny>>) {
+    let (value, failed) = match result {
+        Ok(value) => (value, false),
+        Err(err) => (err.into_value(py).into_any(), true),
+    };
+    if let Err(err) = event_loop.call_method1("call_soon_threadsafe", (Resolve, future, value, failed)) {
+        // A loop closed after the operation finished has nobody left to tell.
+        if !is_closed(event_loop) {
+            err.write_unraisable(py, Some(future));
+        }
+    }
+}
+
+thread_local! {
+    /// Set while a blocking variant of an async method runs on this thread.
+    static BLOCKING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Calls `method`, an async method, and returns its result instead of an awaitable.
+///
+/// The future `method` passes to `future_into_py` runs on the shared runtime with the GIL released
+/// while the calling thread waits for it, so no event loop is needed.
+pub fn blocking<'py, M>(method: M) -> PyResult<Bound<'py, PyAny>>
+where
+    M: FnOnce() -> PyResult<Bound<'py, PyAny>>,
+{
+    let previous = BLOCKING.replace(true);
+    let result = method();
+    BLOCKING.set(previous);
+    result
+}
+
+/// Runs `fut` on the shared runtime and returns an asyncio future for its result, or the result
+/// itself inside `blocking`.
+///
+/// Unlike `pyo3_async_runtimes::tokio::future_into_py`, `fut` is dropped, cancelling its chunk
+/// tasks and deleting its temporary files, as soon as the Python future is cancelled or its event
+/// loop is closed, and a result that can no longer be delivered is discarded silently.
+pub fn future_into_py<F, T>(py: Python<'_>, fut: F) -> PyResult<Bound<'_, PyAny>>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: for<'py> IntoPyObject<'py> + Send + 'static,
+{
+    let fut = telemetry::instrument(fut);
+    if BLOCKING.replace(false) {
+        let fut = checksum::track(failed_response::track(fut));
+        let result = py.detach(|| get_runtime().block_on(AssertUnwindSafe(fut).catch_unwind()));
+        return match result {
+            Ok(result) => Ok(result?.into_pyobject(py).map_err(Into::into)?.into_any()),
+            Err(panic) => Err(PyRuntimeError::new_err(format!(
+                "Rust operation panicked: {}",
+                panic_message(&*panic)
+            ))),
+        };
+    }
+    let event_loop = py.import("asyncio")?.call_method0("get_running_loop")?;
+    let py_future = event_loop.call_method0("create_future")?;
+    let (cancel, cancelled) = oneshot::channel();
+    py_future.call_method1("add_done_callback", (CancelOnDone { cancel: Some(cancel) },))?;
+    let mut closed = closed_signal(&event_loop);
+
+    let event_loop = event_loop.unbind();
+    let future = py_future.clone().unbind();
+    let fut = checksum::track(failed_response::track(fut));
+    get_runtime().spawn(async move {
+        let result = tokio::select! {
+            result = AssertUnwindSafe(fut).catch_unwind() => result,
+            _ = cancelled => return,
+            _ = closed.wait_for(|closed| *closed) => return,
+        };
+        Python::try_attach(|py| {
+            let result = match result {
+                Ok(result) => result.and_then(|value| Ok(value.into_pyobject(py).map_err(Into::into)?.into_any().unbind())),
+                Err(panic) => Err(PyRuntimeError::new_err(format!(
+                    "Rust operation panicked: {}",
+                    panic_message(&*panic)
+                ))),
+            };
+            deliver(py, event_loop.bind(py), future.bind(py), result);
+        });
+    });
+    Ok(py_future)
+}
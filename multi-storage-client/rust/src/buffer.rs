@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyMemoryView;
+use std::sync::Mutex;
+
+/// Address ranges of Python buffers currently being filled by a download.
+static ACTIVE_TARGETS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+/// Reserves `[start, end)` in `ACTIVE_TARGETS` until dropped.
+struct TargetClaim {
+    range: (usize, usize),
+}
+
+impl TargetClaim {
+    fn acquire(start: usize, len: usize) -> PyResult<Self> {
+        let range = (start, start + len);
+        let mut active = ACTIVE_TARGETS.lock().unwrap();
+        if len > 0 && active.iter().any(|&(other_start, other_end)| range.0 < other_end && other_start < range.1) {
+            return Err(PyValueError::new_err(
+                "Target buffer overlaps memory that another download is still writing to",
+            ));
+        }
+        active.push(range);
+        Ok(TargetClaim { range })
+    }
+}
+
+impl Drop for TargetClaim {
+    fn drop(&mut self) {
+        let mut active = ACTIVE_TARGETS.lock().unwrap();
+        if let Some(index) = active.iter().position(|range| *range == self.range) {
+            active.swap_remove(index);
+        }
+    }
+}
+
+/// A writable, C-contiguous Python buffer that download tasks fill without holding the GIL.
+///
+/// Any buffer protocol object is accepted and viewed as raw bytes, whatever its item format, so
+/// numpy arrays can be targeted without a numpy dependency. Byte order is not interpreted.
+pub struct WritableBuffer {
+    buffer: PyBuffer<u8>,
+    _claim: TargetClaim,
+}
+
+impl WritableBuffer {
+    pub fn from_object(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let view = PyMemoryView::from(obj)?;
+        if view.getattr("readonly")?.extract::<bool>()? {
+            return Err(PyValueError::new_err("Target buffer must be writable"));
+        }
+        if !view.getattr("c_contiguous")?.extract::<bool>()? {
+            return Err(PyValueError::new_err("Target buffer must be C-contiguous"));
+        }
+
+        let byte_view = view.call_method1("cast", ("B",))?;
+        let buffer = PyBuffer::<u8>::get(&byte_view)?;
+        let claim = TargetClaim::acquire(buffer.buf_ptr() as usize, buffer.len_bytes())?;
+
+        Ok(WritableBuffer { buffer, _claim: claim })
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.buffer.len_bytes()
+    }
+
+    /// Copies `data` into the buffer at `offset`.
+    ///
+    /// Concurrent callers must write disjoint ranges.
+    pub fn write_at(&self, offset: usize, data: &[u8]) {
+        assert!(offset + data.len() <= self.len_bytes(), "write past the end of the target buffer");
+        // SAFETY: the range is in bounds, the buffer is writable and contiguous, and the export
+        // held by `self.buffer` keeps the memory alive and unresizable.
+        unsafe {
+            let dst = (self.buffer.buf_ptr() as *mut u8).add(offset);
+            std::ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len());
+        }
+    }
+}
+
+/// A C-contiguous Python buffer viewed as raw bytes, whatever its item format, that an upload
+/// reads from without copying.
+///
+/// The buffer export keeps the memory alive and unresizable, but the caller must not write to it
+/// until the upload completes.
+pub struct ReadableBuffer {
+    buffer: PyBuffer<u8>,
+}
+
+impl ReadableBuffer {
+    pub fn from_object(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let view = PyMemoryView::from(obj)?;
+        if !view.getattr("c_contiguous")?.extract::<bool>()? {
+            return Err(PyValueError::new_err("Source buffer must be C-contiguous"));
+        }
+        let byte_view = view.call_method1("cast", ("B",))?;
+        Ok(ReadableBuffer {
+            buffer: PyBuffer::<u8>::get(&byte_view)?,
+        })
+    }
+
+    /// Wraps the buffer in `Bytes` sharing its memory. The export is released when the last
+    /// clone is dropped.
+    pub fn into_bytes(self) -> Bytes {
+        Bytes::from_owner(self)
+    }
+}
+
+impl AsRef<[u8]> for ReadableBuffer {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: the buffer is contiguous and the export held by `self.buffer` keeps the memory
+        // alive and unresizable.
+        unsafe { std::slice::from_raw_parts(self.buffer.buf_ptr() as *const u8, self.buffer.len_bytes()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Once;
+
+    static INIT: Once = Once::new();
+
+    fn initialize_python() {
+        INIT.call_once(|| {
+            Python::initialize();
+        });
+    }
+
+    #[test]
+    fn test_writable_buffer_validation() {
+        initialize_python();
+        Python::attach(|py| {
+            let eval = |code: &str| py.eval(&std::ffi::CString::new(code).unwrap(), None, None).unwrap();
+
+            assert!(WritableBuffer::from_object(&eval("b'abcd'")).is_err());
+            assert!(WritableBuffer::from_object(&eval("memoryview(bytearray(8))[::2]")).is_err());
+
+            let typed = eval("__import__('array').array('f', [0.0, 0.0])");
+            let target = WritableBuffer::from_object(&typed).unwrap();
+            assert_eq!(target.len_bytes(), 8);
+            target.write_at(0, &1.5f32.to_ne_bytes());
+            assert_eq!(typed.get_item(0).unwrap().extract::<f32>().unwrap(), 1.5);
+
+            // A second claim on the same memory is rejected until the first target is dropped.
+            assert!(WritableBuffer::from_object(&typed).is_err());
+            drop(target);
+            assert!(WritableBuffer::from_object(&typed).is_ok());
+        });
+    }
+    #[test]
+    fn test_readable_buffer_shares_memory() {
+        initialize_python();
+        Python::attach(|py| {
+            let eval = |code: &str| py.eval(&std::ffi::CString::new(code).unwrap(), None, None).unwrap();
+
+            assert!(ReadableBuffer::from_object(&eval("memoryview(bytearray(8))[::2]")).is_err());
+
+            let typed = eval("__import__('array').array('f', [1.5, 2.5])");
+            let source = ReadableBuffer::from_object(&typed).unwrap();
+            let address = source.as_ref().as_ptr();
+            let bytes = source.into_bytes();
+            assert_eq!(bytes.as_ptr(), address);
+            assert_eq!(&bytes[..4], &1.5f32.to_ne_bytes());
+            assert_eq!(ReadableBuffer::from_object(&eval("b'abc'")).unwrap().into_bytes(), "abc");
+        });
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use object_store::{Certificate, ClientOptions};
+use std::collections::HashMap;
+
+use crate::logging;
+use crate::{ConfigValue, StorageError};
+
+/// Adds the TLS configs to `options`: `ca_certificate_path` or `ca_certificate_pem`, a PEM bundle
+/// trusted in addition to the system roots, and `allow_invalid_certificates`.
+///
+/// The bundle is read and parsed here so a missing or malformed file fails the client's
+/// construction instead of its first request.
+pub fn client_options(
+    mut options: ClientOptions,
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<ClientOptions, StorageError> {
+    let pem = match (configs.get("ca_certificate_path"), configs.get("ca_certificate_pem")) {
+        (Some(_), Some(_)) => {
+            return Err(StorageError::ConfigError(
+                "rust_client ca_certificate_path and ca_certificate_pem cannot be combined. Remove one of the two \
+                 options."
+                    .to_string(),
+            ))
+        }
+        (Some(path), None) => {
+            let path = path.to_string();
+            let pem = std::fs::read(&path).map_err(|err| {
+                StorageError::ConfigError(format!(
+                    "Could not read rust_client ca_certificate_path {}: {}",
+                    path, err
+                ))
+            })?;
+            Some((pem, format!("ca_certificate_path {}", path)))
+        }
+        (None, Some(pem)) => Some((pem.to_string().into_bytes(), "ca_certificate_pem".to_string())),
+        (None, None) => None,
+    };
+    if let Some((pem, source)) = pem {
+        for certificate in parse_bundle(&pem, &source)? {
+            options = options.with_root_certificate(certificate);
+        }
+    }
+
+    let allow_invalid_certificates = match configs.get("allow_invalid_certificates") {
+        None => false,
+        Some(ConfigValue::Boolean(b)) => *b,
+        Some(ConfigValue::String(s)) => s.parse::<bool>().unwrap_or(false),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client allow_invalid_certificates must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    if allow_invalid_certificates {
+        tracing::warn!(
+            target: logging::TARGET,
+            "allow_invalid_certificates is enabled: TLS certificates and host names are not verified, so any server \
+             can impersonate the endpoint. Only use it in test environments.",
+        );
+        options = options.with_allow_invalid_certificates(true);
+    }
+    Ok(options)
+}
+
+fn parse_bundle(pem: &[u8], source: &str) -> Result<Vec<Certificate>, StorageError> {
+    let certificates = Certificate::from_pem_bundle(pem)
+        .map_err(|err| StorageError::ConfigError(format!("Invalid PEM in rust_client {}: {}", source, err)))?;
+    if certificates.is_empty() {
+        return Err(StorageError::ConfigError(format!(
+            "rust_client {} contains no PEM certificates.",
+            source
+        )));
+    }
+    Ok(certificates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CA_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIBgjCCASigAwIBAgITMtw66RQQAJIAHfDkq/OozqseUDAKBggqhkjOPQQDAjAW
+MRQwEgYDVQQDDAttc2MtdGVzdC1jYTAgFw0yNjEwMTUwNjEzMjJaGA8yMTI2MDky
+MTA2MTMyMlowFjEUMBIGA1UEAwwLbXNjLXRlc3QtY2EwWTATBgcqhkjOPQIBBggq
+hkjOPQMBBwNCAARESfZuIUWguxaiwPbmOCO07fz7ry1yO822q9O3wCefo64r1BtA
+bQWm7+qRzQbI2YqLJ1jUWzpufTrCtXj31UZno1MwUTAdBgNVHQ4EFgQUzRGYghli
+8IiaSau9DtYTHG5nwi8wHwYDVR0jBBgwFoAUzRGYghli8IiaSau9DtYTHG5nwi8w
+DwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNIADBFAiA/IXp3h+KFpxQoFNoo
+InnfcYIrKqi94oSaqhht1p3QQQIhALLRM6qMwbiF857fcDR8txvxD1I16okDN1Ii
+ZaD3jkHK
+-----END CERTIFICATE-----
+";
+
+    fn configs(entries: &[(&str, ConfigValue)]) -> HashMap<String, ConfigValue> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_client_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ca.pem");
+        std::fs::write(&path, CA_PEM).unwrap();
+        let path = ConfigValue::String(path.to_string_lossy().to_string());
+
+        for entries in [
+            vec![],
+            vec![("ca_certificate_path", path.clone())],
+            vec![("ca_certificate_pem", ConfigValue::String(CA_PEM.to_string()))],
+            vec![("allow_invalid_certificates", ConfigValue::String("true".to_string()))],
+        ] {
+            assert!(client_options(ClientOptions::new(), &configs(&entries)).is_ok());
+        }
+
+        let missing = ConfigValue::String(dir.path().join("missing.pem").to_string_lossy().to_string());
+        for entries in [
+            vec![("ca_certificate_path", missing)],
+            vec![(
+                "ca_certificate_pem",
+                ConfigValue::String("not a certificate".to_string()),
+            )],
+            vec![(
+                "ca_certificate_pem",
+                ConfigValue::String(CA_PEM.replace("MIIBgjCCASig", "!!!")),
+            )],
+            vec![
+                ("ca_certificate_path", path),
+                ("ca_certificate_pem", ConfigValue::String(CA_PEM.to_string())),
+            ],
+            vec![("allow_invalid_certificates", ConfigValue::Number(1))],
+        ] {
+            assert!(matches!(
+                client_options(ClientOptions::new(), &configs(&entries)),
+                Err(StorageError::ConfigError(_))
+            ));
+        }
+    }
+}
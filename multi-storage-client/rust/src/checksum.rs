@@ -0,0 +1,488 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures::stream::BoxStream;
+use http::header::HeaderMap;
+use md5::{Digest, Md5};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, UploadPart,
+};
+use sha2::Sha256;
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use crate::StorageError;
+
+const STORE_NAME: &str = "Checksum";
+
+tokio::task_local! {
+    static LATEST: RefCell<Option<UploadChecksum>>;
+    static REPORTED: RefCell<Option<String>>;
+}
+
+/// Algorithm of the checksums computed for uploads, from the `checksum_algorithm` config.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    /// Checked against the CRC32C that GCS reports for the new object.
+    Crc32c,
+    /// Sent with every request by object_store, and checked by S3.
+    Sha256,
+    /// Checked against the ETag S3 returns, which is the MD5 of the object unless it is encrypted
+    /// with KMS or a customer key.
+    Md5,
+}
+
+impl Algorithm {
+    /// Checks that `name` is an algorithm whose checksums `provider` validates, ignoring case.
+    pub fn parse(provider: &str, name: &str) -> Result<Self, StorageError> {
+        let accepted: &[&str] = match provider {
+            "s3" | "s8k" | "gcs_s3" => &["sha256", "md5"],
+            "gcs" => &["crc32c"],
+            _ => {
+                return Err(StorageError::ConfigError(format!(
+                    "checksum_algorithm is not supported by the '{}' provider",
+                    provider
+                )))
+            }
+        };
+        let algorithm = name.to_ascii_lowercase();
+        match algorithm.as_str() {
+            _ if !accepted.contains(&algorithm.as_str()) => Err(StorageError::ConfigError(format!(
+                "Unsupported rust_client checksum_algorithm '{}' for the '{}' provider. Accepted values: {}",
+                name,
+                provider,
+                accepted.join(", ")
+            ))),
+            "crc32c" => Ok(Algorithm::Crc32c),
+            "sha256" => Ok(Algorithm::Sha256),
+            _ => Ok(Algorithm::Md5),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::Crc32c => "crc32c",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Md5 => "md5",
+        }
+    }
+
+    pub fn digest<'a>(self, chunks: impl IntoIterator<Item = &'a [u8]>) -> Vec<u8> {
+        let chunks = chunks.into_iter();
+        match self {
+            Algorithm::Crc32c => chunks.fold(0, crc32c::crc32c_append).to_be_bytes().to_vec(),
+            Algorithm::Sha256 => chunks
+                .fold(Sha256::new(), |hasher, chunk| hasher.chain_update(chunk))
+                .finalize()
+                .to_vec(),
+            Algorithm::Md5 => chunks
+                .fold(Md5::new(), |hasher, chunk| hasher.chain_update(chunk))
+                .finalize()
+                .to_vec(),
+        }
+    }
+
+    /// Encodes `digest` the way stores report it: hex like an ETag for MD5, base64 otherwise.
+    pub fn encode(self, digest: &[u8]) -> String {
+        match self {
+            Algorithm::Md5 => digest.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            _ => BASE64_STANDARD.encode(digest),
+        }
+    }
+
+    /// Checksum of an object uploaded in `parts`, given the digest and size of each: the CRC32C of
+    /// the whole object, or `<digest of the part digests>-<parts>` as S3 reports multipart
+    /// checksums and ETags.
+    fn combine(self, parts: &[(Vec<u8>, usize)]) -> String {
+        if self == Algorithm::Crc32c {
+            let crc = parts.iter().fold(0, |crc, (digest, size)| {
+                let part = u32::from_be_bytes(digest.as_slice().try_into().unwrap());
+                crc32c::crc32c_combine(crc, part, *size)
+            });
+            return self.encode(&crc.to_be_bytes());
+        }
+        let digest = self.digest(parts.iter().map(|(digest, _)| digest.as_slice()));
+        format!("{}-{}", self.encode(&digest), parts.len())
+    }
+
+    /// Computes the digest of `payload` on a blocking thread.
+    pub async fn hash(self, payload: PutPayload) -> object_store::Result<Vec<u8>> {
+        tokio::task::spawn_blocking(move || self.digest(payload.iter().map(AsRef::as_ref)))
+            .await
+            .map_err(|err| object_store::Error::Generic {
+                store: STORE_NAME,
+                source: Box::new(err),
+            })
+    }
+}
+
+/// Checksum of the last object uploaded by an operation, as returned to the caller.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadChecksum {
+    pub algorithm: &'static str,
+    pub value: String,
+}
+
+/// Runs `fut` keeping the checksum of the last object it uploaded, for `latest`.
+pub async fn track<F: Future>(fut: F) -> F::Output {
+    LATEST.scope(RefCell::new(None), fut).await
+}
+
+/// Returns the checksum of the last object uploaded by the current operation, if any.
+pub fn latest() -> Option<UploadChecksum> {
+    LATEST.try_with(|latest| latest.borrow().clone()).ok().flatten()
+}
+
+/// Records the CRC32C that GCS reports in `x-goog-hash` for the enclosing verification, if any.
+pub fn record(headers: &HeaderMap) {
+    let crc32c = headers
+        .get_all("x-goog-hash")
+        .iter()
+        .filter_map(|hash| hash.to_str().ok())
+        .flat_map(|hashes| hashes.split(','))
+        .find_map(|hash| hash.trim().strip_prefix("crc32c="));
+    if let Some(crc32c) = crc32c {
+        let _ = REPORTED.try_with(|reported| *reported.borrow_mut() = Some(crc32c.to_string()));
+    }
+}
+
+/// An uploaded object whose checksum differs from the one the store reports.
+#[derive(Debug)]
+pub struct Mismatch(String);
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Returns the mismatch carried by `err`, if a checksum check produced it.
+pub fn mismatch(err: &object_store::Error) -> Option<&Mismatch> {
+    match err {
+        object_store::Error::Generic {
+            store: STORE_NAME,
+            source,
+        } => source.downcast_ref::<Mismatch>(),
+        _ => None,
+    }
+}
+
+fn is_md5_etag(e_tag: &str) -> bool {
+    let (digest, parts) = e_tag.split_once('-').unwrap_or((e_tag, "0"));
+    digest.len() == 32 && digest.bytes().all(|b| b.is_ascii_hexdigit()) && parts.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Checks `checksum` of the object just written to `location` against the one the store reports,
+/// then records it for the operation's result.
+async fn verify(
+    store: &Arc<dyn ObjectStore>,
+    algorithm: Algorithm,
+    location: &Path,
+    put_result: &PutResult,
+    checksum: String,
+) -> object_store::Result<()> {
+    let reported = match algorithm {
+        // S3 rejects any request whose x-amz-checksum-sha256 header doesn't match its payload.
+        Algorithm::Sha256 => None,
+        Algorithm::Md5 => put_result
+            .e_tag
+            .as_deref()
+            .map(|e_tag| e_tag.trim_matches('"').to_ascii_lowercase())
+            .filter(|e_tag| is_md5_etag(e_tag)),
+        Algorithm::Crc32c => {
+            let reported = REPORTED.scope(RefCell::new(None), async {
+                store.head(location).await?;
+                Ok::<_, object_store::Error>(REPORTED.with(|reported| reported.take()))
+            });
+            // Nothing to compare if GCS didn't report a CRC32C, e.g. through a proxy that drops it.
+            reported.await?
+        }
+    };
+    if let Some(reported) = reported.filter(|reported| *reported != checksum) {
+        let message = format!(
+            "Checksum check failed for {}: computed {} {}, the store reported '{}'",
+            location,
+            algorithm.name(),
+            checksum,
+            reported
+        );
+        return Err(object_store::Error::Generic {
+            store: STORE_NAME,
+            source: Box::new(Mismatch(message)),
+        });
+    }
+    let checksum = UploadChecksum {
+        algorithm: algorithm.name(),
+        value: checksum,
+    };
+    let _ = LATEST.try_with(|latest| *latest.borrow_mut() = Some(checksum));
+    Ok(())
+}
+
+/// Store wrapper that computes a checksum of every object written and checks it against the one
+/// the store reports. Payloads are hashed on blocking threads while they are being sent.
+#[derive(Debug)]
+pub struct ChecksumStore {
+    inner: Arc<dyn ObjectStore>,
+    algorithm: Algorithm,
+}
+
+impl ChecksumStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, algorithm: Algorithm) -> Self {
+        ChecksumStore { inner, algorithm }
+    }
+}
+
+impl fmt::Display for ChecksumStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ChecksumStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for ChecksumStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        let digest = self.algorithm.hash(payload.clone());
+        let (digest, put_result) = tokio::join!(digest, self.inner.put_opts(location, payload, opts));
+        let put_result = put_result?;
+        let checksum = self.algorithm.encode(&digest?);
+        verify(&self.inner, self.algorithm, location, &put_result, checksum).await?;
+        Ok(put_result)
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        let upload = self.inner.put_multipart_opts(location, opts).await?;
+        Ok(Box::new(ChecksumUpload {
+            inner: upload,
+            store: Arc::clone(&self.inner),
+            location: location.clone(),
+            algorithm: self.algorithm,
+            parts: Arc::default(),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> object_store::Result<GetResult> {
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.copy_if_not_exists(from, to).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.rename(from, to).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.inner.rename_if_not_exists(from, to).await
+    }
+}
+
+/// Multipart upload that hashes each part while it uploads and checks the combined checksum once
+/// the upload is complete.
+#[derive(Debug)]
+struct ChecksumUpload {
+    inner: Box<dyn MultipartUpload>,
+    store: Arc<dyn ObjectStore>,
+    location: Path,
+    algorithm: Algorithm,
+    /// Digest and size of each part, in part order, once it is uploaded.
+    parts: Arc<Mutex<Vec<Option<(Vec<u8>, usize)>>>>,
+}
+
+#[async_trait]
+impl MultipartUpload for ChecksumUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let index = {
+            let mut parts = self.parts.lock().unwrap();
+            parts.push(None);
+            parts.len() - 1
+        };
+        let (parts, size) = (Arc::clone(&self.parts), data.content_length());
+        let digest = self.algorithm.hash(data.clone());
+        let upload = self.inner.put_part(data);
+        Box::pin(async move {
+            let (digest, uploaded) = tokio::join!(digest, upload);
+            uploaded?;
+            parts.lock().unwrap()[index] = Some((digest?, size));
+            Ok(())
+        })
+    }
+
+    async fn complete(&mut self) -> object_store::Result<PutResult> {
+        let put_result = self.inner.complete().await?;
+        let parts: Option<Vec<_>> = self.parts.lock().unwrap().iter().cloned().collect();
+        let parts = parts.ok_or_else(|| object_store::Error::Generic {
+            store: STORE_NAME,
+            source: "The upload was completed before all of its parts were uploaded".into(),
+        })?;
+        let checksum = self.algorithm.combine(&parts);
+        verify(&self.store, self.algorithm, &self.location, &put_result, checksum).await?;
+        Ok(put_result)
+    }
+
+    async fn abort(&mut self) -> object_store::Result<()> {
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::HeaderValue;
+    use object_store::memory::InMemory;
+    use object_store::WriteMultipart;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Algorithm::parse("s3", "SHA256").unwrap(), Algorithm::Sha256);
+        assert_eq!(Algorithm::parse("s8k", "md5").unwrap(), Algorithm::Md5);
+        assert_eq!(Algorithm::parse("gcs", "crc32c").unwrap(), Algorithm::Crc32c);
+        match Algorithm::parse("s3", "crc32c").unwrap_err() {
+            StorageError::ConfigError(msg) => assert!(msg.contains("sha256, md5"), "unexpected: {}", msg),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+        assert!(matches!(
+            Algorithm::parse("gcs", "sha256"),
+            Err(StorageError::ConfigError(_))
+        ));
+        assert!(matches!(
+            Algorithm::parse("file", "md5"),
+            Err(StorageError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_digests() {
+        let data: &[&[u8]] = &[b"1234", b"56789"];
+        assert_eq!(
+            Algorithm::Crc32c.encode(&Algorithm::Crc32c.digest(data.iter().copied())),
+            "4waSgw=="
+        );
+        assert_eq!(
+            Algorithm::Md5.encode(&Algorithm::Md5.digest(data.iter().copied())),
+            "25f9e794323b453885f5181f1b624d0b"
+        );
+        assert_eq!(
+            Algorithm::Sha256.encode(&Algorithm::Sha256.digest(data.iter().copied())),
+            "FeKw08M4keuw8e9gnsQZQgwg4yDOlMZfvIwzEkSOsiU="
+        );
+
+        // Part CRC32Cs combine into the CRC32C of the whole object.
+        let parts = [(b"1234".as_slice(), 4), (b"56789".as_slice(), 5)]
+            .map(|(part, size)| (Algorithm::Crc32c.digest([part]), size));
+        assert_eq!(Algorithm::Crc32c.combine(&parts), "4waSgw==");
+
+        let parts = [(Algorithm::Md5.digest([b"1234".as_slice()]), 4)];
+        let composite = Algorithm::Md5.combine(&parts);
+        assert!(composite.ends_with("-1") && is_md5_etag(&composite));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut headers = HeaderMap::new();
+        headers.append("x-goog-hash", HeaderValue::from_static("md5=JfnnlDI7RTiF9RgfG2JNCw=="));
+        headers.append("x-goog-hash", HeaderValue::from_static("crc32c=4waSgw=="));
+        let reported = REPORTED.sync_scope(RefCell::new(None), || {
+            record(&headers);
+            REPORTED.with(|reported| reported.take())
+        });
+        assert_eq!(reported.as_deref(), Some("4waSgw=="));
+    }
+
+    #[tokio::test]
+    async fn test_checksum_store() {
+        let store = ChecksumStore::new(Arc::new(InMemory::new()), Algorithm::Sha256);
+
+        let checksum = track(async {
+            store
+                .put(&Path::from("single"), Bytes::from_static(b"123456789").into())
+                .await
+                .unwrap();
+            latest()
+        });
+        assert_eq!(
+            checksum.await.unwrap().value,
+            "FeKw08M4keuw8e9gnsQZQgwg4yDOlMZfvIwzEkSOsiU="
+        );
+
+        let checksum = track(async {
+            let upload = store.put_multipart(&Path::from("multipart")).await.unwrap();
+            let mut writer = WriteMultipart::new_with_chunk_size(upload, 4);
+            writer.write(b"123456789");
+            writer.finish().await.unwrap();
+            latest()
+        });
+        let parts = [b"1234".as_slice(), b"5678", b"9"].map(|part| (Algorithm::Sha256.digest([part]), part.len()));
+        assert_eq!(checksum.await.unwrap().value, Algorithm::Sha256.combine(&parts));
+
+        // A store that doesn't report a CRC32C has nothing to check against.
+        let store = ChecksumStore::new(Arc::new(InMemory::new()), Algorithm::Crc32c);
+        let checksum = track(async {
+            store
+                .put(&Path::from("unchecked"), Bytes::from_static(b"data").into())
+                .await
+                .unwrap();
+            latest()
+        });
+        assert_eq!(checksum.await.unwrap().algorithm, "crc32c");
+    }
+}
@@ -0,0 +1,515 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, Result, UploadPart,
+};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::StorageError;
+
+const STORE_NAME: &str = "CircuitBreaker";
+
+/// Thresholds of the `circuit_breaker` config.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Fraction of failed requests in the window that trips the breaker.
+    pub failure_rate: f64,
+    /// Number of most recent requests the failure rate is computed over.
+    pub window: usize,
+    /// Requests the window must hold before the breaker can trip.
+    pub min_requests: usize,
+    /// Time the breaker stays open before letting a probe request through.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        CircuitBreakerConfig {
+            failure_rate: 0.5,
+            window: 50,
+            min_requests: 20,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Builds a config from the `circuit_breaker` dict, with defaults for omitted keys.
+    pub fn from_options(options: &HashMap<String, f64>) -> Result<Self, StorageError> {
+        let mut config = CircuitBreakerConfig::default();
+        for (key, &value) in options {
+            let invalid = |expected: &str| {
+                StorageError::ConfigError(format!("circuit_breaker.{} must be {}, got {}", key, expected, value))
+            };
+            match key.as_str() {
+                "failure_rate" if value > 0.0 && value <= 1.0 => config.failure_rate = value,
+                "failure_rate" => return Err(invalid("in (0, 1]")),
+                "window" | "min_requests" if value < 1.0 || value.fract() != 0.0 => {
+                    return Err(invalid("a positive integer"))
+                }
+                "window" => config.window = value as usize,
+                "min_requests" => config.min_requests = value as usize,
+                "cooldown" if value.is_finite() && value > 0.0 => config.cooldown = Duration::from_secs_f64(value),
+                "cooldown" => return Err(invalid("a positive number of seconds")),
+                _ => {
+                    return Err(StorageError::ConfigError(format!(
+                        "Unknown circuit_breaker option '{}', expected failure_rate, window, min_requests or cooldown",
+                        key
+                    )))
+                }
+            }
+        }
+        if config.min_requests > config.window {
+            return Err(StorageError::ConfigError(format!(
+                "circuit_breaker.min_requests ({}) must not exceed circuit_breaker.window ({})",
+                config.min_requests, config.window
+            )));
+        }
+        Ok(config)
+    }
+}
+
+/// Error returned without contacting the store while the breaker is open.
+#[derive(Debug)]
+pub struct CircuitOpen {
+    retry_in: Duration,
+}
+
+impl fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Circuit breaker is open after repeated request failures; retry in {:.1}s",
+            self.retry_in.as_secs_f64()
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+/// Returns the rejection carried by `err`, if the breaker produced it.
+pub fn circuit_open(err: &object_store::Error) -> Option<&CircuitOpen> {
+    match err {
+        object_store::Error::Generic { store: STORE_NAME, source } => source.downcast_ref::<CircuitOpen>(),
+        _ => None,
+    }
+}
+
+/// Only failures that suggest the endpoint itself is unhealthy count toward tripping the breaker.
+/// NotFound, permission, precondition and similar responses mean the store answered.
+fn is_failure<T>(result: &Result<T>) -> bool {
+    matches!(result, Err(err @ object_store::Error::Generic { .. }) if circuit_open(err).is_none())
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { outcomes: VecDeque<bool>, failures: usize },
+    Open { until: Instant },
+    HalfOpen { probing: bool },
+}
+
+impl State {
+    fn closed() -> Self {
+        State::Closed {
+            outcomes: VecDeque::new(),
+            failures: 0,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            State::Closed { .. } => "closed",
+            State::Open { .. } => "open",
+            State::HalfOpen { .. } => "half_open",
+        }
+    }
+}
+
+/// Failure-rate circuit breaker shared by every request of one client.
+///
+/// Once the failure rate over the last `window` requests reaches `failure_rate`, requests fail
+/// fast for `cooldown`. After that a single probe request is let through at a time: its success
+/// closes the breaker and its failure opens it for another cooldown.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<State>,
+    trips: AtomicU64,
+    rejected: AtomicU64,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        CircuitBreaker {
+            config,
+            state: Mutex::new(State::closed()),
+            trips: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    fn admit(self: &Arc<Self>) -> Result<Permit, CircuitOpen> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match &mut *state {
+            State::Closed { .. } => false,
+            State::Open { until } => {
+                let now = Instant::now();
+                if now < *until {
+                    return Err(self.reject(*until - now));
+                }
+                *state = State::HalfOpen { probing: true };
+                drop(state);
+                log_transition("open", "half_open", &self.config);
+                true
+            }
+            State::HalfOpen { probing: true } => return Err(self.reject(Duration::ZERO)),
+            State::HalfOpen { probing } => {
+                *probing = true;
+                true
+            }
+        };
+        Ok(Permit {
+            breaker: Arc::clone(self),
+            probe,
+            recorded: false,
+        })
+    }
+
+    fn reject(&self, retry_in: Duration) -> CircuitOpen {
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+        CircuitOpen { retry_in }
+    }
+
+    fn record(&self, probe: bool, failed: bool) {
+        let mut state = self.state.lock().unwrap();
+        let from = state.name();
+        if probe {
+            *state = if failed {
+                State::Open {
+                    until: Instant::now() + self.config.cooldown,
+                }
+            } else {
+                State::closed()
+            };
+        } else if let State::Closed { outcomes, failures } = &mut *state {
+            // Requests admitted before the breaker opened don't affect it once it has.
+            outcomes.push_back(failed);
+            *failures += failed as usize;
+            if outcomes.len() > self.config.window {
+                *failures -= outcomes.pop_front().unwrap() as usize;
+            }
+            if outcomes.len() >= self.config.min_requests
+                && *failures as f64 >= self.config.failure_rate * outcomes.len() as f64
+            {
+                self.trips.fetch_add(1, Ordering::Relaxed);
+                *state = State::Open {
+                    until: Instant::now() + self.config.cooldown,
+                };
+            }
+        }
+        let to = state.name();
+        drop(state);
+        log_transition(from, to, &self.config);
+    }
+
+    fn release_probe(&self) {
+        if let State::HalfOpen { probing } = &mut *self.state.lock().unwrap() {
+            *probing = false;
+        }
+    }
+
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let state = self.state.lock().unwrap().name();
+        let stats = PyDict::new(py);
+        stats.set_item("state", state)?;
+        stats.set_item("trips", self.trips.load(Ordering::Relaxed))?;
+        stats.set_item("rejected", self.rejected.load(Ordering::Relaxed))?;
+        Ok(stats)
+    }
+}
+
+/// Logs a state change to the `multistorageclient.rust` Python logger. Must be called without
+/// the state lock held, since it waits for the GIL.
+fn log_transition(from: &str, to: &str, config: &CircuitBreakerConfig) {
+    if from == to {
+        return;
+    }
+    let message = match to {
+        "open" => format!(
+            "Circuit breaker opened ({} -> {}); failing requests fast for {:.1}s",
+            from,
+            to,
+            config.cooldown.as_secs_f64()
+        ),
+        _ => format!("Circuit breaker {} -> {}", from, to),
+    };
+    let level = if to == "open" { "warning" } else { "info" };
+    Python::attach(|py| {
+        let logger = py.import("logging")?.call_method1("getLogger", ("multistorageclient.rust",))?;
+        logger.call_method1(level, (message,))?;
+        Ok::<(), PyErr>(())
+    })
+    .ok();
+}
+
+/// Admission to send one request. A probe that is dropped without an outcome, e.g. because its
+/// operation was cancelled, lets the next request probe instead.
+struct Permit {
+    breaker: Arc<CircuitBreaker>,
+    probe: bool,
+    recorded: bool,
+}
+
+impl Permit {
+    fn record<T>(mut self, result: &Result<T>) {
+        self.recorded = true;
+        self.breaker.record(self.probe, is_failure(result));
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.release_probe();
+        }
+    }
+}
+
+fn rejected(err: CircuitOpen) -> object_store::Error {
+    object_store::Error::Generic {
+        store: STORE_NAME,
+        source: Box::new(err),
+    }
+}
+
+/// Store wrapper that runs every request through a `CircuitBreaker`.
+///
+/// Each call counts once, after object_store's own retries. Listings count by their first page.
+#[derive(Debug)]
+pub struct CircuitBreakerStore {
+    inner: Arc<dyn ObjectStore>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakerStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, breaker: Arc<CircuitBreaker>) -> Self {
+        CircuitBreakerStore { inner, breaker }
+    }
+
+    async fn call<T, Fut>(&self, fut: Fut) -> Result<T>
+    where
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let permit = self.breaker.admit().map_err(rejected)?;
+        let result = fut.await;
+        permit.record(&result);
+        result
+    }
+
+    fn stream(&self, inner: BoxStream<'static, Result<ObjectMeta>>) -> BoxStream<'static, Result<ObjectMeta>> {
+        let mut permit = match self.breaker.admit() {
+            Ok(permit) => Some(permit),
+            Err(err) => return stream::once(async move { Err(rejected(err)) }).boxed(),
+        };
+        inner
+            .map(move |item| {
+                if let Some(permit) = permit.take() {
+                    permit.record(&item);
+                }
+                item
+            })
+            .boxed()
+    }
+}
+
+impl fmt::Display for CircuitBreakerStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CircuitBreakerStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for CircuitBreakerStore {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        self.call(self.inner.put_opts(location, payload, opts)).await
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        let upload = self.call(self.inner.put_multipart_opts(location, opts)).await?;
+        Ok(Box::new(CircuitBreakerUpload {
+            inner: upload,
+            breaker: Arc::clone(&self.breaker),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        self.call(self.inner.get_opts(location, options)).await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.call(self.inner.head(location)).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.call(self.inner.delete(location)).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.stream(self.inner.list(prefix))
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.stream(self.inner.list_with_offset(prefix, offset))
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.call(self.inner.list_with_delimiter(prefix)).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(self.inner.copy(from, to)).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(self.inner.copy_if_not_exists(from, to)).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(self.inner.rename(from, to)).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(self.inner.rename_if_not_exists(from, to)).await
+    }
+}
+
+#[derive(Debug)]
+struct CircuitBreakerUpload {
+    inner: Box<dyn MultipartUpload>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+#[async_trait]
+impl MultipartUpload for CircuitBreakerUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let permit = match self.breaker.admit() {
+            Ok(permit) => permit,
+            Err(err) => return Box::pin(async move { Err(rejected(err)) }),
+        };
+        let part = self.inner.put_part(data);
+        Box::pin(async move {
+            let result = part.await;
+            permit.record(&result);
+            result
+        })
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        let permit = self.breaker.admit().map_err(rejected)?;
+        let result = self.inner.complete().await;
+        permit.record(&result);
+        result
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use std::sync::Once;
+
+    static INIT: Once = Once::new();
+
+    fn initialize_python() {
+        INIT.call_once(|| {
+            Python::initialize();
+        });
+    }
+
+    fn generic_error() -> Result<()> {
+        Err(object_store::Error::Generic {
+            store: "S3",
+            source: "connection refused".into(),
+        })
+    }
+
+    #[test]
+    fn test_circuit_breaker_config() {
+        let options = HashMap::from([("failure_rate".to_string(), 0.25), ("cooldown".to_string(), 1.5)]);
+        let config = CircuitBreakerConfig::from_options(&options).unwrap();
+        assert_eq!(config.failure_rate, 0.25);
+        assert_eq!(config.cooldown, Duration::from_millis(1500));
+        assert_eq!(config.window, 50);
+
+        for (key, value) in [("failure_rate", 1.5), ("window", 0.0), ("min_requests", 100.0), ("threshold", 1.0)] {
+            let options = HashMap::from([(key.to_string(), value)]);
+            assert!(CircuitBreakerConfig::from_options(&options).is_err(), "{}={}", key, value);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_trips_and_recovers() {
+        initialize_python();
+        let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig {
+            failure_rate: 0.5,
+            window: 4,
+            min_requests: 4,
+            cooldown: Duration::from_millis(100),
+        }));
+        let store = CircuitBreakerStore::new(Arc::new(InMemory::new()), Arc::clone(&breaker));
+
+        // NotFound responses never trip the breaker.
+        for _ in 0..8 {
+            assert!(matches!(store.head(&Path::from("missing")).await, Err(object_store::Error::NotFound { .. })));
+        }
+        assert_eq!(breaker.trips.load(Ordering::Relaxed), 0);
+
+        for _ in 0..2 {
+            breaker.admit().unwrap().record(&generic_error());
+        }
+        assert_eq!(breaker.trips.load(Ordering::Relaxed), 1);
+        let err = store.head(&Path::from("missing")).await.unwrap_err();
+        assert!(circuit_open(&err).is_some());
+        assert!(matches!(StorageError::from(err), StorageError::CircuitOpen(_)));
+
+        // After the cooldown one probe is admitted at a time, and a failed probe reopens the breaker.
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        let probe = breaker.admit().unwrap();
+        assert!(breaker.admit().is_err());
+        probe.record(&generic_error());
+        assert!(matches!(*breaker.state.lock().unwrap(), State::Open { .. }));
+
+        // A successful probe closes it.
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert!(store.head(&Path::from("missing")).await.is_err());
+        assert!(matches!(*breaker.state.lock().unwrap(), State::Closed { .. }));
+        assert_eq!(breaker.rejected.load(Ordering::Relaxed), 2);
+    }
+}
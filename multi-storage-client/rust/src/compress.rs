@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use flate2::write::GzEncoder;
+use futures::Stream;
+use md5::{Digest, Md5};
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+use crate::StorageError;
+
+const READ_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Compressed bytes collected before they are passed on.
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Encoding {
+    Gzip,
+    Zstd,
+}
+
+/// How an upload is compressed, from its `compress` and `compression_level` arguments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Compression {
+    encoding: Encoding,
+    level: i32,
+}
+
+impl Compression {
+    /// Parses `compress`, `"gzip"` or `"zstd"`, and its `level`, which defaults to 6 for gzip and 3
+    /// for zstd. The Content-Encoding is set from `compress`, so `metadata` can't name one.
+    pub fn parse(
+        compress: Option<&str>,
+        level: Option<i32>,
+        metadata: Option<&HashMap<String, String>>,
+    ) -> Result<Option<Self>, StorageError> {
+        let Some(name) = compress else {
+            return match level {
+                Some(_) => Err(StorageError::ConfigError(
+                    "compression_level requires compress".to_string(),
+                )),
+                None => Ok(None),
+            };
+        };
+        let (encoding, levels, default) = match name {
+            "gzip" => (Encoding::Gzip, 0..=9, 6),
+            "zstd" => (
+                Encoding::Zstd,
+                zstd::compression_level_range(),
+                zstd::DEFAULT_COMPRESSION_LEVEL,
+            ),
+            _ => {
+                return Err(StorageError::ConfigError(format!(
+                    "compress must be 'gzip' or 'zstd', got '{}'",
+                    name
+                )))
+            }
+        };
+        let level = level.unwrap_or(default);
+        if !levels.contains(&level) {
+            return Err(StorageError::ConfigError(format!(
+                "compression_level for {} must be between {} and {}, got {}",
+                name,
+                levels.start(),
+                levels.end(),
+                level
+            )));
+        }
+        let encoded = metadata
+            .into_iter()
+            .flat_map(|metadata| metadata.keys())
+            .find(|key| key.replace('_', "-").eq_ignore_ascii_case("content-encoding"));
+        if let Some(key) = encoded {
+            return Err(StorageError::ConfigError(format!(
+                "compress sets the Content-Encoding, so it can't be combined with a '{}' metadata value",
+                key
+            )));
+        }
+        Ok(Some(Self { encoding, level }))
+    }
+
+    /// Content-Encoding of the compressed object.
+    pub fn content_encoding(self) -> &'static str {
+        match self.encoding {
+            Encoding::Gzip => "gzip",
+            Encoding::Zstd => "zstd",
+        }
+    }
+
+    /// Compresses `data` on a blocking thread.
+    pub async fn compress(self, data: Bytes) -> Result<Bytes, StorageError> {
+        spawn(move || {
+            let mut encoder = Encoder::new(self)?;
+            encoder.write_all(&data)?;
+            Ok(encoder.finish()?.into())
+        })
+        .await
+    }
+
+    /// Compresses the file at `path` on a blocking thread as it is read, yielding the compressed
+    /// output in chunks with the bytes of the file each one consumed.
+    pub fn compress_file(self, path: PathBuf) -> impl Stream<Item = Result<(Bytes, u64), StorageError>> {
+        let (sender, receiver) = mpsc::channel(2);
+        tokio::task::spawn_blocking(move || {
+            let compressed = std::fs::File::open(path).and_then(|file| {
+                self.compress_reader(file, |chunk, read| sender.blocking_send(Ok((chunk, read))).is_ok())
+            });
+            if let Err(err) = compressed {
+                let _ = sender.blocking_send(Err(err.into()));
+            }
+        });
+        futures::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|item| (item, receiver))
+        })
+    }
+
+    /// Hex MD5 of the compressed content of the file at `path`, computed on a blocking thread.
+    pub async fn file_md5(self, path: PathBuf) -> Result<String, StorageError> {
+        spawn(move || {
+            let mut hasher = Md5::new();
+            self.compress_reader(std::fs::File::open(path)?, |chunk, _| {
+                hasher.update(&chunk);
+                true
+            })?;
+            Ok(format!("{:x}", hasher.finalize()))
+        })
+        .await
+    }
+
+    /// Compresses `reader`, passing the output to `sink` in chunks of at least `CHUNK_SIZE` bytes,
+    /// but for the last, until `sink` returns false.
+    fn compress_reader(self, mut reader: impl Read, mut sink: impl FnMut(Bytes, u64) -> bool) -> io::Result<()> {
+        let mut encoder = Encoder::new(self)?;
+        let mut buffer = vec![0; READ_BUFFER_SIZE];
+        let mut consumed = 0;
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            encoder.write_all(&buffer[..read])?;
+            consumed += read as u64;
+            let output = encoder.output();
+            if output.len() >= CHUNK_SIZE && !sink(std::mem::take(output).into(), std::mem::take(&mut consumed)) {
+                return Ok(());
+            }
+        }
+        sink(encoder.finish()?.into(), consumed);
+        Ok(())
+    }
+}
+
+async fn spawn<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T, StorageError> + Send + 'static,
+) -> Result<T, StorageError> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| StorageError::ObjectStoreError(format!("Compression task failed: {}", e)))?
+}
+
+enum Encoder {
+    Gzip(GzEncoder<Vec<u8>>),
+    Zstd(zstd::Encoder<'static, Vec<u8>>),
+}
+
+impl Encoder {
+    fn new(compression: Compression) -> io::Result<Self> {
+        Ok(match compression.encoding {
+            Encoding::Gzip => Self::Gzip(GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::new(compression.level as u32),
+            )),
+            Encoding::Zstd => Self::Zstd(zstd::Encoder::new(Vec::new(), compression.level)?),
+        })
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        match self {
+            Self::Gzip(encoder) => encoder.write_all(data),
+            Self::Zstd(encoder) => encoder.write_all(data),
+        }
+    }
+
+    /// The output produced so far and not taken yet.
+    fn output(&mut self) -> &mut Vec<u8> {
+        match self {
+            Self::Gzip(encoder) => encoder.get_mut(),
+            Self::Zstd(encoder) => encoder.get_mut(),
+        }
+    }
+
+    fn finish(self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(encoder) => encoder.finish(),
+            Self::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    fn decompress(compression: Compression, data: &[u8]) -> Vec<u8> {
+        let mut decompressed = Vec::new();
+        match compression.encoding {
+            Encoding::Gzip => flate2::read::GzDecoder::new(data)
+                .read_to_end(&mut decompressed)
+                .unwrap(),
+            Encoding::Zstd => zstd::Decoder::new(data)
+                .unwrap()
+                .read_to_end(&mut decompressed)
+                .unwrap(),
+        };
+        decompressed
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Compression::parse(None, None, None).unwrap(), None);
+        let gzip = Compression::parse(Some("gzip"), None, None).unwrap().unwrap();
+        assert_eq!((gzip.content_encoding(), gzip.level), ("gzip", 6));
+        let zstd = Compression::parse(Some("zstd"), Some(19), None).unwrap().unwrap();
+        assert_eq!((zstd.content_encoding(), zstd.level), ("zstd", 19));
+
+        assert!(Compression::parse(None, Some(3), None).is_err());
+        assert!(Compression::parse(Some("brotli"), None, None).is_err());
+        assert!(Compression::parse(Some("gzip"), Some(10), None).is_err());
+        let metadata = HashMap::from([("Content_Encoding".to_string(), "gzip".to_string())]);
+        assert!(Compression::parse(Some("gzip"), None, Some(&metadata)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_compress() {
+        let data = Bytes::from("compressible ".repeat(200_000));
+        for name in ["gzip", "zstd"] {
+            let compression = Compression::parse(Some(name), None, None).unwrap().unwrap();
+            let compressed = compression.compress(data.clone()).await.unwrap();
+            assert!(compressed.len() < data.len() / 10);
+            assert_eq!(decompress(compression, &compressed), data);
+
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("data");
+            std::fs::write(&path, &data).unwrap();
+            let chunks: Vec<(Bytes, u64)> = compression.compress_file(path.clone()).try_collect().await.unwrap();
+            assert_eq!(chunks.iter().map(|(_, read)| read).sum::<u64>(), data.len() as u64);
+            let streamed: Vec<u8> = chunks.into_iter().flat_map(|(chunk, _)| chunk).collect();
+            assert_eq!(decompress(compression, &streamed), data);
+            assert_eq!(
+                compression.file_md5(path).await.unwrap(),
+                format!("{:x}", Md5::digest(&streamed))
+            );
+
+            let missing = compression.compress_file(dir.path().join("missing"));
+            assert!(missing.try_collect::<Vec<_>>().await.is_err());
+        }
+    }
+}
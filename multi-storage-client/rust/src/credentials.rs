@@ -15,15 +15,31 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "aws")]
 use object_store::aws::AwsCredential;
+#[cfg(feature = "gcp")]
 use object_store::gcp::GcpCredential;
 use pyo3::prelude::*;
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock, Weak};
+use std::time::Instant;
 use tokio::sync::Mutex;
+#[cfg(feature = "aws")]
 use aws_credential_types::provider::{ProvideCredentials, SharedCredentialsProvider};
 
+use crate::logging;
+use crate::runtime::get_runtime;
+
 const DEFAULT_REFRESH_CREDENTIALS_THRESHOLD: i64 = 600; // 10 minutes
 
+// Minimum time between refreshes forced by denied requests, so that requests denied for other
+// reasons don't call the Python provider every time
+const FORCED_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+// Minimum delay of a background refresh, for credentials that already expire within the refresh
+// threshold when fetched
+const MIN_REFRESH_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// Generic cached credential representation
 #[derive(Debug)]
 struct CredentialCache<C> {
@@ -41,6 +57,11 @@ struct CoreCredentialsProvider {
     refresh_lock: Arc<Mutex<()>>,
     // Time in seconds before expiration to trigger credential refresh
     refresh_threshold: i64,
+    // Error of the last background refresh, reported if the foreground retry fails as well
+    background_error: std::sync::Mutex<Option<String>>,
+    // Number of forced refreshes, and when the last one started
+    forced_refreshes: AtomicU64,
+    last_forced_refresh: std::sync::Mutex<Option<Instant>>,
 }
 
 impl CoreCredentialsProvider {
@@ -49,6 +70,9 @@ impl CoreCredentialsProvider {
             py_provider,
             refresh_lock: Arc::new(Mutex::new(())),
             refresh_threshold: refresh_threshold.unwrap_or(DEFAULT_REFRESH_CREDENTIALS_THRESHOLD),
+            background_error: std::sync::Mutex::new(None),
+            forced_refreshes: AtomicU64::new(0),
+            last_forced_refresh: std::sync::Mutex::new(None),
         }
     }
 
@@ -80,19 +104,47 @@ impl Clone for CoreCredentialsProvider {
             py_provider: Python::attach(|py| self.py_provider.clone_ref(py)),
             refresh_lock: Arc::clone(&self.refresh_lock),
             refresh_threshold: self.refresh_threshold,
+            background_error: std::sync::Mutex::new(None),
+            forced_refreshes: AtomicU64::new(0),
+            last_forced_refresh: std::sync::Mutex::new(None),
         }
     }
 }
 
-// Helper function to parse expiration time from RFC3339 string
-fn parse_expiration(expiration: Option<String>) -> DateTime<Utc> {
-    if let Some(exp_str) = expiration {
-        DateTime::parse_from_rfc3339(&exp_str)
-            .map(|dt| dt.with_timezone(&Utc))
-            .unwrap_or_else(|_| Utc::now() + Duration::hours(1))
+// Helper function to parse the `expiration` of Python credentials: an RFC3339 string, a
+// `datetime` or epoch seconds. Credentials without one are treated as long-lived, and those whose
+// expiration can't be interpreted are refreshed within the hour.
+fn parse_expiration(expiration: &Bound<'_, PyAny>) -> DateTime<Utc> {
+    if expiration.is_none() {
+        return Utc::now() + Duration::days(365);
+    }
+    expiration_time(expiration).unwrap_or_else(|| {
+        let expiration = expiration.repr().map(|repr| repr.to_string()).unwrap_or_default();
+        tracing::warn!(
+            target: logging::TARGET,
+            expiration,
+            "Can't interpret the credentials expiration, refreshing them within the hour"
+        );
+        Utc::now() + Duration::hours(1)
+    })
+}
+
+fn expiration_time(expiration: &Bound<'_, PyAny>) -> Option<DateTime<Utc>> {
+    if let Ok(exp_str) = expiration.extract::<String>() {
+        return DateTime::parse_from_rfc3339(&exp_str)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc));
+    }
+    // A naive `datetime` is taken as local time, like `datetime.timestamp()` does.
+    let seconds = if expiration.hasattr("timestamp").unwrap_or(false) {
+        expiration.call_method0("timestamp").ok()?.extract::<f64>().ok()?
     } else {
-        Utc::now() + Duration::days(365)
+        expiration.extract::<f64>().ok()?
+    };
+    if !seconds.is_finite() {
+        return None;
     }
+    DateTime::from_timestamp_millis((seconds * 1000.0) as i64)
 }
 
 // Helper to convert tokio JoinError to object_store::Error
@@ -106,21 +158,205 @@ fn join_error_to_object_store_error(e: tokio::task::JoinError) -> object_store::
     }
 }
 
-// Helper to convert PyErr to object_store::Error
-fn py_err_to_object_store_error(e: PyErr) -> object_store::Error {
+// Helper to convert PyErr to object_store::Error, naming the failed background refresh that
+// preceded it, if any
+fn py_err_to_object_store_error(e: PyErr, background_error: Option<String>) -> object_store::Error {
+    let message = match background_error {
+        Some(background_error) => format!(
+            "Failed to refresh credentials: {:?} (the background refresh failed before with: {})",
+            e, background_error
+        ),
+        None => format!("Failed to refresh credentials: {:?}", e),
+    };
     object_store::Error::Generic {
         store: "credentials_provider",
-        source: Box::new(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            format!("Failed to refresh credentials: {:?}", e),
-        )),
+        source: Box::new(std::io::Error::new(std::io::ErrorKind::Other, message)),
     }
 }
 
+// A provider whose credentials come from MSC's Python credentials provider, through the
+// credential cache it shares with its clones.
+trait PythonCredentials: Clone + Send + Sync + 'static {
+    type Credential: Send + Sync + 'static;
+
+    fn from_parts(
+        core: Arc<CoreCredentialsProvider>,
+        cached_credentials: Arc<RwLock<Option<CredentialCache<Self::Credential>>>>,
+    ) -> Self;
+
+    fn core(&self) -> &Arc<CoreCredentialsProvider>;
+
+    fn cached_credentials(&self) -> &Arc<RwLock<Option<CredentialCache<Self::Credential>>>>;
+
+    fn get_credentials(&self, py: Python) -> PyResult<CredentialCache<Self::Credential>>;
+}
+
+// Returns the cached credential unless it is due for refresh.
+fn cached_credential<P: PythonCredentials>(provider: &P) -> Option<Arc<P::Credential>> {
+    let cached_guard = provider.cached_credentials().read().unwrap();
+    cached_guard
+        .as_ref()
+        .filter(|cached_cred| !provider.core().should_refresh(cached_cred.expire_time))
+        .map(|cached_cred| Arc::clone(&cached_cred.credential))
+}
+
+// Gets the credentials from the Python credentials provider, refreshing them first if necessary,
+// and swaps them into the cache. Requests that already hold the previous credential keep using it.
+async fn fetch_credentials<P: PythonCredentials>(
+    provider: &P,
+) -> object_store::Result<PyResult<(Arc<P::Credential>, DateTime<Utc>)>> {
+    let this = provider.clone();
+    tokio::task::spawn_blocking(move || {
+        Python::attach(|py| {
+            let core = this.core();
+            let mut refreshed_credential = this.get_credentials(py)?;
+            if core.should_refresh(refreshed_credential.expire_time) {
+                core.refresh_credentials(py)?;
+                refreshed_credential = this.get_credentials(py)?;
+            }
+
+            let fetched = (
+                Arc::clone(&refreshed_credential.credential),
+                refreshed_credential.expire_time,
+            );
+            *this.cached_credentials().write().unwrap() = Some(refreshed_credential);
+            Ok(fetched)
+        })
+    })
+    .await
+    .map_err(join_error_to_object_store_error)
+}
+
+// Retrieves credentials from the cache, or from Python on the first call and after a background
+// refresh failed.
+//
+// Uses a two-tier caching strategy with double-checked locking to minimize Python GIL
+// contention while ensuring credentials are refreshed before expiration.
+async fn get_credential<P: PythonCredentials>(provider: &P) -> object_store::Result<Arc<P::Credential>> {
+    // Fast path: Check the cache without blocking
+    if let Some(credential) = cached_credential(provider) {
+        return Ok(credential);
+    }
+
+    // Acquire refresh lock to coordinate refresh (prevents thundering herd)
+    let _refresh_guard = provider.core().acquire_refresh_lock().await;
+
+    // Double-check: another thread might have refreshed while we waited
+    if let Some(credential) = cached_credential(provider) {
+        return Ok(credential);
+    }
+
+    let background_error = provider.core().background_error.lock().unwrap().take();
+    let (credential, expire_time) = fetch_credentials(provider)
+        .await?
+        .map_err(|e| py_err_to_object_store_error(e, background_error))?;
+    schedule_refresh(provider, expire_time);
+    Ok(credential)
+}
+
+// Refreshes the credentials `refresh_threshold` seconds before `expire_time` in a background task,
+// so that requests don't wait for the Python provider. The task only holds weak references and
+// ends with the provider. If the refresh fails, the next request retries it in the foreground.
+//
+// Credentials that are already due are refreshed halfway through their remaining lifetime, and at
+// least `MIN_REFRESH_DELAY` later, and no further refresh is scheduled if one didn't extend them.
+fn schedule_refresh<P: PythonCredentials>(provider: &P, expire_time: DateTime<Utc>) {
+    let now = Utc::now();
+    let refresh_at = expire_time - Duration::seconds(provider.core().refresh_threshold);
+    let delay = match (refresh_at - now).to_std() {
+        Ok(delay) if !delay.is_zero() => delay,
+        _ => ((expire_time - now).to_std().unwrap_or_default() / 2).max(MIN_REFRESH_DELAY),
+    };
+    let core = Arc::downgrade(provider.core());
+    let cached_credentials = Arc::downgrade(provider.cached_credentials());
+    get_runtime().spawn(async move {
+        tokio::time::sleep(delay).await;
+        let (Some(core), Some(cached_credentials)) = (Weak::upgrade(&core), Weak::upgrade(&cached_credentials)) else {
+            return;
+        };
+        let provider = P::from_parts(core, cached_credentials);
+        let _refresh_guard = provider.core().acquire_refresh_lock().await;
+        // A request refreshed the credentials first and scheduled the next refresh itself.
+        if cached_credential(&provider).is_some() {
+            return;
+        }
+        let error = match fetch_credentials(&provider).await {
+            Ok(Ok((_, refreshed))) if refreshed > expire_time => return schedule_refresh(&provider, refreshed),
+            // The provider returned the same credentials, so the next request refreshes them.
+            Ok(Ok(_)) => return,
+            Ok(Err(e)) => format!("{:?}", e),
+            Err(e) => e.to_string(),
+        };
+        tracing::warn!(
+            target: logging::TARGET,
+            error = logging::redact(&error),
+            "Background credential refresh failed, the next request retries it"
+        );
+        *provider.core().background_error.lock().unwrap() = Some(error);
+    });
+}
+
+/// Credentials that can be refreshed before they expire, e.g. when a request was denied because
+/// the token was revoked early.
+#[async_trait]
+pub trait ForceRefresh: std::fmt::Debug + Send + Sync {
+    /// Identifies the credentials in use, to be passed to `force_refresh` if a request sent with
+    /// them is denied.
+    fn generation(&self) -> u64;
+
+    /// Refreshes the credentials unless they changed since `generation` or were refreshed less
+    /// than `FORCED_REFRESH_INTERVAL` ago. Returns whether the request should be retried, i.e.
+    /// whether the credentials changed.
+    async fn force_refresh(&self, generation: u64) -> bool;
+}
+
+async fn force_refresh<P: PythonCredentials>(provider: &P, generation: u64) -> bool {
+    let core = provider.core();
+    let _refresh_guard = core.acquire_refresh_lock().await;
+    if core.forced_refreshes.load(Ordering::Acquire) != generation {
+        return true;
+    }
+    {
+        let mut last_forced_refresh = core.last_forced_refresh.lock().unwrap();
+        if last_forced_refresh.is_some_and(|at| at.elapsed() < FORCED_REFRESH_INTERVAL) {
+            return false;
+        }
+        *last_forced_refresh = Some(Instant::now());
+    }
+
+    let this = provider.clone();
+    let refreshed = tokio::task::spawn_blocking(move || {
+        Python::attach(|py| {
+            this.core().refresh_credentials(py)?;
+            let refreshed_credential = this.get_credentials(py)?;
+            let expire_time = refreshed_credential.expire_time;
+            *this.cached_credentials().write().unwrap() = Some(refreshed_credential);
+            Ok::<_, PyErr>(expire_time)
+        })
+    })
+    .await;
+    let error = match refreshed {
+        Ok(Ok(expire_time)) => {
+            core.forced_refreshes.fetch_add(1, Ordering::Release);
+            schedule_refresh(provider, expire_time);
+            return true;
+        }
+        Ok(Err(e)) => format!("{:?}", e),
+        Err(e) => e.to_string(),
+    };
+    tracing::warn!(
+        target: logging::TARGET,
+        error = logging::redact(&error),
+        "Failed to refresh the credentials of a denied request"
+    );
+    false
+}
+
 // A credential provider that bridges Python credentials provider to Rust's object_store for AWS.
 //
 // This provider wraps a Python credentials object and handles credential caching,
 // refreshing, and thread-safe access for AWS/S3-compatible storage services.
+#[cfg(feature = "aws")]
 pub struct AwsCredentialsProvider {
     // Core logic shared across all providers
     core: Arc<CoreCredentialsProvider>,
@@ -128,6 +364,7 @@ pub struct AwsCredentialsProvider {
     cached_credentials: Arc<RwLock<Option<CredentialCache<AwsCredential>>>>,
 }
 
+#[cfg(feature = "aws")]
 impl Clone for AwsCredentialsProvider {
     fn clone(&self) -> Self {
         Self {
@@ -137,6 +374,7 @@ impl Clone for AwsCredentialsProvider {
     }
 }
 
+#[cfg(feature = "aws")]
 impl std::fmt::Debug for AwsCredentialsProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug_struct = f.debug_struct("AwsCredentialsProvider");
@@ -145,6 +383,7 @@ impl std::fmt::Debug for AwsCredentialsProvider {
     }
 }
 
+#[cfg(feature = "aws")]
 impl AwsCredentialsProvider {
     pub fn new(py_provider: Py<PyAny>, refresh_threshold: Option<i64>) -> Self {
         Self {
@@ -152,6 +391,41 @@ impl AwsCredentialsProvider {
             cached_credentials: Arc::new(RwLock::new(None)),
         }
     }
+}
+
+#[cfg(feature = "aws")]
+#[async_trait]
+impl ForceRefresh for AwsCredentialsProvider {
+    fn generation(&self) -> u64 {
+        self.core.forced_refreshes.load(Ordering::Acquire)
+    }
+
+    async fn force_refresh(&self, generation: u64) -> bool {
+        force_refresh(self, generation).await
+    }
+}
+
+#[cfg(feature = "aws")]
+impl PythonCredentials for AwsCredentialsProvider {
+    type Credential = AwsCredential;
+
+    fn from_parts(
+        core: Arc<CoreCredentialsProvider>,
+        cached_credentials: Arc<RwLock<Option<CredentialCache<AwsCredential>>>>,
+    ) -> Self {
+        Self {
+            core,
+            cached_credentials,
+        }
+    }
+
+    fn core(&self) -> &Arc<CoreCredentialsProvider> {
+        &self.core
+    }
+
+    fn cached_credentials(&self) -> &Arc<RwLock<Option<CredentialCache<AwsCredential>>>> {
+        &self.cached_credentials
+    }
 
     fn get_credentials(&self, py: Python) -> PyResult<CredentialCache<AwsCredential>> {
         let credentials = self.core.py_provider.call_method0(py, "get_credentials")?;
@@ -159,9 +433,9 @@ impl AwsCredentialsProvider {
         let access_key = credentials.getattr(py, "access_key")?.extract::<String>(py)?;
         let secret_key = credentials.getattr(py, "secret_key")?.extract::<String>(py)?;
         let token = credentials.getattr(py, "token")?.extract::<Option<String>>(py)?;
-        let expiration = credentials.getattr(py, "expiration")?.extract::<Option<String>>(py)?;
+        let expiration = credentials.getattr(py, "expiration")?;
 
-        let expire_time = parse_expiration(expiration);
+        let expire_time = parse_expiration(expiration.bind(py));
 
         Ok(CredentialCache {
             credential: Arc::new(AwsCredential {
@@ -175,96 +449,38 @@ impl AwsCredentialsProvider {
 }
 
 // Implements object_store's credential provider by delegating to MSC's Python credentials provider.
-//
-// Uses a two-tier caching strategy with double-checked locking to minimize Python GIL
-// contention while ensuring credentials are refreshed before expiration.
+#[cfg(feature = "aws")]
 #[async_trait]
 impl object_store::CredentialProvider for AwsCredentialsProvider {
     type Credential = AwsCredential;
 
-    // Retrieves credentials from Python credentials provider, refreshing them if necessary.
     async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
-        // Fast path: Check the cache without blocking
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-
-        // Acquire refresh lock to coordinate refresh (prevents thundering herd)
-        let _refresh_guard = self.core.acquire_refresh_lock().await;
-
-        // Double-check: another thread might have refreshed while we waited
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-
-        // Spawn blocking task to refresh credentials
-        let cached_arc = Arc::clone(&self.cached_credentials);
-        let core = Arc::clone(&self.core);
-        let this = self.clone();
-
-        tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                // Get the credentials from the Python credentials provider
-                let mut refreshed_credential = this.get_credentials(py)?;
-
-                // Check if the credentials need to be refreshed and refresh them if necessary
-                if core.should_refresh(refreshed_credential.expire_time) {
-                    core.refresh_credentials(py)?;
-                    refreshed_credential = this.get_credentials(py)?;
-                }
-
-                // Create credential to return
-                let credential = AwsCredential {
-                    key_id: refreshed_credential.credential.key_id.clone(),
-                    secret_key: refreshed_credential.credential.secret_key.clone(),
-                    token: refreshed_credential.credential.token.clone(),
-                };
-
-                // Update cache with write lock
-                {
-                    let mut cached_guard = cached_arc.write().unwrap();
-                    *cached_guard = Some(refreshed_credential);
-                }
-
-                Ok(credential)
-            })
-        })
-        .await
-        .map_err(join_error_to_object_store_error)?
-        .map_err(py_err_to_object_store_error)
-        .map(Arc::new)
-        .map_err(Into::into)
+        get_credential(self).await
     }
 }
 
 // Wrapper for AWS SDK credentials provider that implements object_store's CredentialProvider.
 // This allows using AWS SDK's default credential chain (environment variables, instance metadata, etc.)
+#[cfg(feature = "aws")]
 pub struct AwsSdkCredentialsProvider {
     sdk_provider: SharedCredentialsProvider,
 }
 
+#[cfg(feature = "aws")]
 impl std::fmt::Debug for AwsSdkCredentialsProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AwsSdkCredentialsProvider").finish()
     }
 }
 
+#[cfg(feature = "aws")]
 impl AwsSdkCredentialsProvider {
     pub fn new(sdk_provider: SharedCredentialsProvider) -> Self {
         Self { sdk_provider }
     }
 }
 
+#[cfg(feature = "aws")]
 #[async_trait]
 impl object_store::CredentialProvider for AwsSdkCredentialsProvider {
     type Credential = AwsCredential;
@@ -292,6 +508,7 @@ impl object_store::CredentialProvider for AwsSdkCredentialsProvider {
 }
 
 // A GCP credential provider that bridges Python credentials provider to Rust's object_store.
+#[cfg(feature = "gcp")]
 pub struct GcpCredentialsProvider {
     // Core logic shared across all providers
     core: Arc<CoreCredentialsProvider>,
@@ -299,6 +516,7 @@ pub struct GcpCredentialsProvider {
     cached_credentials: Arc<RwLock<Option<CredentialCache<GcpCredential>>>>,
 }
 
+#[cfg(feature = "gcp")]
 impl Clone for GcpCredentialsProvider {
     fn clone(&self) -> Self {
         Self {
@@ -308,6 +526,7 @@ impl Clone for GcpCredentialsProvider {
     }
 }
 
+#[cfg(feature = "gcp")]
 impl std::fmt::Debug for GcpCredentialsProvider {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug_struct = f.debug_struct("GcpCredentialsProvider");
@@ -316,6 +535,7 @@ impl std::fmt::Debug for GcpCredentialsProvider {
     }
 }
 
+#[cfg(feature = "gcp")]
 impl GcpCredentialsProvider {
     pub fn new(py_provider: Py<PyAny>, refresh_threshold: Option<i64>) -> Self {
         Self {
@@ -323,6 +543,41 @@ impl GcpCredentialsProvider {
             cached_credentials: Arc::new(RwLock::new(None)),
         }
     }
+}
+
+#[cfg(feature = "gcp")]
+#[async_trait]
+impl ForceRefresh for GcpCredentialsProvider {
+    fn generation(&self) -> u64 {
+        self.core.forced_refreshes.load(Ordering::Acquire)
+    }
+
+    async fn force_refresh(&self, generation: u64) -> bool {
+        force_refresh(self, generation).await
+    }
+}
+
+#[cfg(feature = "gcp")]
+impl PythonCredentials for GcpCredentialsProvider {
+    type Credential = GcpCredential;
+
+    fn from_parts(
+        core: Arc<CoreCredentialsProvider>,
+        cached_credentials: Arc<RwLock<Option<CredentialCache<GcpCredential>>>>,
+    ) -> Self {
+        Self {
+            core,
+            cached_credentials,
+        }
+    }
+
+    fn core(&self) -> &Arc<CoreCredentialsProvider> {
+        &self.core
+    }
+
+    fn cached_credentials(&self) -> &Arc<RwLock<Option<CredentialCache<GcpCredential>>>> {
+        &self.cached_credentials
+    }
 
     fn get_credentials(&self, py: Python) -> PyResult<CredentialCache<GcpCredential>> {
         let credentials = self.core.py_provider.call_method0(py, "get_credentials")?;
@@ -337,9 +592,9 @@ impl GcpCredentialsProvider {
                 )
             })?;
 
-        let expiration = credentials.getattr(py, "expiration")?.extract::<Option<String>>(py)?;
+        let expiration = credentials.getattr(py, "expiration")?;
 
-        let expire_time = parse_expiration(expiration);
+        let expire_time = parse_expiration(expiration.bind(py));
 
         Ok(CredentialCache {
             credential: Arc::new(GcpCredential {
@@ -351,73 +606,13 @@ impl GcpCredentialsProvider {
 }
 
 // Implements object_store's credential provider for GCP by delegating to MSC's Python credentials provider.
-//
-// Uses a two-tier caching strategy with double-checked locking to minimize Python GIL
-// contention while ensuring credentials are refreshed before expiration.
+#[cfg(feature = "gcp")]
 #[async_trait]
 impl object_store::CredentialProvider for GcpCredentialsProvider {
     type Credential = GcpCredential;
 
-    // Retrieves GCP credentials from Python credentials provider, refreshing them if necessary.
     async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
-        // Fast path: Check the cache without blocking
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-
-        // Acquire refresh lock to coordinate refresh (prevents thundering herd)
-        let _refresh_guard = self.core.acquire_refresh_lock().await;
-
-        // Double-check: another thread might have refreshed while we waited
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-
-        // Spawn blocking task to refresh credentials
-        let cached_arc = Arc::clone(&self.cached_credentials);
-        let core = Arc::clone(&self.core);
-        let this = self.clone();
-
-        tokio::task::spawn_blocking(move || {
-            Python::attach(|py| {
-                // Get the credentials from the Python credentials provider
-                let mut refreshed_credential = this.get_credentials(py)?;
-
-                // Check if the credentials need to be refreshed and refresh them if necessary
-                if core.should_refresh(refreshed_credential.expire_time) {
-                    core.refresh_credentials(py)?;
-                    refreshed_credential = this.get_credentials(py)?;
-                }
-
-                // Return the refreshed credentials and cache them
-                let credential = GcpCredential {
-                    bearer: refreshed_credential.credential.bearer.clone(),
-                };
-
-                // Update cache with write lock
-                {
-                    let mut cached_guard = cached_arc.write().unwrap();
-                    *cached_guard = Some(refreshed_credential);
-                }
-
-                Ok(credential)
-            })
-        })
-        .await
-        .map_err(join_error_to_object_store_error)?
-        .map_err(py_err_to_object_store_error)
-        .map(Arc::new)
-        .map_err(Into::into)
+        get_credential(self).await
     }
 }
 
@@ -477,6 +672,7 @@ mod tests {
         secret_key: String,
         token: Option<String>,
         expiration: Arc<RwLock<Option<String>>>,
+        lifetime: i64,
         call_count: Arc<AtomicUsize>,
         refresh_count: Arc<AtomicUsize>,
     }
@@ -495,6 +691,7 @@ mod tests {
                 secret_key,
                 token,
                 expiration: Arc::new(RwLock::new(expiration)),
+                lifetime: 5,
                 call_count: Arc::new(AtomicUsize::new(0)),
                 refresh_count: Arc::new(AtomicUsize::new(0)),
             }
@@ -514,7 +711,7 @@ mod tests {
 
         fn refresh_credentials(&mut self) {
             self.refresh_count.fetch_add(1, Ordering::SeqCst);
-            let new_expiration = (Utc::now() + Duration::seconds(5)).to_rfc3339();
+            let new_expiration = (Utc::now() + Duration::seconds(self.lifetime)).to_rfc3339();
             let mut expiration = self.expiration.write().unwrap();
             *expiration = Some(new_expiration);
         }
@@ -528,7 +725,16 @@ mod tests {
         }
     }
 
+    impl MockCredentialsProvider {
+        // Sets how long the credentials are valid for after each refresh.
+        fn with_lifetime(mut self, seconds: i64) -> Self {
+            self.lifetime = seconds;
+            self
+        }
+    }
+
     #[test]
+    #[cfg(feature = "aws")]
     fn test_cached_credential_creation() {
         let credential = Arc::new(AwsCredential {
             key_id: "test_key".to_string(),
@@ -547,6 +753,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "aws")]
     fn test_should_refresh_expired() {
         initialize_python();
         Python::attach(|py| {
@@ -571,6 +778,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "aws")]
     fn test_get_credentials_from_python() {
         initialize_python();
         Python::attach(|py| {
@@ -597,6 +805,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "aws")]
     fn test_refresh_credentials_succeeds() {
         initialize_python();
         Python::attach(|py| {
@@ -626,6 +835,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[cfg(feature = "aws")]
     async fn test_concurrent_access_async() {
         use object_store::CredentialProvider;
         use tokio::sync::Barrier;
@@ -683,6 +893,133 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    #[cfg(feature = "aws")]
+    async fn test_unexpired_credentials_are_served_from_cache() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+        let (mock_provider_obj, provider) = Python::attach(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockCredentialsProvider::new(
+                    "cached_access".to_string(),
+                    "cached_secret".to_string(),
+                    None,
+                    Some((Utc::now() + Duration::hours(1)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            let provider = AwsCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), None);
+            (mock_provider_obj, provider)
+        });
+
+        // Credentials are fetched on the first request, not when the store is built.
+        Python::attach(|py| assert_eq!(mock_provider_obj.borrow(py).get_call_count(), 0));
+        for _ in 0..3 {
+            assert_eq!(provider.get_credential().await.unwrap().key_id, "cached_access");
+        }
+        Python::attach(|py| {
+            assert_eq!(mock_provider_obj.borrow(py).get_call_count(), 1);
+            assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 0);
+        });
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "aws")]
+    async fn test_credentials_are_refreshed_in_background() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+        let (mock_provider_obj, provider) = Python::attach(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockCredentialsProvider::new(
+                    "background_access".to_string(),
+                    "background_secret".to_string(),
+                    None,
+                    Some((Utc::now() + Duration::seconds(5)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            // Refreshes a second after the first request, and then every second.
+            let provider = AwsCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), Some(4));
+            (mock_provider_obj, provider)
+        });
+
+        assert_eq!(provider.get_credential().await.unwrap().key_id, "background_access");
+        tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+        let call_count = Python::attach(|py| {
+            assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 1);
+            mock_provider_obj.borrow(py).get_call_count()
+        });
+
+        // Requests are served from the refreshed cache without calling Python.
+        assert_eq!(provider.get_credential().await.unwrap().key_id, "background_access");
+        Python::attach(|py| assert_eq!(mock_provider_obj.borrow(py).get_call_count(), call_count));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "aws")]
+    async fn test_short_lived_credentials_are_not_refreshed_in_a_loop() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+        let (mock_provider_obj, provider) = Python::attach(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockCredentialsProvider::new(
+                    "short_lived_access".to_string(),
+                    "short_lived_secret".to_string(),
+                    None,
+                    Some((Utc::now() + Duration::minutes(5)).to_rfc3339()),
+                )
+                .with_lifetime(300),
+            )
+            .unwrap();
+            // Every refresh returns credentials that expire within the default 10 minute threshold.
+            let provider = AwsCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), None);
+            (mock_provider_obj, provider)
+        });
+
+        assert_eq!(provider.get_credential().await.unwrap().key_id, "short_lived_access");
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        Python::attach(|py| assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 1));
+    }
+
+    #[test]
+    fn test_parse_expiration() {
+        initialize_python();
+        Python::attach(|py| {
+            let expected = DateTime::parse_from_rfc3339("2030-01-02T03:04:05Z").unwrap().with_timezone(&Utc);
+            let datetime = py.import("datetime").unwrap();
+            let utc = datetime.getattr("timezone").unwrap().getattr("utc").unwrap();
+            let expirations = [
+                "2030-01-02T03:04:05Z".into_pyobject(py).unwrap().into_any(),
+                "2030-01-02T03:04:05+00:00".into_pyobject(py).unwrap().into_any(),
+                datetime
+                    .getattr("datetime")
+                    .unwrap()
+                    .call_method1("fromtimestamp", (expected.timestamp(), utc))
+                    .unwrap(),
+                expected.timestamp().into_pyobject(py).unwrap().into_any(),
+                (expected.timestamp() as f64).into_pyobject(py).unwrap().into_any(),
+            ];
+            for expiration in expirations {
+                assert_eq!(parse_expiration(&expiration), expected, "{}", expiration);
+            }
+
+            assert!(parse_expiration(&py.None().into_bound(py)) > Utc::now() + Duration::days(364));
+            for invalid in [
+                "tomorrow".into_pyobject(py).unwrap().into_any(),
+                f64::NAN.into_pyobject(py).unwrap().into_any(),
+            ] {
+                let expire_time = parse_expiration(&invalid);
+                assert!(expire_time > Utc::now() && expire_time <= Utc::now() + Duration::hours(1));
+            }
+        });
+    }
+
     // GCP-specific tests: focus on token field extraction and None token error handling
     #[pyclass]
     struct MockGcpCredentials {
@@ -697,13 +1034,18 @@ mod tests {
     struct MockGcpCredentialsProvider {
         token: Option<String>,
         expiration: Option<String>,
+        refresh_count: usize,
     }
 
     #[pymethods]
     impl MockGcpCredentialsProvider {
         #[new]
         fn new(token: Option<String>, expiration: Option<String>) -> Self {
-            Self { token, expiration }
+            Self {
+                token,
+                expiration,
+                refresh_count: 0,
+            }
         }
 
         fn get_credentials(&self, py: Python) -> PyResult<Py<PyAny>> {
@@ -717,10 +1059,14 @@ mod tests {
             .map(|obj| obj.into())
         }
 
-        fn refresh_credentials(&self) {}
+        fn refresh_credentials(&mut self) {
+            self.refresh_count += 1;
+            self.expiration = Some((Utc::now() + Duration::hours(1)).to_rfc3339());
+        }
     }
 
     #[test]
+    #[cfg(feature = "gcp")]
     fn test_gcp_get_credentials_with_valid_token() {
         initialize_python();
         Python::attach(|py| {
@@ -743,6 +1089,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "gcp")]
     fn test_gcp_none_token_error() {
         initialize_python();
         Python::attach(|py| {
@@ -765,5 +1112,30 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    #[cfg(feature = "gcp")]
+    async fn test_gcp_expired_token_is_refreshed_once() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+        let (mock_provider_obj, provider) = Python::attach(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("ya29.refreshed_token".to_string()),
+                    Some((Utc::now() - Duration::seconds(1)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            let provider = GcpCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), None);
+            (mock_provider_obj, provider)
+        });
+
+        for _ in 0..3 {
+            assert_eq!(provider.get_credential().await.unwrap().bearer, "ya29.refreshed_token");
+        }
+        Python::attach(|py| assert_eq!(mock_provider_obj.borrow(py).refresh_count, 1));
+    }
+
 }
 
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::PathBuf;
+use tokio::fs::{self, ReadDir};
+
+/// A regular file found by a [`DirectoryWalk`].
+#[derive(Debug)]
+pub struct LocalEntry {
+    /// Path below the walked directory, with `/` separators.
+    pub relative: String,
+    pub path: PathBuf,
+}
+
+/// Depth-first walk of the regular files below a local directory.
+///
+/// Symbolic links are skipped unless `follow_symlinks` is set, in which case a link to a
+/// directory that is already being walked is skipped to avoid cycles.
+pub struct DirectoryWalk {
+    root: PathBuf,
+    follow_symlinks: bool,
+    /// Directories left to read, relative to `root`.
+    pending: Vec<String>,
+    current: Option<(String, ReadDir)>,
+    visited: HashSet<PathBuf>,
+}
+
+impl DirectoryWalk {
+    pub fn new(root: PathBuf, follow_symlinks: bool) -> Self {
+        DirectoryWalk {
+            root,
+            follow_symlinks,
+            pending: vec![String::new()],
+            current: None,
+            visited: HashSet::new(),
+        }
+    }
+
+    fn join(directory: &str, name: &str) -> String {
+        if directory.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", directory, name)
+        }
+    }
+
+    /// Returns the next file, or the relative path and error of an entry that couldn't be read.
+    /// A directory that can't be read is reported once and not descended into.
+    pub async fn next(&mut self) -> Option<Result<LocalEntry, (String, io::Error)>> {
+        loop {
+            let (directory, entries) = match self.current.as_mut() {
+                Some(current) => (current.0.clone(), &mut current.1),
+                None => {
+                    let directory = self.pending.pop()?;
+                    let path = self.root.join(&directory);
+                    if self.follow_symlinks {
+                        match fs::canonicalize(&path).await {
+                            Ok(canonical) if !self.visited.insert(canonical) => continue,
+                            Ok(_) => {}
+                            Err(err) => return Some(Err((directory, err))),
+                        }
+                    }
+                    match fs::read_dir(&path).await {
+                        Ok(entries) => self.current = Some((directory, entries)),
+                        Err(err) => return Some(Err((directory, err))),
+                    }
+                    continue;
+                }
+            };
+
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => {
+                    self.current = None;
+                    continue;
+                }
+                Err(err) => {
+                    self.current = None;
+                    return Some(Err((directory, err)));
+                }
+            };
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                let relative = Self::join(&directory, &name.to_string_lossy());
+                let err = io::Error::new(io::ErrorKind::InvalidData, "file name is not valid UTF-8");
+                return Some(Err((relative, err)));
+            };
+            let relative = Self::join(&directory, name);
+
+            let mut file_type = match entry.file_type().await {
+                Ok(file_type) => file_type,
+                Err(err) => return Some(Err((relative, err))),
+            };
+            if file_type.is_symlink() {
+                if !self.follow_symlinks {
+                    continue;
+                }
+                file_type = match fs::metadata(entry.path()).await {
+                    Ok(metadata) => metadata.file_type(),
+                    Err(err) => return Some(Err((relative, err))),
+                };
+            }
+            if file_type.is_dir() {
+                self.pending.push(relative);
+            } else if file_type.is_file() {
+                return Some(Ok(LocalEntry {
+                    relative,
+                    path: entry.path(),
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn walk(root: PathBuf, follow_symlinks: bool) -> Vec<String> {
+        let mut walk = DirectoryWalk::new(root, follow_symlinks);
+        let mut files = Vec::new();
+        while let Some(entry) = walk.next().await {
+            files.push(entry.unwrap().relative);
+        }
+        files.sort();
+        files
+    }
+
+    #[tokio::test]
+    async fn test_directory_walk() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("a/b")).unwrap();
+        std::fs::create_dir_all(dir.path().join("empty")).unwrap();
+        std::fs::write(dir.path().join("top.txt"), b"1").unwrap();
+        std::fs::write(dir.path().join("a/one.bin"), b"22").unwrap();
+        std::fs::write(dir.path().join("a/b/two.bin"), b"333").unwrap();
+
+        assert_eq!(walk(dir.path().to_path_buf(), false).await, ["a/b/two.bin", "a/one.bin", "top.txt"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_directory_walk_symlinks() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("data")).unwrap();
+        std::fs::write(dir.path().join("data/file.txt"), b"x").unwrap();
+        std::os::unix::fs::symlink(dir.path().join("data/file.txt"), dir.path().join("link.txt")).unwrap();
+        // A link back to the root would recurse forever if cycles weren't detected.
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("data/loop")).unwrap();
+
+        assert_eq!(walk(dir.path().to_path_buf(), false).await, ["data/file.txt"]);
+        assert_eq!(walk(dir.path().to_path_buf(), true).await, ["data/file.txt", "link.txt"]);
+    }
+}
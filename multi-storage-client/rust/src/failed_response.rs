@@ -0,0 +1,171 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use http::header::{HeaderMap, RETRY_AFTER};
+use http::StatusCode;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Headers stores return to identify a request, checked in order.
+const REQUEST_ID_HEADERS: [&str; 4] = [
+    "x-amz-request-id",
+    "x-ms-request-id",
+    "x-guploader-uploadid",
+    "x-request-id",
+];
+
+/// The last error response seen by an operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedResponse {
+    pub status: u16,
+    pub request_id: Option<String>,
+    /// How long the server asked clients to wait before retrying, from `Retry-After`.
+    pub retry_after: Option<Duration>,
+}
+
+/// Whether `status` means the store is throttling requests: 429, or the 503 S3 and Azure use for
+/// `SlowDown` and `ServerBusy`.
+pub fn is_throttling(status: u16) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS.as_u16() || status == StatusCode::SERVICE_UNAVAILABLE.as_u16()
+}
+
+/// Parses `Retry-After` as either a number of seconds or an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((date - Utc::now()).to_std().unwrap_or_default())
+}
+
+/// What `track` has seen of an operation's requests.
+#[derive(Debug, Default)]
+struct Tracked {
+    last: Option<FailedResponse>,
+    /// Requests that failed, with an error response or without any.
+    failures: u32,
+}
+
+tokio::task_local! {
+    static LAST: Arc<Mutex<Tracked>>;
+}
+
+/// Runs `fut` keeping track of whether the last response it, or work it propagates to, received
+/// was an error, and of how many of its requests failed.
+pub async fn track<F: Future>(fut: F) -> F::Output {
+    LAST.scope(Arc::default(), fut).await
+}
+
+/// Carries the current tracking into `fut`, for futures that will be spawned onto other tasks.
+pub fn propagate<F: Future>(fut: F) -> impl Future<Output = F::Output> {
+    let last = LAST.try_with(Arc::clone).ok();
+    async move {
+        match last {
+            Some(last) => LAST.scope(last, fut).await,
+            None => fut.await,
+        }
+    }
+}
+
+/// Records a response for the enclosing `track`, if any: an error response replaces the last
+/// one and a successful one clears it, so that a retry that succeeded leaves nothing behind.
+pub fn record(status: StatusCode, headers: &HeaderMap) {
+    let failed = (status.is_client_error() || status.is_server_error()).then(|| FailedResponse {
+        status: status.as_u16(),
+        request_id: REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|name| headers.get(*name)?.to_str().ok().map(str::to_string)),
+        retry_after: retry_after(headers),
+    });
+    let _ = LAST.try_with(|tracked| {
+        let mut tracked = tracked.lock().unwrap();
+        tracked.failures += u32::from(failed.is_some());
+        tracked.last = failed;
+    });
+}
+
+/// Records a request that failed without a response, e.g. on a connection reset.
+pub fn record_unanswered() {
+    let _ = LAST.try_with(|tracked| tracked.lock().unwrap().failures += 1);
+}
+
+/// Returns the last error response of the enclosing `track`, unless a later request succeeded.
+pub fn last() -> Option<FailedResponse> {
+    LAST.try_with(|tracked| tracked.lock().unwrap().last.clone())
+        .ok()
+        .flatten()
+}
+
+/// Number of requests of the enclosing `track` that failed. Once the operation has succeeded,
+/// each of them was retried.
+pub fn failures() -> u32 {
+    LAST.try_with(|tracked| tracked.lock().unwrap().failures).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[tokio::test]
+    async fn test_track_failed_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("generic"));
+        headers.insert("x-amz-request-id", HeaderValue::from_static("4442587FB7D0A2F9"));
+
+        let (failed, recovered) = track(async {
+            record(StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new());
+            tokio::spawn(propagate(async move { record(StatusCode::FORBIDDEN, &headers) }))
+                .await
+                .unwrap();
+            let failed = last();
+            record(StatusCode::OK, &HeaderMap::new());
+            record_unanswered();
+            assert_eq!(failures(), 3);
+            (failed, last())
+        })
+        .await;
+        let expected = FailedResponse {
+            status: 403,
+            request_id: Some("4442587FB7D0A2F9".to_string()),
+            retry_after: None,
+        };
+        assert_eq!(failed, Some(expected));
+        assert_eq!(recovered, None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        let throttled = track(async {
+            record(StatusCode::TOO_MANY_REQUESTS, &headers);
+            last()
+        })
+        .await
+        .unwrap();
+        assert!(is_throttling(throttled.status));
+        assert_eq!(throttled.retry_after, Some(Duration::from_secs(7)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        // Outside of `track` there is nowhere to record to.
+        record(StatusCode::FORBIDDEN, &HeaderMap::new());
+        assert_eq!(last(), None);
+        assert_eq!(failures(), 0);
+    }
+}
@@ -0,0 +1,402 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use object_store::multipart::{MultipartStore, PartId};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartId, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts,
+    PutOptions, PutPayload, PutResult, Result,
+};
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::logging;
+
+type Build<S> = Box<dyn Fn(&str) -> Result<S> + Send + Sync>;
+
+/// Splits an `endpoint_url` holding several comma-separated endpoints.
+pub fn parse_endpoints(endpoint_url: &str) -> Vec<String> {
+    endpoint_url
+        .split(',')
+        .map(str::trim)
+        .filter(|endpoint| !endpoint.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// object_store reports connection errors and exhausted retries as `Generic`. Other errors, e.g.
+/// NotFound, are answers from a healthy endpoint.
+fn should_fail_over<T>(result: &Result<T>) -> bool {
+    matches!(result, Err(object_store::Error::Generic { .. }))
+}
+
+struct Endpoints<S> {
+    urls: Vec<String>,
+    /// Built on first use, so endpoints that are never failed over to cost nothing.
+    stores: Vec<Mutex<Option<Arc<S>>>>,
+    build: Build<S>,
+    /// The endpoint that last answered, tried first by the next call.
+    current: AtomicUsize,
+}
+
+impl<S> Endpoints<S> {
+    fn store(&self, index: usize) -> Result<Arc<S>> {
+        let mut store = self.stores[index].lock().unwrap();
+        if let Some(store) = store.as_ref() {
+            return Ok(Arc::clone(store));
+        }
+        let built = Arc::new((self.build)(&self.urls[index])?);
+        *store = Some(Arc::clone(&built));
+        Ok(built)
+    }
+
+    /// Runs `op` against the current endpoint and then the following ones while it fails over,
+    /// at most once per endpoint. The last error is returned if every endpoint failed.
+    async fn call<T, F, Fut>(&self, operation: &'static str, op: F) -> Result<T>
+    where
+        F: Fn(Arc<S>) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut index = self.current.load(Ordering::Relaxed);
+        let mut attempts = 1;
+        loop {
+            let result = match self.store(index) {
+                Ok(store) => op(store).await,
+                Err(err) => Err(err),
+            };
+            let endpoint = self.urls[index].as_str();
+            if !should_fail_over(&result) {
+                self.current.store(index, Ordering::Relaxed);
+                tracing::debug!(target: logging::TARGET, operation, endpoint, "Served by endpoint");
+                return result;
+            }
+            if attempts == self.urls.len() {
+                return result;
+            }
+            attempts += 1;
+            index = (index + 1) % self.urls.len();
+            if let Err(err) = &result {
+                let next_endpoint = self.urls[index].as_str();
+                let error = logging::redact(&logging::chain(err));
+                tracing::warn!(
+                    target: logging::TARGET,
+                    operation,
+                    endpoint,
+                    next_endpoint,
+                    error,
+                    "Endpoint failed, failing over"
+                );
+            }
+        }
+    }
+
+    /// Fails listings over until one of them returns its first entry.
+    fn stream<F>(self: &Arc<Self>, list: F) -> BoxStream<'static, Result<ObjectMeta>>
+    where
+        S: Send + Sync + 'static,
+        F: Fn(&S) -> BoxStream<'static, Result<ObjectMeta>> + Send + Sync + 'static,
+    {
+        let endpoints = Arc::clone(self);
+        stream::once(async move {
+            let listing = endpoints
+                .call("list", |store| {
+                    let mut listing = list(&store);
+                    async move {
+                        match listing.next().await {
+                            Some(Err(err)) => Err(err),
+                            first => Ok((first, listing)),
+                        }
+                    }
+                })
+                .await;
+            match listing {
+                Ok((first, rest)) => stream::iter(first).chain(rest).boxed(),
+                Err(err) => stream::once(async move { Err(err) }).boxed(),
+            }
+        })
+        .flatten()
+        .boxed()
+    }
+}
+
+/// Store over several endpoints of the same bucket, e.g. gateways of an S3-compatible cluster. A
+/// call that fails with a connection error or after its retries moves on to the next endpoint,
+/// which then serves the following calls.
+///
+/// Uploads stay on the endpoint that started them, listings fail over until their first page.
+pub struct FailoverStore<S> {
+    endpoints: Arc<Endpoints<S>>,
+}
+
+impl<S> FailoverStore<S> {
+    /// Builds the store of the first endpoint right away, so configuration errors are raised at
+    /// construction.
+    pub fn new(urls: Vec<String>, build: impl Fn(&str) -> Result<S> + Send + Sync + 'static) -> Result<Self> {
+        let endpoints = Endpoints {
+            stores: urls.iter().map(|_| Mutex::new(None)).collect(),
+            urls,
+            build: Box::new(build),
+            current: AtomicUsize::new(0),
+        };
+        endpoints.store(0)?;
+        Ok(FailoverStore {
+            endpoints: Arc::new(endpoints),
+        })
+    }
+}
+
+impl<S> fmt::Debug for FailoverStore<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FailoverStore")
+            .field("endpoints", &self.endpoints.urls)
+            .finish()
+    }
+}
+
+impl<S> fmt::Display for FailoverStore<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FailoverStore({})", self.endpoints.urls.join(", "))
+    }
+}
+
+#[async_trait]
+impl<S: ObjectStore> ObjectStore for FailoverStore<S> {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        let (payload, opts) = (&payload, &opts);
+        self.endpoints
+            .call("put", |store| async move {
+                store.put_opts(location, payload.clone(), opts.clone()).await
+            })
+            .await
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        let opts = &opts;
+        self.endpoints
+            .call("put_multipart", |store| async move {
+                store.put_multipart_opts(location, opts.clone()).await
+            })
+            .await
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        let options = &options;
+        self.endpoints
+            .call(
+                "get",
+                |store| async move { store.get_opts(location, options.clone()).await },
+            )
+            .await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.endpoints
+            .call("head", |store| async move { store.head(location).await })
+            .await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.endpoints
+            .call("delete", |store| async move { store.delete(location).await })
+            .await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        let prefix = prefix.cloned();
+        self.endpoints.stream(move |store| store.list(prefix.as_ref()))
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        let (prefix, offset) = (prefix.cloned(), offset.clone());
+        self.endpoints
+            .stream(move |store| store.list_with_offset(prefix.as_ref(), &offset))
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.endpoints
+            .call("list", |store| async move { store.list_with_delimiter(prefix).await })
+            .await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.endpoints
+            .call("copy", |store| async move { store.copy(from, to).await })
+            .await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.endpoints
+            .call("copy", |store| async move { store.copy_if_not_exists(from, to).await })
+            .await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.endpoints
+            .call("rename", |store| async move { store.rename(from, to).await })
+            .await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.endpoints
+            .call(
+                "rename",
+                |store| async move { store.rename_if_not_exists(from, to).await },
+            )
+            .await
+    }
+}
+
+/// Endpoints of the same bucket share its uploads, so each request of a resumed upload may fail
+/// over on its own.
+#[async_trait]
+impl<S: ObjectStore + MultipartStore> MultipartStore for FailoverStore<S> {
+    async fn create_multipart(&self, path: &Path) -> Result<MultipartId> {
+        self.endpoints
+            .call(
+                "create_multipart",
+                |store| async move { store.create_multipart(path).await },
+            )
+            .await
+    }
+
+    async fn put_part(&self, path: &Path, id: &MultipartId, part_idx: usize, data: PutPayload) -> Result<PartId> {
+        let data = &data;
+        self.endpoints
+            .call("put_part", |store| async move {
+                store.put_part(path, id, part_idx, data.clone()).await
+            })
+            .await
+    }
+
+    async fn complete_multipart(&self, path: &Path, id: &MultipartId, parts: Vec<PartId>) -> Result<PutResult> {
+        let parts = &parts;
+        self.endpoints
+            .call("complete_multipart", |store| async move {
+                store.complete_multipart(path, id, parts.clone()).await
+            })
+            .await
+    }
+
+    async fn abort_multipart(&self, path: &Path, id: &MultipartId) -> Result<()> {
+        self.endpoints
+            .call("abort_multipart", |store| async move {
+                store.abort_multipart(path, id).await
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use std::sync::atomic::AtomicBool;
+
+    /// Stands in for an endpoint's store: its URL, and whether it is down.
+    struct Endpoint {
+        url: String,
+        down: Arc<AtomicBool>,
+    }
+
+    fn endpoints(urls: &[&str], down: &Arc<AtomicBool>, built: &Arc<AtomicUsize>) -> Endpoints<Endpoint> {
+        let (down, built) = (Arc::clone(down), Arc::clone(built));
+        Endpoints {
+            urls: urls.iter().map(|url| url.to_string()).collect(),
+            stores: urls.iter().map(|_| Mutex::new(None)).collect(),
+            build: Box::new(move |url| {
+                built.fetch_add(1, Ordering::Relaxed);
+                let down = if url.starts_with("dead") {
+                    Arc::new(AtomicBool::new(true))
+                } else {
+                    Arc::clone(&down)
+                };
+                Ok(Endpoint {
+                    url: url.to_string(),
+                    down,
+                })
+            }),
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    async fn serve(store: Arc<Endpoint>) -> Result<String> {
+        if store.down.load(Ordering::Relaxed) {
+            return Err(object_store::Error::Generic {
+                store: "S3",
+                source: "connection refused".into(),
+            });
+        }
+        Ok(store.url.clone())
+    }
+
+    #[test]
+    fn test_parse_endpoints() {
+        assert_eq!(parse_endpoints("http://a"), ["http://a"]);
+        assert_eq!(parse_endpoints(" http://a, http://b ,"), ["http://a", "http://b"]);
+    }
+
+    #[tokio::test]
+    async fn test_failover() {
+        let (down, built) = (Arc::new(AtomicBool::new(false)), Arc::new(AtomicUsize::new(0)));
+        let endpoints = endpoints(&["dead-1", "http://live", "dead-2"], &down, &built);
+
+        assert_eq!(endpoints.call("get", serve).await.unwrap(), "http://live");
+        assert_eq!(built.load(Ordering::Relaxed), 2);
+        // The live endpoint is sticky.
+        assert_eq!(endpoints.call("get", serve).await.unwrap(), "http://live");
+        assert_eq!(endpoints.current.load(Ordering::Relaxed), 1);
+
+        // A full rotation returns the last error and keeps the selection.
+        down.store(true, Ordering::Relaxed);
+        assert!(matches!(
+            endpoints.call("get", serve).await,
+            Err(object_store::Error::Generic { .. })
+        ));
+        assert_eq!(built.load(Ordering::Relaxed), 3);
+        assert_eq!(endpoints.current.load(Ordering::Relaxed), 1);
+
+        // Errors answered by the endpoint aren't failed over.
+        let not_found = |_: Arc<Endpoint>| async {
+            Err::<(), _>(object_store::Error::NotFound {
+                path: "key".to_string(),
+                source: "missing".into(),
+            })
+        };
+        assert!(matches!(
+            endpoints.call("head", not_found).await,
+            Err(object_store::Error::NotFound { .. })
+        ));
+        assert_eq!(endpoints.current.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failover_store() {
+        let urls = vec!["http://a".to_string(), "http://b".to_string()];
+        let store = FailoverStore::new(urls, |_| Ok(InMemory::new())).unwrap();
+        let location = Path::from("data/a.bin");
+        store.put(&location, PutPayload::from_static(b"data")).await.unwrap();
+        assert_eq!(
+            store.get(&location).await.unwrap().bytes().await.unwrap().as_ref(),
+            b"data"
+        );
+        let listed: Vec<_> = store.list(Some(&Path::from("data"))).collect().await;
+        assert_eq!(listed.len(), 1);
+        assert!(store.list(Some(&Path::from("missing"))).next().await.is_none());
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::Regex;
+
+use crate::StorageError;
+
+enum Segment {
+    /// `**`, any number of directories.
+    Recursive,
+    Pattern(Regex),
+}
+
+/// A key pattern with `*` and `?` within a path segment, `[...]` character classes and `**` for
+/// any number of directories.
+pub struct Glob {
+    prefix: String,
+    segments: Vec<Segment>,
+    pattern: Regex,
+}
+
+fn has_wildcard(segment: &str) -> bool {
+    segment.contains(['*', '?', '['])
+}
+
+/// Translates one path segment to a regex that never matches `/`.
+fn translate(segment: &str) -> String {
+    let chars: Vec<char> = segment.chars().collect();
+    let mut regex = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                let negated = matches!(chars.get(i + 1), Some('!' | '^'));
+                let start = i + 1 + negated as usize;
+                // A `]` right after the opening bracket is part of the class.
+                let close = (start + 1..chars.len()).find(|&j| chars[j] == ']');
+                if let Some(close) = close {
+                    let class: String = chars[start..close]
+                        .iter()
+                        .map(|&c| match c {
+                            '\\' | '[' | ']' | '&' | '~' => format!("\\{}", c),
+                            c => c.to_string(),
+                        })
+                        .collect();
+                    if negated {
+                        regex.push_str(&format!("[^{}/]", class));
+                    } else {
+                        regex.push_str(&format!("[{}&&[^/]]", class));
+                    }
+                    i = close + 1;
+                    continue;
+                }
+                regex.push_str(&regex::escape("["));
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    regex
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Result<Self, StorageError> {
+        let parts: Vec<&str> = pattern.trim_start_matches('/').split('/').collect();
+        let literal = parts[..parts.len() - 1].iter().take_while(|part| !has_wildcard(part)).count();
+        let prefix = parts[..literal].join("/");
+
+        let literal_dirs: String = parts[..literal].iter().map(|part| format!("{}/", part)).collect();
+        let mut full = format!("^{}", regex::escape(&literal_dirs));
+        let mut segments = Vec::new();
+        let rest = &parts[literal..];
+        for (index, part) in rest.iter().enumerate() {
+            let last = index == rest.len() - 1;
+            if *part == "**" {
+                full.push_str(if last { ".*" } else { "(?:.*/)?" });
+                segments.push(Segment::Recursive);
+                continue;
+            }
+            let segment = translate(part);
+            full.push_str(&segment);
+            if !last {
+                full.push('/');
+            }
+            segments.push(Segment::Pattern(compile(&format!("^{}$", segment), pattern)?));
+        }
+        full.push('$');
+
+        Ok(Glob {
+            prefix,
+            segments,
+            pattern: compile(&full, pattern)?,
+        })
+    }
+
+    /// The directory to list from: the segments before the first one with a wildcard.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn matches(&self, key: &str) -> bool {
+        self.pattern.is_match(key)
+    }
+
+    /// Whether any key under `directory`, a directory below the prefix, can match.
+    pub fn may_contain(&self, directory: &str) -> bool {
+        let relative = directory.strip_prefix(self.prefix.as_str()).unwrap_or(directory).trim_matches('/');
+        for (index, part) in relative.split('/').enumerate() {
+            // The last segment names objects, so matches can't be below a directory at that level.
+            match self.segments.get(index) {
+                Some(Segment::Recursive) => return true,
+                Some(Segment::Pattern(segment)) if index + 1 < self.segments.len() => {
+                    if !segment.is_match(part) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+fn compile(regex: &str, pattern: &str) -> Result<Regex, StorageError> {
+    Regex::new(regex).map_err(|e| StorageError::ConfigError(format!("Invalid glob pattern '{}': {}", pattern, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        let glob = Glob::new("datasets/*/shard-*.tar").unwrap();
+        assert_eq!(glob.prefix(), "datasets");
+        assert!(glob.matches("datasets/train/shard-000.tar"));
+        assert!(!glob.matches("datasets/train/nested/shard-000.tar"));
+        assert!(!glob.matches("datasets/train/shard-000.tar.idx"));
+
+        let glob = Glob::new("data/**/part-?[0-9].bin").unwrap();
+        assert_eq!(glob.prefix(), "data");
+        assert!(glob.matches("data/part-a1.bin"));
+        assert!(glob.matches("data/x/y/part-b2.bin"));
+        assert!(!glob.matches("data/x/part-ab.bin"));
+
+        let glob = Glob::new("logs/[!a]*.txt").unwrap();
+        assert!(glob.matches("logs/b.txt"));
+        assert!(!glob.matches("logs/a.txt"));
+
+        let glob = Glob::new("*.json").unwrap();
+        assert_eq!(glob.prefix(), "");
+        assert!(glob.matches("config.json"));
+        assert!(!glob.matches("dir/config.json"));
+
+        assert!(Glob::new("plain/key.bin").unwrap().matches("plain/key.bin"));
+    }
+
+    #[test]
+    fn test_glob_may_contain() {
+        let glob = Glob::new("datasets/train-*/shards/*.tar").unwrap();
+        assert!(glob.may_contain("datasets/train-1"));
+        assert!(glob.may_contain("datasets/train-1/shards"));
+        assert!(!glob.may_contain("datasets/eval"));
+        assert!(!glob.may_contain("datasets/train-1/meta"));
+        assert!(!glob.may_contain("datasets/train-1/shards/nested"));
+
+        let glob = Glob::new("datasets/**/*.tar").unwrap();
+        assert!(glob.may_contain("datasets/a/b/c"));
+    }
+}
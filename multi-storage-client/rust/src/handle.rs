@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use std::future::Future;
+use std::sync::Mutex;
+use tokio::task::{AbortHandle, JoinError, JoinHandle};
+
+use crate::bridge::future_into_py;
+use crate::runtime::get_runtime;
+
+/// The result of awaiting a download that was abandoned before it completed.
+#[pyclass(frozen)]
+pub struct RustDownloadCancelled;
+
+#[pymethods]
+impl RustDownloadCancelled {
+    fn __repr__(&self) -> &'static str {
+        "RustDownloadCancelled()"
+    }
+
+    fn __bool__(&self) -> bool {
+        false
+    }
+}
+
+/// A download running in the background that can be abandoned without persisting its output.
+#[pyclass]
+pub struct RustDownloadHandle {
+    abort: AbortHandle,
+    task: Mutex<Option<JoinHandle<PyResult<u64>>>>,
+}
+
+impl RustDownloadHandle {
+    pub fn spawn<F>(download: F) -> Self
+    where
+        F: Future<Output = PyResult<u64>> + Send + 'static,
+    {
+        let task = get_runtime().spawn(download);
+        RustDownloadHandle {
+            abort: task.abort_handle(),
+            task: Mutex::new(Some(task)),
+        }
+    }
+}
+
+fn resolve(py: Python<'_>, outcome: Result<PyResult<u64>, JoinError>) -> PyResult<Py<PyAny>> {
+    match outcome {
+        Ok(Ok(bytes_downloaded)) => Ok(bytes_downloaded.into_pyobject(py)?.into_any().unbind()),
+        Ok(Err(err)) => Err(err),
+        Err(err) if err.is_cancelled() => Ok(Py::new(py, RustDownloadCancelled)?.into_any()),
+        Err(err) => Err(PyRuntimeError::new_err(format!("Download task failed: {}", err))),
+    }
+}
+
+#[pymethods]
+impl RustDownloadHandle {
+    /// Stops scheduling chunks, cancels those in flight and deletes the temporary file.
+    ///
+    /// Awaiting the handle then returns `RustDownloadCancelled`. Has no effect once the download
+    /// has finished.
+    fn abandon(&self) {
+        self.abort.abort();
+    }
+
+    fn done(&self) -> bool {
+        self.abort.is_finished()
+    }
+
+    fn __await__<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let task = self
+            .task
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| PyRuntimeError::new_err("Download handle has already been awaited"))?;
+        let result = future_into_py(py, async move {
+            let outcome = task.await;
+            Python::attach(|py| resolve(py, outcome))
+        })?;
+        result.call_method0("__await__")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Once;
+    use std::time::Duration;
+
+    static INIT: Once = Once::new();
+
+    fn initialize_python() {
+        INIT.call_once(|| {
+            Python::initialize();
+        });
+    }
+
+    #[test]
+    fn test_abandon() {
+        initialize_python();
+
+        let pending = RustDownloadHandle::spawn(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(1)
+        });
+        pending.abandon();
+        let outcome = get_runtime().block_on(pending.task.lock().unwrap().take().unwrap());
+        Python::attach(|py| {
+            let value = resolve(py, outcome).unwrap();
+            assert!(value.bind(py).is_instance_of::<RustDownloadCancelled>());
+        });
+
+        // Abandoning a finished download keeps its result.
+        let finished = RustDownloadHandle::spawn(async { Ok(42) });
+        while !finished.done() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        finished.abandon();
+        let outcome = get_runtime().block_on(finished.task.lock().unwrap().take().unwrap());
+        Python::attach(|py| {
+            assert_eq!(resolve(py, outcome).unwrap().extract::<u64>(py).unwrap(), 42);
+        });
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use object_store::ClientOptions;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{ConfigValue, StorageError};
+
+/// object_store's own User-Agent, which it would send without ours. Backends recognize clients by it.
+const OBJECT_STORE_USER_AGENT: &str = "object_store/0.12.4";
+
+/// Headers that are computed per request, or signed, and would be wrong if sent with a fixed value.
+const RESERVED: [&str; 5] = [
+    "authorization",
+    "content-length",
+    "host",
+    "transfer-encoding",
+    "user-agent",
+];
+
+/// S3 and GCS reject `x-amz-*` and `x-goog-*` headers that aren't signed, and default headers are
+/// added after signing.
+const SIGNED_PREFIXES: [&str; 2] = ["x-amz-", "x-goog-"];
+
+static VERSION: PyOnceLock<String> = PyOnceLock::new();
+
+/// Version of the installed `multi-storage-client` package.
+fn msc_version() -> &'static str {
+    Python::attach(|py| {
+        VERSION.get_or_init(py, || {
+            py.import("importlib.metadata")
+                .and_then(|metadata| metadata.call_method1("version", ("multi-storage-client",)))
+                .and_then(|version| version.extract::<String>())
+                .unwrap_or_else(|_| "unknown".to_string())
+        })
+    })
+}
+
+/// Sent as `User-Agent`: object_store's, then ours with the package version, then `user_agent`.
+fn user_agent(configured: Option<&str>) -> String {
+    let user_agent = format!("{} multi-storage-client/{}", OBJECT_STORE_USER_AGENT, msc_version());
+    match configured {
+        Some(configured) => format!("{} {}", user_agent, configured),
+        None => user_agent,
+    }
+}
+
+/// Whether `value` only has visible ASCII characters, and spaces between them.
+fn is_visible_ascii(value: &str) -> bool {
+    !value.starts_with(' ') && !value.ends_with(' ') && value.bytes().all(|b| b == b' ' || b.is_ascii_graphic())
+}
+
+/// Parses the `default_headers` config, sent with every request.
+fn default_headers(headers: &BTreeMap<String, String>) -> Result<HeaderMap, StorageError> {
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        let invalid = |reason: &str| {
+            StorageError::ConfigError(format!(
+                "Invalid rust_client default_headers entry {}: {}",
+                name, reason
+            ))
+        };
+        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid("not a valid header name"))?;
+        if RESERVED.contains(&header_name.as_str()) {
+            return Err(invalid("the header is set by the client"));
+        }
+        if SIGNED_PREFIXES
+            .iter()
+            .any(|prefix| header_name.as_str().starts_with(prefix))
+        {
+            return Err(invalid("x-amz-* and x-goog-* headers have to be signed"));
+        }
+        if !is_visible_ascii(value) {
+            return Err(invalid("the value must be visible ASCII"));
+        }
+        header_map.insert(
+            header_name,
+            HeaderValue::from_str(value).map_err(|_| invalid("invalid value"))?,
+        );
+    }
+    Ok(header_map)
+}
+
+/// Adds the `default_headers` and the User-Agent, with the `user_agent` config appended, to `options`.
+pub fn client_options(
+    mut options: ClientOptions,
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<ClientOptions, StorageError> {
+    match configs.get("default_headers") {
+        None => {}
+        Some(ConfigValue::Map(headers)) => options = options.with_default_headers(default_headers(headers)?),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client default_headers must be a dict, got {}.",
+                other.to_string()
+            )))
+        }
+    }
+    let configured = configs.get("user_agent").map(|user_agent| user_agent.to_string());
+    if configured
+        .as_deref()
+        .is_some_and(|configured| !is_visible_ascii(configured))
+    {
+        return Err(StorageError::ConfigError(
+            "rust_client user_agent must be visible ASCII.".to_string(),
+        ));
+    }
+    let user_agent = HeaderValue::from_str(&user_agent(configured.as_deref()))
+        .map_err(|err| StorageError::ConfigError(format!("Invalid rust_client user_agent: {}", err)))?;
+    Ok(options.with_user_agent(user_agent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Once;
+
+    static INIT: Once = Once::new();
+
+    fn initialize_python() {
+        INIT.call_once(|| {
+            Python::initialize();
+        });
+    }
+
+    fn headers(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_headers() {
+        let header_map = default_headers(&headers(&[("X-NV-Team", "storage"), ("x-trace", "a b")])).unwrap();
+        assert_eq!(header_map.get("x-nv-team").unwrap(), "storage");
+        assert_eq!(header_map.len(), 2);
+
+        for entries in [
+            [("Authorization", "Bearer token")],
+            [("content-length", "1")],
+            [("x-amz-acl", "private")],
+            [("bad header", "value")],
+            [("x-nv-team", "caf\u{e9}")],
+            [("x-nv-team", "line\nbreak")],
+        ] {
+            assert!(matches!(
+                default_headers(&headers(&entries)),
+                Err(StorageError::ConfigError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_user_agent() {
+        initialize_python();
+        let user_agent = user_agent(Some("team-a/2.0"));
+        assert!(user_agent.starts_with("object_store/"));
+        assert!(user_agent.contains(" multi-storage-client/"));
+        assert!(user_agent.ends_with(" team-a/2.0"));
+    }
+}
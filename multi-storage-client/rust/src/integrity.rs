@@ -0,0 +1,272 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::header::HeaderMap;
+use md5::{Digest, Md5};
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use std::cell::Cell;
+use std::future::Future;
+use std::io::Read;
+use std::path::{Path as StdPath, PathBuf};
+use std::sync::Arc;
+
+use crate::logging;
+use crate::StorageError;
+
+const READ_BUFFER_SIZE: usize = 1024 * 1024;
+
+tokio::task_local! {
+    static ENCRYPTED: Cell<bool>;
+}
+
+/// Returns the MD5 digest an ETag stands for, if it is a plain MD5.
+///
+/// ETags of multipart uploads (`<md5 of part md5s>-<parts>`) and of stores that don't use MD5 return `None`.
+pub fn etag_md5(e_tag: Option<&str>) -> Option<String> {
+    let e_tag = e_tag?.trim_matches('"');
+    (e_tag.len() == 32 && e_tag.bytes().all(|b| b.is_ascii_hexdigit())).then(|| e_tag.to_ascii_lowercase())
+}
+
+/// Runs `fut` and returns whether a response it received reports an object encrypted with a KMS
+/// or customer key, whose ETag isn't its MD5 even when it looks like one.
+pub async fn capture_encryption<F: Future>(fut: F) -> (F::Output, bool) {
+    ENCRYPTED
+        .scope(Cell::new(false), async {
+            let output = fut.await;
+            (output, ENCRYPTED.with(Cell::get))
+        })
+        .await
+}
+
+/// Records whether a response reports SSE-KMS or SSE-C for the enclosing `capture_encryption`, if any.
+pub fn record(headers: &HeaderMap) {
+    let kms = headers
+        .get("x-amz-server-side-encryption")
+        .and_then(|sse| sse.to_str().ok())
+        .is_some_and(|sse| sse.starts_with("aws:kms"));
+    if kms || headers.contains_key("x-amz-server-side-encryption-customer-algorithm") {
+        let _ = ENCRYPTED.try_with(|encrypted| encrypted.set(true));
+    }
+}
+
+fn md5_file(path: &StdPath) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Md5::new();
+    let mut buffer = vec![0; READ_BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Hex MD5 of the file at `path`, computed on a blocking thread.
+pub async fn file_md5(path: PathBuf) -> Result<String, StorageError> {
+    tokio::task::spawn_blocking(move || md5_file(&path))
+        .await
+        .map_err(|e| StorageError::ObjectStoreError(format!("MD5 task failed: {}", e)))?
+        .map_err(StorageError::from)
+}
+
+/// Hex MD5 of `data`, computed on a blocking thread.
+pub async fn bytes_md5(data: bytes::Bytes) -> Result<String, StorageError> {
+    tokio::task::spawn_blocking(move || format!("{:x}", Md5::digest(&data)))
+        .await
+        .map_err(|e| StorageError::ObjectStoreError(format!("MD5 task failed: {}", e)))
+}
+
+/// Checks the object just uploaded to `location` against the `sent` bytes with a `head`: by size,
+/// and by the MD5 `md5` computes of the source when its ETag is a plain MD5, as for single-part
+/// uploads. Only the size is checked without `md5_etags` or when the `head` reports SSE-KMS or
+/// SSE-C. An object that doesn't match is deleted. Returns the object's metadata.
+pub async fn verify_upload(
+    store: &Arc<dyn ObjectStore>,
+    location: &Path,
+    sent: u64,
+    md5: impl Future<Output = Result<String, StorageError>>,
+    md5_etags: bool,
+) -> Result<ObjectMeta, StorageError> {
+    let (meta, encrypted) = capture_encryption(store.head(location)).await;
+    let meta = meta?;
+    let mismatch = if meta.size != sent {
+        Some(format!(
+            "Size check failed for {}: sent {} bytes, the store reports {}",
+            location, sent, meta.size
+        ))
+    } else if let Some(expected) = etag_md5(meta.e_tag.as_deref()).filter(|_| md5_etags && !encrypted) {
+        let actual = md5.await?;
+        (actual != expected)
+            .then(|| format!("MD5 check failed for {}: sent {}, the store reports {}", location, actual, expected))
+    } else {
+        None
+    };
+    let Some(mismatch) = mismatch else {
+        return Ok(meta);
+    };
+    if let Err(err) = store.delete(location).await {
+        let error = logging::chain(&err);
+        tracing::warn!(
+            target: logging::TARGET,
+            key = %location,
+            error,
+            "Can't delete the object that failed verification"
+        );
+    }
+    Err(StorageError::IntegrityError(mismatch))
+}
+
+/// Checks that the `written` bytes downloaded to `path` match `meta`, by MD5 when its ETag is a
+/// plain MD5 and `md5_etags` is set, and by size otherwise. Returns the method used, `"md5"` or
+/// `"size"`.
+pub async fn verify_download(
+    path: PathBuf,
+    written: u64,
+    meta: &ObjectMeta,
+    md5_etags: bool,
+) -> Result<&'static str, StorageError> {
+    if written != meta.size {
+        return Err(StorageError::IntegrityError(format!(
+            "Size check failed for {}: expected {} bytes, received {}",
+            meta.location, meta.size, written
+        )));
+    }
+    let Some(expected) = etag_md5(meta.e_tag.as_deref()).filter(|_| md5_etags) else {
+        return Ok("size");
+    };
+    let actual = file_md5(path).await?;
+    if actual != expected {
+        return Err(StorageError::IntegrityError(format!(
+            "MD5 check failed for {}: expected {}, computed {}",
+            meta.location, expected, actual
+        )));
+    }
+    Ok("md5")
+}
+
+/// Whether the file at `path` already holds the object described by `meta`: the same size and,
+/// when its ETag is a plain MD5 and `md5_etags` is set, the same MD5.
+pub async fn matches_local(path: PathBuf, meta: &ObjectMeta, md5_etags: bool) -> Result<bool, StorageError> {
+    match tokio::fs::metadata(&path).await {
+        Ok(metadata) if metadata.is_file() && metadata.len() == meta.size => {}
+        Ok(_) => return Ok(false),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.into()),
+    }
+    let Some(expected) = etag_md5(meta.e_tag.as_deref()).filter(|_| md5_etags) else {
+        return Ok(true);
+    };
+    Ok(file_md5(path).await? == expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    fn meta(size: u64, e_tag: Option<&str>) -> ObjectMeta {
+        ObjectMeta {
+            location: Path::from("object"),
+            last_modified: chrono::Utc::now(),
+            size,
+            e_tag: e_tag.map(str::to_string),
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_etag_md5() {
+        assert_eq!(
+            etag_md5(Some("\"5D41402ABC4B2A76B9719D911017C592\"")).as_deref(),
+            Some("5d41402abc4b2a76b9719d911017c592")
+        );
+        assert_eq!(etag_md5(Some("\"5d41402abc4b2a76b9719d911017c592-3\"")), None);
+        assert_eq!(etag_md5(Some("CJrJ1M3h")), None);
+        assert_eq!(etag_md5(None), None);
+    }
+
+    #[tokio::test]
+    async fn test_capture_encryption() {
+        let mut kms = HeaderMap::new();
+        kms.insert("x-amz-server-side-encryption", HeaderValue::from_static("aws:kms"));
+        let mut customer = HeaderMap::new();
+        customer.insert("x-amz-server-side-encryption-customer-algorithm", HeaderValue::from_static("AES256"));
+        let mut s3_managed = HeaderMap::new();
+        s3_managed.insert("x-amz-server-side-encryption", HeaderValue::from_static("AES256"));
+
+        assert!(capture_encryption(async { record(&kms) }).await.1);
+        assert!(capture_encryption(async { record(&customer) }).await.1);
+        assert!(!capture_encryption(async { record(&s3_managed) }).await.1);
+        assert!(!capture_encryption(async {}).await.1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_download() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hello.txt");
+        std::fs::write(&path, b"hello").unwrap();
+        let md5 = Some("\"5d41402abc4b2a76b9719d911017c592\"");
+
+        assert_eq!(verify_download(path.clone(), 5, &meta(5, md5), true).await.unwrap(), "md5");
+        assert_eq!(verify_download(path.clone(), 5, &meta(5, Some("abc-2")), true).await.unwrap(), "size");
+        assert!(matches!(
+            verify_download(path.clone(), 4, &meta(5, None), true).await,
+            Err(StorageError::IntegrityError(_))
+        ));
+
+        std::fs::write(&path, b"jello").unwrap();
+        assert!(matches!(
+            verify_download(path.clone(), 5, &meta(5, md5), true).await,
+            Err(StorageError::IntegrityError(_))
+        ));
+        // The ETags of encrypted objects look like MD5s but aren't, so only the size is checked.
+        assert_eq!(verify_download(path, 5, &meta(5, md5), false).await.unwrap(), "size");
+    }
+
+    #[tokio::test]
+    async fn test_verify_upload() {
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let location = Path::from("object");
+        store.put(&location, "hello".into()).await.unwrap();
+
+        // InMemory ETags aren't MD5s, so only the size is checked.
+        let meta = verify_upload(&store, &location, 5, bytes_md5("jello".into()), true).await.unwrap();
+        assert_eq!(meta.size, 5);
+
+        let err = verify_upload(&store, &location, 6, bytes_md5("hello!".into()), true).await.unwrap_err();
+        assert!(matches!(err, StorageError::IntegrityError(_)));
+        assert!(matches!(store.head(&location).await, Err(object_store::Error::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_matches_local() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hello.txt");
+        let md5 = Some("\"5d41402abc4b2a76b9719d911017c592\"");
+        assert!(!matches_local(path.clone(), &meta(5, md5), true).await.unwrap());
+
+        std::fs::write(&path, b"hello").unwrap();
+        assert!(matches_local(path.clone(), &meta(5, md5), true).await.unwrap());
+        assert!(matches_local(path.clone(), &meta(5, Some("abc-2")), true).await.unwrap());
+        assert!(!matches_local(path.clone(), &meta(6, None), true).await.unwrap());
+
+        std::fs::write(&path, b"jello").unwrap();
+        assert!(!matches_local(path.clone(), &meta(5, md5), true).await.unwrap());
+        assert!(matches_local(path, &meta(5, md5), false).await.unwrap());
+    }
+}
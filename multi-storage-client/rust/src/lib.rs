@@ -14,43 +14,147 @@
 // limitations under the License.
 
 use chrono::{DateTime, Utc};
-use object_store::aws::{AmazonS3Builder, Checksum};
+use futures::StreamExt;
+#[cfg(feature = "aws")]
+use object_store::aws::{AmazonS3Builder, AmazonS3ConfigKey, Checksum, S3EncryptionConfigKey};
+#[cfg(feature = "gcp")]
 use object_store::gcp::GoogleCloudStorageBuilder;
+#[cfg(feature = "http")]
+use object_store::http::HttpBuilder;
 use object_store::RetryConfig;
 use object_store::BackoffConfig;
-use object_store::{path::Path, ObjectMeta, ObjectStore, PutPayload, WriteMultipart};
+use object_store::{
+    path::Path, Attribute, Attributes, GetOptions, GetRange, ObjectMeta, ObjectStore, PutMode, PutMultipartOpts, PutOptions, PutPayload, PutResult,
+    UpdateVersion, WriteMultipart,
+};
+use object_store::client::{HttpError, HttpErrorKind};
 use object_store::ClientOptions;
-use object_store::limit::LimitStore;
+use object_store::multipart::MultipartStore;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyModule};
+use pyo3::types::{PyDict, PyMemoryView, PyModule};
 use pyo3::{Py, PyAny};
-use pyo3::exceptions::PyException;
-use pyo3_async_runtimes::tokio::future_into_py;
+use pyo3::exceptions::{PyException, PyFileExistsError};
 use pyo3_bytes::PyBytes;
-use std::collections::{HashMap, VecDeque};
+use pyo3_async_runtimes::TaskLocals;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error as StdError;
 use std::path::Path as StdPath;
-use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
 use std::time::Duration;
 use tempfile::NamedTempFile;
 use thiserror::Error;
 use tokio::fs;
-use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 use tokio::sync::{mpsc, Semaphore};
 use tokio::task::JoinSet;
-use http::StatusCode;
+use http::{HeaderName, HeaderValue, StatusCode};
+#[cfg(feature = "aws")]
 use aws_smithy_http_client::{tls, Builder};
+#[cfg(feature = "aws")]
 use aws_config::BehaviorVersion;
-
+#[cfg(feature = "aws")]
+use aws_credential_types::provider::ProvideCredentials;
+
+mod abort;
+#[cfg(feature = "aws")]
+mod access_point;
+mod append;
+#[cfg(feature = "aws")]
+mod aws_chunked;
+mod benchmark;
+mod bridge;
+mod buffer;
+mod certificates;
+mod checksum;
+mod circuit;
+mod compress;
 mod credentials;
+mod directory;
+mod failed_response;
+#[cfg(feature = "aws")]
+mod failover;
+mod glob;
+mod handle;
+mod headers;
+mod integrity;
+mod limit;
+mod listing;
+mod local;
+mod local_io;
+mod logging;
+mod metacache;
+mod metrics;
+mod mmap;
+mod presign;
+mod progress;
+mod ratelimit;
+#[cfg(any(feature = "aws", feature = "http"))]
+mod readonly;
+#[cfg(any(feature = "aws", feature = "gcp"))]
+mod reauth;
+mod request_tag;
+mod resumable;
+mod retry;
+mod runtime;
+mod samples;
+mod server_time;
+mod singleflight;
+mod storage_class;
+mod stream;
+mod sync;
+mod telemetry;
 mod types;
 
-use credentials::{AwsCredentialsProvider, AwsSdkCredentialsProvider, GcpCredentialsProvider};
-use types::{ByteRangeLike, ListResult, ObjectMetadata, RustRetryConfig};
+#[cfg(feature = "aws")]
+use access_point::AccessPointArn;
+#[cfg(feature = "aws")]
+use credentials::{AwsCredentialsProvider, AwsSdkCredentialsProvider};
+#[cfg(any(feature = "aws", feature = "gcp"))]
+use credentials::ForceRefresh;
+#[cfg(feature = "gcp")]
+use credentials::GcpCredentialsProvider;
+use bridge::{blocking, future_into_py};
+use directory::DirectoryWalk;
+#[cfg(feature = "aws")]
+use failover::FailoverStore;
+use handle::{RustDownloadCancelled, RustDownloadHandle};
+use glob::Glob;
+use listing::{list_tree, walk_tree, ListOptions, ObjectFilter, RustListIterator, Traversal, Usage};
+use limit::SharedLimitStore;
+use local::StreamingUpload;
+#[cfg(unix)]
+use local_io::PositionalFile;
+use local_io::{LocalFile, LocalIo};
+use presign::Presigner;
+use progress::Progress;
+use ratelimit::RateLimitedStore;
+use checksum::ChecksumStore;
+use circuit::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerStore};
+use compress::Compression;
+use benchmark::{BenchmarkDirection, BenchmarkOptions, DEFAULT_BENCHMARK_PREFIX_PATTERN};
+use retry::{run_with_retry, with_deadline, Deadline, RetryPolicy};
+use runtime::get_runtime;
+use metacache::{NegativeCache, NegativeCacheStore};
+use metrics::Metrics;
+use singleflight::{dedupe, SingleFlight};
+use sync::{sync_to_local, SyncOptions};
+use stream::{
+    ObjectReaderState, ObjectWriterState, RustGetStream, RustObjectReader, RustObjectWriter, RustReadStream,
+    RustWriteStream,
+};
+use types::{
+    BenchmarkPhaseStats, BenchmarkReport, ByteRangeLike, DeleteResult, DirectoryDownloadResult, DirectoryUploadResult,
+    ListResult, ListStats, ObjectMetadata, PartInfo, RustRetryConfig, SyncResult, TransferResult, TransferStats,
+    UsageSummary,
+};
 
 pyo3::create_exception!(multistorageclient_rust, RustRetryableError, PyException);
 pyo3::create_exception!(multistorageclient_rust, RustClientError, PyException);
+pyo3::create_exception!(multistorageclient_rust, RustAlreadyExistsError, PyFileExistsError);
+pyo3::create_exception!(multistorageclient_rust, RustCircuitOpenError, RustRetryableError);
+pyo3::create_exception!(multistorageclient_rust, RustIntegrityError, RustClientError);
+pyo3::create_exception!(multistorageclient_rust, RustPreconditionFailedError, RustClientError);
+pyo3::create_exception!(multistorageclient_rust, RustThrottledError, RustRetryableError);
 
 #[derive(Error, Debug)]
 pub enum StorageError {
@@ -68,25 +172,139 @@ pub enum StorageError {
     RetryExhaustedError(String),
     #[error("HTTP error: {0}")]
     HttpError(String, Option<u16>),
+    #[error("Deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+    #[error("Object already exists: {0}")]
+    AlreadyExists(String),
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+    #[error("{0}")]
+    CircuitOpen(String),
+    #[error("Object not found: {0}")]
+    NotFound(String),
+    #[error("Integrity check failed: {0}")]
+    IntegrityError(String),
+    #[error("Throttled: {0}")]
+    Throttled(String, Option<Duration>),
+    #[error("{0}")]
+    Annotated(Box<StorageError>, Box<ErrorDetails>),
+}
+
+/// Diagnostics gathered while running an operation, exposed as attributes of the raised Python exception.
+#[derive(Debug, Default, Clone)]
+pub struct ErrorDetails {
+    pub attempts: u32,
+    pub retry_delay: Duration,
+    pub elapsed: Duration,
+    pub first_error: Option<String>,
+    pub last_error: Option<String>,
+}
+
+impl ErrorDetails {
+    const ATTRIBUTES: [&'static str; 5] = ["attempts", "retry_delay_secs", "elapsed_secs", "first_error", "last_error"];
+
+    fn set_attributes(&self, py: Python<'_>, err: &PyErr) {
+        let value = err.value(py);
+        let _ = value.setattr("attempts", self.attempts);
+        let _ = value.setattr("retry_delay_secs", self.retry_delay.as_secs_f64());
+        let _ = value.setattr("elapsed_secs", self.elapsed.as_secs_f64());
+        let _ = value.setattr("first_error", self.first_error.as_deref());
+        let _ = value.setattr("last_error", self.last_error.as_deref());
+    }
+}
+
+impl StorageError {
+    /// Returns the underlying error, looking through any `Annotated` wrappers.
+    pub fn root(&self) -> &StorageError {
+        match self {
+            StorageError::Annotated(inner, _) => inner.root(),
+            other => other,
+        }
+    }
+
+    /// Returns the HTTP status of the response the error came from, when the error itself says.
+    pub fn status_code(&self) -> Option<u16> {
+        match self.root() {
+            StorageError::HttpError(_, status) => *status,
+            StorageError::AlreadyExists(_) => Some(StatusCode::CONFLICT.as_u16()),
+            StorageError::PreconditionFailed(_) => Some(StatusCode::PRECONDITION_FAILED.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Returns an equivalent error for another caller of a shared operation.
+    ///
+    /// Wrapped I/O and persist errors keep their kind and message but not their source.
+    pub fn duplicate(&self) -> StorageError {
+        match self {
+            StorageError::ObjectStoreError(msg) => StorageError::ObjectStoreError(msg.clone()),
+            StorageError::IoError(err) => StorageError::IoError(std::io::Error::new(err.kind(), err.to_string())),
+            StorageError::ConfigError(msg) => StorageError::ConfigError(msg.clone()),
+            StorageError::InvalidPathError(msg) => StorageError::InvalidPathError(msg.clone()),
+            StorageError::TempFileError(err) => {
+                StorageError::IoError(std::io::Error::new(err.error.kind(), err.to_string()))
+            }
+            StorageError::RetryExhaustedError(msg) => StorageError::RetryExhaustedError(msg.clone()),
+            StorageError::HttpError(msg, status) => StorageError::HttpError(msg.clone(), *status),
+            StorageError::DeadlineExceeded(msg) => StorageError::DeadlineExceeded(msg.clone()),
+            StorageError::AlreadyExists(msg) => StorageError::AlreadyExists(msg.clone()),
+            StorageError::PreconditionFailed(msg) => StorageError::PreconditionFailed(msg.clone()),
+            StorageError::CircuitOpen(msg) => StorageError::CircuitOpen(msg.clone()),
+            StorageError::NotFound(msg) => StorageError::NotFound(msg.clone()),
+            StorageError::IntegrityError(msg) => StorageError::IntegrityError(msg.clone()),
+            StorageError::Throttled(msg, retry_after) => StorageError::Throttled(msg.clone(), *retry_after),
+            StorageError::Annotated(inner, details) => StorageError::Annotated(Box::new(inner.duplicate()), details.clone()),
+        }
+    }
+
+    pub fn annotate(self, details: ErrorDetails) -> StorageError {
+        match self {
+            StorageError::Annotated(inner, _) => StorageError::Annotated(inner, Box::new(details)),
+            other => StorageError::Annotated(Box::new(other), Box::new(details)),
+        }
+    }
 }
 
+/// Matches the status in the message of a generic 4xx error, e.g. "Client error with status 401"
+/// or "non-2xx status code: 403".
+static GENERIC_STATUS_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"status(?: code)?:? (4\d\d)\b").unwrap());
+
 /// Extracts an HTTP status code from an `object_store::Error`.
 ///
-/// Maps specific `object_store::Error` variants (e.g., `NotFound`, `PermissionDenied`)
-/// to their corresponding HTTP status codes (e.g., 404, 403).
-/// Returns `None` if the error doesn't map to a specific status code.
-fn extract_status_code(err: &object_store::Error) -> Option<u16> {
-    match err {
-        object_store::Error::NotFound { .. } => return Some(StatusCode::NOT_FOUND.as_u16()),
-        object_store::Error::NotModified { .. } => return Some(StatusCode::NOT_MODIFIED.as_u16()),
-        object_store::Error::Precondition { .. } => return Some(StatusCode::PRECONDITION_FAILED.as_u16()),
-        object_store::Error::AlreadyExists { .. } => return Some(StatusCode::CONFLICT.as_u16()),
-        object_store::Error::PermissionDenied { .. } => return Some(StatusCode::FORBIDDEN.as_u16()),
-        object_store::Error::Unauthenticated { .. } => return Some(StatusCode::UNAUTHORIZED.as_u16()),
-        _ => {}
-    }
+/// Specific variants (e.g. `NotFound`, `PermissionDenied`) map to their status code (e.g. 404,
+/// 403). A `Generic` error, such as a failed listing or token request, only carries the status in
+/// its `message`, where a 4xx code is looked up. Returns `None` otherwise.
+fn extract_status_code(err: &object_store::Error, message: &str) -> Option<u16> {
+    let status = match err {
+        object_store::Error::NotFound { .. } => StatusCode::NOT_FOUND,
+        object_store::Error::NotModified { .. } => StatusCode::NOT_MODIFIED,
+        object_store::Error::Precondition { .. } => StatusCode::PRECONDITION_FAILED,
+        object_store::Error::AlreadyExists { .. } => StatusCode::CONFLICT,
+        object_store::Error::PermissionDenied { .. } => StatusCode::FORBIDDEN,
+        object_store::Error::Unauthenticated { .. } => StatusCode::UNAUTHORIZED,
+        object_store::Error::Generic { .. } => {
+            return GENERIC_STATUS_PATTERN.captures(message).and_then(|captures| captures[1].parse().ok())
+        }
+        _ => return None,
+    };
+    Some(status.as_u16())
+}
 
-    None
+/// Whether `err` failed to send the request or lost the response, judged by the kind of the
+/// `HttpError` in its source chain.
+fn is_connection_error(err: &object_store::Error) -> bool {
+    let mut current = err.source();
+    while let Some(source) = current {
+        if let Some(http_error) = source.downcast_ref::<HttpError>() {
+            return matches!(
+                http_error.kind(),
+                HttpErrorKind::Connect | HttpErrorKind::Request | HttpErrorKind::Timeout | HttpErrorKind::Interrupted
+            );
+        }
+        current = source.source();
+    }
+    false
 }
 
 /// Formats the full error chain into a readable string.
@@ -110,27 +328,45 @@ impl From<object_store::Error> for StorageError {
     ///
     /// This conversion:
     /// 1. Formats the entire error chain for better debugging.
-    /// 2. Extracts HTTP status codes for specific error types (NotFound, PermissionDenied, etc.).
-    /// 3. Classifies specific connection and credential errors as `RetryExhaustedError`.
-    /// 4. Wraps other errors as generic `ObjectStoreError`.
+    /// 2. Maps `AlreadyExists`, `Precondition` and throttling (429 or 503) responses to their own
+    ///    variants.
+    /// 3. Extracts HTTP status codes for other specific error types (NotFound, PermissionDenied, etc.)
+    ///    and for generic 4xx errors.
+    /// 4. Classifies connection errors, and credential refresh failures, as `RetryExhaustedError`.
+    /// 5. Wraps other errors as generic `ObjectStoreError`.
     fn from(err: object_store::Error) -> Self {
-        let error_msg = format_error_chain(&err);
-
-        // Attempt to extract status code from the error chain if it's an HTTP error
-        let status_code = extract_status_code(&err);
+        if let Some(open) = circuit::circuit_open(&err) {
+            return StorageError::CircuitOpen(open.to_string());
+        }
+        if let Some(mismatch) = checksum::mismatch(&err) {
+            return StorageError::IntegrityError(mismatch.to_string());
+        }
 
-        if let Some(code) = status_code {
-            return StorageError::HttpError(error_msg, Some(code))
-        } else {
-            // Errors cannot be classified as retryable
-            if error_msg.contains("HTTP error: error sending request") ||
-                error_msg.contains("HTTP error: request or response body error") ||
-                error_msg.contains("Failed to refresh credentials") {
-                return StorageError::RetryExhaustedError(error_msg)
+        let error_msg = format_error_chain(&err);
+        // Throttling responses are retried inside object_store and surface as generic errors, so
+        // they are recognised by the status of the last failed response instead.
+        let failed = failed_response::last();
+        let retry_after = failed.as_ref().and_then(|failed| failed.retry_after);
+
+        match extract_status_code(&err, &error_msg) {
+            _ if matches!(err, object_store::Error::AlreadyExists { .. }) => StorageError::AlreadyExists(error_msg),
+            Some(412) => StorageError::PreconditionFailed(error_msg),
+            Some(429) => StorageError::Throttled(error_msg, retry_after),
+            None if failed.is_some_and(|failed| failed_response::is_throttling(failed.status)) => {
+                StorageError::Throttled(error_msg, retry_after)
+            }
+            Some(code) => StorageError::HttpError(error_msg, Some(code)),
+            None if is_connection_error(&err) => StorageError::RetryExhaustedError(error_msg),
+            // Errors without a typed source: sources boxed by the credential providers, or
+            // connection errors whose HttpError isn't exposed as a source.
+            None if error_msg.contains("HTTP error: error sending request")
+                || error_msg.contains("HTTP error: request or response body error")
+                || error_msg.contains("Failed to refresh credentials") =>
+            {
+                StorageError::RetryExhaustedError(error_msg)
             }
-
             // Let the Python layer to handle the extra retry
-            return StorageError::ObjectStoreError(error_msg)
+            None => StorageError::ObjectStoreError(error_msg),
         }
     }
 }
@@ -142,9 +378,28 @@ impl From<StorageError> for PyErr {
     /// - `ConfigError` -> `ValueError`
     /// - `RetryExhaustedError` -> `RustRetryableError` (custom Python exception)
     /// - `HttpError` -> `RustClientError` (custom Python exception with status code)
+    /// - `DeadlineExceeded` -> `TimeoutError`
+    /// - `AlreadyExists` -> `RustAlreadyExistsError` (a `FileExistsError`)
+    /// - `PreconditionFailed` -> `RustPreconditionFailedError` (a `RustClientError` with status code 412)
+    /// - `CircuitOpen` -> `RustCircuitOpenError` (a `RustRetryableError`)
+    /// - `NotFound` -> `FileNotFoundError`
+    /// - `IntegrityError` -> `RustIntegrityError` (a `RustClientError`)
+    /// - `Throttled` -> `RustThrottledError` (a `RustRetryableError` with `retry_after_secs`)
+    /// - `Annotated` -> the mapping of the wrapped error, with the `ErrorDetails` set as attributes
     /// - Others -> `RuntimeError`
+    ///
+    /// Errors raised inside a tagged operation carry the tag as a `request_tag` attribute. Errors
+    /// from a failed response or after retries also carry its `status_code`, `request_id` and
+    /// the `retry_count`.
     fn from(err: StorageError) -> PyErr {
-        match err {
+        let failed = failed_response::last();
+        let status_code = err.status_code().or(failed.as_ref().map(|failed| failed.status));
+        let request_id = failed.and_then(|failed| failed.request_id);
+        let retry_count = match &err {
+            StorageError::Annotated(_, details) => details.attempts.saturating_sub(1),
+            other => retry::inner_retries(&other.to_string()),
+        };
+        let py_err = match err {
             StorageError::ConfigError(msg) => {
                 pyo3::exceptions::PyValueError::new_err(msg)
             }
@@ -157,21 +412,74 @@ impl From<StorageError> for PyErr {
             StorageError::InvalidPathError(msg) => {
                 pyo3::exceptions::PyValueError::new_err(msg)
             }
+            StorageError::DeadlineExceeded(msg) => {
+                pyo3::exceptions::PyTimeoutError::new_err(msg)
+            }
+            StorageError::AlreadyExists(msg) => {
+                RustAlreadyExistsError::new_err(msg)
+            }
+            StorageError::PreconditionFailed(msg) => {
+                RustPreconditionFailedError::new_err((msg, StatusCode::PRECONDITION_FAILED.as_u16()))
+            }
+            StorageError::CircuitOpen(msg) => {
+                RustCircuitOpenError::new_err(msg)
+            }
+            StorageError::NotFound(msg) => {
+                pyo3::exceptions::PyFileNotFoundError::new_err(msg)
+            }
+            StorageError::IntegrityError(msg) => {
+                RustIntegrityError::new_err(msg)
+            }
+            StorageError::Throttled(msg, retry_after) => {
+                let err = RustThrottledError::new_err(msg);
+                Python::attach(|py| {
+                    let _ = err.value(py).setattr("retry_after_secs", retry_after.map(|wait| wait.as_secs_f64()));
+                });
+                err
+            }
+            StorageError::Annotated(inner, details) => {
+                let err = PyErr::from(*inner);
+                Python::attach(|py| details.set_attributes(py, &err));
+                err
+            }
             _ => {
                 pyo3::exceptions::PyRuntimeError::new_err(err.to_string())
             }
+        };
+        if let Some(tag) = request_tag::current() {
+            Python::attach(|py| {
+                let _ = py_err.value(py).setattr("request_tag", tag.to_str().ok());
+            });
+        }
+        if status_code.is_some() || request_id.is_some() || retry_count > 0 {
+            Python::attach(|py| {
+                let value = py_err.value(py);
+                let _ = value.setattr("status_code", status_code);
+                let _ = value.setattr("request_id", request_id);
+                let _ = value.setattr("retry_count", retry_count);
+            });
         }
+        py_err
     }
 }
 
 // Multipart upload and download default settings
 const DEFAULT_MULTIPART_CHUNKSIZE: usize = 32 * 1024 * 1024;
 const DEFAULT_MAX_CONCURRENCY: usize = 8;
+const DEFAULT_STREAM_CHUNK_SIZE: usize = 8 * 1024 * 1024;
 
 const S3_MAX_MULTIPART_PARTS: u64 = 10_000;
 const S3_MIN_PART_SIZE_BYTES: usize = 5 * 1024 * 1024;
 const S3_MAX_PART_SIZE_BYTES: u64 = 5 * 1024 * 1024 * 1024;
 
+/// `return_stats` replaces the byte count just like `detailed` does, so only one of them can be asked for.
+fn check_return_stats(detailed: bool, return_stats: bool) -> Result<(), StorageError> {
+    if detailed && return_stats {
+        return Err(StorageError::ConfigError("detailed and return_stats cannot be combined".to_string()));
+    }
+    Ok(())
+}
+
 fn multipart_safe_chunk_size(object_size: u64, requested_chunk_size: usize) -> Result<usize, StorageError> {
     let max_object_multipart: u64 = S3_MAX_MULTIPART_PARTS * S3_MAX_PART_SIZE_BYTES;
     if object_size > max_object_multipart {
@@ -212,15 +520,298 @@ fn get_timeout_secs(configs: &HashMap<String, ConfigValue>, key: &str, default:
         .unwrap_or(default)
 }
 
-fn parse_checksum_algorithm(configs: &HashMap<String, ConfigValue>) -> Result<Option<Checksum>, StorageError> {
+/// Applies the connection pool and HTTP version configs to `client_options`. Without them the pool
+/// keeps idle connections for `DEFAULT_POOL_IDLE_TIMEOUT` and HTTP/2 is negotiated over TLS.
+fn connection_options(
+    mut client_options: ClientOptions,
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<ClientOptions, StorageError> {
+    let pool_idle_timeout_secs = get_timeout_secs(configs, "pool_idle_timeout", DEFAULT_POOL_IDLE_TIMEOUT);
+    client_options = client_options.with_pool_idle_timeout(Duration::from_secs(pool_idle_timeout_secs));
+
+    if configs.contains_key("pool_max_idle_per_host") {
+        let max_idle = get_timeout_secs(configs, "pool_max_idle_per_host", usize::MAX as u64);
+        client_options = client_options.with_pool_max_idle_per_host(max_idle as usize);
+    }
+
+    let http2 = match configs.get("http2") {
+        None => None,
+        Some(ConfigValue::Boolean(b)) => Some(*b),
+        Some(ConfigValue::String(s)) if s.parse::<bool>().is_ok() => s.parse().ok(),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client http2 must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    match http2 {
+        Some(true) => client_options = client_options.with_http2_only(),
+        Some(false) => client_options = client_options.with_http1_only(),
+        None => {}
+    }
+
+    if configs.contains_key("http2_keep_alive_secs") {
+        let interval_secs = get_timeout_secs(configs, "http2_keep_alive_secs", 0);
+        client_options = client_options.with_http2_keep_alive_interval(Duration::from_secs(interval_secs));
+    }
+
+    // object_store builds the HTTP client itself and doesn't expose TCP keepalive.
+    if let Some(value) = configs.get("tcp_keepalive_secs") {
+        return Err(StorageError::ConfigError(format!(
+            "rust_client tcp_keepalive_secs '{}' is not supported: the HTTP client doesn't expose TCP keepalive. \
+             Use http2_keep_alive_secs instead, which pings idle HTTP/2 connections.",
+            value.to_string()
+        )));
+    }
+    Ok(client_options)
+}
+
+/// Applies the `retry_*` config keys on top of `retry_config`, or the defaults without one.
+fn resolve_retry_config(configs: &HashMap<String, ConfigValue>, retry_config: Option<&RustRetryConfig>) -> RetryConfig {
+    let mut resolved = get_retry_config(retry_config);
+    resolved.max_retries = get_timeout_secs(configs, "retry_max_attempts", resolved.max_retries as u64) as usize;
+    resolved.backoff.init_backoff = Duration::from_millis(get_timeout_secs(
+        configs,
+        "retry_init_backoff_ms",
+        resolved.backoff.init_backoff.as_millis() as u64,
+    ));
+    resolved.backoff.max_backoff = Duration::from_millis(get_timeout_secs(
+        configs,
+        "retry_max_backoff_ms",
+        resolved.backoff.max_backoff.as_millis() as u64,
+    ));
+    resolved.retry_timeout = Duration::from_secs(get_timeout_secs(
+        configs,
+        "retry_timeout_secs",
+        resolved.retry_timeout.as_secs(),
+    ));
+    resolved
+}
+
+/// Builds the operation-level retry policy: `retry_operation_attempts` attempts of the whole
+/// operation (default: 1), backing off like the per-request retries of `retry_config`.
+fn resolve_retry_policy(
+    configs: &HashMap<String, ConfigValue>,
+    retry_config: &RetryConfig,
+) -> Result<RetryPolicy, StorageError> {
+    let max_attempts = get_timeout_secs(configs, "retry_operation_attempts", 1);
+    if max_attempts == 0 {
+        return Err(StorageError::ConfigError(
+            "rust_client retry_operation_attempts must be at least 1".to_string(),
+        ));
+    }
+    Ok(RetryPolicy {
+        max_attempts: max_attempts.min(u32::MAX as u64) as u32,
+        init_backoff: retry_config.backoff.init_backoff,
+        max_backoff: retry_config.backoff.max_backoff,
+        multiplier: retry_config.backoff.base,
+    })
+}
+
+/// Validates a deadline given in seconds; `None` means no deadline.
+fn parse_deadline(secs: Option<f64>) -> Result<Option<Duration>, StorageError> {
+    match secs {
+        None => Ok(None),
+        Some(secs) if secs.is_finite() && secs > 0.0 => Ok(Some(Duration::from_secs_f64(secs))),
+        Some(secs) => Err(StorageError::ConfigError(format!(
+            "deadline must be a positive number of seconds, got {}",
+            secs
+        ))),
+    }
+}
+
+/// Runs a whole call within `deadline`, raising `TimeoutError` if it expires first.
+async fn call_within<T, Fut>(deadline: Option<Deadline>, fut: Fut) -> PyResult<T>
+where
+    Fut: std::future::Future<Output = PyResult<T>>,
+{
+    with_deadline(deadline, async { Ok(fut.await) }).await?
+}
+
+/// Converts an RFC 3339 string or a `datetime` to a UTC timestamp. A naive `datetime` is taken
+/// as local time, as `datetime.timestamp()` does.
+fn parse_timestamp(value: &Bound<'_, PyAny>, name: &str) -> PyResult<DateTime<Utc>> {
+    if let Ok(text) = value.extract::<String>() {
+        return DateTime::parse_from_rfc3339(&text)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|e| StorageError::ConfigError(format!("Invalid {} '{}': {}", name, text, e)).into());
+    }
+    let secs: f64 = value
+        .call_method0("timestamp")
+        .and_then(|secs| secs.extract())
+        .map_err(|_| StorageError::ConfigError(format!("{} must be an RFC 3339 string or a datetime", name)))?;
+    DateTime::from_timestamp_micros((secs * 1e6).round() as i64)
+        .ok_or_else(|| StorageError::ConfigError(format!("{} is out of range", name)).into())
+}
+
+/// Reads `trailing_checksum`, the algorithm of the checksums sent in an aws-chunked trailer with
+/// every put and part, which can't be combined with `checksum_algorithm`.
+#[cfg(feature = "aws")]
+fn parse_trailing_checksum(
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<Option<checksum::Algorithm>, StorageError> {
+    let algorithm = match configs.get("trailing_checksum") {
+        None => return Ok(None),
+        Some(ConfigValue::String(s)) => match s.to_ascii_lowercase().as_str() {
+            "sha256" => checksum::Algorithm::Sha256,
+            "crc32c" => checksum::Algorithm::Crc32c,
+            _ => {
+                return Err(StorageError::ConfigError(format!(
+                    "Unsupported rust_client trailing_checksum '{}'. Accepted values: sha256, crc32c",
+                    s
+                )))
+            }
+        },
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client trailing_checksum must be a string, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    if configs.contains_key("checksum_algorithm") {
+        return Err(StorageError::ConfigError(
+            "rust_client trailing_checksum and checksum_algorithm can't be combined, set one of them.".to_string(),
+        ));
+    }
+    Ok(Some(algorithm))
+}
+
+/// Reads `use_default_credentials`, which can't be combined with a credentials provider or keys
+/// in the config since it resolves credentials from the environment and instance metadata.
+#[cfg(feature = "aws")]
+fn parse_use_default_credentials(
+    configs: &HashMap<String, ConfigValue>,
+    has_credentials_provider: bool,
+) -> Result<bool, StorageError> {
+    let use_default_credentials = match configs.get("use_default_credentials") {
+        None => false,
+        Some(ConfigValue::Boolean(b)) => *b,
+        Some(ConfigValue::String(s)) => s.parse::<bool>().unwrap_or(false),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client use_default_credentials must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    let explicit_keys: Vec<&str> = ["access_key", "secret_key", "session_token"]
+        .into_iter()
+        .filter(|key| configs.contains_key(*key))
+        .collect();
+    if use_default_credentials && (has_credentials_provider || !explicit_keys.is_empty()) {
+        let explicit = if has_credentials_provider {
+            "a credentials_provider".to_string()
+        } else {
+            explicit_keys.join(", ")
+        };
+        return Err(StorageError::ConfigError(format!(
+            "rust_client use_default_credentials cannot be combined with {}. Remove one of the two.",
+            explicit
+        )));
+    }
+    Ok(use_default_credentials)
+}
+
+/// Reads `anonymous`, which sends unsigned requests and makes the client read-only. It can't be
+/// combined with any source of credentials.
+#[cfg(feature = "aws")]
+fn parse_anonymous(
+    configs: &HashMap<String, ConfigValue>,
+    has_credentials_provider: bool,
+) -> Result<bool, StorageError> {
+    let anonymous = match configs.get("anonymous") {
+        None => false,
+        Some(ConfigValue::Boolean(b)) => *b,
+        Some(ConfigValue::String(s)) => s.parse::<bool>().unwrap_or(false),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client anonymous must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    let mut credentials: Vec<&str> = ["access_key", "secret_key", "session_token", "use_default_credentials"]
+        .into_iter()
+        .filter(|key| configs.contains_key(*key))
+        .collect();
+    if has_credentials_provider {
+        credentials.insert(0, "a credentials_provider");
+    }
+    if anonymous && !credentials.is_empty() {
+        return Err(StorageError::ConfigError(format!(
+            "rust_client anonymous cannot be combined with {}. Remove one of the two.",
+            credentials.join(", ")
+        )));
+    }
+    Ok(anonymous)
+}
+
+/// Reads `unsigned_payload`, which can't be combined with `checksum_algorithm` since that hashes
+/// every payload anyway.
+#[cfg(feature = "aws")]
+fn parse_unsigned_payload(configs: &HashMap<String, ConfigValue>) -> Result<bool, StorageError> {
+    let unsigned_payload = match configs.get("unsigned_payload") {
+        None => false,
+        Some(ConfigValue::Boolean(b)) => *b,
+        Some(ConfigValue::String(s)) => s.parse::<bool>().unwrap_or(false),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client unsigned_payload must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    if unsigned_payload && configs.contains_key("checksum_algorithm") {
+        return Err(StorageError::ConfigError(
+            "rust_client unsigned_payload cannot be combined with checksum_algorithm, which hashes every payload \
+             before it is sent. Remove one of the two options."
+                .to_string(),
+        ));
+    }
+    Ok(unsigned_payload)
+}
+
+/// Reads `request_payer`, which requester-pays buckets need to accept a request. S3 bills the
+/// requester's account, so unsigned requests are rejected.
+#[cfg(feature = "aws")]
+fn parse_request_payer(configs: &HashMap<String, ConfigValue>) -> Result<bool, StorageError> {
+    let request_payer = match configs.get("request_payer") {
+        None => false,
+        Some(ConfigValue::Boolean(b)) => *b,
+        Some(ConfigValue::String(s)) => s.parse::<bool>().unwrap_or(false),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client request_payer must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    let skip_signature = ["skip_signature", "anonymous"].into_iter().any(|key| {
+        configs
+            .get(key)
+            .is_some_and(|value| value.to_string().parse().unwrap_or(false))
+    });
+    if request_payer && skip_signature {
+        return Err(StorageError::ConfigError(
+            "rust_client request_payer cannot be combined with skip_signature or anonymous: requester-pays \
+             buckets only accept signed requests. Remove one of the two options."
+                .to_string(),
+        ));
+    }
+    Ok(request_payer)
+}
+
+/// Reads `checksum_algorithm`, the checksum computed for every object uploaded, which `provider` must be
+/// able to validate.
+fn parse_checksum_algorithm(
+    provider: &str,
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<Option<checksum::Algorithm>, StorageError> {
     match configs.get("checksum_algorithm") {
         None => Ok(None),
-        Some(ConfigValue::String(s)) => Checksum::from_str(s).map(Some).map_err(|_| {
-            StorageError::ConfigError(format!(
-                "Unsupported rust_client checksum_algorithm '{}'. object_store currently supports: 'sha256'.",
-                s
-            ))
-        }),
+        Some(ConfigValue::String(s)) => checksum::Algorithm::parse(provider, s).map(Some),
         Some(other) => Err(StorageError::ConfigError(format!(
             "rust_client checksum_algorithm must be a string, got {}.",
             other.to_string()
@@ -228,6 +819,136 @@ fn parse_checksum_algorithm(configs: &HashMap<String, ConfigValue>) -> Result<Op
     }
 }
 
+/// How the bucket is addressed in request URLs, from the `addressing_style` config.
+#[cfg(feature = "aws")]
+#[derive(Debug, PartialEq)]
+enum AddressingStyle {
+    /// `https://endpoint/bucket/key`.
+    Path,
+    /// `https://bucket.endpoint/key`. A custom `endpoint_url` is used as is, so it has to name the bucket.
+    Virtual,
+}
+
+/// Reads `addressing_style`, or the Python provider's `force_path_style`. `None` keeps object_store's
+/// default, path style, unless the bucket is an access point.
+#[cfg(feature = "aws")]
+fn parse_addressing_style(configs: &HashMap<String, ConfigValue>) -> Result<Option<AddressingStyle>, StorageError> {
+    let addressing_style = match configs.get("addressing_style") {
+        None => None,
+        Some(ConfigValue::String(s)) => match s.to_lowercase().as_str() {
+            "path" => Some(AddressingStyle::Path),
+            "virtual" => Some(AddressingStyle::Virtual),
+            _ => {
+                return Err(StorageError::ConfigError(format!(
+                    "Unsupported rust_client addressing_style: {}. Accepted values: \"path\", \"virtual\".",
+                    s
+                )))
+            }
+        },
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client addressing_style must be \"path\" or \"virtual\", got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    let force_path_style = match configs.get("force_path_style") {
+        None => None,
+        Some(ConfigValue::Boolean(b)) => Some(*b),
+        Some(ConfigValue::String(s)) => Some(s.parse::<bool>().unwrap_or(false)),
+        Some(other) => {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client force_path_style must be a boolean, got {}.",
+                other.to_string()
+            )))
+        }
+    };
+    match (addressing_style, force_path_style) {
+        (Some(AddressingStyle::Virtual), Some(true)) => Err(StorageError::ConfigError(
+            "rust_client addressing_style=\"virtual\" conflicts with force_path_style=True. Remove one of the two \
+             options."
+                .to_string(),
+        )),
+        (Some(style), _) => Ok(Some(style)),
+        (None, Some(true)) => Ok(Some(AddressingStyle::Path)),
+        (None, _) => Ok(None),
+    }
+}
+
+/// Server-side encryption of the objects written, from the `sse`, `sse_kms_key_id` and `sse_c_key`
+/// configs.
+#[cfg(feature = "aws")]
+#[derive(Debug, PartialEq)]
+enum ServerSideEncryption {
+    /// `sse="AES256"`, with keys managed by S3.
+    S3Managed,
+    /// `sse="aws:kms"`, with the bucket's default KMS key unless `sse_kms_key_id` names one.
+    Kms(Option<String>),
+    /// `sse_c_key`, a base64-encoded 256-bit key that is also sent to read the objects back.
+    CustomerKey(String),
+}
+
+#[cfg(feature = "aws")]
+impl ServerSideEncryption {
+    /// Whether S3 returns ETags that aren't the MD5 of objects encrypted this way.
+    fn hides_md5(&self) -> bool {
+        !matches!(self, ServerSideEncryption::S3Managed)
+    }
+}
+
+#[cfg(feature = "aws")]
+fn parse_server_side_encryption(
+    configs: &HashMap<String, ConfigValue>,
+) -> Result<Option<ServerSideEncryption>, StorageError> {
+    let string = |key: &str| match configs.get(key) {
+        None => Ok(None),
+        Some(ConfigValue::String(s)) => Ok(Some(s.clone())),
+        Some(other) => Err(StorageError::ConfigError(format!(
+            "rust_client {} must be a string, got {}.",
+            key,
+            other.to_string()
+        ))),
+    };
+    let (sse, kms_key_id, customer_key) = (string("sse")?, string("sse_kms_key_id")?, string("sse_c_key")?);
+
+    if let Some(customer_key) = customer_key {
+        if let Some(sse) = sse {
+            return Err(StorageError::ConfigError(format!(
+                "rust_client sse_c_key cannot be combined with sse '{}'. Remove one of the two options.",
+                sse
+            )));
+        }
+        if kms_key_id.is_some() {
+            return Err(StorageError::ConfigError(
+                "rust_client sse_c_key cannot be combined with sse_kms_key_id. Remove one of the two options."
+                    .to_string(),
+            ));
+        }
+        // 32 bytes encode to 43 base64 characters and one padding character.
+        let is_base64 = |c: char| c.is_ascii_alphanumeric() || c == '+' || c == '/';
+        if customer_key.len() != 44 || !customer_key[..43].chars().all(is_base64) || !customer_key.ends_with('=') {
+            return Err(StorageError::ConfigError(
+                "rust_client sse_c_key must be a base64-encoded 256-bit key.".to_string(),
+            ));
+        }
+        return Ok(Some(ServerSideEncryption::CustomerKey(customer_key)));
+    }
+
+    match sse.as_deref() {
+        None if kms_key_id.is_some() => Ok(Some(ServerSideEncryption::Kms(kms_key_id))),
+        None => Ok(None),
+        Some("aws:kms") => Ok(Some(ServerSideEncryption::Kms(kms_key_id))),
+        Some("AES256") if kms_key_id.is_none() => Ok(Some(ServerSideEncryption::S3Managed)),
+        Some("AES256") => Err(StorageError::ConfigError(
+            "rust_client sse_kms_key_id requires sse \"aws:kms\", got \"AES256\".".to_string(),
+        )),
+        Some(other) => Err(StorageError::ConfigError(format!(
+            "Unsupported rust_client sse '{}'. Supported values: 'aws:kms', 'AES256'.",
+            other
+        ))),
+    }
+}
+
 fn get_retry_config(retry_config: Option<&RustRetryConfig>) -> RetryConfig {
     if let Some(rust_retry_config) = retry_config {
         let backoff_config = BackoffConfig {
@@ -257,138 +978,353 @@ fn get_retry_config(retry_config: Option<&RustRetryConfig>) -> RetryConfig {
     }
 }
 
+/// Returns the cargo feature that gates the given provider, or `None` for unknown providers.
+fn provider_feature(provider: &str) -> Option<&'static str> {
+    match provider {
+        "s3" | "s8k" | "gcs_s3" => Some("aws"),
+        "gcs" => Some("gcp"),
+        "file" => Some("local"),
+        "http" => Some("http"),
+        _ => None,
+    }
+}
+
+/// Returns the providers compiled into this build.
+fn supported_providers() -> Vec<&'static str> {
+    let mut providers = Vec::new();
+    #[cfg(feature = "aws")]
+    providers.extend(["s3", "s8k", "gcs_s3"]);
+    #[cfg(feature = "gcp")]
+    providers.push("gcs");
+    #[cfg(feature = "local")]
+    providers.push("file");
+    #[cfg(feature = "http")]
+    providers.push("http");
+    providers
+}
+
+/// Builds the error returned for a provider that cannot be created in this build.
+///
+/// Known providers whose feature was disabled at compile time name the missing feature so the
+/// caller knows to rebuild rather than fix their configuration.
+fn unsupported_provider_error(provider: &str) -> StorageError {
+    match provider_feature(provider) {
+        Some(feature) => StorageError::ConfigError(format!(
+            "Provider '{}' requires the '{}' feature, which was not enabled when multistorageclient_rust was built.",
+            provider, feature
+        )),
+        None => StorageError::ConfigError(format!(
+            "Unsupported provider type: '{}'. Supported providers are: {}",
+            provider,
+            supported_providers().join(", ")
+        )),
+    }
+}
+
+/// A provider's store, plus direct access to its multipart uploads on providers whose uploads can
+/// be continued by id, and the signer of its presigned URLs on providers with signed requests.
+type ProviderStore = (
+    Arc<dyn ObjectStore>,
+    Option<Arc<dyn MultipartStore>>,
+    Option<Arc<dyn Presigner>>,
+);
+
+/// Builds the store of `provider`, sending at most as many requests at once as `connections` has permits.
 fn create_store(
     provider: &str,
     configs: Option<&HashMap<String, ConfigValue>>,
     py_credentials_provider: Option<Py<PyAny>>,
-    max_pool_connections: usize,
-    retry_config: Option<&RustRetryConfig>,
-) -> PyResult<Arc<dyn ObjectStore>> {
-    let store = match provider {
+    connections: &Arc<Semaphore>,
+    retry_config: &RetryConfig,
+) -> PyResult<ProviderStore> {
+    let (store, multipart_store, presigner): ProviderStore = match provider {
+        #[cfg(feature = "aws")]
         "s3" | "s8k" | "gcs_s3" => {
-            build_s3_store(configs, py_credentials_provider, retry_config)?
+            let (store, multipart_store, presigner) = build_s3_store(configs, py_credentials_provider, retry_config)?;
+            let anonymous = configs
+                .and_then(|configs| configs.get("anonymous"))
+                .is_some_and(|anonymous| anonymous.to_string().parse().unwrap_or(false));
+            if anonymous {
+                // Writes are rejected before they are sent, including resumed multipart uploads.
+                (Arc::new(readonly::ReadOnlyStore::anonymous(store)), None, None)
+            } else {
+                (store, multipart_store, presigner)
+            }
         }
+        #[cfg(feature = "gcp")]
         "gcs" => {
             build_gcs_store(configs, py_credentials_provider, retry_config)?
         }
+        #[cfg(feature = "local")]
+        "file" => {
+            (local::build_store(configs)?, None, None)
+        }
+        #[cfg(feature = "http")]
+        "http" => {
+            (build_http_store(configs, retry_config)?, None, None)
+        }
         _ => {
-            return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Unsupported provider type: '{}'. Supported providers are: s3, s8k, gcs_s3, gcs",
-                provider
-            )));
+            return Err(unsupported_provider_error(provider).into());
         }
     };
 
-    let limited_store = LimitStore::new(store, max_pool_connections);
-    Ok(Arc::new(limited_store))
+    let limited_store: Arc<dyn ObjectStore> = Arc::new(SharedLimitStore::new(store, Arc::clone(connections)));
+    let store: Arc<dyn ObjectStore> = match configs.and_then(|configs| configs.get("rate_limit_group")) {
+        Some(group) => Arc::new(RateLimitedStore::new(limited_store, &group.to_string())),
+        None => limited_store,
+    };
+    let algorithm = configs.map(|configs| parse_checksum_algorithm(provider, configs)).transpose()?.flatten();
+    let store: Arc<dyn ObjectStore> = match algorithm {
+        Some(algorithm) => Arc::new(ChecksumStore::new(store, algorithm)),
+        None => store,
+    };
+    Ok((store, multipart_store, presigner))
+}
+
+/// Describes the bucket and endpoint `configs` resolve to, for errors raised at construction.
+fn describe_target(provider: &str, configs: &HashMap<String, ConfigValue>) -> String {
+    let config = |key: &str| configs.get(key).map(ConfigValue::to_string);
+    let bucket = config("bucket").unwrap_or_default();
+    #[cfg(feature = "aws")]
+    if provider_feature(provider) == Some("aws") {
+        let access_point = AccessPointArn::parse(&bucket, None).ok().flatten();
+        let region = config("region_name")
+            .or_else(|| access_point.as_ref().map(|access_point| access_point.region.clone()))
+            .unwrap_or_else(|| "us-east-1".to_string());
+        let endpoint = config("endpoint_url")
+            .or_else(|| access_point.map(|access_point| access_point.endpoint()))
+            .unwrap_or_else(|| format!("https://s3.{}.amazonaws.com", region));
+        return format!("bucket '{}' at endpoint '{}' (region '{}')", bucket, endpoint, region);
+    }
+    let endpoint = config("url").unwrap_or_else(|| format!("the default {} endpoint", provider));
+    format!("bucket '{}' at {}", bucket, endpoint)
+}
+
+/// Lists at most one object to check that the bucket exists and is readable, for `validate_on_init`.
+///
+/// Anonymous clients are often allowed to read objects but not to list them, so a permission
+/// error only fails validation for signed requests.
+async fn validate_store(store: &Arc<dyn ObjectStore>, anonymous: bool) -> Result<(), object_store::Error> {
+    match store.list(None).next().await {
+        None | Some(Ok(_)) => Ok(()),
+        Some(Err(object_store::Error::PermissionDenied { .. })) if anonymous => Ok(()),
+        Some(Err(err)) => Err(err),
+    }
+}
+
+/// Appended to permission errors of S3 clients without `request_payer`, the usual cause of a 403 on
+/// a public dataset.
+const REQUEST_PAYER_HINT: &str = " If the bucket is requester-pays, set request_payer=True.";
+
+/// Maps a failed `validate_on_init` check to `FileNotFoundError`, `PermissionError` or
+/// `ConnectionError`, naming the bucket, endpoint and region that were tried.
+fn validation_error(err: object_store::Error, target: &str, request_payer_hint: bool) -> PyErr {
+    let mut message = format!("Could not access {}: {}", target, format_error_chain(&err));
+    let status = match StorageError::from(err) {
+        StorageError::HttpError(_, status) => status,
+        _ => None,
+    };
+    match status {
+        Some(404) => pyo3::exceptions::PyFileNotFoundError::new_err(message),
+        Some(401 | 403) => {
+            if request_payer_hint {
+                message.push_str(REQUEST_PAYER_HINT);
+            }
+            pyo3::exceptions::PyPermissionError::new_err(message)
+        }
+        _ => pyo3::exceptions::PyConnectionError::new_err(message),
+    }
 }
 
 /// Load AWS credentials provider from the default credential chain
+#[cfg(feature = "aws")]
 fn load_aws_credentials_provider(profile_name_config: Option<&ConfigValue>) -> Result<AwsSdkCredentialsProvider, StorageError> {
     // Load AWS config asynchronously
-    let sdk_config = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-        handle.block_on(async {
-            let http_client = Builder::new()
-                .tls_provider(tls::Provider::Rustls(tls::rustls_provider::CryptoMode::Ring))
-                .build_https();
-            let mut config_loader = aws_config::defaults(BehaviorVersion::latest())
-                .http_client(http_client);
-            if let Some(profile_name_val) = profile_name_config {
-                config_loader = config_loader.profile_name(profile_name_val.to_string());
-            }
-            config_loader
-                .load()
-                .await
-        })
-    } else {
-        let rt = tokio::runtime::Runtime::new()
-            .map_err(|e| StorageError::ConfigError(format!("Failed to create tokio runtime: {:?}", e)))?;
-        rt.block_on(async {
-            let http_client = Builder::new()
-                .tls_provider(tls::Provider::Rustls(tls::rustls_provider::CryptoMode::Ring))
-                .build_https();
-            let mut config_loader = aws_config::defaults(BehaviorVersion::latest())
-                .http_client(http_client);
-            if let Some(profile_name_val) = profile_name_config {
-                config_loader = config_loader.profile_name(profile_name_val.to_string());
-            }
-            config_loader
-                .load()
-                .await
-        })
+    let load = async {
+        let http_client = Builder::new()
+            .tls_provider(tls::Provider::Rustls(tls::rustls_provider::CryptoMode::Ring))
+            .build_https();
+        let mut config_loader = aws_config::defaults(BehaviorVersion::latest())
+            .http_client(http_client);
+        if let Some(profile_name_val) = profile_name_config {
+            config_loader = config_loader.profile_name(profile_name_val.to_string());
+        }
+        let sdk_config = config_loader
+            .load()
+            .await;
+        let credentials_provider = sdk_config.credentials_provider()
+            .ok_or_else(|| StorageError::ConfigError(
+                "No AWS credentials provider found in SDK config".to_string()
+            ))?;
+        // Without any credentials every request would fail to sign, so fail here instead.
+        credentials_provider.provide_credentials().await.map_err(|err| {
+            StorageError::ConfigError(format!(
+                "No AWS credentials found ({}). Pass a credentials_provider, set the AWS_* environment variables \
+                 or use_default_credentials=True, or set anonymous=True (or skip_signature=True) to read a \
+                 public bucket.",
+                err
+            ))
+        })?;
+        Ok::<_, StorageError>(credentials_provider)
     };
-
-    let credentials_provider = sdk_config.credentials_provider()
-        .ok_or_else(|| StorageError::ConfigError(
-            "No AWS credentials provider found in SDK config".to_string()
-        ))?;
+    // Loading may query the instance metadata service, so other Python threads keep running meanwhile.
+    let credentials_provider = match tokio::runtime::Handle::try_current() {
+        Ok(handle) => handle.block_on(load),
+        Err(_) => Python::attach(|py| py.detach(|| get_runtime().block_on(load))),
+    }?;
 
     Ok(AwsSdkCredentialsProvider::new(credentials_provider))
 }
 
 fn parse_path(path: &str) -> Result<Path, StorageError> {
     // Use Path::parse instead of Path::from to avoid double encoding
-    Path::parse(path).map_err(|e| StorageError::InvalidPathError(format!("Failed to parse path '{}': {:?}", path, e)))
+    let path = Path::parse(path)
+        .map_err(|e| StorageError::InvalidPathError(format!("Failed to parse path '{}': {:?}", path, e)))?;
+    telemetry::record_key(&path);
+    Ok(path)
+}
+
+/// Builds the connector that stamps each request with its operation's tag, under the header
+/// named by `request_tag_header`.
+#[cfg(any(feature = "aws", feature = "gcp", feature = "http"))]
+fn tagging_connector(configs: &HashMap<String, ConfigValue>) -> Result<request_tag::TaggingConnector, StorageError> {
+    let header = match configs.get("request_tag_header") {
+        Some(header) => header.to_string(),
+        None => request_tag::DEFAULT_REQUEST_TAG_HEADER.to_string(),
+    };
+    Ok(request_tag::TaggingConnector::new(request_tag::parse_header(&header)?))
 }
 
+#[cfg(feature = "aws")]
 fn build_s3_store<'a>(
     configs: Option<&'a HashMap<String, ConfigValue>>,
     py_credentials_provider: Option<Py<PyAny>>,
-    retry_config: Option<&RustRetryConfig>,
-) -> PyResult<Arc<dyn ObjectStore>> {
-    // TODO: Add support for other configuration fields of AmazonS3Builder, full list here:
-    // https://docs.rs/object_store/latest/src/object_store/aws/builder.rs.html#123
-    let mut builder = AmazonS3Builder::new();
-
+    retry_config: &RetryConfig,
+) -> PyResult<ProviderStore> {
     let configs = configs.ok_or_else(|| {
         StorageError::ConfigError("Configuration dictionary is required for S3 provider.".to_string())
     })?;
 
-    if let Some(py_creds_provider) = py_credentials_provider {
-        let aws_provider = AwsCredentialsProvider::new(py_creds_provider, None);
-        builder = builder.with_credentials(Arc::new(aws_provider));
-    } else {
+    // TODO: Add support for other configuration fields of AmazonS3Builder, full list here:
+    // https://docs.rs/object_store/latest/src/object_store/aws/builder.rs.html#123
+    let mut builder = AmazonS3Builder::new();
+    let mut credentials: Option<Arc<dyn ForceRefresh>> = None;
+
+    let anonymous = parse_anonymous(configs, py_credentials_provider.is_some())?;
+    let skip_signature = anonymous
+        || configs
+            .get("skip_signature")
+            .is_some_and(|skip_signature| skip_signature.to_string().parse().unwrap_or(false));
+
+    if parse_use_default_credentials(configs, py_credentials_provider.is_some())? {
+        // object_store resolves environment, web identity and instance profile credentials and
+        // refreshes them itself.
+        builder = AmazonS3Builder::from_env();
+    } else if let Some(py_creds_provider) = py_credentials_provider {
+        let aws_provider = Arc::new(AwsCredentialsProvider::new(py_creds_provider, None));
+        builder = builder.with_credentials(aws_provider.clone());
+        credentials = Some(aws_provider);
+    } else if !skip_signature {
         // Use AWS SDK default credential chain
         let aws_provider = load_aws_credentials_provider(configs.get("profile_name"))?;
         builder = builder.with_credentials(Arc::new(aws_provider));
     }
 
-    if let Some(bucket_val) = configs.get("bucket") {
-        builder = builder.with_bucket_name(bucket_val.to_string());
-    }
+    let region_name = configs.get("region_name").map(|region_val| region_val.to_string());
+    let bucket = configs.get("bucket").map(|bucket_val| bucket_val.to_string());
+    let access_point = match &bucket {
+        Some(bucket) => AccessPointArn::parse(bucket, region_name.as_deref())?,
+        None => None,
+    };
+
+    if let Some(access_point) = &access_point {
+        builder = builder
+            .with_bucket_name(access_point.name.clone())
+            .with_region(access_point.region.clone())
+            .with_endpoint(access_point.endpoint())
+            .with_virtual_hosted_style_request(true);
+    } else {
+        if let Some(bucket_val) = bucket {
+            builder = builder.with_bucket_name(bucket_val);
+        }
 
-    if let Some(region_val) = configs.get("region_name") {
-        builder = builder.with_region(region_val.to_string());
+        if let Some(region_val) = region_name {
+            builder = builder.with_region(region_val);
+        }
     }
 
-    if let Some(endpoint_val) = configs.get("endpoint_url") {
-        builder = builder.with_endpoint(endpoint_val.to_string());
+    let endpoints = match configs.get("endpoint_url") {
+        Some(endpoint_val) => failover::parse_endpoints(&endpoint_val.to_string()),
+        None => Vec::new(),
+    };
+    if let Some(endpoint) = endpoints.first() {
+        builder = builder.with_endpoint(endpoint.clone());
     }
 
-    if let Some(skip_signature) = configs.get("skip_signature") {
-        match skip_signature {
-            ConfigValue::Boolean(b) => {
-                if *b {
-                    builder = builder.with_skip_signature(true);
-                }
-            }
-            ConfigValue::String(s) => {
-                if s.parse::<bool>().unwrap_or(false) {
-                    builder = builder.with_skip_signature(true);
-                }
-            }
-            _ => {}
+    match parse_addressing_style(configs)? {
+        Some(AddressingStyle::Path) if access_point.is_some() => {
+            return Err(StorageError::ConfigError(
+                "Access points only accept virtual-hosted style requests, remove addressing_style=\"path\"."
+                    .to_string(),
+            )
+            .into())
         }
+        Some(style) => builder = builder.with_virtual_hosted_style_request(style == AddressingStyle::Virtual),
+        None => {}
+    }
+
+    if skip_signature {
+        builder = builder.with_skip_signature(true);
     }
 
     // Configure retry
-    let retry_cfg = get_retry_config(retry_config);
-    builder = builder.with_retry(retry_cfg);
+    builder = builder.with_retry(retry_config.clone());
+
+    // Sent with every request, including listings and multipart parts.
+    let request_payer = parse_request_payer(configs)?;
+    if request_payer {
+        builder = builder.with_request_payer(true);
+    }
+
+    // Send UNSIGNED-PAYLOAD instead of a payload hash, e.g. behind a signing proxy. Trailing
+    // checksums are signed over STREAMING-UNSIGNED-PAYLOAD-TRAILER, so payloads aren't hashed for them.
+    let trailing_checksum = parse_trailing_checksum(configs)?;
+    if parse_unsigned_payload(configs)? || trailing_checksum.is_some() {
+        builder = builder.with_unsigned_payload(true);
+    }
+
+    // Configure upload-only object integrity checksum. MD5 is checked against the ETag instead.
+    let checksum_algorithm = parse_checksum_algorithm("s3", configs)?;
+    if checksum_algorithm == Some(checksum::Algorithm::Sha256) {
+        builder = builder.with_checksum_algorithm(Checksum::SHA256);
+    }
 
-    // Configure upload-only object integrity checksum.
-    if let Some(checksum) = parse_checksum_algorithm(configs)? {
-        builder = builder.with_checksum_algorithm(checksum);
+    // object_store sends the encryption headers on puts, copies and the request starting a multipart
+    // upload. A customer key is also sent with every part and on gets and heads, since S3 needs it to
+    // read or extend an object encrypted with it, while SSE-KMS headers are rejected on parts.
+    let sse = parse_server_side_encryption(configs)?;
+    let hides_md5 = sse.as_ref().is_some_and(ServerSideEncryption::hides_md5);
+    if checksum_algorithm == Some(checksum::Algorithm::Md5) && hides_md5 {
+        return Err(StorageError::ConfigError(
+            "rust_client checksum_algorithm \"md5\" cannot be combined with sse \"aws:kms\" or sse_c_key: the ETags of \
+             encrypted objects aren't their MD5. Use \"sha256\" instead."
+                .to_string(),
+        )
+        .into());
     }
+    builder = match sse {
+        None => builder,
+        Some(ServerSideEncryption::S3Managed) => builder.with_config(
+            AmazonS3ConfigKey::Encryption(S3EncryptionConfigKey::ServerSideEncryption),
+            "AES256",
+        ),
+        Some(ServerSideEncryption::Kms(None)) => builder.with_config(
+            AmazonS3ConfigKey::Encryption(S3EncryptionConfigKey::ServerSideEncryption),
+            "aws:kms",
+        ),
+        Some(ServerSideEncryption::Kms(Some(key_id))) => builder.with_sse_kms_encryption(key_id),
+        Some(ServerSideEncryption::CustomerKey(key)) => builder.with_ssec_encryption(key),
+    };
 
     // Configure client options
     let mut client_options = ClientOptions::new();
@@ -415,29 +1351,91 @@ fn build_s3_store<'a>(
         }
     }
 
-    client_options = client_options.with_pool_idle_timeout(std::time::Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT));
+    client_options = connection_options(client_options, configs)?;
+    client_options = certificates::client_options(client_options, configs)?;
+    client_options = headers::client_options(client_options, configs)?;
 
     builder = builder.with_client_options(client_options);
+    let signing_credentials = aws_chunked::SigningCredentials::default();
+    builder = match trailing_checksum {
+        Some(_) => builder.with_http_connector(aws_chunked::Connector::new(
+            tagging_connector(configs)?,
+            signing_credentials.clone(),
+        )),
+        None => builder.with_http_connector(tagging_connector(configs)?),
+    };
+    if trailing_checksum.is_some() && !skip_signature {
+        let store = builder.clone().build().map_err(StorageError::from)?;
+        signing_credentials.set(store.credentials().clone());
+    }
+
+    // URLs are presigned for the first endpoint, as clients of the URLs can't fail over.
+    let presigner: Option<Arc<dyn Presigner>> = if skip_signature {
+        None
+    } else {
+        let region = builder
+            .get_config_value(&AmazonS3ConfigKey::Region)
+            .unwrap_or_else(|| "us-east-1".to_string());
+        let store = builder.clone().build().map_err(StorageError::from)?;
+        Some(Arc::new(presign::S3Presigner::new(store, region, request_payer)))
+    };
 
-    let store = builder.build().map_err(StorageError::from)?;
+    // The other endpoints share the configuration, and credentials, of the first one.
+    let (store, multipart_store): (Arc<dyn ObjectStore>, Arc<dyn MultipartStore>) = if endpoints.len() > 1 {
+        let store = FailoverStore::new(endpoints, move |endpoint| {
+            builder.clone().with_endpoint(endpoint).build()
+        });
+        with_trailing_checksum(Arc::new(store.map_err(StorageError::from)?), trailing_checksum)
+    } else {
+        let store = Arc::new(builder.build().map_err(StorageError::from)?);
+        with_trailing_checksum(store, trailing_checksum)
+    };
 
-    Ok(Arc::new(store))
+    Ok((with_reauth(store, credentials), Some(multipart_store), presigner))
 }
 
-fn build_gcs_store<'a>(
-    configs: Option<&'a HashMap<String, ConfigValue>>,
-    py_credentials_provider: Option<Py<PyAny>>,
-    retry_config: Option<&RustRetryConfig>,
-) -> PyResult<Arc<dyn ObjectStore>> {
-    let mut builder = GoogleCloudStorageBuilder::new();
+/// Sends the puts and parts of `store` with a trailing checksum of `algorithm`, if any.
+#[cfg(feature = "aws")]
+fn with_trailing_checksum<S: ObjectStore + MultipartStore>(
+    store: Arc<S>,
+    algorithm: Option<checksum::Algorithm>,
+) -> (Arc<dyn ObjectStore>, Arc<dyn MultipartStore>) {
+    match algorithm {
+        Some(algorithm) => {
+            let store = Arc::new(aws_chunked::AwsChunkedStore::new(store, algorithm));
+            (store.clone(), store)
+        }
+        None => (store.clone(), store),
+    }
+}
+
+/// Retries requests denied for their `credentials`, e.g. a token revoked before it expired, once
+/// the credentials were refreshed.
+#[cfg(any(feature = "aws", feature = "gcp"))]
+fn with_reauth(store: Arc<dyn ObjectStore>, credentials: Option<Arc<dyn ForceRefresh>>) -> Arc<dyn ObjectStore> {
+    match credentials {
+        Some(credentials) => Arc::new(reauth::ReauthStore::new(store, credentials)),
+        None => store,
+    }
+}
+
+#[cfg(feature = "gcp")]
+fn build_gcs_store<'a>(
+    configs: Option<&'a HashMap<String, ConfigValue>>,
+    py_credentials_provider: Option<Py<PyAny>>,
+    retry_config: &RetryConfig,
+) -> PyResult<ProviderStore> {
+    let mut builder = GoogleCloudStorageBuilder::new();
+    let mut credentials: Option<Arc<dyn ForceRefresh>> = None;
 
     let configs = configs.ok_or_else(|| {
         StorageError::ConfigError("Configuration dictionary is required for GCS provider.".to_string())
     })?;
 
     if let Some(py_creds_provider) = py_credentials_provider {
-        let gcp_provider = GcpCredentialsProvider::new(py_creds_provider, None);
-        builder = builder.with_credentials(Arc::new(gcp_provider));
+        let gcp_provider = Arc::new(GcpCredentialsProvider::new(py_creds_provider, None));
+        builder = builder.with_credentials(gcp_provider.clone());
+        credentials = Some(gcp_provider);
     }
 
     if let Some(bucket_val) = configs.get("bucket") {
@@ -456,20 +1454,11 @@ fn build_gcs_store<'a>(
         builder = builder.with_application_credentials(application_credentials.to_string());
     }
 
-    if let Some(skip_signature) = configs.get("skip_signature") {
-        match skip_signature {
-            ConfigValue::Boolean(b) => {
-                if *b {
-                    builder = builder.with_skip_signature(true);
-                }
-            }
-            ConfigValue::String(s) => {
-                if s.parse::<bool>().unwrap_or(false) {
-                    builder = builder.with_skip_signature(true);
-                }
-            }
-            _ => {}
-        }
+    let skip_signature = configs
+        .get("skip_signature")
+        .is_some_and(|skip_signature| skip_signature.to_string().parse().unwrap_or(false));
+    if skip_signature {
+        builder = builder.with_skip_signature(true);
     }
 
     if let Some(proxy_url) = configs.get("proxy_url") {
@@ -489,8 +1478,7 @@ fn build_gcs_store<'a>(
     }
 
     // Configure retry
-    let retry_cfg = get_retry_config(retry_config);
-    builder = builder.with_retry(retry_cfg);
+    builder = builder.with_retry(retry_config.clone());
 
     // Configure client options
     let mut client_options = ClientOptions::new();
@@ -501,576 +1489,3859 @@ fn build_gcs_store<'a>(
     let read_timeout_secs = get_timeout_secs(&configs, "read_timeout", DEFAULT_READ_TIMEOUT);
     client_options = client_options.with_timeout(std::time::Duration::from_secs(read_timeout_secs));
 
-    client_options = client_options.with_pool_idle_timeout(std::time::Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT));
+    client_options = connection_options(client_options, configs)?;
+    client_options = certificates::client_options(client_options, configs)?;
+    client_options = headers::client_options(client_options, configs)?;
 
     builder = builder.with_client_options(client_options);
+    builder = builder.with_http_connector(tagging_connector(configs)?);
 
-    let store = builder.build().map_err(StorageError::from)?;
+    let presigner: Option<Arc<dyn Presigner>> = if skip_signature {
+        None
+    } else {
+        let store = builder.clone().build().map_err(StorageError::from)?;
+        Some(Arc::new(presign::StorePresigner::new(store, "gcs")))
+    };
+    let store = Arc::new(builder.build().map_err(StorageError::from)?);
 
-    Ok(Arc::new(store))
+    Ok((with_reauth(store.clone(), credentials), Some(store), presigner))
 }
 
-#[derive(Clone)]
-enum ConfigValue {
-    String(String),
-    Number(i64),
-    Boolean(bool),
-}
+/// Builds the read-only `http` provider, which serves objects relative to the `url` config.
+#[cfg(feature = "http")]
+fn build_http_store(
+    configs: Option<&HashMap<String, ConfigValue>>,
+    retry_config: &RetryConfig,
+) -> PyResult<Arc<dyn ObjectStore>> {
+    let configs = configs.ok_or_else(|| {
+        StorageError::ConfigError("Configuration dictionary is required for HTTP provider.".to_string())
+    })?;
+    let url = configs
+        .get("url")
+        .ok_or_else(|| StorageError::ConfigError("The 'http' provider requires a 'url' config".to_string()))?;
 
-impl ConfigValue {
-    fn to_string(&self) -> String {
-        match self {
-            ConfigValue::String(s) => s.clone(),
-            ConfigValue::Number(n) => n.to_string(),
-            ConfigValue::Boolean(b) => b.to_string(),
-        }
-    }
-}
+    let mut client_options = ClientOptions::new();
 
-#[pyclass]
-pub struct RustClient {
-    store: Arc<dyn ObjectStore>,
-    max_concurrency: usize,
-    multipart_chunksize: usize,
-}
+    let connect_timeout_secs = get_timeout_secs(configs, "connect_timeout", DEFAULT_CONNECT_TIMEOUT);
+    client_options = client_options.with_connect_timeout(std::time::Duration::from_secs(connect_timeout_secs));
 
-#[pymethods]
-impl RustClient {
-    #[new]
-    #[pyo3(signature = (provider="s3", configs=None, credentials_provider=None, retry=None))]
-    fn new(
-        provider: &str,
-        configs: Option<&Bound<'_, PyDict>>,
-        credentials_provider: Option<Py<PyAny>>,
-        retry: Option<RustRetryConfig>,
-    ) -> PyResult<Self> {
-        let provider = provider.to_lowercase();
+    let read_timeout_secs = get_timeout_secs(configs, "read_timeout", DEFAULT_READ_TIMEOUT);
+    client_options = client_options.with_timeout(std::time::Duration::from_secs(read_timeout_secs));
 
-        // Convert Python Dict to Rust HashMap<String, ConfigValue>
-        let mut configs_map = HashMap::new();
-        let mut max_concurrency = DEFAULT_MAX_CONCURRENCY;
-        let mut max_pool_connections = DEFAULT_POOL_CONNECTIONS;
-        let mut multipart_chunksize = DEFAULT_MULTIPART_CHUNKSIZE;
+    client_options = connection_options(client_options, configs)?;
 
-        if let Some(configs_dict) = configs {
-            for (key, value) in configs_dict.iter() {
-                let key_str = key.extract::<String>()?;
+    let url = url.to_string();
+    client_options = client_options.with_allow_http(url.starts_with("http://"));
+    client_options = certificates::client_options(client_options, configs)?;
+    client_options = headers::client_options(client_options, configs)?;
 
-                // Convert Python values to ConfigValue
-                Python::attach(|_py| {
-                    if key_str == "max_concurrency" {
-                        if let Ok(int_val) = value.extract::<i64>() {
-                            max_concurrency = int_val as usize;
-                        }
-                    } else if key_str == "max_pool_connections" {
-                        if let Ok(int_val) = value.extract::<i64>() {
-                            max_pool_connections = int_val as usize;
-                        }
-                    } else if key_str == "multipart_chunksize" {
-                        if let Ok(int_val) = value.extract::<i64>() {
-                            multipart_chunksize = int_val as usize;
-                        }
-                    } else {
-                        if let Ok(bool_val) = value.extract::<bool>() {
-                            configs_map.insert(key_str.clone(), ConfigValue::Boolean(bool_val));
-                        } else if let Ok(int_val) = value.extract::<i64>() {
-                            configs_map.insert(key_str.clone(), ConfigValue::Number(int_val));
-                        } else {
-                            // Fallback: try to convert to string
-                            if let Ok(str_val) = value.extract::<String>() {
-                                configs_map.insert(key_str.clone(), ConfigValue::String(str_val));
-                            }
-                        }
-                    }
-                    Ok::<(), PyErr>(())
-                })?;
-            }
-        }
+    let store = HttpBuilder::new()
+        .with_url(url)
+        .with_retry(retry_config.clone())
+        .with_client_options(client_options)
+        .with_http_connector(tagging_connector(configs)?)
+        .build()
+        .map_err(StorageError::from)?;
 
-        let store = create_store(
-            &provider,
-            Some(&configs_map),
-            credentials_provider,
-            max_pool_connections,
-            retry.as_ref(),
-        )?;
+    Ok(Arc::new(readonly::ReadOnlyStore::new(Arc::new(store), "http")))
+}
 
-        Ok(Self {
-            store,
-            max_concurrency,
-            multipart_chunksize,
-        })
+/// Uploads `data` to `remote_path`, using a multipart upload when it is larger than `chunksize`.
+#[allow(clippy::too_many_arguments)]
+async fn upload_bytes_multipart(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    data_bytes: bytes::Bytes,
+    chunksize: usize,
+    concurrency: usize,
+    mode: WriteMode,
+    attributes: &Attributes,
+    progress: Option<&Progress>,
+) -> Result<TransferResult, StorageError> {
+    let bytes_uploaded = data_bytes.len() as u64;
+    if let Some(progress) = progress {
+        progress.start(bytes_uploaded);
     }
 
-    #[pyo3(signature = (path, data))]
-    fn put<'p>(&self, py: Python<'p>, path: &str, data: PyBytes) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let path = parse_path(path)?;
-        let data_bytes = data.into_inner();
-        let bytes_written = data_bytes.len() as u64;
+    if data_bytes.len() <= chunksize {
         let payload = PutPayload::from_bytes(data_bytes);
+        let put_result = store
+            .put_opts(remote_path, payload, mode.put_options(attributes))
+            .await
+            .map_err(StorageError::from)?;
+        if let Some(progress) = progress {
+            progress.advance(bytes_uploaded).await?;
+        }
+        return Ok(TransferResult::new(bytes_uploaded, put_result));
+    }
 
-        future_into_py(py, async move {
-            store
-                .put(&path, payload)
+    if mode == WriteMode::Create {
+        ensure_absent(store, remote_path).await?;
+    }
+    let chunksize = multipart_safe_chunk_size(data_bytes.len() as u64, chunksize)?;
+    let upload = store.put_multipart_opts(remote_path, multipart_options(attributes)).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    let upload = abort::on_drop(upload);
+    let upload = progress::upload(upload, progress);
+    let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+
+    let mut offset = 0;
+    while offset < data_bytes.len() {
+        let end = std::cmp::min(offset + chunksize, data_bytes.len());
+        let chunk = &data_bytes[offset..end];
+
+        writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
+        writer.write(chunk);
+
+        offset = end;
+    }
+
+    let put_result = writer.finish().await.map_err(StorageError::from)?;
+
+    Ok(TransferResult::new(bytes_uploaded, put_result).with_parts(chunksize))
+}
+
+/// Downloads `start..start + target.len_bytes()` of an object into `target` with parallel ranged gets.
+async fn download_into_buffer(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    start: u64,
+    target: Arc<buffer::WritableBuffer>,
+    chunksize: usize,
+    concurrency: usize,
+) -> Result<u64, StorageError> {
+    let total_size = target.len_bytes();
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut join_set = JoinSet::new();
+
+    let mut offset = 0;
+    while offset < total_size {
+        let len = std::cmp::min(chunksize, total_size - offset);
+        let store = Arc::clone(store);
+        let remote_path = remote_path.clone();
+        let semaphore = Arc::clone(&semaphore);
+        let target = Arc::clone(&target);
+
+        join_set.spawn(request_tag::propagate(async move {
+            let _permit = semaphore.acquire().await.unwrap();
+            let range_start = start + offset as u64;
+            let data = store
+                .get_range(&remote_path, range_start..range_start + len as u64)
                 .await
                 .map_err(StorageError::from)?;
-            Ok(bytes_written)
-        })
+            if data.len() != len {
+                return Err(StorageError::ObjectStoreError(format!(
+                    "Expected {} bytes at offset {}, received {}",
+                    len,
+                    range_start,
+                    data.len()
+                )));
+            }
+            target.write_at(offset, &data);
+            Ok::<(), StorageError>(())
+        }));
+
+        offset += len;
     }
 
-    #[pyo3(signature = (path, range=None))]
-    fn get<'p>(
-        &self,
-        py: Python<'p>,
-        path: &str,
-        range: Option<ByteRangeLike>,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let path = parse_path(path)?;
+    while let Some(result) = join_set.join_next().await {
+        result.map_err(|e| StorageError::ObjectStoreError(e.to_string()))??;
+    }
 
-        if let Some(byte_range) = range {
-            future_into_py(py, async move {
-                let start = byte_range.offset;
-                let length = byte_range.size;
-                let result = store
-                    .get_range(&path, start..start + length)
-                    .await
-                    .map_err(StorageError::from)?;
-                Ok(PyBytes::new(result))
-            })
-        } else {
-            future_into_py(py, async move {
-                let result = store.get(&path).await.map_err(StorageError::from)?;
-                let data = result.bytes().await.map_err(StorageError::from)?;
-                Ok(PyBytes::new(data))
-            })
+    Ok(total_size as u64)
+}
+
+/// Checks that `start..end` is a valid range exactly as long as the `target_len` bytes being filled.
+fn check_target_range(start: u64, end: u64, target_len: usize) -> Result<(), StorageError> {
+    if end < start {
+        return Err(StorageError::ConfigError(format!("Invalid range: end {} is before start {}", end, start)));
+    }
+    if end - start != target_len as u64 {
+        return Err(StorageError::ConfigError(format!(
+            "Range {}..{} is {} bytes but the target buffer is {} bytes",
+            start,
+            end,
+            end - start,
+            target_len
+        )));
+    }
+    Ok(())
+}
+
+/// Splits a list of buffers, viewed as one logical stream, into `(buffer index, start, end)` pieces that
+/// never straddle a `chunksize` part boundary of that stream.
+fn split_at_part_boundaries(buffer_lens: &[usize], chunksize: usize) -> Vec<(usize, usize, usize)> {
+    let mut pieces = Vec::new();
+    let mut stream_offset = 0;
+    for (index, &len) in buffer_lens.iter().enumerate() {
+        let mut start = 0;
+        while start < len {
+            let part_remaining = chunksize - stream_offset % chunksize;
+            let end = std::cmp::min(len, start + part_remaining);
+            pieces.push((index, start, end));
+            stream_offset += end - start;
+            start = end;
         }
     }
+    pieces
+}
 
-    #[pyo3(signature = (local_path, remote_path))]
-    fn upload<'p>(
-        &self,
-        py: Python<'p>,
-        local_path: &str,
-        remote_path: &str,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let local_path = local_path.to_string();
-        let remote_path = parse_path(remote_path)?;
+/// Uploads `buffers` back to back as a single object, handing slices of the original buffers to the
+/// multipart writer instead of concatenating them first.
+async fn upload_buffers_multipart(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    buffers: Vec<bytes::Bytes>,
+    chunksize: usize,
+    concurrency: usize,
+    mode: WriteMode,
+    attributes: &Attributes,
+) -> Result<TransferResult, StorageError> {
+    let total_size: usize = buffers.iter().map(|buffer| buffer.len()).sum();
+
+    if total_size <= chunksize {
+        let payload: PutPayload = buffers.into_iter().collect();
+        let put_result = store
+            .put_opts(remote_path, payload, mode.put_options(attributes))
+            .await
+            .map_err(StorageError::from)?;
+        return Ok(TransferResult::new(total_size as u64, put_result));
+    }
 
-        future_into_py(py, async move {
-            let data = fs::read(local_path).await.map_err(StorageError::from)?;
-            let bytes_uploaded = data.len() as u64;
-            store
-                .put(&remote_path, data.into())
-                .await
-                .map_err(StorageError::from)?;
-            Ok(bytes_uploaded)
-        })
+    if mode == WriteMode::Create {
+        ensure_absent(store, remote_path).await?;
     }
 
-    #[pyo3(signature = (remote_path, local_path))]
-    fn download<'p>(
-        &self,
-        py: Python<'p>,
-        remote_path: &str,
-        local_path: &str,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let remote_path = parse_path(remote_path)?;
-        let local_path = local_path.to_string();
+    let chunksize = multipart_safe_chunk_size(total_size as u64, chunksize)?;
+    let upload = store.put_multipart_opts(remote_path, multipart_options(attributes)).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    let upload = abort::on_drop(upload);
+    let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
 
-        future_into_py(py, async move {
-            let result = store.get(&remote_path).await.map_err(StorageError::from)?;
-            let data = result.bytes().await.map_err(StorageError::from)?;
-            let bytes_downloaded = data.len() as u64;
-            fs::write(&local_path, data)
-                .await
-                .map_err(StorageError::from)?;
-            Ok(bytes_downloaded)
-        })
+    let buffer_lens: Vec<usize> = buffers.iter().map(|buffer| buffer.len()).collect();
+    for (index, start, end) in split_at_part_boundaries(&buffer_lens, chunksize) {
+        writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
+        writer.put(buffers[index].slice(start..end));
     }
 
-    #[pyo3(signature = (local_path, remote_path, multipart_chunksize=None, max_concurrency=None))]
-    fn upload_multipart_from_file<'p>(
-        &self,
-        py: Python<'p>,
-        local_path: &str,
-        remote_path: &str,
-        multipart_chunksize: Option<usize>,
-        max_concurrency: Option<usize>,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let local_path = local_path.to_string();
-        let remote_path = parse_path(remote_path)?;
-        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
-        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+    let put_result = writer.finish().await.map_err(StorageError::from)?;
 
-        future_into_py(py, async move {
-            let mut file = tokio::fs::File::open(local_path).await.map_err(StorageError::from)?;
-            let file_size = file.metadata().await.map_err(StorageError::from)?.len();
-            let chunksize = multipart_safe_chunk_size(file_size, chunksize)?;
-            let upload = store.put_multipart(&remote_path).await.map_err(StorageError::from)?;
-            let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
-
-            let mut buffer = vec![0u8; chunksize];
-            loop {
-                let n = file.read(&mut buffer).await.map_err(StorageError::from)?;
-                if n == 0 {
-                    break;
-                }
-                writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
-                writer.write(&buffer[..n]);
-            }
+    Ok(TransferResult::new(total_size as u64, put_result).with_parts(chunksize))
+}
 
-            writer.finish().await.map_err(StorageError::from)?;
+/// Awaits the next chunk of the Python async iterator `chunks` on its event loop, returning `None`
+/// once it is exhausted.
+async fn next_chunk(chunks: &Py<PyAny>, locals: &TaskLocals) -> PyResult<Option<bytes::Bytes>> {
+    let next = Python::attach(|py| {
+        let awaitable = chunks.bind(py).call_method0("__anext__")?;
+        pyo3_async_runtimes::into_future_with_locals(locals, awaitable)
+    })?;
+    match next.await {
+        Ok(chunk) => Python::attach(|py| Ok(Some(buffer::ReadableBuffer::from_object(chunk.bind(py))?.into_bytes()))),
+        Err(err) if Python::attach(|py| err.is_instance_of::<pyo3::exceptions::PyStopAsyncIteration>(py)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
 
-            Ok(file_size)
-        })
+/// Uploads the chunks of the Python async iterator `chunks` back to back as a single object, using
+/// a multipart upload once they add up to more than `chunksize`.
+#[allow(clippy::too_many_arguments)]
+async fn upload_async_iter(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    chunks: Py<PyAny>,
+    locals: TaskLocals,
+    chunksize: usize,
+    concurrency: usize,
+    mode: WriteMode,
+    attributes: &Attributes,
+) -> PyResult<TransferResult> {
+    if mode == WriteMode::Create {
+        ensure_absent(store, remote_path).await?;
     }
+    let chunks = futures::stream::try_unfold((chunks, locals), |(chunks, locals)| async move {
+        let chunk = next_chunk(&chunks, &locals).await?;
+        Ok::<_, PyErr>(chunk.map(|chunk| (chunk, (chunks, locals))))
+    });
+    let chunks = std::pin::pin!(chunks);
+    upload_chunks(store, remote_path, chunks, chunksize, concurrency, mode, attributes).await
+}
 
-    #[pyo3(signature = (remote_path, data, multipart_chunksize=None, max_concurrency=None))]
-    fn upload_multipart_from_bytes<'p>(
-        &self,
-        py: Python<'p>,
-        remote_path: &str,
-        data: PyBytes,
-        multipart_chunksize: Option<usize>,
-        max_concurrency: Option<usize>,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let remote_path = parse_path(remote_path)?;
-        let data_bytes = data.into_inner();
-        let bytes_uploaded = data_bytes.len() as u64;
-        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
-        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+/// Uploads the chunks of `chunks` back to back as a single object, using a multipart upload once
+/// they add up to more than `chunksize`. If `chunks` yields an error, the multipart upload is
+/// aborted and the error returned.
+///
+/// The next chunk is only pulled once fewer than `concurrency` parts are in flight, so at most
+/// about `concurrency + 1` parts are held in memory however fast the chunks are produced.
+async fn upload_chunks<E: From<StorageError>>(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    mut chunks: impl futures::Stream<Item = Result<bytes::Bytes, E>> + Unpin,
+    chunksize: usize,
+    concurrency: usize,
+    mode: WriteMode,
+    attributes: &Attributes,
+) -> Result<TransferResult, E> {
+    let mut head = Vec::new();
+    let mut head_len = 0;
+    while head_len <= chunksize {
+        let Some(chunk) = chunks.next().await.transpose()? else {
+            let payload: PutPayload = head.into_iter().collect();
+            let put_result = store
+                .put_opts(remote_path, payload, mode.put_options(attributes))
+                .await
+                .map_err(StorageError::from)?;
+            return Ok(TransferResult::new(head_len as u64, put_result));
+        };
+        head_len += chunk.len();
+        head.push(chunk);
+    }
 
-        future_into_py(py, async move {
-            if data_bytes.len() <= chunksize {
-                let payload = PutPayload::from_bytes(data_bytes);
-                store
-                    .put(&remote_path, payload)
-                    .await
-                    .map_err(StorageError::from)?;
-                return Ok(bytes_uploaded);
-            }
+    let upload = store.put_multipart_opts(remote_path, multipart_options(attributes)).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    let upload = abort::on_drop(upload);
+    let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+
+    let mut size = 0;
+    let mut head = head.into_iter();
+    loop {
+        let chunk = match head.next() {
+            Some(chunk) => chunk,
+            None => match chunks.next().await {
+                Some(Ok(chunk)) => chunk,
+                None => break,
+                Some(Err(err)) => {
+                    writer.abort().await.map_err(StorageError::from)?;
+                    return Err(err);
+                }
+            },
+        };
+        // Pieces never straddle a part boundary, so each write starts at most one part upload.
+        let mut start = 0;
+        while start < chunk.len() {
+            let end = chunk.len().min(start + chunksize - size % chunksize);
+            writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
+            writer.put(chunk.slice(start..end));
+            size += end - start;
+            start = end;
+        }
+    }
 
-            let chunksize = multipart_safe_chunk_size(data_bytes.len() as u64, chunksize)?;
-            let upload = store.put_multipart(&remote_path).await.map_err(StorageError::from)?;
-            let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+    let put_result = writer.finish().await.map_err(StorageError::from)?;
 
-            let mut offset = 0;
-            while offset < data_bytes.len() {
-                let end = std::cmp::min(offset + chunksize, data_bytes.len());
-                let chunk = &data_bytes[offset..end];
+    Ok(TransferResult::new(size as u64, put_result).with_parts(chunksize))
+}
 
-                writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
-                writer.write(chunk);
+/// Uploads a memory-mapped file as multipart parts sliced from the map, without copying.
+///
+/// Returns `None`, after aborting the upload, if the file at `local_path` changed size while its
+/// parts were uploading.
+#[allow(clippy::too_many_arguments)]
+async fn upload_mapped_file(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    local_path: &str,
+    mapped: bytes::Bytes,
+    chunksize: usize,
+    concurrency: usize,
+    attributes: &Attributes,
+    progress: Option<&Progress>,
+) -> Result<Option<TransferResult>, StorageError> {
+    let upload = store.put_multipart_opts(remote_path, multipart_options(attributes)).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    let upload = abort::on_drop(upload);
+    let upload = progress::upload(upload, progress);
+    let mut writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+
+    for start in (0..mapped.len()).step_by(chunksize) {
+        writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
+        writer.put(mapped.slice(start..(start + chunksize).min(mapped.len())));
+    }
 
-                offset = end;
-            }
+    // Check the size once every part but the last buffered one has been read from the map.
+    writer.wait_for_capacity(0).await.map_err(StorageError::from)?;
+    if fs::metadata(local_path).await.map_err(StorageError::from)?.len() != mapped.len() as u64 {
+        writer.abort().await.map_err(StorageError::from)?;
+        return Ok(None);
+    }
 
-            writer.finish().await.map_err(StorageError::from)?;
+    let put_result = writer.finish().await.map_err(StorageError::from)?;
+    Ok(Some(TransferResult::new(mapped.len() as u64, put_result).with_parts(chunksize)))
+}
 
-            Ok(bytes_uploaded)
-        })
+/// Uploads `file` as multipart parts read into a reused buffer of `chunksize` bytes.
+async fn upload_file_buffered(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    mut file: LocalFile,
+    chunksize: usize,
+    concurrency: usize,
+    attributes: &Attributes,
+    progress: Option<&Progress>,
+) -> Result<PutResult, StorageError> {
+    let upload = store.put_multipart_opts(remote_path, multipart_options(attributes)).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    let upload = abort::on_drop(upload);
+    let mut writer = WriteMultipart::new_with_chunk_size(progress::upload(upload, progress), chunksize);
+
+    let mut buffer = Vec::with_capacity(chunksize);
+    let mut offset = 0;
+    loop {
+        buffer = file.read_at(offset, buffer).await.map_err(StorageError::from)?;
+        if buffer.is_empty() {
+            break;
+        }
+        offset += buffer.len() as u64;
+        writer.wait_for_capacity(concurrency).await.map_err(StorageError::from)?;
+        writer.write(&buffer);
     }
 
-    #[pyo3(signature = (remote_path, local_path, multipart_chunksize=None, max_concurrency=None))]
-    fn download_multipart_to_file<'p>(
-        &self,
-        py: Python<'p>,
-        remote_path: &str,
-        local_path: &str,
-        multipart_chunksize: Option<usize>,
-        max_concurrency: Option<usize>,
-    ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let remote_path = parse_path(remote_path)?;
-        let local_path = local_path.to_string();
-        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
-        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+    Ok(writer.finish().await?)
+}
 
-        future_into_py(py, async move {
-            let result = store.head(&remote_path).await.map_err(StorageError::from)?;
-            let total_size = result.size;
+/// Uploads the file at `local_path` with a single put, or in parts of `chunksize` bytes when it
+/// is larger. Returns the file size.
+#[allow(clippy::too_many_arguments)]
+async fn upload_local_file(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    local_path: &StdPath,
+    chunksize: usize,
+    concurrency: usize,
+    local_io: LocalIo,
+    retry_policy: &RetryPolicy,
+    attributes: &Attributes,
+) -> Result<u64, StorageError> {
+    let file = fs::File::open(local_path).await?;
+    let file_size = file.metadata().await?.len();
+    if file_size <= chunksize as u64 {
+        drop(file);
+        let data = bytes::Bytes::from(fs::read(local_path).await?);
+        run_with_retry(retry_policy, None, || async {
+            let options = WriteMode::Overwrite.put_options(attributes);
+            Ok(store.put_opts(remote_path, PutPayload::from_bytes(data.clone()), options).await?)
+        })
+        .await?;
+        return Ok(data.len() as u64);
+    }
 
-            // Create the temp file in the same directory of local_path because tempfile.persist()
-            // does not support cross filesystem.
-            let target_path = StdPath::new(&local_path);
-            let temp_dir = target_path.parent().unwrap_or_else(|| StdPath::new("."));
-            let temp_file = NamedTempFile::new_in(temp_dir).map_err(StorageError::from)?;
+    let chunksize = multipart_safe_chunk_size(file_size, chunksize)?;
+    let file = LocalFile::new(file.into_std().await, local_io)?;
+    upload_file_buffered(store, remote_path, file, chunksize, concurrency, attributes, None).await?;
+    Ok(file_size)
+}
 
-            let mut output_file = tokio::fs::File::from_std(temp_file.reopen().map_err(StorageError::from)?);
-            output_file.set_len(total_size).await.map_err(StorageError::from)?;
+/// Downloads the object `meta` describes to `local_path`, creating its parent directories, with
+/// parallel ranged gets when it is larger than `chunksize`. Returns `None` without downloading when
+/// `skip_existing` is set and the local file already matches the object.
+#[allow(clippy::too_many_arguments)]
+async fn download_local_file(
+    store: &Arc<dyn ObjectStore>,
+    meta: &ObjectMeta,
+    local_path: std::path::PathBuf,
+    chunksize: usize,
+    concurrency: usize,
+    local_io: LocalIo,
+    retry_policy: &RetryPolicy,
+    skip_existing: bool,
+    md5_etags: bool,
+) -> Result<Option<u64>, StorageError> {
+    if skip_existing && integrity::matches_local(local_path.clone(), meta, md5_etags).await? {
+        return Ok(None);
+    }
+    if let Some(parent) = local_path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let local_path = local_path.to_string_lossy().into_owned();
+    if meta.size <= chunksize as u64 {
+        let data = run_with_retry(retry_policy, None, || async {
+            Ok(get_version(store, &meta.location, None, None).await?)
+        })
+        .await?;
+        fs::write(&local_path, &data).await?;
+        return Ok(Some(data.len() as u64));
+    }
 
-            let num_chunks = (total_size + chunksize as u64 - 1) / chunksize as u64;
+    let download = download_file_multipart(
+        Arc::clone(store),
+        meta.location.clone(),
+        local_path,
+        chunksize,
+        concurrency,
+        retry_policy.clone(),
+        None,
+        local_io,
+        true,
+        None,
+        false,
+        None,
+        md5_etags,
+    );
+    Ok(Some(download.await?.bytes_transferred))
+}
 
-            let semaphore = Arc::new(Semaphore::new(concurrency));
-            let (tx , mut rx): (
-                mpsc::Sender<Result<(u64, Vec<u8>), StorageError>>,
-                mpsc::Receiver<Result<(u64, Vec<u8>), StorageError>>,
-            ) = mpsc::channel(concurrency);
-
-            // Start a task to process downloaded chunks in arrival order and write to file
-            let write_handle = tokio::task::spawn(async move {
-                while let Some(result) = rx.recv().await {
-                    match result {
-                        Ok((chunk_index, data)) => {
-                            output_file.seek(tokio::io::SeekFrom::Start(chunk_index as u64 * chunksize as u64)).await.map_err(StorageError::from)?;
-                            output_file.write_all(&data).await.map_err(StorageError::from)?;
+/// File a multipart download is written into until every chunk has arrived. Dropping it deletes the file.
+enum DownloadTarget {
+    /// A temporary file next to `local_path` that is renamed into place at the end.
+    Atomic(NamedTempFile),
+    /// `local_path` itself, written in place.
+    InPlace(tempfile::TempPath),
+}
+
+impl DownloadTarget {
+    fn create(local_path: &str, atomic: bool) -> Result<(Self, std::fs::File), StorageError> {
+        if !atomic {
+            let file = std::fs::OpenOptions::new().write(true).create(true).truncate(true).open(local_path)?;
+            return Ok((DownloadTarget::InPlace(tempfile::TempPath::from_path(local_path)), file));
+        }
+        // Create the temp file in the same directory of local_path because tempfile.persist()
+        // does not support cross filesystem.
+        let temp_dir = StdPath::new(local_path).parent().unwrap_or_else(|| StdPath::new("."));
+        let temp_file = NamedTempFile::new_in(temp_dir)?;
+        let file = temp_file.reopen()?;
+        Ok((DownloadTarget::Atomic(temp_file), file))
+    }
+
+    fn path(&self) -> &StdPath {
+        match self {
+            DownloadTarget::Atomic(temp_file) => temp_file.path(),
+            DownloadTarget::InPlace(path) => path,
+        }
+    }
+
+    fn finish(self, local_path: &str) -> Result<(), StorageError> {
+        match self {
+            DownloadTarget::Atomic(temp_file) => {
+                temp_file.persist(local_path)?;
+            }
+            DownloadTarget::InPlace(path) => {
+                path.keep().map_err(|e| StorageError::from(e.error))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Free list of chunk buffers shared by the chunk tasks and the writer of a download, so the
+/// number of allocations follows the concurrency rather than the number of chunks.
+#[derive(Clone)]
+struct BufferPool {
+    chunksize: usize,
+    free: Arc<std::sync::Mutex<Vec<Vec<u8>>>>,
+}
+
+impl BufferPool {
+    fn new(chunksize: usize) -> Self {
+        BufferPool {
+            chunksize,
+            free: Arc::default(),
+        }
+    }
+
+    /// Returns an empty buffer with room for one chunk.
+    fn take(&self) -> Vec<u8> {
+        self.free.lock().unwrap().pop().unwrap_or_else(|| Vec::with_capacity(self.chunksize))
+    }
+
+    fn give_back(&self, mut buffer: Vec<u8>) {
+        buffer.clear();
+        self.free.lock().unwrap().push(buffer);
+    }
+}
+
+type Chunk = Result<Vec<u8>, StorageError>;
+
+/// Where the chunk tasks of a file download put their data.
+#[derive(Clone)]
+enum ChunkSink {
+    /// Each chunk task writes its own chunk with `pwrite`, so writes run in parallel.
+    #[cfg(unix)]
+    Positional(PositionalFile),
+    /// Chunks are sent to one task that writes them in arrival order.
+    Writer(mpsc::Sender<(u64, Chunk)>),
+}
+
+impl ChunkSink {
+    /// Uses positional writes for the `tokio` backend on Unix. Otherwise spawns the writer task
+    /// into `writer`, writing through `local_io`.
+    fn open(
+        file: std::fs::File,
+        local_io: LocalIo,
+        concurrency: usize,
+        pool: &BufferPool,
+        progress: Option<Progress>,
+        writer: &mut JoinSet<Result<u64, StorageError>>,
+    ) -> Result<Self, StorageError> {
+        #[cfg(unix)]
+        if local_io == LocalIo::Tokio {
+            return Ok(ChunkSink::Positional(PositionalFile::new(file)));
+        }
+
+        let mut output_file = LocalFile::new(file, local_io).map_err(StorageError::from)?;
+        let (tx, mut rx) = mpsc::channel::<(u64, Chunk)>(concurrency);
+        let pool = pool.clone();
+        writer.spawn(async move {
+            let mut written = 0;
+            while let Some((offset, chunk)) = rx.recv().await {
+                let data = chunk?;
+                let len = data.len() as u64;
+                let data = output_file.write_all_at(offset, data).await.map_err(StorageError::from)?;
+                pool.give_back(data);
+                written += len;
+                if let Some(progress) = &progress {
+                    progress.advance(len).await?;
+                }
+            }
+            output_file.sync_all().await.map_err(StorageError::from)?;
+            drop(output_file);
+
+            Ok(written)
+        });
+        Ok(ChunkSink::Writer(tx))
+    }
+
+    /// Stores the chunk at `offset`, or the error fetching it. Returns the number of bytes written
+    /// by this call, which is 0 when the chunk is handed to the writer task.
+    #[cfg_attr(not(unix), allow(unused_variables))]
+    async fn put(&self, offset: u64, chunk: Chunk, pool: &BufferPool, progress: Option<&Progress>) -> Result<u64, StorageError> {
+        match self {
+            #[cfg(unix)]
+            ChunkSink::Positional(file) => {
+                let data = chunk?;
+                let len = data.len() as u64;
+                pool.give_back(file.write_all_at(offset, data).await.map_err(StorageError::from)?);
+                if let Some(progress) = progress {
+                    progress.advance(len).await?;
+                }
+                Ok(len)
+            }
+            ChunkSink::Writer(tx) => {
+                let _ = tx.send((offset, chunk)).await;
+                Ok(0)
+            }
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        match self {
+            #[cfg(unix)]
+            ChunkSink::Positional(_) => false,
+            ChunkSink::Writer(tx) => tx.is_closed(),
+        }
+    }
+
+    /// Flushes the file once every chunk has been put. Returns the bytes written by the writer task.
+    async fn finish(self, writer: &mut JoinSet<Result<u64, StorageError>>) -> Result<u64, StorageError> {
+        match self {
+            #[cfg(unix)]
+            ChunkSink::Positional(file) => {
+                file.sync_all().await.map_err(StorageError::from)?;
+                Ok(0)
+            }
+            ChunkSink::Writer(tx) => {
+                drop(tx);
+                match writer.join_next().await {
+                    Some(Ok(result)) => result,
+                    Some(Err(err)) => Err(StorageError::ObjectStoreError(format!("Download writer task failed: {}", err))),
+                    None => unreachable!("the writer task is spawned with the sink"),
+                }
+            }
+        }
+    }
+}
+
+/// Downloads an object to `local_path` with parallel ranged gets.
+///
+/// When `atomic` is set, chunks are written into a temporary file in the same directory that is
+/// renamed into place once every chunk has been written. Otherwise they are written into
+/// `local_path` directly. Either way the file being written is deleted if the download fails or
+/// the returned future is dropped before completion.
+///
+/// With `verify`, the file is checked against the object's ETag, or its size when the ETag isn't an
+/// MD5 or `md5_etags` is unset, before it is put in place.
+#[allow(clippy::too_many_arguments)]
+async fn download_file_multipart(
+    store: Arc<dyn ObjectStore>,
+    remote_path: Path,
+    local_path: String,
+    chunksize: usize,
+    concurrency: usize,
+    retry_policy: RetryPolicy,
+    deadline: Option<Deadline>,
+    local_io: LocalIo,
+    atomic: bool,
+    progress: Option<Progress>,
+    verify: bool,
+    version: Option<String>,
+    md5_etags: bool,
+) -> Result<TransferResult, StorageError> {
+    let (meta, encrypted) = integrity::capture_encryption(head_version(&store, &remote_path, version.as_deref())).await;
+    let meta = meta.map_err(StorageError::from)?;
+    let total_size = meta.size;
+    if let Some(progress) = &progress {
+        progress.start(total_size);
+    }
+
+    let (target, output_file) = DownloadTarget::create(&local_path, atomic)?;
+
+    let output_file = tokio::fs::File::from_std(output_file);
+    output_file.set_len(total_size).await.map_err(StorageError::from)?;
+    let output_file = output_file.into_std().await;
+
+    let num_chunks = (total_size + chunksize as u64 - 1) / chunksize as u64;
+
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let pool = BufferPool::new(chunksize);
+    // Like the chunk tasks below, the writer task is aborted if this future is dropped.
+    let mut writer = JoinSet::new();
+    let sink = ChunkSink::open(output_file, local_io, concurrency, &pool, progress.clone(), &mut writer)?;
+
+    // Download chunks in parallel. Chunk tasks are owned by the set so dropping this future, on
+    // a deadline or when a detached download is abandoned, also cancels them.
+    let mut chunk_tasks = JoinSet::new();
+    let mut written = 0;
+    for chunk_index in 0..num_chunks {
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let store = Arc::clone(&store);
+        let remote_path = remote_path.clone();
+        let version = version.clone();
+        let sink = sink.clone();
+        let pool = pool.clone();
+        let progress = progress.clone();
+        let retry_policy = retry_policy.clone();
+        let start_offset = chunk_index * chunksize as u64;
+        let end_offset = std::cmp::min(start_offset + chunksize as u64, total_size);
+
+        chunk_tasks.spawn(request_tag::propagate(async move {
+            let result = telemetry::chunk(run_with_retry(&retry_policy, deadline, || async {
+                get_version(&store, &remote_path, Some(start_offset..end_offset), version.as_deref())
+                    .await
+                    .map_err(StorageError::from)
+            }))
+            .await;
+            let chunk = result.map(|data| {
+                let mut buffer = pool.take();
+                buffer.extend_from_slice(&data);
+                buffer
+            });
+            let written = sink.put(start_offset, chunk, &pool, progress.as_ref()).await;
+            drop(permit);
+            written
+        }));
+        while let Some(joined) = chunk_tasks.try_join_next() {
+            written += joined.map_err(|e| StorageError::ObjectStoreError(format!("Download chunk task failed: {}", e)))??;
+        }
+        // The writer stops receiving after a failed chunk or write; its error is returned below.
+        if sink.is_closed() {
+            break;
+        }
+    }
+
+    while let Some(joined) = chunk_tasks.join_next().await {
+        written += joined.map_err(|e| StorageError::ObjectStoreError(format!("Download chunk task failed: {}", e)))??;
+    }
+    written += sink.finish(&mut writer).await?;
+
+    let verification = if verify {
+        Some(integrity::verify_download(target.path().to_path_buf(), written, &meta, md5_etags && !encrypted).await?)
+    } else {
+        None
+    };
+    target.finish(&local_path)?;
+
+    Ok(TransferResult {
+        bytes_transferred: total_size,
+        etag: meta.e_tag,
+        version_id: meta.version,
+        verification: verification.map(str::to_string),
+        ..Default::default()
+    })
+}
+
+/// Downloads an object (or the `(offset, size)` range of it) with parallel ranged gets of `chunksize` bytes.
+///
+/// Each chunk is retried under `retry_policy` and gives up once the shared `deadline` expires.
+#[allow(clippy::too_many_arguments)]
+async fn download_bytes_multipart(
+    store: &Arc<dyn ObjectStore>,
+    remote_path: &Path,
+    version: Option<&str>,
+    range: Option<(u64, u64)>,
+    chunksize: usize,
+    concurrency: usize,
+    retry_policy: &RetryPolicy,
+    deadline: Option<Deadline>,
+    progress: Option<&Progress>,
+) -> Result<bytes::Bytes, StorageError> {
+    let (start_offset, end_offset, total_size) = if let Some((start_val, length)) = range {
+        // Range read - no HEAD request needed, we know the exact range
+        let end_val = start_val + length - 1;
+        (start_val, end_val, length)
+    } else {
+        // Full file download - need HEAD request to get total size for chunking
+        let result = head_version(store, remote_path, version).await.map_err(StorageError::from)?;
+        let file_size = result.size;
+        (0, file_size - 1, file_size)
+    };
+
+    if let Some(progress) = progress {
+        progress.start(total_size);
+    }
+
+    if total_size <= chunksize as u64 {
+        let range = start_offset..end_offset + 1;
+        let data = run_with_retry(retry_policy, deadline, || async {
+            get_version(store, remote_path, Some(range.clone()), version).await.map_err(StorageError::from)
+        })
+        .await?;
+        if let Some(progress) = progress {
+            progress.advance(data.len() as u64).await?;
+        }
+        return Ok(data);
+    }
+
+    let num_chunks = (total_size + chunksize as u64 - 1) / chunksize as u64;
+    let mut chunks = Vec::with_capacity(num_chunks as usize);
+
+    // Each chunk is copied straight into its own slice of one allocation, which is reassembled
+    // without copying once every chunk has arrived.
+    let mut buffer = bytes::BytesMut::zeroed(total_size as usize);
+    for i in 0..num_chunks {
+        let chunk_start = start_offset + i * chunksize as u64;
+        let chunk_end = std::cmp::min(chunk_start + chunksize as u64 - 1, end_offset);
+        let slice = buffer.split_to((chunk_end - chunk_start + 1) as usize);
+        chunks.push((chunk_start, chunk_end, slice));
+    }
+
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    // A `JoinSet`, so that the chunks still in flight are cancelled along with the download.
+    let mut tasks = JoinSet::new();
+    let mut slices: Vec<Option<bytes::BytesMut>> = vec![None; chunks.len()];
+
+    for (index, (chunk_start, chunk_end, mut slice)) in chunks.into_iter().enumerate() {
+        let permit = semaphore.clone().acquire_owned().await.unwrap();
+        let store = Arc::clone(store);
+        let remote_path = remote_path.clone();
+        let version = version.map(str::to_string);
+        let retry_policy = retry_policy.clone();
+        let progress = progress.cloned();
+
+        tasks.spawn(request_tag::propagate(async move {
+            let result = telemetry::chunk(run_with_retry(&retry_policy, deadline, || async {
+                get_version(&store, &remote_path, Some(chunk_start..chunk_end + 1), version.as_deref())
+                    .await
+                    .map_err(StorageError::from)
+            }))
+            .await?;
+            drop(permit);
+            if result.len() != slice.len() {
+                return Err(StorageError::ObjectStoreError(format!(
+                    "Expected {} bytes at offset {}, received {}",
+                    slice.len(),
+                    chunk_start,
+                    result.len()
+                )));
+            }
+            slice.copy_from_slice(&result);
+            drop(result);
+            if let Some(progress) = progress {
+                progress.advance(slice.len() as u64).await?;
+            }
+            Ok::<(usize, bytes::BytesMut), StorageError>((index, slice))
+        }));
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (index, slice) = joined.map_err(|e| StorageError::ObjectStoreError(format!("Failed to join multipart download task: {:?}", e)))??;
+        slices[index] = Some(slice);
+    }
+    let mut data = bytes::BytesMut::new();
+    for slice in slices.into_iter().flatten() {
+        data.unsplit(slice);
+    }
+
+    Ok(data.freeze())
+}
+
+/// How write methods treat an existing object at the destination.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum WriteMode {
+    Overwrite,
+    /// Fail with `AlreadyExists` if the object exists. Single-request writes use a conditional put;
+    /// multipart uploads check with a HEAD before starting, which is racy.
+    Create,
+}
+
+impl WriteMode {
+    fn parse(mode: &str) -> Result<Self, StorageError> {
+        match mode {
+            "overwrite" => Ok(WriteMode::Overwrite),
+            "create" => Ok(WriteMode::Create),
+            other => Err(StorageError::ConfigError(format!(
+                "Invalid mode '{}': expected 'overwrite' or 'create'",
+                other
+            ))),
+        }
+    }
+
+    fn put_options(self, attributes: &Attributes) -> PutOptions {
+        let mode = match self {
+            WriteMode::Overwrite => PutMode::Overwrite,
+            WriteMode::Create => PutMode::Create,
+        };
+        PutOptions {
+            mode,
+            attributes: attributes.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the data of a read as `Bytes`, which exposes `data` through the buffer protocol without
+/// copying it, or with `as_memoryview` as a read-only memoryview of those `Bytes`. `data` is kept
+/// alive until the last view of it, e.g. an array made with `np.frombuffer`, is released.
+fn read_result(py: Python<'_>, data: bytes::Bytes, as_memoryview: bool) -> PyResult<Py<PyAny>> {
+    let exporter = Bound::new(py, PyBytes::new(data))?.into_any();
+    if as_memoryview {
+        Ok(PyMemoryView::from(&exporter)?.into_any().unbind())
+    } else {
+        Ok(exporter.unbind())
+    }
+}
+
+fn multipart_options(attributes: &Attributes) -> PutMultipartOpts {
+    PutMultipartOpts {
+        attributes: attributes.clone(),
+        ..Default::default()
+    }
+}
+
+/// Builds the attributes a write sets on the new object from its `content_type` and `metadata`,
+/// rejecting values that can't be sent as headers.
+fn parse_attributes(content_type: Option<&str>, metadata: Option<HashMap<String, String>>) -> Result<Attributes, StorageError> {
+    let mut attributes = Attributes::new();
+    if let Some(content_type) = content_type {
+        if HeaderValue::from_str(content_type).is_err() {
+            return Err(StorageError::ConfigError(format!(
+                "content_type '{}' is not a valid header value",
+                content_type
+            )));
+        }
+        attributes.insert(Attribute::ContentType, content_type.to_string().into());
+    }
+    let metadata = metadata.unwrap_or_default();
+    let mut invalid: Vec<&str> = metadata
+        .iter()
+        .filter(|(key, value)| HeaderName::from_bytes(key.as_bytes()).is_err() || HeaderValue::from_str(value).is_err())
+        .map(|(key, _)| key.as_str())
+        .collect();
+    if !invalid.is_empty() {
+        invalid.sort_unstable();
+        return Err(StorageError::ConfigError(format!(
+            "Metadata can't be sent as headers for keys: {}",
+            invalid.join(", ")
+        )));
+    }
+    for (key, value) in metadata {
+        attributes.insert(Attribute::Metadata(key.into()), value.into());
+    }
+    Ok(attributes)
+}
+
+/// How the local `file` provider writes an object.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LocalWriteMode {
+    /// Write to a temporary file and rename it into place when complete.
+    Atomic,
+    /// Write directly to the destination so other processes can observe progress.
+    Streaming,
+}
+
+impl LocalWriteMode {
+    fn parse(write_mode: &str) -> Result<Self, StorageError> {
+        match write_mode {
+            "atomic" => Ok(LocalWriteMode::Atomic),
+            "streaming" => Ok(LocalWriteMode::Streaming),
+            other => Err(StorageError::ConfigError(format!(
+                "Invalid write_mode '{}': expected 'atomic' or 'streaming'",
+                other
+            ))),
+        }
+    }
+}
+
+fn record_upload(
+    result: &mut DirectoryUploadResult,
+    joined: Result<(String, Result<u64, StorageError>), tokio::task::JoinError>,
+) -> Result<(), StorageError> {
+    match joined.map_err(|e| StorageError::ObjectStoreError(format!("Upload task failed: {}", e)))? {
+        (_, Ok(size)) => {
+            result.files_uploaded += 1;
+            result.bytes_uploaded += size;
+        }
+        (relative, Err(err)) => {
+            result.failed.insert(relative, err.to_string());
+        }
+    }
+    Ok(())
+}
+
+fn record_download(
+    result: &mut DirectoryDownloadResult,
+    joined: Result<(String, Result<Option<u64>, StorageError>), tokio::task::JoinError>,
+) -> Result<(), StorageError> {
+    match joined.map_err(|e| StorageError::ObjectStoreError(format!("Download task failed: {}", e)))? {
+        (_, Ok(Some(size))) => {
+            result.files_downloaded += 1;
+            result.bytes_downloaded += size;
+        }
+        (_, Ok(None)) => result.files_skipped += 1,
+        (key, Err(err)) => {
+            result.failed.insert(key, err.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Runs `op` on each of `paths` with up to `concurrency` in flight. Returns the output of each
+/// path that succeeded and the error message of each that failed, invalid paths included.
+async fn for_each_path<T, F, Fut>(
+    paths: Vec<String>,
+    concurrency: usize,
+    op: F,
+) -> Result<(HashMap<String, T>, HashMap<String, String>), StorageError>
+where
+    T: Send + 'static,
+    F: Fn(Path) -> Fut,
+    Fut: std::future::Future<Output = Result<T, StorageError>> + Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut join_set = JoinSet::new();
+    let mut succeeded = HashMap::new();
+    let mut failed = HashMap::new();
+    let mut record = |joined: Result<(String, Result<T, StorageError>), tokio::task::JoinError>| {
+        match joined.map_err(|e| StorageError::ObjectStoreError(format!("Task failed: {}", e)))? {
+            (key, Ok(output)) => {
+                succeeded.insert(key, output);
+            }
+            (key, Err(err)) => {
+                failed.insert(key, err.to_string());
+            }
+        }
+        Ok::<(), StorageError>(())
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    for key in paths {
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+        let path = match parse_path(&key) {
+            Ok(path) => path,
+            Err(err) => {
+                record(Ok((key, Err(err))))?;
+                continue;
+            }
+        };
+        let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+        let task = op(path);
+        join_set.spawn(request_tag::propagate(async move {
+            let outcome = task.await;
+            drop(permit);
+            (key, outcome)
+        }));
+        while let Some(joined) = join_set.try_join_next() {
+            record(joined)?;
+        }
+    }
+    while let Some(joined) = join_set.join_next().await {
+        record(joined)?;
+    }
+    Ok((succeeded, failed))
+}
+
+fn record_delete(
+    result: &mut DeleteResult,
+    joined: Result<(String, Result<(), StorageError>), tokio::task::JoinError>,
+) -> Result<(), StorageError> {
+    match joined.map_err(|e| StorageError::ObjectStoreError(format!("Delete task failed: {}", e)))? {
+        (key, Ok(())) => result.deleted.push(key),
+        (key, Err(err)) => {
+            result.failed.insert(key, err.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Lists `prefixes` with `list_tree` and returns the objects and directories sorted by key.
+async fn collect_listing(
+    store: &Arc<dyn ObjectStore>,
+    prefixes: &[String],
+    options: &ListOptions,
+) -> Result<ListResult, StorageError> {
+    let roots = prefixes.iter().map(|prefix| parse_path(prefix)).collect::<Result<Vec<_>, _>>()?;
+    let listing = list_tree(store, roots, options).await?;
+
+    let mut all_objects: Vec<ObjectMetadata> = listing.objects.into_iter().map(ObjectMetadata::from).collect();
+    let mut all_directories: Vec<ObjectMetadata> = listing
+        .directories
+        .into_iter()
+        .map(|path| ObjectMetadata {
+            key: path.to_string(),
+            last_modified: DateTime::<Utc>::from_timestamp(0, 0).unwrap(),
+            object_type: "directory".to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+    all_objects.sort_by(|a, b| a.key.cmp(&b.key));
+    all_directories.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let mut last_key = None;
+    if let Some(limit_val) = options.limit {
+        if let Some(bound) = &listing.complete_before {
+            all_objects.retain(|object| object.key < *bound);
+        }
+        all_objects.truncate(limit_val);
+        last_key = all_objects.last().map(|object| object.key.clone());
+    }
+
+    let stats = ListStats {
+        directories_listed: listing.directories_listed,
+        max_pending_directories: listing.max_pending_directories,
+    };
+    let mut result = ListResult::new(all_objects, all_directories).with_stats(stats);
+    result.last_key = last_key;
+    Ok(result)
+}
+
+/// Fetches the metadata of `version` of the object at `path`, or of its latest version.
+async fn head_version(
+    store: &Arc<dyn ObjectStore>,
+    path: &Path,
+    version: Option<&str>,
+) -> object_store::Result<ObjectMeta> {
+    let Some(version) = version else {
+        return store.head(path).await;
+    };
+    let options = GetOptions {
+        head: true,
+        version: Some(version.to_string()),
+        ..Default::default()
+    };
+    Ok(store.get_opts(path, options).await?.meta)
+}
+
+/// Reads `range` of `version` of the object at `path`, the whole object without a range and its
+/// latest version without a version.
+async fn get_version(
+    store: &Arc<dyn ObjectStore>,
+    path: &Path,
+    range: Option<std::ops::Range<u64>>,
+    version: Option<&str>,
+) -> object_store::Result<bytes::Bytes> {
+    match (range, version) {
+        (Some(range), None) => store.get_range(path, range).await,
+        (None, None) => store.get(path).await?.bytes().await,
+        (range, version) => {
+            let options = GetOptions {
+                range: range.map(Into::into),
+                version: version.map(str::to_string),
+                ..Default::default()
+            };
+            store.get_opts(path, options).await?.bytes().await
+        }
+    }
+}
+
+/// Heads `version` of `path`, or its latest version, failing with `NotFound` rather than a 404
+/// `HttpError` if it doesn't exist.
+async fn head_object(
+    store: &Arc<dyn ObjectStore>,
+    path: &Path,
+    version: Option<&str>,
+) -> Result<ObjectMeta, StorageError> {
+    match head_version(store, path, version).await {
+        Err(err @ object_store::Error::NotFound { .. }) => Err(StorageError::NotFound(format_error_chain(&err))),
+        result => result.map_err(StorageError::from),
+    }
+}
+
+/// Like `head_object`, plus the attributes of the response, e.g. the object's content type and
+/// user-defined metadata.
+async fn head_attributes(
+    store: &Arc<dyn ObjectStore>,
+    path: &Path,
+    version: Option<&str>,
+) -> Result<(ObjectMeta, Attributes), StorageError> {
+    let options = GetOptions {
+        head: true,
+        version: version.map(str::to_string),
+        ..Default::default()
+    };
+    match store.get_opts(path, options).await {
+        Ok(result) => Ok((result.meta, result.attributes)),
+        Err(err @ object_store::Error::NotFound { .. }) => Err(StorageError::NotFound(format_error_chain(&err))),
+        Err(err) => Err(StorageError::from(err)),
+    }
+}
+
+/// Copies `from` to `to` within the store and returns the size of the source.
+///
+/// Without `overwrite`, stores that can't copy conditionally fall back to checking that `to` is
+/// absent first, which doesn't guard against a concurrent writer.
+async fn copy_object(store: &Arc<dyn ObjectStore>, from: &Path, to: &Path, overwrite: bool) -> Result<u64, StorageError> {
+    let size = head_object(store, from, None).await?.size;
+    if overwrite {
+        store.copy(from, to).await.map_err(StorageError::from)?;
+        return Ok(size);
+    }
+    match store.copy_if_not_exists(from, to).await {
+        Err(object_store::Error::NotImplemented | object_store::Error::NotSupported { .. }) => {
+            ensure_absent(store, to).await?;
+            store.copy(from, to).await.map_err(StorageError::from)?;
+        }
+        result => result.map_err(StorageError::from)?,
+    }
+    Ok(size)
+}
+
+/// Renames `from` to `to` on a store with a native rename, e.g. a local filesystem, and returns
+/// the size of the object.
+async fn rename_object(store: &Arc<dyn ObjectStore>, from: &Path, to: &Path, overwrite: bool) -> Result<u64, StorageError> {
+    let size = head_object(store, from, None).await?.size;
+    if overwrite {
+        store.rename(from, to).await.map_err(StorageError::from)?;
+        return Ok(size);
+    }
+    match store.rename_if_not_exists(from, to).await {
+        Err(object_store::Error::NotImplemented | object_store::Error::NotSupported { .. }) => {
+            ensure_absent(store, to).await?;
+            store.rename(from, to).await.map_err(StorageError::from)?;
+        }
+        result => result.map_err(StorageError::from)?,
+    }
+    Ok(size)
+}
+
+/// Fails with `AlreadyExists` if `path` exists, for writes that can't be made conditional.
+async fn ensure_absent(store: &Arc<dyn ObjectStore>, path: &Path) -> Result<(), StorageError> {
+    match metacache::fresh(store.head(path)).await {
+        Ok(_) => Err(StorageError::AlreadyExists(format!("Object at location {} already exists", path))),
+        Err(object_store::Error::NotFound { .. }) => Ok(()),
+        Err(e) => Err(StorageError::from(e)),
+    }
+}
+
+/// Starts the upload behind `open_writer` and `open_write_stream`: a write-through file under
+/// `streaming_root` when set, otherwise a multipart upload that is aborted if dropped unfinished.
+async fn open_upload(
+    store: &Arc<dyn ObjectStore>,
+    path: &Path,
+    options: PutMultipartOpts,
+    mode: WriteMode,
+    streaming_root: Option<std::path::PathBuf>,
+) -> Result<Box<dyn object_store::MultipartUpload>, StorageError> {
+    if let Some(root) = streaming_root {
+        let upload = StreamingUpload::open(&root, path, mode == WriteMode::Create).await?;
+        return Ok(Box::new(upload));
+    }
+    if mode == WriteMode::Create {
+        ensure_absent(store, path).await?;
+    }
+    let upload = store.put_multipart_opts(path, options).await;
+    let upload = request_tag::upload(upload.map_err(StorageError::from)?);
+    Ok(abort::on_drop(upload))
+}
+
+#[derive(Clone)]
+enum ConfigValue {
+    String(String),
+    Number(i64),
+    Boolean(bool),
+    Map(BTreeMap<String, String>),
+}
+
+impl ConfigValue {
+    fn to_string(&self) -> String {
+        match self {
+            ConfigValue::String(s) => s.clone(),
+            ConfigValue::Number(n) => n.to_string(),
+            ConfigValue::Boolean(b) => b.to_string(),
+            ConfigValue::Map(map) => map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+#[pyclass]
+pub struct RustClient {
+    provider: String,
+    /// Provider options and credentials the stores were built from, to rebuild them after a fork.
+    configs: HashMap<String, ConfigValue>,
+    credentials_provider: Option<Py<PyAny>>,
+    /// `None` once the client is closed.
+    stores: RwLock<Option<Stores>>,
+    max_concurrency: usize,
+    max_pool_connections: usize,
+    multipart_chunksize: usize,
+    benchmark_prefix_pattern: Regex,
+    /// Per-request retries of the underlying HTTP client.
+    retry_config: RetryConfig,
+    retry_policy: RetryPolicy,
+    deadline: Option<Duration>,
+    local_io: LocalIo,
+    /// Directory holding the objects of the `file` provider.
+    local_root: Option<std::path::PathBuf>,
+    single_flight: Option<Arc<SingleFlight>>,
+    negative_cache: Option<Arc<NegativeCache>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    request_tag_generator: Option<Py<PyAny>>,
+    metrics: Arc<Metrics>,
+    /// Storage class of the objects written, unless a call overrides it.
+    storage_class: Option<String>,
+    /// Whether uploads are checked against the store once complete, unless a call overrides it.
+    verify_upload: bool,
+    /// Largest object `append` rewrites on stores without native appends.
+    max_append_rewrite_size: u64,
+}
+
+/// The stores a client sends its requests through and the process that built them.
+struct Stores {
+    pid: u32,
+    store: Arc<dyn ObjectStore>,
+    /// Multipart uploads of the underlying store, bypassing the wrappers of `store`. Only set for
+    /// providers whose uploads can be continued by id.
+    multipart_store: Option<Arc<dyn MultipartStore>>,
+    /// Permits for the requests in flight, `max_pool_connections` of them, shared by `store` and
+    /// the `retry_stores`.
+    connections: Arc<Semaphore>,
+    /// Stores built for per-call `retry` overrides, keyed by the override, at most
+    /// `MAX_RETRY_STORES` of them.
+    retry_stores: Mutex<HashMap<RetryKey, RetryStores>>,
+    presigner: Option<Arc<dyn Presigner>>,
+}
+
+impl Stores {
+    /// Drops the stores, or leaks them if they were inherited from the parent of a forked process
+    /// as in `RustClient::stores()`. Operations still holding them complete on them.
+    fn release(self) {
+        if self.pid != std::process::id() {
+            std::mem::forget(self);
+        }
+    }
+}
+
+type RetryKey = (usize, u64, u64, u64, u64);
+
+/// The store and multipart store built for a per-call `retry` override.
+type RetryStores = (Arc<dyn ObjectStore>, Option<Arc<dyn MultipartStore>>);
+
+/// Distinct `retry` overrides a client keeps stores for. Building one for another override drops
+/// one of the others, whose calls in flight complete on it.
+const MAX_RETRY_STORES: usize = 8;
+
+fn retry_key(retry: &RustRetryConfig) -> RetryKey {
+    (
+        retry.attempts,
+        retry.timeout,
+        retry.init_backoff_ms,
+        retry.max_backoff,
+        retry.backoff_multiplier.to_bits(),
+    )
+}
+
+fn closed_error() -> PyErr {
+    pyo3::exceptions::PyRuntimeError::new_err("RustClient is closed")
+}
+
+/// Builds the stores of `provider` for the current process, behind the client's circuit breaker
+/// and negative cache.
+#[allow(clippy::too_many_arguments)]
+fn build_stores(
+    py: Python<'_>,
+    provider: &str,
+    configs: &HashMap<String, ConfigValue>,
+    credentials_provider: Option<&Py<PyAny>>,
+    connections: Arc<Semaphore>,
+    retry_config: &RetryConfig,
+    circuit_breaker: Option<&Arc<CircuitBreaker>>,
+    negative_cache: Option<&Arc<NegativeCache>>,
+) -> PyResult<Stores> {
+    let credentials_provider = credentials_provider.map(|provider| provider.clone_ref(py));
+    let (mut store, multipart_store, presigner) = create_store(
+        provider,
+        Some(configs),
+        credentials_provider,
+        &connections,
+        retry_config,
+    )?;
+    if let Some(circuit_breaker) = circuit_breaker {
+        store = Arc::new(CircuitBreakerStore::new(store, Arc::clone(circuit_breaker)));
+    }
+    if let Some(negative_cache) = negative_cache {
+        store = Arc::new(NegativeCacheStore::new(store, Arc::clone(negative_cache)));
+    }
+    Ok(Stores {
+        pid: std::process::id(),
+        store,
+        multipart_store,
+        connections,
+        retry_stores: Mutex::default(),
+        presigner,
+    })
+}
+
+impl RustClient {
+    /// Returns the client's stores, first rebuilding them if the process forked since they were
+    /// built. The connection pools of a forked child's inherited stores are shared with the
+    /// parent, so those stores are leaked rather than dropped, which would close the connections.
+    fn stores(&self) -> PyResult<(Arc<dyn ObjectStore>, Option<Arc<dyn MultipartStore>>)> {
+        let pid = std::process::id();
+        {
+            let stores = self.stores.read().unwrap();
+            let stores = stores.as_ref().ok_or_else(closed_error)?;
+            if stores.pid == pid {
+                return Ok((Arc::clone(&stores.store), stores.multipart_store.clone()));
+            }
+        }
+        let mut stores = self.stores.write().unwrap();
+        let stores = stores.as_mut().ok_or_else(closed_error)?;
+        if stores.pid != pid {
+            let rebuilt = Python::attach(|py| self.build_stores(py))?;
+            std::mem::forget(std::mem::replace(stores, rebuilt));
+        }
+        Ok((Arc::clone(&stores.store), stores.multipart_store.clone()))
+    }
+
+    fn build_stores(&self, py: Python<'_>) -> PyResult<Stores> {
+        build_stores(
+            py,
+            &self.provider,
+            &self.configs,
+            self.credentials_provider.as_ref(),
+            Arc::new(Semaphore::new(self.max_pool_connections)),
+            &self.retry_config,
+            self.circuit_breaker.as_ref(),
+            self.negative_cache.as_ref(),
+        )
+    }
+
+    fn store(&self) -> PyResult<Arc<dyn ObjectStore>> {
+        Ok(self.stores()?.0)
+    }
+
+    /// Returns the signer of presigned URLs, failing for providers and clients that send unsigned
+    /// requests.
+    fn presigner(&self) -> PyResult<Arc<dyn Presigner>> {
+        self.stores()?;
+        let stores = self.stores.read().unwrap();
+        let stores = stores.as_ref().ok_or_else(closed_error)?;
+        let presigner = stores.presigner.clone().ok_or_else(|| {
+            StorageError::ConfigError(match self.provider.as_str() {
+                "s3" | "s8k" | "gcs_s3" | "gcs" => {
+                    "Presigned URLs can't be generated without credentials, remove skip_signature or anonymous"
+                        .to_string()
+                }
+                provider => format!("Presigned URLs are not supported by the '{}' provider", provider),
+            })
+        })?;
+        Ok(presigner)
+    }
+
+    /// Returns the store for a call with the given `retry` override: one built like the client's
+    /// but with those per-request retries, shared with later calls making the same override.
+    fn store_for(&self, py: Python<'_>, retry: Option<&RustRetryConfig>) -> PyResult<Arc<dyn ObjectStore>> {
+        match retry {
+            Some(retry) => Ok(self.retry_stores(py, retry)?.0),
+            None => self.store(),
+        }
+    }
+
+    /// Returns the stores for a `retry` override, building them on first use. They send their
+    /// requests within the client's `max_pool_connections`.
+    fn retry_stores(&self, py: Python<'_>, retry: &RustRetryConfig) -> PyResult<RetryStores> {
+        // Rebuilds the stores after a fork, which drops the overrides built by the parent.
+        self.stores()?;
+        let key = retry_key(retry);
+        let connections = {
+            let stores = self.stores.read().unwrap();
+            let stores = stores.as_ref().ok_or_else(closed_error)?;
+            if let Some(built) = stores.retry_stores.lock().unwrap().get(&key) {
+                return Ok(built.clone());
+            }
+            Arc::clone(&stores.connections)
+        };
+        // Built without holding the locks, as loading credentials may release the GIL.
+        let built = build_stores(
+            py,
+            &self.provider,
+            &self.configs,
+            self.credentials_provider.as_ref(),
+            connections,
+            &get_retry_config(Some(retry)),
+            self.circuit_breaker.as_ref(),
+            self.negative_cache.as_ref(),
+        )?;
+        let stores = self.stores.read().unwrap();
+        let mut retry_stores = stores.as_ref().ok_or_else(closed_error)?.retry_stores.lock().unwrap();
+        if !retry_stores.contains_key(&key) && retry_stores.len() >= MAX_RETRY_STORES {
+            let evicted = *retry_stores.keys().next().unwrap();
+            retry_stores.remove(&evicted);
+        }
+        let built = retry_stores.entry(key).or_insert((built.store, built.multipart_store));
+        Ok(built.clone())
+    }
+
+    /// Starts the deadline for a call, preferring the per-call value over the client default.
+    fn start_deadline(&self, deadline: Option<f64>) -> Result<Option<Deadline>, StorageError> {
+        Ok(parse_deadline(deadline)?.or(self.deadline).map(Deadline::after))
+    }
+
+    /// Resolves the tag for one operation: the per-call value, or else the one returned by
+    /// `request_tag_generator(operation)`. Also starts counting the operation in the client's metrics
+    /// and opens its span when tracing is enabled; the returned guard must be held until the call
+    /// returns, so that a call rejected before its future starts is counted as failed.
+    fn request_tag(
+        &self,
+        py: Python<'_>,
+        operation: &str,
+        request_tag: Option<&str>,
+    ) -> PyResult<(telemetry::Started, Option<HeaderValue>)> {
+        let bucket = telemetry::enabled().then(|| self.configs.get("bucket").map(ConfigValue::to_string)).flatten();
+        let started = telemetry::start(py, operation, &self.provider, bucket.as_deref(), &self.metrics);
+        let tag = match (request_tag, &self.request_tag_generator) {
+            (Some(tag), _) => Some(tag.to_string()),
+            (None, Some(generator)) => generator.call1(py, (operation,))?.extract::<Option<String>>(py)?,
+            (None, None) => None,
+        };
+        let tag = tag.map(|tag| request_tag::parse_tag(&tag)).transpose()?;
+        Ok((started, tag))
+    }
+
+    /// Parses `write_mode`, rejecting streaming writes on providers other than `file`.
+    ///
+    /// Remote object stores only make an object visible once it is complete, so every write to
+    /// them is atomic.
+    fn local_write_mode(&self, write_mode: &str) -> Result<LocalWriteMode, StorageError> {
+        let write_mode = LocalWriteMode::parse(write_mode)?;
+        if write_mode == LocalWriteMode::Streaming && self.provider != "file" {
+            return Err(StorageError::ConfigError(format!(
+                "write_mode='streaming' is only supported by the 'file' provider, not '{}'",
+                self.provider
+            )));
+        }
+        Ok(write_mode)
+    }
+
+    /// Parses the `content_type`, `metadata` and `storage_class` of a write, the latter defaulting
+    /// to the client's. The `file` provider can't store them.
+    fn write_attributes(
+        &self,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        storage_class: Option<&str>,
+    ) -> Result<Attributes, StorageError> {
+        let mut attributes = parse_attributes(content_type, metadata)?;
+        let storage_class = match storage_class {
+            Some(class) => Some(storage_class::parse(&self.provider, class)?),
+            None => self.storage_class.clone(),
+        };
+        if let Some(class) = storage_class {
+            attributes.insert(Attribute::StorageClass, class.into());
+        }
+        if !attributes.is_empty() && self.provider == "file" {
+            return Err(StorageError::ConfigError(
+                "content_type and metadata are not supported by the 'file' provider".to_string(),
+            ));
+        }
+        Ok(attributes)
+    }
+
+    /// Adds the Content-Encoding of `compression` to the `attributes` of a write.
+    fn encoded_attributes(
+        &self,
+        mut attributes: Attributes,
+        compression: Option<Compression>,
+    ) -> Result<Attributes, StorageError> {
+        if let Some(compression) = compression {
+            if self.provider == "file" {
+                return Err(StorageError::ConfigError(
+                    "compress is not supported by the 'file' provider".to_string(),
+                ));
+            }
+            attributes.insert(Attribute::ContentEncoding, compression.content_encoding().into());
+        }
+        Ok(attributes)
+    }
+
+    /// Checks that the provider can address object versions when a `version_id` is given.
+    fn object_version(&self, version_id: Option<String>) -> Result<Option<String>, StorageError> {
+        if version_id.is_some() && !matches!(self.provider.as_str(), "s3" | "s8k" | "gcs") {
+            return Err(StorageError::ConfigError(format!(
+                "version_id is not supported by the '{}' provider",
+                self.provider
+            )));
+        }
+        Ok(version_id)
+    }
+
+    /// Whether ETags that look like an MD5 are the MD5 of the object, which S3 doesn't guarantee
+    /// for objects it encrypts with a KMS or customer key.
+    fn md5_etags(&self) -> bool {
+        #[cfg(feature = "aws")]
+        if provider_feature(&self.provider) == Some("aws") {
+            let sse = parse_server_side_encryption(&self.configs).ok().flatten();
+            return !sse.is_some_and(|sse| sse.hides_md5());
+        }
+        true
+    }
+
+    /// Returns the store to drive a resumable upload with, with the `retry` override if any.
+    /// Resumed uploads are created without attributes, so `content_type`, `metadata` and
+    /// `storage_class` can't be set.
+    fn resumable_store(
+        &self,
+        py: Python<'_>,
+        attributes: &Attributes,
+        retry: Option<&RustRetryConfig>,
+    ) -> PyResult<Arc<dyn MultipartStore>> {
+        let store = match retry {
+            Some(retry) => self.retry_stores(py, retry)?.1,
+            None => self.stores()?.1,
+        };
+        let store = store.ok_or_else(|| {
+            StorageError::ConfigError(format!(
+                "resume_state_path is not supported by the '{}' provider",
+                self.provider
+            ))
+        })?;
+        if !attributes.is_empty() {
+            return Err(StorageError::ConfigError(
+                "content_type, metadata and storage_class can't be combined with resume_state_path".to_string(),
+            )
+            .into());
+        }
+        Ok(store)
+    }
+
+    /// Returns the directory to write through to when `write_mode` is streaming, or `None` for atomic writes.
+    fn streaming_root(&self, write_mode: &str) -> Result<Option<std::path::PathBuf>, StorageError> {
+        match self.local_write_mode(write_mode)? {
+            LocalWriteMode::Streaming => Ok(self.local_root.clone()),
+            LocalWriteMode::Atomic => Ok(None),
+        }
+    }
+
+    /// Downloads `path`, or its `start..end` range, into the writable buffer `target`, failing
+    /// before any request when `end` is given and the range doesn't match the buffer's length.
+    #[allow(clippy::too_many_arguments)]
+    fn download_into<'p>(
+        &self,
+        py: Python<'p>,
+        operation: &str,
+        path: &str,
+        target: &Bound<'p, PyAny>,
+        start: Option<u64>,
+        end: Option<u64>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, operation, request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let target = Arc::new(buffer::WritableBuffer::from_object(target)?);
+        let start = start.unwrap_or(0);
+        if let Some(end) = end {
+            check_target_range(start, end, target.len_bytes())?;
+        }
+        let chunksize = self.multipart_chunksize;
+        let concurrency = self.max_concurrency;
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            if end.is_none() {
+                let size = store.head(&path).await.map_err(StorageError::from)?.size;
+                check_target_range(start, size, target.len_bytes())?;
+            }
+            let bytes_downloaded = download_into_buffer(&store, &path, start, target, chunksize, concurrency).await?;
+            Ok(bytes_downloaded)
+        })))
+    }
+}
+
+#[pymethods]
+impl RustClient {
+    #[new]
+    #[pyo3(signature = (provider="s3", configs=None, credentials_provider=None, retry=None, request_tag_generator=None))]
+    fn new(
+        py: Python<'_>,
+        provider: &str,
+        configs: Option<&Bound<'_, PyDict>>,
+        credentials_provider: Option<Py<PyAny>>,
+        retry: Option<RustRetryConfig>,
+        request_tag_generator: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let provider = provider.to_lowercase();
+
+        // Convert Python Dict to Rust HashMap<String, ConfigValue>
+        let mut configs_map = HashMap::new();
+        let mut max_concurrency = DEFAULT_MAX_CONCURRENCY;
+        let mut max_pool_connections = DEFAULT_POOL_CONNECTIONS;
+        let mut multipart_chunksize = DEFAULT_MULTIPART_CHUNKSIZE;
+        let mut benchmark_prefix_pattern = DEFAULT_BENCHMARK_PREFIX_PATTERN.to_string();
+        let mut deadline = None;
+        let mut local_io = "tokio".to_string();
+        let mut dedupe_gets = false;
+        let mut negative_cache_ttl = metacache::DEFAULT_NEGATIVE_CACHE_TTL_SECS;
+        let mut circuit_breaker = None;
+        let mut validate_on_init = false;
+        let mut verify_upload = false;
+        let mut max_append_rewrite_size = append::DEFAULT_MAX_REWRITE_SIZE;
+
+        if let Some(configs_dict) = configs {
+            for (key, value) in configs_dict.iter() {
+                let key_str = key.extract::<String>()?;
+
+                // Convert Python values to ConfigValue
+                Python::attach(|_py| {
+                    if key_str == "max_concurrency" {
+                        if let Ok(int_val) = value.extract::<i64>() {
+                            max_concurrency = int_val as usize;
+                        }
+                    } else if key_str == "max_pool_connections" {
+                        if let Ok(int_val) = value.extract::<i64>() {
+                            max_pool_connections = int_val as usize;
+                        }
+                    } else if key_str == "multipart_chunksize" {
+                        if let Ok(int_val) = value.extract::<i64>() {
+                            multipart_chunksize = int_val as usize;
+                        }
+                    } else if key_str == "benchmark_prefix_pattern" {
+                        if let Ok(str_val) = value.extract::<String>() {
+                            benchmark_prefix_pattern = str_val;
+                        }
+                    } else if key_str == "deadline" {
+                        if let Ok(float_val) = value.extract::<f64>() {
+                            deadline = Some(float_val);
+                        }
+                    } else if key_str == "local_io" {
+                        if let Ok(str_val) = value.extract::<String>() {
+                            local_io = str_val;
+                        }
+                    } else if key_str == "dedupe_gets" {
+                        if let Ok(bool_val) = value.extract::<bool>() {
+                            dedupe_gets = bool_val;
+                        }
+                    } else if key_str == "negative_cache_ttl" {
+                        if let Ok(float_val) = value.extract::<f64>() {
+                            negative_cache_ttl = float_val;
+                        }
+                    } else if key_str == "validate_on_init" {
+                        if let Ok(bool_val) = value.extract::<bool>() {
+                            validate_on_init = bool_val;
+                        }
+                    } else if key_str == "verify_upload" {
+                        if let Ok(bool_val) = value.extract::<bool>() {
+                            verify_upload = bool_val;
+                        }
+                    } else if key_str == "max_append_rewrite_size" {
+                        if let Ok(int_val) = value.extract::<u64>() {
+                            max_append_rewrite_size = int_val;
+                        }
+                    } else if key_str == "circuit_breaker" {
+                        circuit_breaker = Some(value.extract::<HashMap<String, f64>>().map_err(|_| {
+                            StorageError::ConfigError(
+                                "circuit_breaker must be a dict of numeric thresholds".to_string(),
+                            )
+                        })?);
+                    } else if key_str == "default_headers" {
+                        let headers = value.extract::<BTreeMap<String, String>>().map_err(|_| {
+                            StorageError::ConfigError("default_headers must be a dict of str to str".to_string())
+                        })?;
+                        configs_map.insert(key_str.clone(), ConfigValue::Map(headers));
+                    } else if key_str == "endpoint_url" && value.extract::<String>().is_err() {
+                        let endpoints = value.extract::<Vec<String>>().map_err(|_| {
+                            StorageError::ConfigError("endpoint_url must be a str or a list of str".to_string())
+                        })?;
+                        configs_map.insert(key_str.clone(), ConfigValue::String(endpoints.join(",")));
+                    } else {
+                        if let Ok(bool_val) = value.extract::<bool>() {
+                            configs_map.insert(key_str.clone(), ConfigValue::Boolean(bool_val));
+                        } else if let Ok(int_val) = value.extract::<i64>() {
+                            configs_map.insert(key_str.clone(), ConfigValue::Number(int_val));
+                        } else {
+                            // Fallback: try to convert to string
+                            if let Ok(str_val) = value.extract::<String>() {
+                                configs_map.insert(key_str.clone(), ConfigValue::String(str_val));
+                            }
+                        }
+                    }
+                    Ok::<(), PyErr>(())
+                })?;
+            }
+        }
+
+        let benchmark_prefix_pattern = Regex::new(&benchmark_prefix_pattern).map_err(|e| {
+            StorageError::ConfigError(format!(
+                "Invalid benchmark_prefix_pattern '{}': {}",
+                benchmark_prefix_pattern, e
+            ))
+        })?;
+
+        let deadline = parse_deadline(deadline)?;
+        let local_io = LocalIo::resolve(&local_io)?;
+
+        if !negative_cache_ttl.is_finite() || negative_cache_ttl < 0.0 {
+            return Err(StorageError::ConfigError(format!(
+                "negative_cache_ttl must be a non-negative number of seconds, got {}",
+                negative_cache_ttl
+            ))
+            .into());
+        }
+
+        let circuit_breaker = circuit_breaker
+            .map(|options| CircuitBreakerConfig::from_options(&options))
+            .transpose()?
+            .map(|config| Arc::new(CircuitBreaker::new(config)));
+
+        let retry_config = resolve_retry_config(&configs_map, retry.as_ref());
+        let retry_policy = resolve_retry_policy(&configs_map, &retry_config)?;
+        let negative_cache = (negative_cache_ttl > 0.0)
+            .then(|| Arc::new(NegativeCache::new(Duration::from_secs_f64(negative_cache_ttl))));
+        let stores = build_stores(
+            py,
+            &provider,
+            &configs_map,
+            credentials_provider.as_ref(),
+            Arc::new(Semaphore::new(max_pool_connections)),
+            &retry_config,
+            circuit_breaker.as_ref(),
+            negative_cache.as_ref(),
+        )?;
+
+        let local_root = (provider == "file").then(|| local::base_path(Some(&configs_map))).transpose()?;
+        let storage_class = configs_map
+            .get("storage_class")
+            .map(|class| storage_class::parse(&provider, &class.to_string()))
+            .transpose()?;
+
+        if validate_on_init {
+            let anonymous = ["skip_signature", "anonymous"].into_iter().any(|key| {
+                configs_map.get(key).is_some_and(|value| value.to_string().parse().unwrap_or(false))
+            });
+            let request_payer_hint = matches!(provider.as_str(), "s3" | "s8k")
+                && !configs_map
+                    .get("request_payer")
+                    .is_some_and(|request_payer| request_payer.to_string().parse().unwrap_or(false));
+            py.detach(|| get_runtime().block_on(validate_store(&stores.store, anonymous)))
+                .map_err(|err| validation_error(err, &describe_target(&provider, &configs_map), request_payer_hint))?;
+        }
+
+        Ok(Self {
+            provider,
+            configs: configs_map,
+            credentials_provider,
+            stores: RwLock::new(Some(stores)),
+            max_concurrency,
+            max_pool_connections,
+            multipart_chunksize,
+            benchmark_prefix_pattern,
+            retry_config,
+            retry_policy,
+            deadline,
+            local_io,
+            local_root,
+            single_flight: dedupe_gets.then(Default::default),
+            negative_cache,
+            circuit_breaker,
+            request_tag_generator,
+            metrics: Arc::default(),
+            storage_class,
+            verify_upload,
+            max_append_rewrite_size,
+        })
+    }
+
+    #[pyo3(signature = (path, data, deadline=None, mode="overwrite", write_mode="atomic", detailed=false, request_tag=None, content_type=None, metadata=None, if_not_exists=false, if_match=None, retry=None, storage_class=None, compress=None, compression_level=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn put<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        data: PyBytes,
+        deadline: Option<f64>,
+        mode: &str,
+        write_mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        if_not_exists: bool,
+        if_match: Option<String>,
+        retry: Option<RustRetryConfig>,
+        storage_class: Option<&str>,
+        compress: Option<&str>,
+        compression_level: Option<i32>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "put", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let path = parse_path(path)?;
+        let data_bytes = data.into_inner();
+        let bytes_written = data_bytes.len() as u64;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let mode = if if_not_exists { WriteMode::Create } else { WriteMode::parse(mode)? };
+        let streaming_root = self.streaming_root(write_mode)?;
+        let compression = Compression::parse(compress, compression_level, metadata.as_ref())?;
+        let attributes = self.write_attributes(content_type, metadata, storage_class)?;
+        let attributes = self.encoded_attributes(attributes, compression)?;
+        let mut put_options = mode.put_options(&attributes);
+        if let Some(e_tag) = if_match {
+            if mode == WriteMode::Create || streaming_root.is_some() {
+                return Err(StorageError::ConfigError(
+                    "if_match can't be combined with if_not_exists, mode='create' or write_mode='streaming'".to_string(),
+                )
+                .into());
+            }
+            put_options.mode = PutMode::Update(UpdateVersion {
+                e_tag: Some(e_tag),
+                version: None,
+            });
+        }
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let data_bytes = match compression {
+                Some(compression) => compression.compress(data_bytes).await?,
+                None => data_bytes,
+            };
+            let (put_result, server_time) = server_time::capture(run_with_retry(&retry_policy, deadline, || async {
+                if let Some(root) = &streaming_root {
+                    let upload = StreamingUpload::open(root, &path, mode == WriteMode::Create).await?;
+                    return upload.write_all(data_bytes.clone()).await;
+                }
+                let payload = PutPayload::from_bytes(data_bytes.clone());
+                store.put_opts(&path, payload, put_options.clone()).await.map_err(StorageError::from)
+            }))
+            .await;
+            let mut result = TransferResult::new(data_bytes.len() as u64, put_result?).with_last_modified(server_time);
+            if compression.is_some() {
+                result = result.with_uncompressed_size(bytes_written);
+            }
+            Python::attach(|py| result.into_py_result(py, detailed, false))
+        }))
+    }
+
+    /// Appends `data` to the object at `path`, creating it if needed, and returns its new size.
+    /// The `file` provider appends to the file in place; other providers, GCS included as object_store
+    /// can't compose objects, rewrite the object under an ETag precondition, see `append::rewrite`.
+    #[pyo3(signature = (path, data, deadline=None, request_tag=None))]
+    fn append<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        data: PyBytes,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "append", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let data = data.into_inner();
+        let deadline = self.start_deadline(deadline)?;
+        let local_root = self.local_root.clone();
+        let max_rewrite_size = self.max_append_rewrite_size;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let write = async {
+                match &local_root {
+                    Some(root) => local::append(root, &path, data).await,
+                    None => append::rewrite(&store, &path, data, max_rewrite_size).await,
+                }
+            };
+            Ok(with_deadline(deadline, write).await?)
+        }))
+    }
+
+    #[pyo3(signature = (path, range=None, deadline=None, request_tag=None, version_id=None, retry=None, as_memoryview=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn get<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        range: Option<ByteRangeLike>,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        as_memoryview: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "get", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let path = parse_path(path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let version = self.object_version(version_id)?;
+        // Reads of different versions of a path must not share a fetch.
+        let single_flight = if version.is_none() { self.single_flight.clone() } else { None };
+
+        if let Some(byte_range) = range {
+            future_into_py(py, request_tag::scoped(tag, async move {
+                let start = byte_range.offset;
+                let length = byte_range.size;
+                let fetch_path = path.clone();
+                let fetch = async move {
+                    run_with_retry(&retry_policy, deadline, || async {
+                        get_version(&store, &fetch_path, Some(start..start + length), version.as_deref())
+                            .await
+                            .map_err(StorageError::from)
+                    })
+                    .await
+                };
+                let result = dedupe(single_flight.as_ref(), &path, Some(start..start + length), fetch).await?;
+                Python::attach(|py| read_result(py, result, as_memoryview))
+            }))
+        } else {
+            future_into_py(py, request_tag::scoped(tag, async move {
+                let fetch_path = path.clone();
+                let fetch = async move {
+                    run_with_retry(&retry_policy, deadline, || async {
+                        get_version(&store, &fetch_path, None, version.as_deref()).await.map_err(StorageError::from)
+                    })
+                    .await
+                };
+                let data = dedupe(single_flight.as_ref(), &path, None, fetch).await?;
+                Python::attach(|py| read_result(py, data, as_memoryview))
+            }))
+        }
+    }
+
+    /// Gets the `(start, end)` byte ranges of the object at `path`, returned in request order.
+    /// Ranges may overlap or be out of order; nearby ones are fetched with one request.
+    #[pyo3(signature = (path, ranges, deadline=None, request_tag=None))]
+    fn get_ranges<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        ranges: Vec<(u64, u64)>,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "get_ranges", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        if let Some((start, end)) = ranges.iter().find(|(start, end)| start > end) {
+            return Err(StorageError::ConfigError(format!("Invalid range ({}, {}): start is after end", start, end)).into());
+        }
+        let ranges: Vec<std::ops::Range<u64>> = ranges.into_iter().map(|(start, end)| start..end).collect();
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let data = run_with_retry(&retry_policy, deadline, || async {
+                store.get_ranges(&path, &ranges).await.map_err(StorageError::from)
+            })
+            .await?;
+            Ok(data.into_iter().map(PyBytes::new).collect::<Vec<_>>())
+        }))
+    }
+
+    /// Gets `paths` with up to `max_concurrency` requests in flight, `max_pool_connections` by
+    /// default. Returns the data of each path that was read and the error message of each that
+    /// wasn't. With `total_size_limit`, every path is first checked with a HEAD request and nothing
+    /// is read if their sizes add up to more than the limit.
+    #[pyo3(signature = (paths, max_concurrency=None, total_size_limit=None, request_tag=None, deadline=None))]
+    fn get_many<'p>(
+        &self,
+        py: Python<'p>,
+        paths: Vec<String>,
+        max_concurrency: Option<usize>,
+        total_size_limit: Option<u64>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "get_many", request_tag)?;
+        let store = self.store()?;
+        let concurrency = max_concurrency.unwrap_or(self.max_pool_connections);
+        let retry_policy = self.retry_policy.clone();
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let mut failed = HashMap::new();
+            let mut paths = paths;
+            if let Some(limit) = total_size_limit {
+                let head = |path: Path| {
+                    let store = Arc::clone(&store);
+                    let retry_policy = retry_policy.clone();
+                    async move { run_with_retry(&retry_policy, None, || head_object(&store, &path, None)).await }
+                };
+                let (sizes, head_failed) = for_each_path(paths, concurrency, head).await?;
+                let total: u64 = sizes.values().map(|meta| meta.size).sum();
+                if total > limit {
+                    return Err(StorageError::ConfigError(format!(
+                        "get_many: objects total {} bytes, more than total_size_limit of {} bytes",
+                        total, limit
+                    ))
+                    .into());
+                }
+                failed = head_failed;
+                paths = sizes.into_keys().collect();
+            }
+
+            let get = |path: Path| {
+                let store = Arc::clone(&store);
+                let retry_policy = retry_policy.clone();
+                async move {
+                    run_with_retry(&retry_policy, None, || async {
+                        get_version(&store, &path, None, None).await.map_err(StorageError::from)
+                    })
+                    .await
+                }
+            };
+            let (data, get_failed) = for_each_path(paths, concurrency, get).await?;
+            failed.extend(get_failed);
+            let objects: HashMap<String, PyBytes> =
+                data.into_iter().map(|(key, data)| (key, PyBytes::new(data))).collect();
+            Ok((objects, failed))
+        })))
+    }
+
+    /// Deletes the object at `path`. With `missing_ok`, an object that doesn't exist is not an error.
+    ///
+    /// S3-compatible stores report success for a missing key either way.
+    #[pyo3(signature = (path, missing_ok=false, deadline=None, request_tag=None, retry=None))]
+    fn delete<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        missing_ok: bool,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "delete", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let path = parse_path(path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            run_with_retry(&retry_policy, deadline, || async {
+                match store.delete(&path).await {
+                    Err(object_store::Error::NotFound { .. }) if missing_ok => Ok(()),
+                    result => result.map_err(StorageError::from),
+                }
+            })
+            .await?;
+            Ok(Python::attach(|py| py.None()))
+        }))
+    }
+
+    /// Returns the metadata of the object at `path`, raising `FileNotFoundError` if it doesn't exist.
+    #[pyo3(signature = (path, deadline=None, request_tag=None, version_id=None, retry=None))]
+    fn info<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "info", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let path = parse_path(path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let version = self.object_version(version_id)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let head = run_with_retry(&retry_policy, deadline, || head_attributes(&store, &path, version.as_deref()));
+            let (head, storage_class) = storage_class::capture(head).await;
+            let (meta, attributes) = head?;
+            let mut metadata = ObjectMetadata::from(meta).with_attributes(&attributes);
+            metadata.storage_class = storage_class;
+            Ok(metadata)
+        }))
+    }
+
+    /// Returns whether an object exists at `path`. Errors other than NotFound, such as a denied
+    /// permission, still raise.
+    #[pyo3(signature = (path, deadline=None, request_tag=None, retry=None))]
+    fn exists<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "exists", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let path = parse_path(path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let exists = run_with_retry(&retry_policy, deadline, || async {
+                match store.head(&path).await {
+                    Ok(_) => Ok(true),
+                    Err(object_store::Error::NotFound { .. }) => Ok(false),
+                    Err(err) => Err(StorageError::from(err)),
+                }
+            })
+            .await?;
+            Ok(exists)
+        }))
+    }
+
+    /// Returns a URL presigned for a `method` request of `path`, valid for `expires_in` seconds. GET
+    /// URLs can override the `Content-Disposition` and `Content-Type` headers of the response.
+    #[pyo3(signature = (path, method="GET", expires_in=3600, response_content_disposition=None, response_content_type=None))]
+    fn generate_presigned_url(
+        &self,
+        py: Python<'_>,
+        path: &str,
+        method: &str,
+        expires_in: u64,
+        response_content_disposition: Option<String>,
+        response_content_type: Option<String>,
+    ) -> PyResult<String> {
+        let method = match method.to_ascii_uppercase().as_str() {
+            "GET" => http::Method::GET,
+            "PUT" => http::Method::PUT,
+            _ => {
+                return Err(
+                    StorageError::ConfigError(format!("method must be \"GET\" or \"PUT\", got {:?}", method)).into(),
+                )
+            }
+        };
+        let expires_in = Duration::from_secs(expires_in);
+        if expires_in.is_zero() || expires_in > presign::MAX_EXPIRES_IN {
+            return Err(StorageError::ConfigError(format!(
+                "expires_in must be between 1 and {} seconds, got {}",
+                presign::MAX_EXPIRES_IN.as_secs(),
+                expires_in.as_secs()
+            ))
+            .into());
+        }
+        let query: Vec<(&str, String)> = [
+            ("response-content-disposition", response_content_disposition),
+            ("response-content-type", response_content_type),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect();
+        if !query.is_empty() && method != http::Method::GET {
+            return Err(StorageError::ConfigError(
+                "response_content_disposition and response_content_type are only supported for GET URLs".to_string(),
+            )
+            .into());
+        }
+        let presigner = self.presigner()?;
+        let path = parse_path(path)?;
+        let url = py.detach(|| get_runtime().block_on(presigner.presign(method, &path, expires_in, &query)));
+        Ok(url.map_err(StorageError::from)?)
+    }
+
+    /// Copies `src_path` to `dst_path` within the store, without transferring the data through the client.
+    /// Returns the number of bytes copied.
+    #[pyo3(signature = (src_path, dst_path, overwrite=true, deadline=None, request_tag=None, retry=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn copy<'p>(
+        &self,
+        py: Python<'p>,
+        src_path: &str,
+        dst_path: &str,
+        overwrite: bool,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "copy", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let src_path = parse_path(src_path)?;
+        let dst_path = parse_path(dst_path)?;
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let size = run_with_retry(&retry_policy, deadline, || {
+                copy_object(&store, &src_path, &dst_path, overwrite)
+            })
+            .await?;
+            Ok(size)
+        }))
+    }
+
+    /// Moves `src` to `dst` and returns the number of bytes moved.
+    ///
+    /// Object stores have no rename, so the object is copied and `src` is only deleted once `dst`
+    /// is confirmed to hold it. Raises `FileExistsError` if `dst` exists and `overwrite` is false.
+    #[pyo3(signature = (src, dst, overwrite=true, deadline=None, request_tag=None, retry=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn rename<'p>(
+        &self,
+        py: Python<'p>,
+        src: &str,
+        dst: &str,
+        overwrite: bool,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "rename", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let src = parse_path(src)?;
+        let dst = parse_path(dst)?;
+        let native = self.provider == "file";
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let renamed = async {
+                if native {
+                    return run_with_retry(&retry_policy, deadline, || rename_object(&store, &src, &dst, overwrite)).await;
+                }
+                // Each step is retried on its own, so a copy that already succeeded isn't repeated.
+                let size = run_with_retry(&retry_policy, deadline, || copy_object(&store, &src, &dst, overwrite)).await?;
+                let copied = run_with_retry(&retry_policy, deadline, || metacache::fresh(head_object(&store, &dst, None))).await?;
+                if copied.size != size {
+                    return Err(StorageError::ObjectStoreError(format!(
+                        "Copy of {} to {} has {} bytes instead of {}; the source was kept",
+                        src, dst, copied.size, size
+                    )));
+                }
+                run_with_retry(&retry_policy, deadline, || async {
+                    match store.delete(&src).await {
+                        Err(object_store::Error::NotFound { .. }) => Ok(()),
+                        result => result.map_err(StorageError::from),
+                    }
+                })
+                .await?;
+                Ok(size)
+            };
+            Ok(renamed.await?)
+        }))
+    }
+
+    /// Deletes `paths` with up to `max_concurrency` requests in flight, `max_pool_connections` by
+    /// default. A failed key doesn't stop the others; each outcome is reported in the result.
+    #[pyo3(signature = (paths, max_concurrency=None, missing_ok=false, request_tag=None, deadline=None))]
+    fn delete_many<'p>(
+        &self,
+        py: Python<'p>,
+        paths: Vec<String>,
+        max_concurrency: Option<usize>,
+        missing_ok: bool,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "delete_many", request_tag)?;
+        let store = self.store()?;
+        let concurrency = max_concurrency.unwrap_or(self.max_pool_connections).max(1);
+        let retry_policy = self.retry_policy.clone();
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let semaphore = Arc::new(Semaphore::new(concurrency));
+            let mut join_set = JoinSet::new();
+            let mut result = DeleteResult::default();
+
+            for key in paths {
+                let path = match parse_path(&key) {
+                    Ok(path) => path,
+                    Err(err) => {
+                        result.failed.insert(key, err.to_string());
+                        continue;
+                    }
+                };
+                let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+                let store = Arc::clone(&store);
+                let retry_policy = retry_policy.clone();
+                join_set.spawn(request_tag::propagate(async move {
+                    let outcome = run_with_retry(&retry_policy, None, || async {
+                        match store.delete(&path).await {
+                            Err(object_store::Error::NotFound { .. }) if missing_ok => Ok(()),
+                            result => result.map_err(StorageError::from),
                         }
-                        Err(e) => {
-                            return Err(StorageError::from(e));
+                    })
+                    .await;
+                    drop(permit);
+                    (key, outcome)
+                }));
+                while let Some(joined) = join_set.try_join_next() {
+                    record_delete(&mut result, joined)?;
+                }
+            }
+            while let Some(joined) = join_set.join_next().await {
+                record_delete(&mut result, joined)?;
+            }
+            Ok(result)
+        })))
+    }
+
+    /// Settings this client resolved from its configs and defaults, for debugging.
+    fn get_config<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyDict>> {
+        let config = PyDict::new(py);
+        config.set_item("provider", &self.provider)?;
+        config.set_item("max_concurrency", self.max_concurrency)?;
+        config.set_item("max_pool_connections", self.max_pool_connections)?;
+        config.set_item("multipart_chunksize", self.multipart_chunksize)?;
+        config.set_item("deadline", self.deadline.map(|deadline| deadline.as_secs_f64()))?;
+        config.set_item("retry_max_attempts", self.retry_config.max_retries)?;
+        config.set_item("retry_init_backoff_ms", self.retry_config.backoff.init_backoff.as_millis() as u64)?;
+        config.set_item("retry_max_backoff_ms", self.retry_config.backoff.max_backoff.as_millis() as u64)?;
+        config.set_item("retry_backoff_multiplier", self.retry_config.backoff.base)?;
+        config.set_item("retry_timeout_secs", self.retry_config.retry_timeout.as_secs())?;
+        config.set_item("retry_operation_attempts", self.retry_policy.max_attempts)?;
+        Ok(config)
+    }
+
+    /// Number of reads served by an identical read already in flight, when `dedupe_gets` is enabled.
+    #[getter]
+    fn deduplicated_requests(&self) -> u64 {
+        self.single_flight.as_ref().map_or(0, |single_flight| single_flight.deduplicated())
+    }
+
+    /// Metadata lookups answered from the negative cache instead of the store.
+    #[getter]
+    fn negative_cache_hits(&self) -> u64 {
+        self.negative_cache.as_ref().map_or(0, |cache| cache.hits())
+    }
+
+    /// The circuit breaker's state and counters, or `None` if `circuit_breaker` isn't configured.
+    fn circuit_breaker_stats<'p>(&self, py: Python<'p>) -> PyResult<Option<Bound<'p, PyDict>>> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.stats(py)).transpose()
+    }
+
+    /// Counts, errors by class and latency histogram of each operation run since the client was
+    /// created or last reset, and the bytes sent and received in HTTP requests.
+    fn get_metrics<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyDict>> {
+        self.metrics.to_dict(py)
+    }
+
+    fn reset_metrics(&self) {
+        self.metrics.reset();
+    }
+
+    /// Rebuilds the client's stores, fetching new credentials from `credentials_provider` and
+    /// opening a new connection pool. Operations in flight complete on the previous stores.
+    fn refresh(&self, py: Python<'_>) -> PyResult<()> {
+        if self.stores.read().unwrap().is_none() {
+            return Err(closed_error());
+        }
+        let rebuilt = self.build_stores(py)?;
+        let previous = match self.stores.write().unwrap().as_mut() {
+            Some(stores) => std::mem::replace(stores, rebuilt),
+            None => return Err(closed_error()),
+        };
+        previous.release();
+        Ok(())
+    }
+
+    /// Drops the client's stores, after which its operations raise `RuntimeError`. Operations in
+    /// flight complete first. Closing a closed client does nothing.
+    fn close(&self) {
+        let previous = self.stores.write().unwrap().take();
+        if let Some(previous) = previous {
+            previous.release();
+        }
+    }
+
+    #[pyo3(signature = (path, buffer, start=None, end=None, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn get_into<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        buffer: &Bound<'p, PyAny>,
+        start: Option<u64>,
+        end: Option<u64>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        self.download_into(py, "get_into", path, buffer, start, end, request_tag, deadline)
+    }
+
+    #[pyo3(signature = (path, array, start=None, end=None, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn get_into_array<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        array: &Bound<'p, PyAny>,
+        start: Option<u64>,
+        end: Option<u64>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        self.download_into(py, "get_into_array", path, array, start, end, request_tag, deadline)
+    }
+
+    #[pyo3(signature = (local_path, remote_path, deadline=None, mode="overwrite", write_mode="atomic", detailed=false, request_tag=None, content_type=None, metadata=None, retry=None, storage_class=None, verify_upload=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload<'p>(
+        &self,
+        py: Python<'p>,
+        local_path: &str,
+        remote_path: &str,
+        deadline: Option<f64>,
+        mode: &str,
+        write_mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        retry: Option<RustRetryConfig>,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let local_path = local_path.to_string();
+        let remote_path = parse_path(remote_path)?;
+
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let mode = WriteMode::parse(mode)?;
+        let streaming_root = self.streaming_root(write_mode)?;
+        let attributes = self.write_attributes(content_type, metadata, storage_class)?;
+        let verify_upload = verify_upload.unwrap_or(self.verify_upload);
+        let md5_etags = self.md5_etags();
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let data = bytes::Bytes::from(fs::read(local_path).await.map_err(StorageError::from)?);
+            let bytes_uploaded = data.len() as u64;
+            let (put_result, server_time) = server_time::capture(run_with_retry(&retry_policy, deadline, || async {
+                if let Some(root) = &streaming_root {
+                    let upload = StreamingUpload::open(root, &remote_path, mode == WriteMode::Create).await?;
+                    return upload.write_all(data.clone()).await;
+                }
+                store
+                    .put_opts(&remote_path, PutPayload::from_bytes(data.clone()), mode.put_options(&attributes))
+                    .await
+                    .map_err(StorageError::from)
+            }))
+            .await;
+            let mut result = TransferResult::new(bytes_uploaded, put_result?).with_last_modified(server_time);
+            if verify_upload {
+                let md5 = integrity::bytes_md5(data);
+                let verified = integrity::verify_upload(&store, &remote_path, bytes_uploaded, md5, md5_etags);
+                result = result.with_metadata(with_deadline(deadline, verified).await?);
+            }
+            Python::attach(|py| result.into_py_result(py, detailed, false))
+        }))
+    }
+
+    #[pyo3(signature = (remote_path, local_path, deadline=None, request_tag=None, version_id=None, retry=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn download<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        local_path: &str,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "download", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let remote_path = parse_path(remote_path)?;
+        let local_path = local_path.to_string();
+
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let version = self.object_version(version_id)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let data = run_with_retry(&retry_policy, deadline, || async {
+                get_version(&store, &remote_path, None, version.as_deref()).await.map_err(StorageError::from)
+            })
+            .await?;
+            let bytes_downloaded = data.len() as u64;
+            fs::write(&local_path, data)
+                .await
+                .map_err(StorageError::from)?;
+            Ok(bytes_downloaded)
+        }))
+    }
+
+    #[pyo3(signature = (local_path, remote_path, multipart_chunksize=None, max_concurrency=None, deadline=None, mode="overwrite", use_mmap=false, detailed=false, request_tag=None, progress_callback=None, content_type=None, metadata=None, resume_state_path=None, retry=None, return_stats=false, storage_class=None, verify_upload=None, compress=None, compression_level=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_multipart_from_file<'p>(
+        &self,
+        py: Python<'p>,
+        local_path: &str,
+        remote_path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        mode: &str,
+        use_mmap: bool,
+        detailed: bool,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        resume_state_path: Option<std::path::PathBuf>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
+        compress: Option<&str>,
+        compression_level: Option<i32>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload_multipart_from_file", request_tag)?;
+        check_return_stats(detailed, return_stats)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let local_path = local_path.to_string();
+        let remote_path = parse_path(remote_path)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let deadline = self.start_deadline(deadline)?;
+        let mode = WriteMode::parse(mode)?;
+        let local_io = self.local_io;
+        let compression = Compression::parse(compress, compression_level, metadata.as_ref())?;
+        let attributes = self.write_attributes(content_type, metadata, storage_class)?;
+        let progress = progress_callback.map(Progress::new);
+        let upload_progress = progress.clone();
+        let resumable = match resume_state_path {
+            Some(_) if compression.is_some() => {
+                return Err(
+                    StorageError::ConfigError("compress can't be combined with resume_state_path".to_string()).into(),
+                )
+            }
+            Some(state_path) => Some((self.resumable_store(py, &attributes, retry.as_ref())?, state_path)),
+            None => None,
+        };
+        let attributes = self.encoded_attributes(attributes, compression)?;
+        let verify_upload = verify_upload.unwrap_or(self.verify_upload);
+        let md5_etags = self.md5_etags();
+        let verified_store = Arc::clone(&store);
+        let verified_path = remote_path.clone();
+        let md5_path = std::path::PathBuf::from(&local_path);
+        let md5 = async move {
+            match compression {
+                Some(compression) => compression.file_md5(md5_path).await,
+                None => integrity::file_md5(md5_path).await,
+            }
+        };
+
+        let upload = with_deadline(deadline, async move {
+            let progress = upload_progress.as_ref();
+            let file = tokio::fs::File::open(&local_path).await.map_err(StorageError::from)?;
+            let file_size = file.metadata().await.map_err(StorageError::from)?.len();
+            let chunksize = multipart_safe_chunk_size(file_size, chunksize)?;
+            if let Some(progress) = progress {
+                progress.start(file_size);
+            }
+            if mode == WriteMode::Create {
+                ensure_absent(&store, &remote_path).await?;
+            }
+
+            if let Some(compression) = compression {
+                let chunks = compression.compress_file(local_path.into()).then(|compressed| async move {
+                    let (chunk, read) = compressed?;
+                    if let Some(progress) = progress {
+                        progress.advance(read).await?;
+                    }
+                    Ok::<_, StorageError>(chunk)
+                });
+                let chunks = std::pin::pin!(chunks);
+                let upload = upload_chunks(&store, &remote_path, chunks, chunksize, concurrency, mode, &attributes);
+                return Ok(upload.await?.with_uncompressed_size(file_size));
+            }
+
+            if let Some((multipart_store, state_path)) = resumable {
+                let upload = resumable::upload_file(
+                    multipart_store,
+                    local_path.into(),
+                    remote_path,
+                    chunksize,
+                    concurrency,
+                    state_path,
+                    progress,
+                );
+                let (put_result, file_size) = upload.await?;
+                return Ok(TransferResult::new(file_size, put_result).with_parts(chunksize));
+            }
+
+            if use_mmap && file_size > 0 {
+                match mmap::map_file(&local_path, file_size) {
+                    Ok(mapped) => {
+                        let upload = upload_mapped_file(
+                            &store,
+                            &remote_path,
+                            &local_path,
+                            mapped,
+                            chunksize,
+                            concurrency,
+                            &attributes,
+                            progress,
+                        );
+                        if let Some(result) = upload.await? {
+                            return Ok(result);
                         }
+                        mmap::warn_fallback(&local_path, "file changed size during upload");
+                    }
+                    Err(err) => mmap::warn_fallback(&local_path, &err.to_string()),
+                }
+            }
+
+            let file = LocalFile::new(file.into_std().await, local_io).map_err(StorageError::from)?;
+            let put_result =
+                upload_file_buffered(&store, &remote_path, file, chunksize, concurrency, &attributes, progress).await?;
+
+            Ok(TransferResult::new(file_size, put_result).with_parts(chunksize))
+        });
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let (result, server_time) = server_time::capture(upload).await;
+            let mut result = result.map_err(|e| progress::into_py_err(progress.as_ref(), e))?.with_last_modified(server_time);
+            if verify_upload {
+                let sent = result.bytes_transferred;
+                let verified = integrity::verify_upload(&verified_store, &verified_path, sent, md5, md5_etags);
+                result = result.with_metadata(with_deadline(deadline, verified).await?);
+            }
+            Python::attach(|py| result.into_py_result(py, detailed, return_stats))
+        }))
+    }
+
+    #[pyo3(signature = (remote_path, data, multipart_chunksize=None, max_concurrency=None, deadline=None, mode="overwrite", detailed=false, request_tag=None, progress_callback=None, content_type=None, metadata=None, if_not_exists=false, retry=None, return_stats=false, storage_class=None, verify_upload=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_multipart_from_bytes<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        data: &Bound<'_, PyAny>,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        if_not_exists: bool,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload_multipart_from_bytes", request_tag)?;
+        check_return_stats(detailed, return_stats)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let remote_path = parse_path(remote_path)?;
+        let data_bytes = buffer::ReadableBuffer::from_object(data)?.into_bytes();
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let deadline = self.start_deadline(deadline)?;
+        let mode = if if_not_exists { WriteMode::Create } else { WriteMode::parse(mode)? };
+        let attributes = self.write_attributes(content_type, metadata, storage_class)?;
+        let progress = progress_callback.map(Progress::new);
+        let verify_upload = verify_upload.unwrap_or(self.verify_upload);
+        let md5_etags = self.md5_etags();
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let md5 = integrity::bytes_md5(data_bytes.clone());
+            let upload = upload_bytes_multipart(
+                &store,
+                &remote_path,
+                data_bytes,
+                chunksize,
+                concurrency,
+                mode,
+                &attributes,
+                progress.as_ref(),
+            );
+            let (result, server_time) = server_time::capture(with_deadline(deadline, upload)).await;
+            let result = result.map_err(|e| progress::into_py_err(progress.as_ref(), e))?;
+            let mut result = result.with_last_modified(server_time);
+            if verify_upload {
+                let sent = result.bytes_transferred;
+                let verified = integrity::verify_upload(&store, &remote_path, sent, md5, md5_etags);
+                result = result.with_metadata(with_deadline(deadline, verified).await?);
+            }
+            Python::attach(|py| result.into_py_result(py, detailed, return_stats))
+        }))
+    }
+
+    #[pyo3(signature = (remote_path, buffers, multipart_chunksize=None, max_concurrency=None, deadline=None, mode="overwrite", detailed=false, request_tag=None, content_type=None, metadata=None, return_stats=false, storage_class=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_multipart_from_buffers<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        buffers: Vec<PyBytes>,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        return_stats: bool,
+        storage_class: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload_multipart_from_buffers", request_tag)?;
+        check_return_stats(detailed, return_stats)?;
+        let store = self.store()?;
+        let remote_path = parse_path(remote_path)?;
+        let buffers: Vec<bytes::Bytes> = buffers.into_iter().map(PyBytes::into_inner).collect();
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let deadline = self.start_deadline(deadline)?;
+        let mode = WriteMode::parse(mode)?;
+        let attributes = self.write_attributes(content_type, metadata, storage_class)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let upload = upload_buffers_multipart(&store, &remote_path, buffers, chunksize, concurrency, mode, &attributes);
+            let (result, server_time) = server_time::capture(with_deadline(deadline, upload)).await;
+            let result = result?.with_last_modified(server_time);
+            Python::attach(|py| result.into_py_result(py, detailed, return_stats))
+        }))
+    }
+
+    /// Uploads the chunks yielded by the async iterator `aiter` to `remote_path` as one object,
+    /// re-chunked into parts of `multipart_chunksize` bytes. If the iterator raises, the multipart
+    /// upload is aborted and its exception propagated.
+    #[pyo3(signature = (remote_path, aiter, multipart_chunksize=None, max_concurrency=None, mode="overwrite", deadline=None, request_tag=None, content_type=None, metadata=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_from_async_iter<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        aiter: &Bound<'_, PyAny>,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        mode: &str,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload_from_async_iter", request_tag)?;
+        let store = self.store()?;
+        let remote_path = parse_path(remote_path)?;
+        let chunks = aiter.call_method0("__aiter__")?.unbind();
+        let locals = TaskLocals::with_running_loop(py)?.copy_context(py)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let mode = WriteMode::parse(mode)?;
+        let deadline = self.start_deadline(deadline)?;
+        let attributes = self.write_attributes(content_type, metadata, None)?;
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let upload =
+                upload_async_iter(&store, &remote_path, chunks, locals, chunksize, concurrency, mode, &attributes);
+            let result = call_within(deadline, upload).await?;
+            Python::attach(|py| result.into_py_result(py, false, false))
+        }))
+    }
+
+    /// Uploads the files below `local_dir` to `remote_prefix`, keeping their relative paths, with up
+    /// to `max_concurrency` files in flight, `max_pool_connections` by default. Files larger than
+    /// `multipart_chunksize` are uploaded in parts. A failed file doesn't stop the others.
+    #[pyo3(signature = (local_dir, remote_prefix, max_concurrency=None, include_pattern=None, follow_symlinks=false, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_directory<'p>(
+        &self,
+        py: Python<'p>,
+        local_dir: std::path::PathBuf,
+        remote_prefix: &str,
+        max_concurrency: Option<usize>,
+        include_pattern: Option<&str>,
+        follow_symlinks: bool,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "upload_directory", request_tag)?;
+        let store = self.store()?;
+        let remote_prefix = remote_prefix.trim_matches('/').to_string();
+        let include = include_pattern.map(Glob::new).transpose()?;
+        let concurrency = max_concurrency.unwrap_or(self.max_pool_connections).max(1);
+        let chunksize = self.multipart_chunksize;
+        let part_concurrency = self.max_concurrency;
+        let local_io = self.local_io;
+        let retry_policy = self.retry_policy.clone();
+        let attributes = self.write_attributes(None, None, None)?;
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            if !fs::metadata(&local_dir).await.map_err(StorageError::from)?.is_dir() {
+                return Err(StorageError::ConfigError(format!("{} is not a directory", local_dir.display())).into());
+            }
+            let semaphore = Arc::new(Semaphore::new(concurrency));
+            let mut join_set = JoinSet::new();
+            let mut result = DirectoryUploadResult::default();
+            let mut walk = DirectoryWalk::new(local_dir, follow_symlinks);
+
+            while let Some(entry) = walk.next().await {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err((relative, err)) => {
+                        result.failed.insert(relative, err.to_string());
+                        continue;
+                    }
+                };
+                if include.as_ref().is_some_and(|glob| !glob.matches(&entry.relative)) {
+                    continue;
+                }
+                let key = if remote_prefix.is_empty() {
+                    entry.relative.clone()
+                } else {
+                    format!("{}/{}", remote_prefix, entry.relative)
+                };
+                let remote_path = match parse_path(&key) {
+                    Ok(path) => path,
+                    Err(err) => {
+                        result.failed.insert(entry.relative, err.to_string());
+                        continue;
                     }
+                };
+                let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+                let store = Arc::clone(&store);
+                let retry_policy = retry_policy.clone();
+                let attributes = attributes.clone();
+                join_set.spawn(request_tag::propagate(async move {
+                    let upload = upload_local_file(
+                        &store,
+                        &remote_path,
+                        &entry.path,
+                        chunksize,
+                        part_concurrency,
+                        local_io,
+                        &retry_policy,
+                        &attributes,
+                    );
+                    let outcome = upload.await;
+                    drop(permit);
+                    (entry.relative, outcome)
+                }));
+                while let Some(joined) = join_set.try_join_next() {
+                    record_upload(&mut result, joined)?;
                 }
-                output_file.flush().await.map_err(StorageError::from)?;
-                output_file.sync_all().await.map_err(StorageError::from)?;
-                drop(output_file);
+            }
+            while let Some(joined) = join_set.join_next().await {
+                record_upload(&mut result, joined)?;
+            }
+            Ok(result)
+        })))
+    }
 
-                Ok::<(), StorageError>(())
-            });
+    /// Downloads the objects under `remote_prefix` into `local_dir`, keeping their keys relative to
+    /// the prefix, with up to `max_concurrency` objects in flight, `max_pool_connections` by default.
+    /// Objects larger than `multipart_chunksize` are downloaded in parallel chunks. A failed object
+    /// doesn't stop the others.
+    #[pyo3(signature = (remote_prefix, local_dir, max_concurrency=None, suffix=None, skip_existing=false, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_directory<'p>(
+        &self,
+        py: Python<'p>,
+        remote_prefix: &str,
+        local_dir: &str,
+        max_concurrency: Option<usize>,
+        suffix: Option<String>,
+        skip_existing: bool,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "download_directory", request_tag)?;
+        let store = self.store()?;
+        let root = parse_path(remote_prefix)?;
+        let local_dir = std::path::PathBuf::from(local_dir);
+        let concurrency = max_concurrency.unwrap_or(self.max_pool_connections).max(1);
+        let chunksize = self.multipart_chunksize;
+        let chunk_concurrency = self.max_concurrency;
+        let local_io = self.local_io;
+        let retry_policy = self.retry_policy.clone();
+        let md5_etags = self.md5_etags();
+        let options = ListOptions {
+            limit: None,
+            filter: ObjectFilter {
+                suffix,
+                ..Default::default()
+            },
+            max_depth: None,
+            max_concurrency: DEFAULT_POOL_CONNECTIONS,
+            traversal: Traversal::Bfs,
+            start_after: None,
+            descend: None,
+        };
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let listing = list_tree(&store, vec![root.clone()], &options).await?;
+            let semaphore = Arc::new(Semaphore::new(concurrency));
+            let mut join_set = JoinSet::new();
+            let mut result = DirectoryDownloadResult::default();
 
-            // Download chunks in parallel
-            for chunk_index in 0..num_chunks {
-                let permit = semaphore.clone().acquire_owned().await.unwrap();
+            for meta in listing.objects {
+                let key = meta.location.to_string();
+                let relative = key.strip_prefix(root.as_ref()).unwrap_or(&key).trim_start_matches('/');
+                if relative.is_empty() {
+                    continue;
+                }
+                let local_path = local_dir.join(relative);
+                let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
                 let store = Arc::clone(&store);
-                let remote_path = remote_path.clone();
-                let tx = tx.clone();
-                let start_offset = chunk_index * chunksize as u64;
-                let end_offset = std::cmp::min(start_offset + chunksize as u64, total_size);
-
-                tokio::task::spawn(async move {
-                    let range = start_offset..end_offset;
-                    match store.get_range(&remote_path, range).await {
-                        Ok(result) => {
-                            let data = result.to_vec();
-                            let _ = tx.send(Ok((chunk_index, data))).await;
-                        }
-                        Err(e) => {
-                            let _ = tx.send(Err(StorageError::from(e))).await;
-                        }
-                    }
+                let retry_policy = retry_policy.clone();
+                join_set.spawn(request_tag::propagate(async move {
+                    let download = download_local_file(
+                        &store,
+                        &meta,
+                        local_path,
+                        chunksize,
+                        chunk_concurrency,
+                        local_io,
+                        &retry_policy,
+                        skip_existing,
+                        md5_etags,
+                    );
+                    let outcome = download.await;
                     drop(permit);
-                });
+                    (key, outcome)
+                }));
+                while let Some(joined) = join_set.try_join_next() {
+                    record_download(&mut result, joined)?;
+                }
+            }
+            while let Some(joined) = join_set.join_next().await {
+                record_download(&mut result, joined)?;
+            }
+            Ok(result)
+        })))
+    }
+
+    /// Mirrors the objects under `remote_prefix` into `local_dir`, downloading only new and changed
+    /// ones and, with `delete_extraneous`, deleting local files that have no object.
+    #[pyo3(signature = (remote_prefix, local_dir, delete_extraneous=false, dry_run=false, max_concurrency=None, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn sync<'p>(
+        &self,
+        py: Python<'p>,
+        remote_prefix: &str,
+        local_dir: &str,
+        delete_extraneous: bool,
+        dry_run: bool,
+        max_concurrency: Option<usize>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "sync", request_tag)?;
+        let store = self.store()?;
+        let root = parse_path(remote_prefix)?;
+        let local_dir = std::path::PathBuf::from(local_dir);
+        let options = SyncOptions {
+            delete_extraneous,
+            dry_run,
+            concurrency: max_concurrency.unwrap_or(self.max_pool_connections),
+            chunksize: self.multipart_chunksize,
+            chunk_concurrency: self.max_concurrency,
+            local_io: self.local_io,
+            retry_policy: self.retry_policy.clone(),
+            md5_etags: self.md5_etags(),
+        };
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            Ok(sync_to_local(store, root, local_dir, options).await?)
+        })))
+    }
+
+    #[pyo3(signature = (remote_path, local_path, multipart_chunksize=None, max_concurrency=None, deadline=None, detachable=false, request_tag=None, atomic=true, progress_callback=None, verify_checksum=false, detailed=false, version_id=None, retry=None, return_stats=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_multipart_to_file<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        local_path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        detachable: bool,
+        request_tag: Option<&str>,
+        atomic: bool,
+        progress_callback: Option<Py<PyAny>>,
+        verify_checksum: bool,
+        detailed: bool,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "download_multipart_to_file", request_tag)?;
+        check_return_stats(detailed, return_stats)?;
+        if detachable && return_stats {
+            return Err(StorageError::ConfigError("return_stats cannot be combined with detachable".to_string()).into());
+        }
+        let store = self.store_for(py, retry.as_ref())?;
+        let remote_path = parse_path(remote_path)?;
+        let local_path = local_path.to_string();
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let progress = progress_callback.map(Progress::new);
+        let version = self.object_version(version_id)?;
+
+        let download = request_tag::scoped(
+            tag,
+            with_deadline(
+                deadline,
+                download_file_multipart(
+                    store,
+                    remote_path,
+                    local_path,
+                    chunksize,
+                    concurrency,
+                    retry_policy,
+                    deadline,
+                    self.local_io,
+                    atomic,
+                    progress.clone(),
+                    verify_checksum,
+                    version,
+                    self.md5_etags(),
+                ),
+            ),
+        );
+        if detachable {
+            let download = async move { Ok::<_, PyErr>(download.await?.bytes_transferred) };
+            return Ok(Bound::new(py, RustDownloadHandle::spawn(telemetry::instrument(download)))?.into_any());
+        }
+        future_into_py(py, async move {
+            let result = download.await.map_err(|e| progress::into_py_err(progress.as_ref(), e))?;
+            Python::attach(|py| result.into_py_result(py, detailed, return_stats))
+        })
+    }
+
+    #[pyo3(signature = (remote_path, range=None, multipart_chunksize=None, max_concurrency=None, deadline=None, request_tag=None, progress_callback=None, version_id=None, retry=None, return_stats=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_multipart_to_bytes<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        range: Option<ByteRangeLike>,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "download_multipart_to_bytes", request_tag)?;
+        let store = self.store_for(py, retry.as_ref())?;
+        let remote_path = parse_path(remote_path)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let range = range.map(|byte_range| (byte_range.offset, byte_range.size));
+        let retry_policy = self.retry_policy.clone();
+        let deadline = self.start_deadline(deadline)?;
+        let version = self.object_version(version_id)?;
+
+        let progress = progress_callback.map(Progress::new);
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let download = download_bytes_multipart(
+                &store,
+                &remote_path,
+                version.as_deref(),
+                range,
+                chunksize,
+                concurrency,
+                &retry_policy,
+                deadline,
+                progress.as_ref(),
+            );
+            let data = with_deadline(deadline, download)
+                .await
+                .map_err(|e| progress::into_py_err(progress.as_ref(), e))?;
+            let stats = return_stats.then(|| telemetry::transfer_stats(data.len() as u64));
+            Python::attach(|py| match stats {
+                Some(stats) => (PyBytes::new(data), stats).into_py_any(py),
+                None => PyBytes::new(data).into_py_any(py),
+            })
+        }))
+    }
+
+    #[pyo3(signature = (path, multipart_chunksize=None, max_concurrency=None, request_tag=None))]
+    fn open_read_stream<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        request_tag: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "open_read_stream", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let single_flight = self.single_flight.clone();
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let size = store.head(&path).await.map_err(StorageError::from)?.size;
+            let chunks = stream::spawn_chunk_reader(store, path, size, chunksize, concurrency, single_flight);
+            Ok(RustReadStream::new(chunks))
+        }))
+    }
+
+    /// Returns an async iterator over the bytes of `path`, from `start` up to `end` exclusive, in
+    /// chunks of `chunk_size` bytes. The object is streamed by a single get rather than ranged gets.
+    #[pyo3(signature = (path, chunk_size=DEFAULT_STREAM_CHUNK_SIZE, start=None, end=None, request_tag=None))]
+    fn get_stream(
+        &self,
+        py: Python<'_>,
+        path: &str,
+        chunk_size: usize,
+        start: Option<u64>,
+        end: Option<u64>,
+        request_tag: Option<&str>,
+    ) -> PyResult<RustGetStream> {
+        let (_started, tag) = self.request_tag(py, "get_stream", request_tag)?;
+        if chunk_size == 0 {
+            return Err(StorageError::ConfigError("chunk_size must be greater than 0".to_string()).into());
+        }
+        let range = match (start, end) {
+            (start, Some(end)) if end <= start.unwrap_or(0) => {
+                let message = format!("end ({}) must be greater than start ({})", end, start.unwrap_or(0));
+                return Err(StorageError::ConfigError(message).into());
+            }
+            (start, Some(end)) => Some(GetRange::Bounded(start.unwrap_or(0)..end)),
+            (Some(start), None) => Some(GetRange::Offset(start)),
+            (None, None) => None,
+        };
+        let path = parse_path(path)?;
+        Ok(RustGetStream::spawn(self.store()?, path, range, chunk_size, tag))
+    }
+
+    #[pyo3(signature = (path, buffer_size=None, request_tag=None))]
+    fn open_reader<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        buffer_size: Option<usize>,
+        request_tag: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "open_reader", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let buffer_size = buffer_size.unwrap_or(self.multipart_chunksize);
+
+        future_into_py(py, request_tag::scoped(tag.clone(), async move {
+            let size = head_object(&store, &path, None).await?.size;
+            Ok(RustObjectReader::new(ObjectReaderState::new(store, path, size, buffer_size), tag))
+        }))
+    }
+
+    /// Opens `path` for incremental writes, uploading every `multipart_chunksize` bytes as a part.
+    /// The upload is aborted if the writer is dropped before it is closed.
+    #[pyo3(signature = (path, multipart_chunksize=None, max_concurrency=None, request_tag=None, mode="overwrite", write_mode="atomic"))]
+    #[allow(clippy::too_many_arguments)]
+    fn open_writer<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        request_tag: Option<&str>,
+        mode: &str,
+        write_mode: &str,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "open_writer", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize).max(S3_MIN_PART_SIZE_BYTES);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let mode = WriteMode::parse(mode)?;
+        let streaming_root = self.streaming_root(write_mode)?;
+        let options = multipart_options(&self.write_attributes(None, None, None)?);
+
+        future_into_py(py, request_tag::scoped(tag.clone(), async move {
+            let upload = open_upload(&store, &path, options, mode, streaming_root).await?;
+            let writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+            Ok(RustObjectWriter::new(ObjectWriterState::new(writer, concurrency), tag))
+        }))
+    }
+
+    #[pyo3(signature = (path, multipart_chunksize=None, max_concurrency=None, mode="overwrite", write_mode="atomic", request_tag=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn open_write_stream<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        mode: &str,
+        write_mode: &str,
+        request_tag: Option<&str>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "open_write_stream", request_tag)?;
+        let store = self.store()?;
+        let path = parse_path(path)?;
+        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize).max(S3_MIN_PART_SIZE_BYTES);
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let mode = WriteMode::parse(mode)?;
+        let streaming_root = self.streaming_root(write_mode)?;
+        let options = multipart_options(&self.write_attributes(None, None, None)?);
+
+        future_into_py(py, request_tag::scoped(tag, async move {
+            let upload = open_upload(&store, &path, options, mode, streaming_root).await?;
+            let writer = WriteMultipart::new_with_chunk_size(upload, chunksize);
+            Ok(RustWriteStream::new(writer, concurrency))
+        }))
+    }
+
+    #[pyo3(signature = (requests, max_concurrency=None, request_tag=None, deadline=None))]
+    fn read_samples<'p>(
+        &self,
+        py: Python<'p>,
+        requests: Vec<(String, u64, u64)>,
+        max_concurrency: Option<usize>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "read_samples", request_tag)?;
+        let store = self.store()?;
+        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
+        let max_read_size = self.multipart_chunksize as u64;
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let results = samples::read_samples(&store, requests, concurrency, max_read_size).await;
+
+            let mut data = Vec::with_capacity(results.len());
+            let mut errors = HashMap::new();
+            for (index, result) in results.into_iter().enumerate() {
+                match result {
+                    Ok(bytes) => data.push(Some(PyBytes::new(bytes))),
+                    Err(message) => {
+                        data.push(None);
+                        errors.insert(index, message);
+                    }
+                }
             }
+            Ok((data, errors))
+        })))
+    }
+
+    #[pyo3(signature = (prefix, object_size, num_objects, concurrency, direction="both", read_only=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn benchmark<'p>(
+        &self,
+        py: Python<'p>,
+        prefix: &str,
+        object_size: u64,
+        num_objects: usize,
+        concurrency: usize,
+        direction: &str,
+        read_only: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let store = self.store()?;
+        let direction = BenchmarkDirection::parse(direction)?;
+        if !read_only {
+            benchmark::check_prefix_allowed(prefix, &self.benchmark_prefix_pattern)?;
+        }
+        let options = BenchmarkOptions {
+            prefix: prefix.to_string(),
+            object_size,
+            num_objects,
+            concurrency,
+            direction,
+            read_only,
+            multipart_chunksize: self.multipart_chunksize,
+            max_concurrency: self.max_concurrency,
+        };
+
+        future_into_py(py, async move {
+            let report = benchmark::run_benchmark(store, options).await?;
+            Ok(report)
+        })
+    }
+
+    /// Lists the objects whose keys match the glob `pattern`, sorted by key. Only directories that
+    /// can hold a match are listed.
+    #[pyo3(signature = (pattern, max_concurrency=None, request_tag=None, deadline=None))]
+    fn glob<'p>(
+        &self,
+        py: Python<'p>,
+        pattern: &str,
+        max_concurrency: Option<usize>,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "glob", request_tag)?;
+        let store = self.store()?;
+        let glob = Arc::new(Glob::new(pattern)?);
+        let root = parse_path(glob.prefix())?;
+        let filter = Arc::clone(&glob);
+        let options = ListOptions {
+            limit: None,
+            filter: ObjectFilter::default(),
+            max_depth: None,
+            max_concurrency: max_concurrency.unwrap_or(DEFAULT_POOL_CONNECTIONS),
+            traversal: Traversal::Bfs,
+            start_after: None,
+            descend: Some(Arc::new(move |directory: &Path| filter.may_contain(directory.as_ref()))),
+        };
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let listing = list_tree(&store, vec![root], &options).await?;
+            let mut objects: Vec<ObjectMetadata> = listing
+                .objects
+                .into_iter()
+                .filter(|object| glob.matches(object.location.as_ref()))
+                .map(ObjectMetadata::from)
+                .collect();
+            objects.sort_by(|a, b| a.key.cmp(&b.key));
+
+            let stats = ListStats {
+                directories_listed: listing.directories_listed,
+                max_pending_directories: listing.max_pending_directories,
+            };
+            Ok(ListResult::new(objects, Vec::new()).with_stats(stats))
+        })))
+    }
+
+    /// Counts the objects under `prefix` and their total size, walking it like `list_recursive`
+    /// without keeping the listed objects.
+    #[pyo3(signature = (prefix, max_depth=None, suffix=None, max_concurrency=DEFAULT_POOL_CONNECTIONS, request_tag=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn du<'p>(
+        &self,
+        py: Python<'p>,
+        prefix: &str,
+        max_depth: Option<usize>,
+        suffix: Option<String>,
+        max_concurrency: usize,
+        request_tag: Option<&str>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "du", request_tag)?;
+        let store = self.store()?;
+        let root = parse_path(prefix)?;
+        let options = ListOptions {
+            limit: None,
+            filter: ObjectFilter {
+                suffix,
+                ..Default::default()
+            },
+            max_depth,
+            max_concurrency,
+            traversal: Traversal::Bfs,
+            start_after: None,
+            descend: None,
+        };
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            let mut usage = Usage::default();
+            let walk = walk_tree(&store, vec![root], &options, &mut usage).await?;
+            let mut summary = UsageSummary::from(usage);
+            summary.stats = Some(ListStats {
+                directories_listed: walk.directories_listed,
+                max_pending_directories: walk.max_pending_directories,
+            });
+            Ok(summary)
+        })))
+    }
 
-            drop(tx);
+    /// Returns an async iterator over the objects under `prefix`, in batches of up to `batch_size`.
+    #[pyo3(signature = (prefix, batch_size=1000, suffix=None, request_tag=None, start_after=None))]
+    fn list_iter(
+        &self,
+        py: Python<'_>,
+        prefix: &str,
+        batch_size: usize,
+        suffix: Option<String>,
+        request_tag: Option<&str>,
+        start_after: Option<&str>,
+    ) -> PyResult<RustListIterator> {
+        let (_started, tag) = self.request_tag(py, "list_iter", request_tag)?;
+        let prefix = parse_path(prefix)?;
+        let start_after = start_after.map(parse_path).transpose()?;
+        Ok(RustListIterator::spawn(self.store()?, prefix, batch_size, suffix, start_after, tag))
+    }
+
+    #[pyo3(signature = (prefixes, limit=None, suffix=None, max_depth=None, max_concurrency=DEFAULT_POOL_CONNECTIONS, traversal="bfs", request_tag=None, start_after=None, modified_before=None, modified_after=None, min_size=None, max_size=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn list_recursive<'p>(
+        &self,
+        py: Python<'p>,
+        prefixes: Vec<String>,
+        limit: Option<usize>,
+        suffix: Option<String>,
+        max_depth: Option<usize>,
+        max_concurrency: usize,
+        traversal: &str,
+        request_tag: Option<&str>,
+        start_after: Option<String>,
+        modified_before: Option<&Bound<'_, PyAny>>,
+        modified_after: Option<&Bound<'_, PyAny>>,
+        min_size: Option<u64>,
+        max_size: Option<u64>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        let (_started, tag) = self.request_tag(py, "list_recursive", request_tag)?;
+        let store = self.store()?;
+        let options = ListOptions {
+            limit,
+            filter: ObjectFilter {
+                suffix,
+                modified_before: modified_before.map(|t| parse_timestamp(t, "modified_before")).transpose()?,
+                modified_after: modified_after.map(|t| parse_timestamp(t, "modified_after")).transpose()?,
+                min_size,
+                max_size,
+            },
+            max_depth,
+            max_concurrency,
+            traversal: Traversal::parse(traversal)?,
+            start_after,
+            descend: None,
+        };
+
+        let deadline = parse_deadline(deadline)?.map(Deadline::after);
+        future_into_py(py, request_tag::scoped(tag, call_within(deadline, async move {
+            Ok(collect_listing(&store, &prefixes, &options).await?)
+        })))
+    }
+
+    /// Blocking variant of `list_recursive`, which releases the GIL while it runs.
+    #[pyo3(signature = (prefixes, limit=None, suffix=None, max_depth=None, max_concurrency=DEFAULT_POOL_CONNECTIONS, traversal="bfs", request_tag=None, start_after=None, modified_before=None, modified_after=None, min_size=None, max_size=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn list_recursive_blocking<'p>(
+        &self,
+        py: Python<'p>,
+        prefixes: Vec<String>,
+        limit: Option<usize>,
+        suffix: Option<String>,
+        max_depth: Option<usize>,
+        max_concurrency: usize,
+        traversal: &str,
+        request_tag: Option<&str>,
+        start_after: Option<String>,
+        modified_before: Option<&Bound<'_, PyAny>>,
+        modified_after: Option<&Bound<'_, PyAny>>,
+        min_size: Option<u64>,
+        max_size: Option<u64>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| {
+            self.list_recursive(
+                py,
+                prefixes,
+                limit,
+                suffix,
+                max_depth,
+                max_concurrency,
+                traversal,
+                request_tag,
+                start_after,
+                modified_before,
+                modified_after,
+                min_size,
+                max_size,
+                deadline,
+            )
+        })
+    }
+
+    /// Blocking variant of `put`, which releases the GIL while it runs.
+    #[pyo3(signature = (path, data, deadline=None, mode="overwrite", write_mode="atomic", detailed=false, request_tag=None, content_type=None, metadata=None, if_not_exists=false, if_match=None, retry=None, storage_class=None, compress=None, compression_level=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn put_sync<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        data: PyBytes,
+        deadline: Option<f64>,
+        mode: &str,
+        write_mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        if_not_exists: bool,
+        if_match: Option<String>,
+        retry: Option<RustRetryConfig>,
+        storage_class: Option<&str>,
+        compress: Option<&str>,
+        compression_level: Option<i32>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| {
+            self.put(
+                py,
+                path,
+                data,
+                deadline,
+                mode,
+                write_mode,
+                detailed,
+                request_tag,
+                content_type,
+                metadata,
+                if_not_exists,
+                if_match,
+                retry,
+                storage_class,
+                compress,
+                compression_level,
+            )
+        })
+    }
+
+    /// Blocking variant of `get`, which releases the GIL while it runs.
+    #[pyo3(signature = (path, range=None, deadline=None, request_tag=None, version_id=None, retry=None, as_memoryview=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn get_sync<'p>(
+        &self,
+        py: Python<'p>,
+        path: &str,
+        range: Option<ByteRangeLike>,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        as_memoryview: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| self.get(py, path, range, deadline, request_tag, version_id, retry, as_memoryview))
+    }
 
-            write_handle.await.unwrap()?;
+    /// Blocking variant of `upload`, which releases the GIL while it runs.
+    #[pyo3(signature = (local_path, remote_path, deadline=None, mode="overwrite", write_mode="atomic", detailed=false, request_tag=None, content_type=None, metadata=None, retry=None, storage_class=None, verify_upload=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_sync<'p>(
+        &self,
+        py: Python<'p>,
+        local_path: &str,
+        remote_path: &str,
+        deadline: Option<f64>,
+        mode: &str,
+        write_mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        retry: Option<RustRetryConfig>,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| {
+            self.upload(
+                py,
+                local_path,
+                remote_path,
+                deadline,
+                mode,
+                write_mode,
+                detailed,
+                request_tag,
+                content_type,
+                metadata,
+                retry,
+                storage_class,
+                verify_upload,
+            )
+        })
+    }
 
-            temp_file.persist(&local_path).map_err(StorageError::from)?;
+    /// Blocking variant of `download`, which releases the GIL while it runs.
+    #[pyo3(signature = (remote_path, local_path, deadline=None, request_tag=None, version_id=None, retry=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_sync<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        local_path: &str,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| self.download(py, remote_path, local_path, deadline, request_tag, version_id, retry))
+    }
 
-            Ok(total_size)
+    /// Blocking variant of `upload_multipart_from_file`, which releases the GIL while it runs.
+    #[pyo3(signature = (local_path, remote_path, multipart_chunksize=None, max_concurrency=None, deadline=None, mode="overwrite", use_mmap=false, detailed=false, request_tag=None, progress_callback=None, content_type=None, metadata=None, resume_state_path=None, retry=None, return_stats=false, storage_class=None, verify_upload=None, compress=None, compression_level=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_multipart_from_file_sync<'p>(
+        &self,
+        py: Python<'p>,
+        local_path: &str,
+        remote_path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        mode: &str,
+        use_mmap: bool,
+        detailed: bool,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        resume_state_path: Option<std::path::PathBuf>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
+        compress: Option<&str>,
+        compression_level: Option<i32>,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| {
+            self.upload_multipart_from_file(
+                py,
+                local_path,
+                remote_path,
+                multipart_chunksize,
+                max_concurrency,
+                deadline,
+                mode,
+                use_mmap,
+                detailed,
+                request_tag,
+                progress_callback,
+                content_type,
+                metadata,
+                resume_state_path,
+                retry,
+                return_stats,
+                storage_class,
+                verify_upload,
+                compress,
+                compression_level,
+            )
         })
     }
 
-    #[pyo3(signature = (remote_path, range=None, multipart_chunksize=None, max_concurrency=None))]
-    fn download_multipart_to_bytes<'p>(
+    /// Blocking variant of `upload_multipart_from_bytes`, which releases the GIL while it runs.
+    #[pyo3(signature = (remote_path, data, multipart_chunksize=None, max_concurrency=None, deadline=None, mode="overwrite", detailed=false, request_tag=None, progress_callback=None, content_type=None, metadata=None, if_not_exists=false, retry=None, return_stats=false, storage_class=None, verify_upload=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_multipart_from_bytes_sync<'p>(
         &self,
         py: Python<'p>,
         remote_path: &str,
-        range: Option<ByteRangeLike>,
+        data: &Bound<'_, PyAny>,
         multipart_chunksize: Option<usize>,
         max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        mode: &str,
+        detailed: bool,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        if_not_exists: bool,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+        storage_class: Option<&str>,
+        verify_upload: Option<bool>,
     ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-        let remote_path = parse_path(remote_path)?;
-        let chunksize = multipart_chunksize.unwrap_or(self.multipart_chunksize);
-        let concurrency = max_concurrency.unwrap_or(self.max_concurrency);
-
-        future_into_py(py, async move {
-            let (start_offset, end_offset, total_size) = if let Some(byte_range) = range {
-                // Range read - no HEAD request needed, we know the exact range
-                let start_val = byte_range.offset;
-                let length = byte_range.size;
-                let end_val = start_val + length - 1;
-                (start_val, end_val, length)
-            } else {
-                // Full file download - need HEAD request to get total size for chunking
-                let result = store.head(&remote_path).await.map_err(StorageError::from)?;
-                let file_size = result.size;
-                (0, file_size - 1, file_size)
-            };
-
-            if total_size <= chunksize as u64 {
-                let range = start_offset..end_offset + 1;
-                let result = store.get_range(&remote_path, range).await.map_err(StorageError::from)?;
-                return Ok(PyBytes::new(result));
-            }
-
-            let num_chunks = (total_size + chunksize as u64 - 1) / chunksize as u64;
-            let mut chunks = Vec::with_capacity(num_chunks as usize);
-
-            for i in 0..num_chunks {
-                let chunk_start = start_offset + i * chunksize as u64;
-                let chunk_end = std::cmp::min(chunk_start + chunksize as u64 - 1, end_offset);
-                chunks.push((chunk_start, chunk_end));
-            }
-
-            let semaphore = Arc::new(Semaphore::new(concurrency));
-            let mut tasks = Vec::with_capacity(chunks.len());
-
-            for (chunk_start, chunk_end) in chunks {
-                let permit = semaphore.clone().acquire_owned().await.unwrap();
-                let store = Arc::clone(&store);
-                let remote_path = remote_path.clone();
-
-                tasks.push(tokio::task::spawn(async move {
-                    let range = chunk_start..chunk_end + 1;
-                    let result = store.get_range(&remote_path, range).await.map_err(StorageError::from)?;
-                    drop(permit);
-                    Ok::<bytes::Bytes, StorageError>(result)
-                }));
-            }
-
-            let mut segments = Vec::with_capacity(tasks.len());
-            for task in tasks {
-                let data = task.await.map_err(|e| StorageError::ObjectStoreError(format!("Failed to join multipart download task: {:?}", e)))??;
-                segments.push(data);
-            }
-
-            let final_data = segments.concat();
-
-            Ok(PyBytes::new(final_data.into()))
+        blocking(|| {
+            self.upload_multipart_from_bytes(
+                py,
+                remote_path,
+                data,
+                multipart_chunksize,
+                max_concurrency,
+                deadline,
+                mode,
+                detailed,
+                request_tag,
+                progress_callback,
+                content_type,
+                metadata,
+                if_not_exists,
+                retry,
+                return_stats,
+                storage_class,
+                verify_upload,
+            )
         })
     }
 
-    #[pyo3(signature = (prefixes, limit=None, suffix=None, max_depth=None, max_concurrency=DEFAULT_POOL_CONNECTIONS))]
-    fn list_recursive<'p>(
+    /// Blocking variant of `download_multipart_to_file`, which releases the GIL while it runs.
+    #[pyo3(signature = (remote_path, local_path, multipart_chunksize=None, max_concurrency=None, deadline=None, detachable=false, request_tag=None, atomic=true, progress_callback=None, verify_checksum=false, detailed=false, version_id=None, retry=None, return_stats=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_multipart_to_file_sync<'p>(
         &self,
         py: Python<'p>,
-        prefixes: Vec<String>,
-        limit: Option<usize>,
-        suffix: Option<String>,
-        max_depth: Option<usize>,
-        max_concurrency: usize,
+        remote_path: &str,
+        local_path: &str,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        detachable: bool,
+        request_tag: Option<&str>,
+        atomic: bool,
+        progress_callback: Option<Py<PyAny>>,
+        verify_checksum: bool,
+        detailed: bool,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
     ) -> PyResult<Bound<'p, PyAny>> {
-        let store = Arc::clone(&self.store);
-
-        future_into_py(py, async move {
-            async fn list_single_directory(
-                store: Arc<dyn ObjectStore>,
-                prefix: Path,
-                limit: Option<usize>,
-                suffix: Option<&str>,
-                depth: usize,
-            ) -> Result<(Vec<ObjectMeta>, Vec<Path>, usize), StorageError> {
-                let mut objects = Vec::new();
-                let mut directories = Vec::new();
-
-                let list_result = store
-                    .list_with_delimiter(Some(&prefix))
-                    .await
-                    .map_err(StorageError::from)?;
-
-                for entry in list_result.objects {
-                    if limit.is_some_and(|x| objects.len() >= x) {
-                        break;
-                    }
-
-                    if let Some(suffix_filter) = suffix {
-                        if !entry.location.to_string().ends_with(suffix_filter) {
-                            continue;
-                        }
-                    }
-
-                    objects.push(entry);
-                }
-
-                for common_prefix in list_result.common_prefixes {
-                    directories.push(common_prefix);
-                }
-
-                Ok((objects, directories, depth))
-            }
-
-            let mut dirs_to_visit = VecDeque::new();
-            for prefix in prefixes {
-                let path = parse_path(&prefix)?;
-                dirs_to_visit.push_back((path, 0));
-            }
-
-            let mut total_found: usize = 0;
-            let mut all_objects: Vec<ObjectMetadata> = Vec::new();
-            let mut all_directories: Vec<ObjectMetadata> = Vec::new();
-            let mut join_set = JoinSet::new();
-
-            while !dirs_to_visit.is_empty() || !join_set.is_empty() {
-                if !join_set.is_empty() {
-                    let result: Result<(Vec<ObjectMeta>, Vec<Path>, usize), StorageError> =
-                        join_set.join_next().await.unwrap().unwrap();
-                    let (objects, directories, depth) = result?;
-
-                    for directory in &directories {
-                        if max_depth.map_or(true, |max_d| depth < max_d) {
-                            dirs_to_visit.push_back((directory.clone(), depth + 1));
-                        }
-                    }
-
-                    for obj in objects {
-                        let metadata = ObjectMetadata::new(
-                            obj.location.to_string(),
-                            obj.size,
-                            obj.last_modified.to_rfc3339(),
-                            "file".to_string(),
-                            obj.e_tag.clone(),
-                        );
-                        all_objects.push(metadata);
-                    }
-
-                    for path in directories {
-                        let metadata = ObjectMetadata::new(
-                            path.to_string(),
-                            0,
-                            DateTime::<Utc>::from_timestamp(0, 0).unwrap().to_rfc3339(),
-                            "directory".to_string(),
-                            None,
-                        );
-                        all_directories.push(metadata);
-                    }
-
-                    total_found = all_objects.len();
-
-                    if limit.is_some_and(|x| total_found >= x) {
-                        break;
-                    }
-                }
-
-                while !dirs_to_visit.is_empty() && join_set.len() < max_concurrency {
-                    let (prefix, depth) = dirs_to_visit.pop_front().unwrap();
-
-                    if max_depth.is_some_and(|x| depth >= x) {
-                        continue;
-                    }
-
-                    let store_clone = Arc::clone(&store);
-                    let suffix_clone = suffix.clone();
-                    let remaining_limit = limit.map(|x| x - total_found);
-
-                    join_set.spawn(async move {
-                        list_single_directory(
-                            store_clone,
-                            prefix,
-                            remaining_limit,
-                            suffix_clone.as_deref(),
-                            depth,
-                        )
-                        .await
-                    });
-                }
-            }
-
-            all_objects.sort_by(|a, b| a.key.cmp(&b.key));
-            all_directories.sort_by(|a, b| a.key.cmp(&b.key));
-
-            if let Some(limit_val) = limit {
-                all_objects.truncate(limit_val);
-            }
+        blocking(|| {
+            self.download_multipart_to_file(
+                py,
+                remote_path,
+                local_path,
+                multipart_chunksize,
+                max_concurrency,
+                deadline,
+                detachable,
+                request_tag,
+                atomic,
+                progress_callback,
+                verify_checksum,
+                detailed,
+                version_id,
+                retry,
+                return_stats,
+            )
+        })
+    }
 
-            Ok(ListResult::new(all_objects, all_directories))
+    /// Blocking variant of `download_multipart_to_bytes`, which releases the GIL while it runs.
+    #[pyo3(signature = (remote_path, range=None, multipart_chunksize=None, max_concurrency=None, deadline=None, request_tag=None, progress_callback=None, version_id=None, retry=None, return_stats=false))]
+    #[allow(clippy::too_many_arguments)]
+    fn download_multipart_to_bytes_sync<'p>(
+        &self,
+        py: Python<'p>,
+        remote_path: &str,
+        range: Option<ByteRangeLike>,
+        multipart_chunksize: Option<usize>,
+        max_concurrency: Option<usize>,
+        deadline: Option<f64>,
+        request_tag: Option<&str>,
+        progress_callback: Option<Py<PyAny>>,
+        version_id: Option<String>,
+        retry: Option<RustRetryConfig>,
+        return_stats: bool,
+    ) -> PyResult<Bound<'p, PyAny>> {
+        blocking(|| {
+            self.download_multipart_to_bytes(
+                py,
+                remote_path,
+                range,
+                multipart_chunksize,
+                max_concurrency,
+                deadline,
+                request_tag,
+                progress_callback,
+                version_id,
+                retry,
+                return_stats,
+            )
         })
     }
 }
@@ -1080,9 +5351,51 @@ fn multistorageclient_rust(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_class::<RustClient>()?;
     m.add_class::<ObjectMetadata>()?;
     m.add_class::<ListResult>()?;
+    m.add_class::<ListStats>()?;
+    m.add_class::<UsageSummary>()?;
+    m.add_class::<TransferResult>()?;
+    m.add_class::<TransferStats>()?;
+    m.add_class::<DeleteResult>()?;
+    m.add_class::<DirectoryUploadResult>()?;
+    m.add_class::<DirectoryDownloadResult>()?;
+    m.add_class::<SyncResult>()?;
+    m.add_class::<PartInfo>()?;
     m.add_class::<RustRetryConfig>()?;
+    m.add_class::<BenchmarkReport>()?;
+    m.add_class::<BenchmarkPhaseStats>()?;
+    m.add_class::<RustReadStream>()?;
+    m.add_class::<RustGetStream>()?;
+    m.add_class::<RustWriteStream>()?;
+    m.add_class::<RustObjectReader>()?;
+    m.add_class::<RustObjectWriter>()?;
+    m.add_class::<RustDownloadHandle>()?;
+    m.add_class::<RustListIterator>()?;
+    m.add_class::<RustDownloadCancelled>()?;
+    m.add_function(wrap_pyfunction!(ratelimit::set_rate_limit, m)?)?;
+    m.add_function(wrap_pyfunction!(runtime::configure_runtime, m)?)?;
+    m.add_function(wrap_pyfunction!(logging::set_log_level, m)?)?;
+    let os = _py.import("os")?;
+    if os.hasattr("register_at_fork")? {
+        let kwargs = PyDict::new(_py);
+        kwargs.set_item("after_in_child", wrap_pyfunction!(runtime::reset_after_fork, m)?)?;
+        os.call_method("register_at_fork", (), Some(&kwargs))?;
+    }
+    m.add_function(wrap_pyfunction!(ratelimit::rate_limit_stats, m)?)?;
+    telemetry::register(m)?;
     m.add("RustRetryableError", _py.get_type::<RustRetryableError>())?;
     m.add("RustClientError", _py.get_type::<RustClientError>())?;
+    m.add("RustAlreadyExistsError", _py.get_type::<RustAlreadyExistsError>())?;
+    m.add("RustCircuitOpenError", _py.get_type::<RustCircuitOpenError>())?;
+    m.add("RustIntegrityError", _py.get_type::<RustIntegrityError>())?;
+    m.add("RustPreconditionFailedError", _py.get_type::<RustPreconditionFailedError>())?;
+    m.add("RustThrottledError", _py.get_type::<RustThrottledError>())?;
+    for error_type in [_py.get_type::<RustRetryableError>(), _py.get_type::<RustClientError>()] {
+        let response_attributes = ["request_tag", "status_code", "request_id", "retry_count"];
+        for attribute in ErrorDetails::ATTRIBUTES.into_iter().chain(response_attributes) {
+            error_type.setattr(attribute, _py.None())?;
+        }
+    }
+    _py.get_type::<RustThrottledError>().setattr("retry_after_secs", _py.None())?;
     Ok(())
 }
 
@@ -1123,6 +5436,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_storage_error_from_object_store() {
+        fn source(message: &str) -> Box<dyn StdError + Send + Sync> {
+            Box::new(io::Error::other(message.to_string()))
+        }
+        let path = "bucket/key".to_string();
+        let status = |err: object_store::Error| match StorageError::from(err) {
+            StorageError::HttpError(_, status) => status,
+            other => panic!("Expected HttpError, got {:?}", other),
+        };
+
+        assert_eq!(status(object_store::Error::NotFound { path: path.clone(), source: source("missing") }), Some(404));
+        assert_eq!(status(object_store::Error::NotModified { path: path.clone(), source: source("same") }), Some(304));
+        assert_eq!(status(object_store::Error::PermissionDenied { path: path.clone(), source: source("denied") }), Some(403));
+        assert_eq!(status(object_store::Error::Unauthenticated { path: path.clone(), source: source("token") }), Some(401));
+        assert!(matches!(
+            StorageError::from(object_store::Error::AlreadyExists { path: path.clone(), source: source("exists") }),
+            StorageError::AlreadyExists(msg) if msg.contains("exists")
+        ));
+        assert!(matches!(
+            StorageError::from(object_store::Error::Precondition { path, source: source("etag") }),
+            StorageError::PreconditionFailed(msg) if msg.contains("etag")
+        ));
+
+        // Generic errors only carry 4xx statuses in their message.
+        let generic = |message: &str| object_store::Error::Generic { store: "GCS", source: source(message) };
+        assert_eq!(status(generic("Client error with status 401 Unauthorized: invalid token")), Some(401));
+        assert_eq!(status(generic("Server returned non-2xx status code: 403 Forbidden")), Some(403));
+        assert!(matches!(
+            StorageError::from(generic("Client error with status 412 Precondition Failed")),
+            StorageError::PreconditionFailed(_)
+        ));
+        assert!(matches!(
+            StorageError::from(generic("Server returned non-2xx status code: 503 Service Unavailable")),
+            StorageError::ObjectStoreError(_)
+        ));
+
+        let connect = object_store::Error::Generic {
+            store: "S3",
+            source: Box::new(HttpError::new(HttpErrorKind::Connect, io::Error::other("connection refused"))),
+        };
+        assert!(matches!(StorageError::from(connect), StorageError::RetryExhaustedError(_)));
+        let decode = object_store::Error::Generic {
+            store: "S3",
+            source: Box::new(HttpError::new(HttpErrorKind::Decode, io::Error::other("invalid xml"))),
+        };
+        assert!(matches!(StorageError::from(decode), StorageError::ObjectStoreError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_storage_error_throttled() {
+        let generic = |message: &str| object_store::Error::Generic {
+            store: "S3",
+            source: Box::new(io::Error::other(message.to_string())),
+        };
+        assert!(matches!(
+            StorageError::from(generic("Client error with status 429 Too Many Requests")),
+            StorageError::Throttled(_, None)
+        ));
+
+        // A retried 503 only says so in the response it came from.
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::RETRY_AFTER, http::HeaderValue::from_static("3"));
+        let err = failed_response::track(async {
+            failed_response::record(StatusCode::SERVICE_UNAVAILABLE, &headers);
+            StorageError::from(generic("Error after 10 retries, max_retries: 10: Server error, body contains Error"))
+        })
+        .await;
+        assert!(matches!(err, StorageError::Throttled(_, Some(wait)) if wait == Duration::from_secs(3)));
+        assert!(retry::is_transient(&err));
+    }
+
     #[test]
     fn test_get_timeout_secs() {
         let mut configs = HashMap::new();
@@ -1151,6 +5536,224 @@ mod tests {
         assert_eq!(get_timeout_secs(&configs, "connect_timeout", DEFAULT_CONNECT_TIMEOUT), DEFAULT_CONNECT_TIMEOUT);
     }
 
+    #[test]
+    fn test_parse_deadline() {
+        assert_eq!(parse_deadline(None).unwrap(), None);
+        assert_eq!(parse_deadline(Some(2.5)).unwrap(), Some(Duration::from_millis(2500)));
+        for invalid in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(parse_deadline(Some(invalid)), Err(StorageError::ConfigError(_))));
+        }
+    }
+
+    #[test]
+    fn test_check_target_range() {
+        assert!(check_target_range(0, 16, 16).is_ok());
+        assert!(check_target_range(8, 24, 16).is_ok());
+        assert!(check_target_range(0, 0, 0).is_ok());
+
+        let err = check_target_range(0, 12, 16).unwrap_err();
+        assert!(matches!(err, StorageError::ConfigError(ref msg) if msg.contains("12 bytes") && msg.contains("16 bytes")));
+        assert!(check_target_range(16, 8, 8).is_err());
+    }
+
+    #[test]
+    fn test_split_at_part_boundaries() {
+        // Stream of 3 + 10 + 0 + 4 bytes cut into 5-byte parts: 0..5 | 5..10 | 10..15 | 15..17.
+        let pieces = split_at_part_boundaries(&[3, 10, 0, 4], 5);
+        assert_eq!(
+            pieces,
+            vec![(0, 0, 3), (1, 0, 2), (1, 2, 7), (1, 7, 10), (3, 0, 2), (3, 2, 4)]
+        );
+
+        let mut stream_offset = 0;
+        for (_, start, end) in &pieces {
+            assert_eq!(stream_offset / 5, (stream_offset + end - start - 1) / 5);
+            stream_offset += end - start;
+        }
+        assert_eq!(stream_offset, 17);
+
+        assert!(split_at_part_boundaries(&[], 5).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upload_buffers_multipart_round_trip() {
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let buffers: Vec<bytes::Bytes> = [3 * 1024 * 1024 + 17, 0, 4 * 1024 * 1024, 1024 * 1024 + 5]
+            .iter()
+            .enumerate()
+            .map(|(index, &len)| bytes::Bytes::from(vec![index as u8 + 1; len]))
+            .collect();
+        let expected: Vec<u8> = buffers.iter().flat_map(|buffer| buffer.iter().copied()).collect();
+
+        for (key, chunksize) in [("multipart", S3_MIN_PART_SIZE_BYTES), ("single", 64 * 1024 * 1024)] {
+            let path = Path::from(key);
+            let uploaded = upload_buffers_multipart(&store, &path, buffers.clone(), chunksize, 2, WriteMode::Overwrite, &Attributes::new()).await.unwrap();
+            assert_eq!(uploaded.bytes_transferred, expected.len() as u64);
+            assert_eq!(uploaded.etag, store.head(&path).await.unwrap().e_tag);
+            let part_sizes = uploaded.parts.map(|parts| parts.iter().map(|part| part.size).collect::<Vec<_>>());
+            match key {
+                "multipart" => assert_eq!(part_sizes, Some(vec![5 * 1024 * 1024, 3 * 1024 * 1024 + 22])),
+                _ => assert_eq!(part_sizes, None),
+            }
+
+            let data = store.get(&path).await.unwrap().bytes().await.unwrap();
+            assert_eq!(data.as_ref(), expected.as_slice());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_bytes_multipart_chunks() {
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let path = Path::from("object");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        store.put(&path, bytes::Bytes::from(data.clone()).into()).await.unwrap();
+        let retry_policy = RetryPolicy::default();
+
+        let downloaded = download_bytes_multipart(&store, &path, None, None, 1000, 3, &retry_policy, None, None).await.unwrap();
+        assert_eq!(downloaded.as_ref(), data.as_slice());
+
+        let range = download_bytes_multipart(&store, &path, None, Some((1500, 4321)), 1000, 3, &retry_policy, None, None)
+            .await
+            .unwrap();
+        assert_eq!(range.as_ref(), &data[1500..5821]);
+    }
+
+    #[test]
+    fn test_buffer_pool_reuses_buffers() {
+        let pool = BufferPool::new(1024);
+        let mut buffer = pool.take();
+        assert!(buffer.is_empty() && buffer.capacity() >= 1024);
+        buffer.extend_from_slice(b"chunk");
+        let address = buffer.as_ptr();
+        pool.give_back(buffer);
+
+        let reused = pool.take();
+        assert!(reused.is_empty());
+        assert_eq!(reused.as_ptr(), address);
+        assert!(pool.take().capacity() >= 1024);
+    }
+
+    #[tokio::test]
+    async fn test_write_attributes() {
+        let metadata = HashMap::from([("owner".to_string(), "training".to_string())]);
+        let attributes = parse_attributes(Some("application/json"), Some(metadata)).unwrap();
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+
+        for (key, size) in [("single", 16), ("multipart", S3_MIN_PART_SIZE_BYTES + 1)] {
+            let path = Path::from(key);
+            let data = bytes::Bytes::from(vec![0u8; size]);
+            upload_bytes_multipart(&store, &path, data, S3_MIN_PART_SIZE_BYTES, 2, WriteMode::Overwrite, &attributes, None)
+                .await
+                .unwrap();
+            let stored = store.get(&path).await.unwrap().attributes;
+            assert_eq!(stored.get(&Attribute::ContentType).map(|value| value.as_ref()), Some("application/json"));
+            assert_eq!(stored.get(&Attribute::Metadata("owner".into())).map(|value| value.as_ref()), Some("training"));
+        }
+
+        let metadata = HashMap::from([
+            ("bad key".to_string(), "value".to_string()),
+            ("good".to_string(), "value".to_string()),
+            ("newline".to_string(), "a\nb".to_string()),
+        ]);
+        match parse_attributes(None, Some(metadata)) {
+            Err(StorageError::ConfigError(msg)) => assert!(msg.ends_with("bad key, newline"), "{}", msg),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+        assert!(parse_attributes(Some("text/plain\r\n"), None).is_err());
+        assert!(parse_attributes(None, None).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_multipart_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let expected: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let path = Path::from("object");
+        store.put(&path, expected.clone().into()).await.unwrap();
+
+        let local_path = dir.path().join("object.bin").display().to_string();
+        let download = download_file_multipart(
+            Arc::clone(&store),
+            path,
+            local_path.clone(),
+            64,
+            4,
+            RetryPolicy::default(),
+            None,
+            LocalIo::Tokio,
+            false,
+            None,
+            true,
+            None,
+            true,
+        );
+        let downloaded = download.await.unwrap();
+        assert_eq!(downloaded.bytes_transferred, expected.len() as u64);
+        assert_eq!(downloaded.verification.as_deref(), Some("size"));
+        assert_eq!(std::fs::read(&local_path).unwrap(), expected);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // A download that doesn't finish removes the file it was writing in place.
+        let partial_path = dir.path().join("partial.bin").display().to_string();
+        let (target, _file) = DownloadTarget::create(&partial_path, false).unwrap();
+        assert!(StdPath::new(&partial_path).exists());
+        drop(target);
+        assert!(!StdPath::new(&partial_path).exists());
+    }
+
+    #[tokio::test]
+    async fn test_upload_mapped_file() {
+        use std::io::Write;
+
+        let expected: Vec<u8> = (0..2 * S3_MIN_PART_SIZE_BYTES + 17).map(|i| (i % 251) as u8).collect();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&expected).unwrap();
+        file.flush().unwrap();
+        let local_path = file.path().to_str().unwrap().to_string();
+
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let path = Path::from("mapped");
+        let mapped = mmap::map_file(&local_path, expected.len() as u64).unwrap();
+        let uploaded = upload_mapped_file(&store, &path, &local_path, mapped, S3_MIN_PART_SIZE_BYTES, 2, &Attributes::new(), None).await.unwrap();
+        assert_eq!(uploaded.map(|result| result.bytes_transferred), Some(expected.len() as u64));
+        let data = store.get(&path).await.unwrap().bytes().await.unwrap();
+        assert_eq!(data.as_ref(), expected.as_slice());
+
+        // A file that grows after being mapped is not completed.
+        let mapped = mmap::map_file(&local_path, expected.len() as u64).unwrap();
+        file.write_all(b"appended").unwrap();
+        file.flush().unwrap();
+        let path = Path::from("changed");
+        let uploaded = upload_mapped_file(&store, &path, &local_path, mapped, S3_MIN_PART_SIZE_BYTES, 2, &Attributes::new(), None).await.unwrap();
+        assert!(uploaded.is_none());
+        assert!(store.head(&path).await.is_err());
+    }
+
+    #[test]
+    fn test_local_write_mode_parse() {
+        assert_eq!(LocalWriteMode::parse("atomic").unwrap(), LocalWriteMode::Atomic);
+        assert_eq!(LocalWriteMode::parse("streaming").unwrap(), LocalWriteMode::Streaming);
+        assert!(LocalWriteMode::parse("direct").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_write_mode_create() {
+        assert_eq!(WriteMode::parse("overwrite").unwrap(), WriteMode::Overwrite);
+        assert!(WriteMode::parse("append").is_err());
+
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let large = bytes::Bytes::from(vec![7u8; S3_MIN_PART_SIZE_BYTES + 1]);
+        for (key, data) in [("single", bytes::Bytes::from_static(b"data")), ("multipart", large)] {
+            let path = Path::from(key);
+            let upload = |mode| upload_bytes_multipart(&store, &path, data.clone(), S3_MIN_PART_SIZE_BYTES, 2, mode, &Attributes::new(), None);
+
+            upload(WriteMode::Create).await.unwrap();
+            let err = upload(WriteMode::Create).await.unwrap_err();
+            assert!(matches!(err, StorageError::AlreadyExists(_)), "{}: {:?}", key, err);
+            upload(WriteMode::Overwrite).await.unwrap();
+        }
+    }
+
     #[test]
     fn test_multipart_safe_chunk_size() {
         let min_part = 5 * 1024 * 1024;
@@ -1202,23 +5805,176 @@ mod tests {
         assert_eq!(path.to_string(), "folder/file (with spaces).txt");
     }
 
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_use_default_credentials() {
+        let mut configs = HashMap::new();
+        assert!(!parse_use_default_credentials(&configs, true).unwrap());
+
+        configs.insert("use_default_credentials".to_string(), ConfigValue::Boolean(true));
+        assert!(parse_use_default_credentials(&configs, false).unwrap());
+        assert!(matches!(
+            parse_use_default_credentials(&configs, true),
+            Err(StorageError::ConfigError(msg)) if msg.contains("credentials_provider")
+        ));
+
+        configs.insert("access_key".to_string(), ConfigValue::String("key".to_string()));
+        assert!(matches!(
+            parse_use_default_credentials(&configs, false),
+            Err(StorageError::ConfigError(msg)) if msg.contains("access_key")
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_anonymous() {
+        let mut configs = HashMap::new();
+        assert!(!parse_anonymous(&configs, true).unwrap());
+
+        configs.insert("anonymous".to_string(), ConfigValue::String("true".to_string()));
+        assert!(parse_anonymous(&configs, false).unwrap());
+        assert!(matches!(
+            parse_anonymous(&configs, true),
+            Err(StorageError::ConfigError(msg)) if msg.contains("credentials_provider")
+        ));
+
+        configs.insert("use_default_credentials".to_string(), ConfigValue::Boolean(true));
+        assert!(matches!(
+            parse_anonymous(&configs, false),
+            Err(StorageError::ConfigError(msg)) if msg.contains("use_default_credentials")
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_unsigned_payload() {
+        let mut configs = HashMap::new();
+        assert!(!parse_unsigned_payload(&configs).unwrap());
+
+        configs.insert("unsigned_payload".to_string(), ConfigValue::Boolean(true));
+        assert!(parse_unsigned_payload(&configs).unwrap());
+
+        configs.insert("checksum_algorithm".to_string(), ConfigValue::String("sha256".to_string()));
+        assert!(matches!(parse_unsigned_payload(&configs), Err(StorageError::ConfigError(_))));
+
+        configs.insert("unsigned_payload".to_string(), ConfigValue::Boolean(false));
+        assert!(!parse_unsigned_payload(&configs).unwrap());
+    }
+
+    #[test]
+    fn test_connection_options() {
+        use object_store::ClientConfigKey;
+
+        let mut configs = HashMap::new();
+        let options = connection_options(ClientOptions::new(), &configs).unwrap();
+        assert_eq!(options.get_config_value(&ClientConfigKey::Http1Only).as_deref(), Some("false"));
+        assert_eq!(options.get_config_value(&ClientConfigKey::Http2Only).as_deref(), Some("false"));
+
+        configs.insert("http2".to_string(), ConfigValue::String("false".to_string()));
+        configs.insert("pool_max_idle_per_host".to_string(), ConfigValue::String("8".to_string()));
+        let options = connection_options(ClientOptions::new(), &configs).unwrap();
+        assert_eq!(options.get_config_value(&ClientConfigKey::Http1Only).as_deref(), Some("true"));
+        assert_eq!(options.get_config_value(&ClientConfigKey::PoolMaxIdlePerHost).as_deref(), Some("8"));
+
+        configs.insert("http2".to_string(), ConfigValue::Boolean(true));
+        let options = connection_options(ClientOptions::new(), &configs).unwrap();
+        assert_eq!(options.get_config_value(&ClientConfigKey::Http2Only).as_deref(), Some("true"));
+
+        configs.insert("http2".to_string(), ConfigValue::String("h2".to_string()));
+        assert!(matches!(connection_options(ClientOptions::new(), &configs), Err(StorageError::ConfigError(_))));
+
+        configs.insert("http2".to_string(), ConfigValue::Boolean(true));
+        configs.insert("tcp_keepalive_secs".to_string(), ConfigValue::Number(30));
+        assert!(matches!(connection_options(ClientOptions::new(), &configs), Err(StorageError::ConfigError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_addressing_style() {
+        let mut configs = HashMap::new();
+        assert_eq!(parse_addressing_style(&configs).unwrap(), None);
+
+        configs.insert("force_path_style".to_string(), ConfigValue::String("true".to_string()));
+        assert_eq!(parse_addressing_style(&configs).unwrap(), Some(AddressingStyle::Path));
+        configs.insert("force_path_style".to_string(), ConfigValue::Boolean(false));
+        assert_eq!(parse_addressing_style(&configs).unwrap(), None);
+
+        configs.insert("addressing_style".to_string(), ConfigValue::String("Virtual".to_string()));
+        assert_eq!(parse_addressing_style(&configs).unwrap(), Some(AddressingStyle::Virtual));
+        configs.insert("force_path_style".to_string(), ConfigValue::Boolean(true));
+        assert!(matches!(parse_addressing_style(&configs), Err(StorageError::ConfigError(_))));
+
+        configs.insert("addressing_style".to_string(), ConfigValue::String("path".to_string()));
+        assert_eq!(parse_addressing_style(&configs).unwrap(), Some(AddressingStyle::Path));
+        configs.insert("addressing_style".to_string(), ConfigValue::String("auto".to_string()));
+        assert!(matches!(parse_addressing_style(&configs), Err(StorageError::ConfigError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_request_payer() {
+        let mut configs = HashMap::new();
+        assert!(!parse_request_payer(&configs).unwrap());
+
+        for value in [ConfigValue::Boolean(true), ConfigValue::String("true".to_string())] {
+            configs.insert("request_payer".to_string(), value);
+            let request_payer = parse_request_payer(&configs).unwrap();
+            let builder = AmazonS3Builder::new().with_request_payer(request_payer);
+            assert_eq!(builder.get_config_value(&AmazonS3ConfigKey::RequestPayer).as_deref(), Some("true"));
+        }
+
+        configs.insert("request_payer".to_string(), ConfigValue::Number(1));
+        assert!(matches!(parse_request_payer(&configs), Err(StorageError::ConfigError(_))));
+
+        configs.insert("request_payer".to_string(), ConfigValue::Boolean(true));
+        configs.insert("skip_signature".to_string(), ConfigValue::Boolean(true));
+        assert!(matches!(parse_request_payer(&configs), Err(StorageError::ConfigError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_trailing_checksum() {
+        let mut configs = HashMap::new();
+        assert_eq!(parse_trailing_checksum(&configs).unwrap(), None);
+
+        configs.insert("trailing_checksum".to_string(), ConfigValue::String("CRC32C".to_string()));
+        assert_eq!(parse_trailing_checksum(&configs).unwrap(), Some(checksum::Algorithm::Crc32c));
+        configs.insert("trailing_checksum".to_string(), ConfigValue::String("sha256".to_string()));
+        assert_eq!(parse_trailing_checksum(&configs).unwrap(), Some(checksum::Algorithm::Sha256));
+
+        configs.insert("trailing_checksum".to_string(), ConfigValue::String("md5".to_string()));
+        match parse_trailing_checksum(&configs).unwrap_err() {
+            StorageError::ConfigError(msg) => assert!(msg.contains("sha256, crc32c"), "unexpected: {}", msg),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+
+        configs.insert("trailing_checksum".to_string(), ConfigValue::String("sha256".to_string()));
+        configs.insert("checksum_algorithm".to_string(), ConfigValue::String("sha256".to_string()));
+        match parse_trailing_checksum(&configs).unwrap_err() {
+            StorageError::ConfigError(msg) => assert!(msg.contains("can't be combined"), "unexpected: {}", msg),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parse_checksum_algorithm() {
         let mut configs = HashMap::new();
 
         // Missing key returns None.
-        assert_eq!(parse_checksum_algorithm(&configs).unwrap(), None);
+        assert_eq!(parse_checksum_algorithm("s3", &configs).unwrap(), None);
 
-        // Accepts the canonical "sha256" form; case-folding is delegated to object_store::aws::Checksum::FromStr.
         configs.insert("checksum_algorithm".to_string(), ConfigValue::String("sha256".to_string()));
-        assert_eq!(parse_checksum_algorithm(&configs).unwrap(), Some(Checksum::SHA256));
-
-        // Rejects unsupported algorithm names with a message naming both the bad input and the supported value.
+        assert_eq!(parse_checksum_algorithm("s3", &configs).unwrap(), Some(checksum::Algorithm::Sha256));
         configs.insert("checksum_algorithm".to_string(), ConfigValue::String("md5".to_string()));
-        let err = parse_checksum_algorithm(&configs).unwrap_err();
+        assert_eq!(parse_checksum_algorithm("s3", &configs).unwrap(), Some(checksum::Algorithm::Md5));
+
+        // Rejects algorithms the provider can't validate with a message naming the bad input and the accepted values.
+        configs.insert("checksum_algorithm".to_string(), ConfigValue::String("crc32c".to_string()));
+        assert_eq!(parse_checksum_algorithm("gcs", &configs).unwrap(), Some(checksum::Algorithm::Crc32c));
+        let err = parse_checksum_algorithm("s3", &configs).unwrap_err();
         match err {
             StorageError::ConfigError(msg) => {
-                assert!(msg.contains("md5") && msg.contains("sha256"), "unexpected: {}", msg);
+                assert!(msg.contains("crc32c") && msg.contains("sha256"), "unexpected: {}", msg);
             }
             _ => panic!("Expected ConfigError"),
         }
@@ -1226,7 +5982,7 @@ mod tests {
         // Rejects non-string values so typos in direct RustClient configs don't silently disable integrity.
         for bad in [ConfigValue::Boolean(true), ConfigValue::Number(256)] {
             configs.insert("checksum_algorithm".to_string(), bad);
-            let err = parse_checksum_algorithm(&configs).unwrap_err();
+            let err = parse_checksum_algorithm("s3", &configs).unwrap_err();
             match err {
                 StorageError::ConfigError(msg) => {
                     assert!(msg.contains("must be a string"), "unexpected: {}", msg);
@@ -1236,6 +5992,81 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "aws")]
+    fn test_parse_server_side_encryption() {
+        let string = |s: &str| ConfigValue::String(s.to_string());
+        let parse = |pairs: &[(&str, ConfigValue)]| {
+            let configs: HashMap<String, ConfigValue> = pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect();
+            parse_server_side_encryption(&configs)
+        };
+        let customer_key = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+
+        assert_eq!(parse(&[]).unwrap(), None);
+        assert_eq!(
+            parse(&[("sse", string("AES256"))]).unwrap(),
+            Some(ServerSideEncryption::S3Managed)
+        );
+        assert_eq!(
+            parse(&[("sse", string("aws:kms"))]).unwrap(),
+            Some(ServerSideEncryption::Kms(None))
+        );
+        assert_eq!(
+            parse(&[("sse", string("aws:kms")), ("sse_kms_key_id", string("alias/data"))]).unwrap(),
+            Some(ServerSideEncryption::Kms(Some("alias/data".to_string())))
+        );
+        assert_eq!(
+            parse(&[("sse_kms_key_id", string("alias/data"))]).unwrap(),
+            Some(ServerSideEncryption::Kms(Some("alias/data".to_string())))
+        );
+        assert_eq!(
+            parse(&[("sse_c_key", string(customer_key))]).unwrap(),
+            Some(ServerSideEncryption::CustomerKey(customer_key.to_string()))
+        );
+
+        for bad in [
+            vec![("sse", string("aws:kms")), ("sse_c_key", string(customer_key))],
+            vec![
+                ("sse_kms_key_id", string("alias/data")),
+                ("sse_c_key", string(customer_key)),
+            ],
+            vec![("sse", string("AES256")), ("sse_kms_key_id", string("alias/data"))],
+            vec![("sse", string("aws:kms:dsse"))],
+            vec![("sse", ConfigValue::Boolean(true))],
+            vec![("sse_c_key", string("not-a-key"))],
+        ] {
+            assert!(matches!(parse(&bad), Err(StorageError::ConfigError(_))));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_provider_error() {
+        match unsupported_provider_error("unknown") {
+            StorageError::ConfigError(msg) => {
+                assert!(msg.contains("Unsupported provider type: 'unknown'"), "unexpected: {}", msg);
+                for provider in supported_providers() {
+                    assert!(msg.contains(provider), "unexpected: {}", msg);
+                }
+            }
+            _ => panic!("Expected ConfigError"),
+        }
+
+        // Known providers compiled out of this build name the feature that would enable them.
+        #[cfg(not(feature = "gcp"))]
+        match unsupported_provider_error("gcs") {
+            StorageError::ConfigError(msg) => assert!(msg.contains("'gcp' feature"), "unexpected: {}", msg),
+            _ => panic!("Expected ConfigError"),
+        }
+        #[cfg(not(feature = "aws"))]
+        match unsupported_provider_error("s3") {
+            StorageError::ConfigError(msg) => assert!(msg.contains("'aws' feature"), "unexpected: {}", msg),
+            _ => panic!("Expected ConfigError"),
+        }
+    }
+
     #[test]
     fn test_get_retry_config() {
         // Test with RustRetryConfig
@@ -1261,4 +6092,98 @@ mod tests {
         assert_eq!(retry_config.backoff.max_backoff, Duration::from_secs(DEFAULT_RETRY_MAX_BACKOFF));
         assert_eq!(retry_config.backoff.base, DEFAULT_RETRY_BACKOFF_BASE);
     }
+
+    #[test]
+    fn test_resolve_retry_config() {
+        let configs = HashMap::from([
+            ("retry_max_attempts".to_string(), ConfigValue::Number(2)),
+            ("retry_init_backoff_ms".to_string(), ConfigValue::Number(50)),
+            ("retry_max_backoff_ms".to_string(), ConfigValue::String("500".to_string())),
+            ("retry_timeout_secs".to_string(), ConfigValue::Number(5)),
+        ]);
+        let rust_retry_cfg = RustRetryConfig {
+            attempts: 5,
+            timeout: 120,
+            init_backoff_ms: 200,
+            max_backoff: 10,
+            backoff_multiplier: 1.5,
+        };
+        let retry_config = resolve_retry_config(&configs, Some(&rust_retry_cfg));
+        assert_eq!(retry_config.max_retries, 2);
+        assert_eq!(retry_config.retry_timeout, Duration::from_secs(5));
+        assert_eq!(retry_config.backoff.init_backoff, Duration::from_millis(50));
+        assert_eq!(retry_config.backoff.max_backoff, Duration::from_millis(500));
+        assert_eq!(retry_config.backoff.base, 1.5);
+
+        // Keys that aren't set keep the values of the RustRetryConfig, or the defaults.
+        let retry_config = resolve_retry_config(&HashMap::new(), None);
+        assert_eq!(retry_config.max_retries, DEFAULT_RETRY_MAX_RETRIES);
+        assert_eq!(retry_config.backoff.max_backoff, Duration::from_secs(DEFAULT_RETRY_MAX_BACKOFF));
+    }
+
+    #[test]
+    fn test_resolve_retry_policy() {
+        let configs = HashMap::from([
+            ("retry_operation_attempts".to_string(), ConfigValue::Number(3)),
+            ("retry_init_backoff_ms".to_string(), ConfigValue::Number(50)),
+        ]);
+        let policy = resolve_retry_policy(&configs, &resolve_retry_config(&configs, None)).unwrap();
+        assert_eq!(policy.max_attempts, 3);
+        assert_eq!(policy.backoff(1), Duration::from_millis(50));
+        assert_eq!(policy.backoff(2), Duration::from_millis(100));
+
+        let policy = resolve_retry_policy(&HashMap::new(), &get_retry_config(None)).unwrap();
+        assert_eq!(policy.max_attempts, 1);
+
+        let configs = HashMap::from([("retry_operation_attempts".to_string(), ConfigValue::Number(0))]);
+        assert!(resolve_retry_policy(&configs, &get_retry_config(None)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_collect_listing_resumes() {
+        let store: Arc<dyn ObjectStore> = Arc::new(object_store::memory::InMemory::new());
+        let mut keys = vec!["root/top.bin".to_string()];
+        for i in 0..3 {
+            for j in 0..3 {
+                keys.push(format!("root/a{}/b{}/data.bin", i, j));
+            }
+        }
+        for key in &keys {
+            store.put(&Path::from(key.as_str()), PutPayload::from_static(b"x")).await.unwrap();
+        }
+        keys.sort();
+        let prefixes = ["root".to_string()];
+        let options = |limit, start_after: Option<String>, max_depth| ListOptions {
+            limit,
+            filter: ObjectFilter::default(),
+            max_depth,
+            max_concurrency: 4,
+            traversal: Traversal::Bfs,
+            start_after,
+            descend: None,
+        };
+        let keys_of = |entries: &[ObjectMetadata]| entries.iter().map(|entry| entry.key.clone()).collect::<Vec<_>>();
+
+        // Resuming after each page's last key lists every object once, in order.
+        let mut listed = Vec::new();
+        let mut start_after = None;
+        loop {
+            let page = collect_listing(&store, &prefixes, &options(Some(4), start_after, None)).await.unwrap();
+            assert!(page.objects.len() <= 4);
+            listed.extend(keys_of(&page.objects));
+            start_after = page.last_key;
+            if start_after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(listed, keys);
+
+        let start_after = Some("root/a1/b2/data.bin".to_string());
+        let page = collect_listing(&store, &prefixes, &options(None, start_after.clone(), None)).await.unwrap();
+        assert_eq!(keys_of(&page.objects), keys[6..]);
+        // A start point below max_depth still skips the directories that come before it.
+        let page = collect_listing(&store, &prefixes, &options(None, start_after, Some(1))).await.unwrap();
+        assert_eq!(keys_of(&page.objects), vec!["root/top.bin"]);
+        assert_eq!(keys_of(&page.prefixes), vec!["root/a1", "root/a2"]);
+    }
 }
@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
+use futures::FutureExt;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, GetResultPayload, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts,
+    PutOptions, PutPayload, PutResult, Result, UploadPart,
+};
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Store wrapper that bounds the requests in flight, like object_store's `LimitStore`, but with
+/// permits taken from a semaphore that can be shared with other stores, such as the stores a
+/// client builds for per-call `retry` overrides.
+///
+/// The permit of a get or list is held until its stream is dropped.
+#[derive(Debug)]
+pub struct SharedLimitStore {
+    inner: Arc<dyn ObjectStore>,
+    permits: Arc<Semaphore>,
+}
+
+impl SharedLimitStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, permits: Arc<Semaphore>) -> Self {
+        SharedLimitStore { inner, permits }
+    }
+}
+
+async fn acquire(permits: &Arc<Semaphore>) -> OwnedSemaphorePermit {
+    let permit = Arc::clone(permits).acquire_owned().await;
+    permit.expect("the semaphore is never closed")
+}
+
+/// Keeps `permit` until `stream` is dropped.
+fn hold<T: Send + 'static>(stream: BoxStream<'static, T>, permit: OwnedSemaphorePermit) -> BoxStream<'static, T> {
+    stream
+        .map(move |item| {
+            let _ = &permit;
+            item
+        })
+        .boxed()
+}
+
+impl fmt::Display for SharedLimitStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SharedLimitStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for SharedLimitStore {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        let _permit = acquire(&self.permits).await;
+        let upload = self.inner.put_multipart_opts(location, opts).await?;
+        Ok(Box::new(LimitedUpload {
+            inner: upload,
+            permits: Arc::clone(&self.permits),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        let permit = acquire(&self.permits).await;
+        let result = self.inner.get_opts(location, options).await?;
+        let payload = match result.payload {
+            GetResultPayload::Stream(stream) => GetResultPayload::Stream(hold(stream, permit)),
+            payload => payload,
+        };
+        Ok(GetResult { payload, ..result })
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        let (inner, prefix) = (Arc::clone(&self.inner), prefix.cloned());
+        let permits = Arc::clone(&self.permits);
+        let listing = async move {
+            let permit = acquire(&permits).await;
+            hold(inner.list(prefix.as_ref()), permit)
+        };
+        listing.flatten_stream().boxed()
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        let (inner, prefix, offset) = (Arc::clone(&self.inner), prefix.cloned(), offset.clone());
+        let permits = Arc::clone(&self.permits);
+        let listing = async move {
+            let permit = acquire(&permits).await;
+            hold(inner.list_with_offset(prefix.as_ref(), &offset), permit)
+        };
+        listing.flatten_stream().boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.rename(from, to).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.rename_if_not_exists(from, to).await
+    }
+}
+
+#[derive(Debug)]
+struct LimitedUpload {
+    inner: Box<dyn MultipartUpload>,
+    permits: Arc<Semaphore>,
+}
+
+#[async_trait]
+impl MultipartUpload for LimitedUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let permits = Arc::clone(&self.permits);
+        let part = self.inner.put_part(data);
+        Box::pin(async move {
+            let _permit = acquire(&permits).await;
+            part.await
+        })
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.complete().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        let _permit = acquire(&self.permits).await;
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_shared_permits() {
+        let permits = Arc::new(Semaphore::new(1));
+        let inner: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let first = SharedLimitStore::new(Arc::clone(&inner), Arc::clone(&permits));
+        let second = SharedLimitStore::new(inner, permits);
+        let location = Path::from("object");
+        first.put(&location, "hello".into()).await.unwrap();
+
+        // The first store's get holds the only permit until its body is dropped.
+        let get = first.get(&location).await.unwrap();
+        let blocked = tokio::time::timeout(Duration::from_millis(50), second.head(&location));
+        assert!(blocked.await.is_err());
+        assert_eq!(get.bytes().await.unwrap(), "hello");
+        assert_eq!(second.head(&location).await.unwrap().size, 5);
+
+        let mut listing = second.list(None);
+        assert_eq!(listing.next().await.unwrap().unwrap().location, location);
+        let blocked = tokio::time::timeout(Duration::from_millis(50), first.head(&location));
+        assert!(blocked.await.is_err());
+        drop(listing);
+        first.head(&location).await.unwrap();
+    }
+}
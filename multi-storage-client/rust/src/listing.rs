@@ -0,0 +1,589 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use http::HeaderValue;
+use object_store::{path::Path, ObjectMeta, ObjectStore};
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::{AbortHandle, JoinSet};
+
+use crate::bridge::future_into_py;
+use crate::request_tag;
+use crate::runtime::get_runtime;
+use crate::telemetry;
+use crate::types::ObjectMetadata;
+use crate::StorageError;
+
+/// Order in which `list_tree` visits pending directories.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Traversal {
+    /// Level by level. The pending queue can grow to the widest level of the tree.
+    Bfs,
+    /// Deepest pending directory first. The pending queue stays within depth times fan-out.
+    Dfs,
+}
+
+impl Traversal {
+    pub fn parse(traversal: &str) -> Result<Self, StorageError> {
+        match traversal {
+            "bfs" => Ok(Traversal::Bfs),
+            "dfs" => Ok(Traversal::Dfs),
+            other => Err(StorageError::ConfigError(format!(
+                "Invalid traversal '{}': expected 'bfs' or 'dfs'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Conditions an object must meet to be listed.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectFilter {
+    pub suffix: Option<String>,
+    pub modified_before: Option<DateTime<Utc>>,
+    pub modified_after: Option<DateTime<Utc>>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl ObjectFilter {
+    pub fn matches(&self, meta: &ObjectMeta) -> bool {
+        self.suffix.as_deref().map_or(true, |suffix| meta.location.as_ref().ends_with(suffix))
+            && self.modified_before.map_or(true, |before| meta.last_modified < before)
+            && self.modified_after.map_or(true, |after| meta.last_modified > after)
+            && self.min_size.map_or(true, |min_size| meta.size >= min_size)
+            && self.max_size.map_or(true, |max_size| meta.size <= max_size)
+    }
+}
+
+/// Decides whether a directory can hold keys of interest. Directories it rejects are skipped.
+pub type DirectoryFilter = Arc<dyn Fn(&Path) -> bool + Send + Sync>;
+
+pub struct ListOptions {
+    pub limit: Option<usize>,
+    pub filter: ObjectFilter,
+    pub max_depth: Option<usize>,
+    pub max_concurrency: usize,
+    pub traversal: Traversal,
+    /// Only list keys that sort after this one.
+    pub start_after: Option<String>,
+    pub descend: Option<DirectoryFilter>,
+}
+
+/// Receives the entries of each directory `walk_tree` lists.
+pub trait TreeSink {
+    fn add(&mut self, objects: Vec<ObjectMeta>, directories: Vec<Path>);
+    /// Number of objects received so far that sort before `bound`, or all of them if `None`.
+    fn count_before(&self, bound: Option<&str>) -> usize;
+}
+
+/// Traversal statistics of a `walk_tree`.
+#[derive(Debug, Default)]
+pub struct TreeWalk {
+    pub directories_listed: u64,
+    pub max_pending_directories: u64,
+    /// With a `limit`, every key before this one has been listed; keys from it on may be missing.
+    pub complete_before: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct TreeListing {
+    pub objects: Vec<ObjectMeta>,
+    pub directories: Vec<Path>,
+    pub directories_listed: u64,
+    pub max_pending_directories: u64,
+    /// With a `limit`, every key before this one has been listed; keys from it on may be missing.
+    pub complete_before: Option<String>,
+}
+
+impl TreeSink for TreeListing {
+    fn add(&mut self, objects: Vec<ObjectMeta>, directories: Vec<Path>) {
+        self.objects.extend(objects);
+        self.directories.extend(directories);
+    }
+
+    fn count_before(&self, bound: Option<&str>) -> usize {
+        match bound {
+            Some(bound) => self.objects.iter().filter(|o| o.location.as_ref() < bound).count(),
+            None => self.objects.len(),
+        }
+    }
+}
+
+/// Totals of the objects under a tree, without keeping the objects themselves.
+#[derive(Debug, Default)]
+pub struct Usage {
+    pub object_count: u64,
+    pub total_bytes: u64,
+    pub largest_object: Option<ObjectMeta>,
+    pub latest_modified: Option<DateTime<Utc>>,
+}
+
+impl TreeSink for Usage {
+    fn add(&mut self, objects: Vec<ObjectMeta>, _directories: Vec<Path>) {
+        for object in objects {
+            self.object_count += 1;
+            self.total_bytes += object.size;
+            if self.latest_modified.map_or(true, |latest| object.last_modified > latest) {
+                self.latest_modified = Some(object.last_modified);
+            }
+            if self.largest_object.as_ref().map_or(true, |largest| object.size > largest.size) {
+                self.largest_object = Some(object);
+            }
+        }
+    }
+
+    fn count_before(&self, _bound: Option<&str>) -> usize {
+        self.object_count as usize
+    }
+}
+
+struct DirectoryListing {
+    prefix: Path,
+    objects: Vec<ObjectMeta>,
+    directories: Vec<Path>,
+    depth: usize,
+    /// First object left out because of the limit.
+    left_out: Option<Path>,
+}
+
+/// The prefix shared by every key under `directory`.
+fn key_prefix(directory: &Path) -> String {
+    match directory.as_ref() {
+        "" => String::new(),
+        directory => format!("{}/", directory),
+    }
+}
+
+/// Whether every key under `directory` sorts at or before `start_after`.
+fn listed_before(directory: &Path, start_after: &str) -> bool {
+    let prefix = key_prefix(directory);
+    prefix.as_str() < start_after && !start_after.starts_with(&prefix)
+}
+
+async fn list_single_directory(
+    store: Arc<dyn ObjectStore>,
+    prefix: Path,
+    limit: Option<usize>,
+    filter: &ObjectFilter,
+    start_after: Option<&str>,
+    descend: Option<DirectoryFilter>,
+    depth: usize,
+) -> Result<DirectoryListing, StorageError> {
+    let mut objects = Vec::new();
+    let mut directories = Vec::new();
+    let mut left_out = None;
+
+    let list_result = store
+        .list_with_delimiter(Some(&prefix))
+        .await
+        .map_err(StorageError::from)?;
+
+    for entry in list_result.objects {
+        if start_after.is_some_and(|start_after| entry.location.as_ref() <= start_after) {
+            continue;
+        }
+
+        if !filter.matches(&entry) {
+            continue;
+        }
+
+        if limit.is_some_and(|x| objects.len() >= x) {
+            left_out = Some(entry.location);
+            break;
+        }
+
+        objects.push(entry);
+    }
+
+    for common_prefix in list_result.common_prefixes {
+        if start_after.is_some_and(|start_after| listed_before(&common_prefix, start_after)) {
+            continue;
+        }
+        if descend.as_ref().is_some_and(|descend| !descend(&common_prefix)) {
+            continue;
+        }
+        directories.push(common_prefix);
+    }
+
+    Ok(DirectoryListing {
+        prefix,
+        objects,
+        directories,
+        depth,
+        left_out,
+    })
+}
+
+/// Returns the first key that may not have been listed yet: the smallest of the directories
+/// still to list and the objects left out of listed ones.
+fn first_unlisted<'a>(
+    pending: impl Iterator<Item = &'a Path>,
+    in_flight: &[Path],
+    left_out: Option<&Path>,
+) -> Option<String> {
+    let directories = pending.chain(in_flight).map(key_prefix);
+    directories.chain(left_out.map(|key| key.to_string())).min()
+}
+
+/// Lists every object and directory under `roots`, up to `max_concurrency` directories at a time.
+///
+/// All workers share one frontier of pending directories. Whenever a listing completes, its
+/// subdirectories join the frontier and free slots immediately take the next directory in
+/// `traversal` order, whichever subtree it belongs to, so one slow subtree doesn't hold back the
+/// rest. The order of the returned entries is unspecified.
+///
+/// With a `limit`, listing continues until `limit` objects are known to come before every key
+/// not listed yet, which `complete_before` records, so the first `limit` keys in order are exact.
+pub async fn list_tree(
+    store: &Arc<dyn ObjectStore>,
+    roots: Vec<Path>,
+    options: &ListOptions,
+) -> Result<TreeListing, StorageError> {
+    let mut listing = TreeListing::default();
+    let walk = walk_tree(store, roots, options, &mut listing).await?;
+    listing.directories_listed = walk.directories_listed;
+    listing.max_pending_directories = walk.max_pending_directories;
+    listing.complete_before = walk.complete_before;
+    Ok(listing)
+}
+
+/// Walks the tree like `list_tree`, handing each directory's entries to `sink` as soon as it is
+/// listed instead of collecting them.
+pub async fn walk_tree<S: TreeSink>(
+    store: &Arc<dyn ObjectStore>,
+    roots: Vec<Path>,
+    options: &ListOptions,
+    sink: &mut S,
+) -> Result<TreeWalk, StorageError> {
+    let mut pending: VecDeque<(Path, usize)> = roots.into_iter().map(|root| (root, 0)).collect();
+    let mut walk = TreeWalk {
+        max_pending_directories: pending.len() as u64,
+        ..Default::default()
+    };
+    let mut join_set = JoinSet::new();
+    let mut in_flight: Vec<Path> = Vec::new();
+    let mut left_out: Option<Path> = None;
+
+    while !pending.is_empty() || !join_set.is_empty() {
+        if !join_set.is_empty() {
+            let result: Result<DirectoryListing, StorageError> = join_set.join_next().await.unwrap().unwrap();
+            let directory = result?;
+            walk.directories_listed += 1;
+            if let Some(position) = in_flight.iter().position(|prefix| *prefix == directory.prefix) {
+                in_flight.swap_remove(position);
+            }
+
+            for subdirectory in &directory.directories {
+                if options.max_depth.map_or(true, |max_d| directory.depth < max_d) {
+                    pending.push_back((subdirectory.clone(), directory.depth + 1));
+                }
+            }
+            walk.max_pending_directories = walk.max_pending_directories.max(pending.len() as u64);
+
+            sink.add(directory.objects, directory.directories);
+            if let Some(key) = directory.left_out {
+                left_out = Some(match left_out.take() {
+                    Some(current) => current.min(key),
+                    None => key,
+                });
+            }
+
+            if let Some(limit) = options.limit {
+                let bound = first_unlisted(pending.iter().map(|(prefix, _)| prefix), &in_flight, left_out.as_ref());
+                if sink.count_before(bound.as_deref()) >= limit {
+                    walk.complete_before = bound;
+                    return Ok(walk);
+                }
+            }
+        }
+
+        while join_set.len() < options.max_concurrency {
+            let next = match options.traversal {
+                Traversal::Bfs => pending.pop_front(),
+                Traversal::Dfs => pending.pop_back(),
+            };
+            let Some((prefix, depth)) = next else {
+                break;
+            };
+
+            if options.max_depth.is_some_and(|x| depth >= x) {
+                continue;
+            }
+
+            let store_clone = Arc::clone(store);
+            let filter = options.filter.clone();
+            let start_after_clone = options.start_after.clone();
+            let descend = options.descend.clone();
+            let limit = options.limit;
+            in_flight.push(prefix.clone());
+
+            join_set.spawn(request_tag::propagate(async move {
+                list_single_directory(
+                    store_clone,
+                    prefix,
+                    limit,
+                    &filter,
+                    start_after_clone.as_deref(),
+                    descend,
+                    depth,
+                )
+                .await
+            }));
+        }
+    }
+
+    walk.complete_before = left_out.map(|key| key.to_string());
+    Ok(walk)
+}
+
+type Batch = Result<Vec<ObjectMeta>, StorageError>;
+
+/// Lists every object under `prefix` after `start_after` and sends them to `batches` in groups of
+/// up to `batch_size`, keeping only keys ending in `suffix`. Stops after the first error or once the receiver is dropped.
+async fn send_batches(
+    store: Arc<dyn ObjectStore>,
+    prefix: Path,
+    batch_size: usize,
+    suffix: Option<String>,
+    start_after: Option<Path>,
+    batches: mpsc::Sender<Batch>,
+) {
+    let batch_size = batch_size.max(1);
+    let mut entries = match &start_after {
+        Some(offset) => store.list_with_offset(Some(&prefix), offset),
+        None => store.list(Some(&prefix)),
+    };
+    let mut batch = Vec::with_capacity(batch_size);
+    while let Some(entry) = entries.next().await {
+        let meta = match entry {
+            Ok(meta) => meta,
+            Err(err) => {
+                let _ = batches.send(Err(err.into())).await;
+                return;
+            }
+        };
+        if suffix.as_deref().is_some_and(|suffix| !meta.location.as_ref().ends_with(suffix)) {
+            continue;
+        }
+        batch.push(meta);
+        if batch.len() == batch_size {
+            let full = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
+            if batches.send(Ok(full)).await.is_err() {
+                return;
+            }
+        }
+    }
+    if !batch.is_empty() {
+        let _ = batches.send(Ok(batch)).await;
+    }
+}
+
+/// Async iterator over the objects under a prefix, in batches, as the listing pages arrive.
+///
+/// At most one batch is listed ahead of the consumer. Dropping the iterator aborts the listing.
+#[pyclass]
+pub struct RustListIterator {
+    batches: Arc<Mutex<mpsc::Receiver<Batch>>>,
+    task: AbortHandle,
+}
+
+impl RustListIterator {
+    pub fn spawn(
+        store: Arc<dyn ObjectStore>,
+        prefix: Path,
+        batch_size: usize,
+        suffix: Option<String>,
+        start_after: Option<Path>,
+        tag: Option<HeaderValue>,
+    ) -> Self {
+        let (tx, rx) = mpsc::channel(1);
+        let listing = send_batches(store, prefix, batch_size, suffix, start_after, tx);
+        let task = get_runtime().spawn(telemetry::instrument(request_tag::scoped(tag, listing)));
+        RustListIterator {
+            batches: Arc::new(Mutex::new(rx)),
+            task: task.abort_handle(),
+        }
+    }
+}
+
+impl Drop for RustListIterator {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[pymethods]
+impl RustListIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let batches = Arc::clone(&self.batches);
+        future_into_py(py, async move {
+            match batches.lock().await.recv().await {
+                Some(batch) => Ok(batch?.into_iter().map(ObjectMetadata::from).collect::<Vec<_>>()),
+                None => Err(PyStopAsyncIteration::new_err(())),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use object_store::memory::InMemory;
+
+    /// Builds `root/a{i}/b{j}/data.bin` plus a `.idx` sidecar, `fan_out` directories wide at each level.
+    async fn wide_tree(fan_out: usize) -> Arc<dyn ObjectStore> {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        for i in 0..fan_out {
+            for j in 0..fan_out {
+                for name in ["data.bin", "data.idx"] {
+                    let path = Path::from(format!("root/a{}/b{}/{}", i, j, name));
+                    store.put(&path, Bytes::from_static(b"x").into()).await.unwrap();
+                }
+            }
+        }
+        store
+    }
+
+    async fn list(store: &Arc<dyn ObjectStore>, traversal: Traversal, max_depth: Option<usize>) -> TreeListing {
+        let options = ListOptions {
+            limit: None,
+            filter: ObjectFilter {
+                suffix: Some(".bin".to_string()),
+                ..Default::default()
+            },
+            max_depth,
+            max_concurrency: 4,
+            traversal,
+            start_after: None,
+            descend: None,
+        };
+        let mut listing = list_tree(store, vec![Path::from("root")], &options).await.unwrap();
+        listing.objects.sort_by(|a, b| a.location.cmp(&b.location));
+        listing.directories.sort();
+        listing
+    }
+
+    #[test]
+    fn test_parse_traversal() {
+        assert_eq!(Traversal::parse("dfs").unwrap(), Traversal::Dfs);
+        assert!(Traversal::parse("random").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_traversals_match() {
+        let store = wide_tree(8).await;
+        for max_depth in [None, Some(1)] {
+            let bfs = list(&store, Traversal::Bfs, max_depth).await;
+            let dfs = list(&store, Traversal::Dfs, max_depth).await;
+            let keys = |listing: &TreeListing| listing.objects.iter().map(|o| o.location.clone()).collect::<Vec<_>>();
+            assert_eq!(keys(&bfs), keys(&dfs));
+            assert_eq!(bfs.directories, dfs.directories);
+            assert_eq!(bfs.directories_listed, dfs.directories_listed);
+        }
+        assert_eq!(list(&store, Traversal::Dfs, None).await.objects.len(), 64);
+    }
+
+    #[tokio::test]
+    async fn test_dfs_pending_stays_flat() {
+        let fan_out = 40;
+        let store = wide_tree(fan_out).await;
+        let bfs = list(&store, Traversal::Bfs, None).await;
+        let dfs = list(&store, Traversal::Dfs, None).await;
+
+        // BFS queues most of the second level at once; DFS only holds the siblings along the
+        // paths its workers are descending.
+        assert!(bfs.max_pending_directories as usize >= fan_out * fan_out / 2);
+        assert!(dfs.max_pending_directories as usize <= fan_out * (1 + 4));
+    }
+
+    #[tokio::test]
+    async fn test_walk_tree_usage() {
+        let store = wide_tree(4).await;
+        let large = Path::from("root/a1/b2/large.bin");
+        store.put(&large, Bytes::from_static(b"large").into()).await.unwrap();
+        let options = ListOptions {
+            limit: None,
+            filter: ObjectFilter {
+                suffix: Some(".bin".to_string()),
+                ..Default::default()
+            },
+            max_depth: None,
+            max_concurrency: 4,
+            traversal: Traversal::Bfs,
+            start_after: None,
+            descend: None,
+        };
+
+        let mut usage = Usage::default();
+        let walk = walk_tree(&store, vec![Path::from("root")], &options, &mut usage).await.unwrap();
+        assert_eq!(walk.directories_listed, 1 + 4 + 16);
+        assert_eq!(usage.object_count, 17);
+        assert_eq!(usage.total_bytes, 16 + 5);
+        assert_eq!(usage.largest_object.unwrap().location, large);
+        let head = store.head(&large).await.unwrap();
+        assert_eq!(usage.latest_modified, Some(head.last_modified));
+    }
+
+    #[test]
+    fn test_object_filter() {
+        let now = chrono::Utc::now();
+        let meta = ObjectMeta {
+            location: Path::from("root/data.bin"),
+            last_modified: now,
+            size: 100,
+            e_tag: None,
+            version: None,
+        };
+        let hour = chrono::Duration::hours(1);
+        assert!(ObjectFilter::default().matches(&meta));
+        let filter = ObjectFilter {
+            suffix: Some(".bin".to_string()),
+            modified_before: Some(now + hour),
+            modified_after: Some(now - hour),
+            min_size: Some(100),
+            max_size: Some(100),
+        };
+        assert!(filter.matches(&meta));
+        assert!(!ObjectFilter { suffix: Some(".idx".to_string()), ..filter.clone() }.matches(&meta));
+        assert!(!ObjectFilter { modified_before: Some(now - hour), ..filter.clone() }.matches(&meta));
+        assert!(!ObjectFilter { modified_after: Some(now + hour), ..filter.clone() }.matches(&meta));
+        assert!(!ObjectFilter { min_size: Some(101), ..filter.clone() }.matches(&meta));
+        assert!(!ObjectFilter { max_size: Some(99), ..filter }.matches(&meta));
+    }
+
+    #[tokio::test]
+    async fn test_send_batches() {
+        let store = wide_tree(4).await;
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(send_batches(store, Path::from("root"), 5, Some(".bin".to_string()), None, tx));
+
+        let mut sizes = Vec::new();
+        while let Some(batch) = rx.recv().await {
+            sizes.push(batch.unwrap().len());
+        }
+        assert_eq!(sizes, vec![5, 5, 5, 1]);
+    }
+}
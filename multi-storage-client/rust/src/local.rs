@@ -0,0 +1,200 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use object_store::path::Path;
+use object_store::{MultipartUpload, PutPayload, PutResult, UploadPart};
+use std::collections::HashMap;
+use std::io::{self, SeekFrom};
+use std::path::{Path as StdPath, PathBuf};
+use std::sync::Arc;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+use crate::{ConfigValue, StorageError};
+
+/// Resolves the `file` provider's `base_path` config to an absolute directory, creating it if needed.
+pub fn base_path(configs: Option<&HashMap<String, ConfigValue>>) -> Result<PathBuf, StorageError> {
+    let base_path = configs
+        .and_then(|configs| configs.get("base_path"))
+        .map(ConfigValue::to_string)
+        .filter(|base_path| !base_path.is_empty())
+        .ok_or_else(|| StorageError::ConfigError("The 'file' provider requires a 'base_path' config".to_string()))?;
+    std::fs::create_dir_all(&base_path)?;
+    Ok(std::fs::canonicalize(&base_path)?)
+}
+
+/// Builds the `file` provider's store, which keeps objects as files under `base_path`.
+///
+/// Object paths can't contain `.` or `..` segments, so every object stays under `base_path`.
+#[cfg(feature = "local")]
+pub fn build_store(
+    configs: Option<&HashMap<String, ConfigValue>>,
+) -> Result<Arc<dyn object_store::ObjectStore>, StorageError> {
+    let root = base_path(configs)?;
+    let store = object_store::local::LocalFileSystem::new_with_prefix(root).map_err(StorageError::from)?;
+    Ok(Arc::new(store))
+}
+
+fn write_error(path: &StdPath, source: io::Error) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "LocalFileSystem",
+        source: format!("Unable to write {}: {}", path.display(), source).into(),
+    }
+}
+
+/// Upload for `write_mode="streaming"` that writes each part straight into the destination file,
+/// so other processes see the object grow instead of it appearing on completion.
+#[derive(Debug)]
+pub struct StreamingUpload {
+    path: PathBuf,
+    file: Arc<Mutex<File>>,
+    offset: u64,
+}
+
+impl StreamingUpload {
+    /// Opens `location` under `root`, truncating an existing file, or failing with `AlreadyExists`
+    /// if there is one and `create_new` is set.
+    pub async fn open(root: &StdPath, location: &Path, create_new: bool) -> Result<Self, StorageError> {
+        let path = root.join(location.as_ref());
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut options = OpenOptions::new();
+        options.write(true);
+        if create_new {
+            options.create_new(true);
+        } else {
+            options.create(true).truncate(true);
+        }
+        let file = options.open(&path).await.map_err(|err| match err.kind() {
+            io::ErrorKind::AlreadyExists => {
+                StorageError::AlreadyExists(format!("Object at location {} already exists", location))
+            }
+            _ => StorageError::from(err),
+        })?;
+        Ok(StreamingUpload {
+            path,
+            file: Arc::new(Mutex::new(file)),
+            offset: 0,
+        })
+    }
+
+    /// Writes `data` as the whole object.
+    pub async fn write_all(mut self, data: bytes::Bytes) -> Result<PutResult, StorageError> {
+        self.put_part(PutPayload::from_bytes(data)).await.map_err(StorageError::from)?;
+        self.complete().await.map_err(StorageError::from)
+    }
+}
+
+/// Appends `data` to the file of `location` under `root`, creating it if needed, and returns its
+/// new size. The file is opened in append mode, so writes land at its end even with concurrent
+/// appenders.
+pub async fn append(root: &StdPath, location: &Path, data: bytes::Bytes) -> Result<u64, StorageError> {
+    let path = root.join(location.as_ref());
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut file = OpenOptions::new().append(true).create(true).open(&path).await?;
+    file.write_all(&data).await?;
+    file.sync_data().await?;
+    Ok(file.metadata().await?.len())
+}
+
+#[async_trait]
+impl MultipartUpload for StreamingUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let offset = self.offset;
+        self.offset += data.content_length() as u64;
+        let file = Arc::clone(&self.file);
+        let path = self.path.clone();
+        Box::pin(async move {
+            let mut file = file.lock().await;
+            file.seek(SeekFrom::Start(offset)).await.map_err(|e| write_error(&path, e))?;
+            for chunk in &data {
+                file.write_all(chunk).await.map_err(|e| write_error(&path, e))?;
+            }
+            file.flush().await.map_err(|e| write_error(&path, e))
+        })
+    }
+
+    async fn complete(&mut self) -> object_store::Result<PutResult> {
+        let file = self.file.lock().await;
+        file.sync_all().await.map_err(|e| write_error(&self.path, e))?;
+        Ok(PutResult {
+            e_tag: None,
+            version: None,
+        })
+    }
+
+    async fn abort(&mut self) -> object_store::Result<()> {
+        tokio::fs::remove_file(&self.path).await.map_err(|e| write_error(&self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::{ObjectStore, WriteMultipart};
+
+    #[tokio::test]
+    async fn test_streaming_upload_is_visible_before_completion() {
+        let root = tempfile::tempdir().unwrap();
+        let location = Path::from("dir/streamed.bin");
+        let upload = StreamingUpload::open(root.path(), &location, false).await.unwrap();
+        let mut writer = WriteMultipart::new_with_chunk_size(Box::new(upload), 4);
+
+        writer.write(b"abcdefgh");
+        writer.wait_for_capacity(0).await.unwrap();
+        assert_eq!(std::fs::read(root.path().join("dir/streamed.bin")).unwrap(), b"abcdefgh");
+
+        writer.write(b"ij");
+        writer.finish().await.unwrap();
+        assert_eq!(std::fs::read(root.path().join("dir/streamed.bin")).unwrap(), b"abcdefghij");
+
+        match StreamingUpload::open(root.path(), &location, true).await {
+            Err(StorageError::AlreadyExists(_)) => {}
+            other => panic!("Expected AlreadyExists, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_append() {
+        let root = tempfile::tempdir().unwrap();
+        let location = Path::from("logs/app.log");
+        assert_eq!(append(root.path(), &location, "one\n".into()).await.unwrap(), 4);
+        assert_eq!(append(root.path(), &location, "two\n".into()).await.unwrap(), 8);
+        assert_eq!(std::fs::read(root.path().join("logs/app.log")).unwrap(), b"one\ntwo\n");
+    }
+
+    #[cfg(feature = "local")]
+    #[tokio::test]
+    async fn test_local_store_is_rooted_at_base_path() {
+        let root = tempfile::tempdir().unwrap();
+        let base = root.path().join("base");
+        let configs = HashMap::from([("base_path".to_string(), ConfigValue::String(base.display().to_string()))]);
+        let store = build_store(Some(&configs)).unwrap();
+
+        store.put(&Path::from("a/b.txt"), PutPayload::from_static(b"data")).await.unwrap();
+        assert_eq!(std::fs::read(base.join("a/b.txt")).unwrap(), b"data");
+        assert!(crate::parse_path("../outside.txt").is_err());
+
+        match base_path(None) {
+            Err(StorageError::ConfigError(msg)) => assert!(msg.contains("base_path")),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{self, SeekFrom};
+#[cfg(unix)]
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+use crate::StorageError;
+
+/// Backend for the local-disk side of file transfers, selected by the `local_io` config key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LocalIo {
+    Tokio,
+    Uring,
+}
+
+impl LocalIo {
+    /// Resolves a `local_io` value. `auto` picks io_uring when it is compiled in and the kernel
+    /// supports it, and tokio::fs otherwise.
+    pub fn resolve(value: &str) -> Result<Self, StorageError> {
+        match value {
+            "tokio" => Ok(LocalIo::Tokio),
+            "auto" if uring_available() => Ok(LocalIo::Uring),
+            "auto" => Ok(LocalIo::Tokio),
+            "uring" if uring_available() => Ok(LocalIo::Uring),
+            "uring" => Err(StorageError::ConfigError(
+                "local_io='uring' requires a Linux build with the 'io_uring' feature and a kernel that supports io_uring"
+                    .to_string(),
+            )),
+            other => Err(StorageError::ConfigError(format!(
+                "Invalid local_io '{}': expected 'auto', 'uring' or 'tokio'",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(all(feature = "io_uring", target_os = "linux"))]
+fn uring_available() -> bool {
+    static AVAILABLE: std::sync::LazyLock<bool> = std::sync::LazyLock::new(|| io_uring::IoUring::new(2).is_ok());
+    *AVAILABLE
+}
+
+#[cfg(not(all(feature = "io_uring", target_os = "linux")))]
+fn uring_available() -> bool {
+    false
+}
+
+/// A local file read or written at explicit offsets through the selected `LocalIo` backend.
+pub enum LocalFile {
+    Tokio { file: tokio::fs::File, position: u64 },
+    #[cfg(all(feature = "io_uring", target_os = "linux"))]
+    Uring(uring::UringFile),
+}
+
+impl LocalFile {
+    pub fn new(file: std::fs::File, local_io: LocalIo) -> io::Result<Self> {
+        match local_io {
+            LocalIo::Tokio => Ok(LocalFile::Tokio {
+                file: tokio::fs::File::from_std(file),
+                position: 0,
+            }),
+            #[cfg(all(feature = "io_uring", target_os = "linux"))]
+            LocalIo::Uring => Ok(LocalFile::Uring(uring::UringFile::new(file)?)),
+            #[cfg(not(all(feature = "io_uring", target_os = "linux")))]
+            LocalIo::Uring => Err(io::Error::new(io::ErrorKind::Unsupported, "io_uring is not available")),
+        }
+    }
+
+    /// Reads up to `buf.capacity()` bytes at `offset` and returns `buf` holding exactly the bytes
+    /// read, so one allocation can be reused across calls. An empty result means end of file.
+    pub async fn read_at(&mut self, offset: u64, mut buf: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self {
+            LocalFile::Tokio { file, position } => {
+                if *position != offset {
+                    file.seek(SeekFrom::Start(offset)).await?;
+                }
+                buf.resize(buf.capacity(), 0);
+                let n = file.read(&mut buf).await?;
+                buf.truncate(n);
+                *position = offset + n as u64;
+                Ok(buf)
+            }
+            #[cfg(all(feature = "io_uring", target_os = "linux"))]
+            LocalFile::Uring(file) => file.read_at(offset, buf).await,
+        }
+    }
+
+    /// Writes all of `data` at `offset` and returns it, so its allocation can be reused.
+    pub async fn write_all_at(&mut self, offset: u64, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self {
+            LocalFile::Tokio { file, position } => {
+                if *position != offset {
+                    file.seek(SeekFrom::Start(offset)).await?;
+                }
+                file.write_all(&data).await?;
+                *position = offset + data.len() as u64;
+                Ok(data)
+            }
+            #[cfg(all(feature = "io_uring", target_os = "linux"))]
+            LocalFile::Uring(file) => file.write_all_at(offset, data).await,
+        }
+    }
+
+    pub async fn sync_all(&mut self) -> io::Result<()> {
+        match self {
+            LocalFile::Tokio { file, .. } => {
+                file.flush().await?;
+                file.sync_all().await
+            }
+            #[cfg(all(feature = "io_uring", target_os = "linux"))]
+            LocalFile::Uring(file) => file.sync_all().await,
+        }
+    }
+}
+
+/// A file written at explicit offsets with `pwrite` on the blocking pool.
+///
+/// Clones share the file and no write depends on a file position, so writes from any number of
+/// tasks run in parallel.
+#[cfg(unix)]
+#[derive(Clone)]
+pub struct PositionalFile {
+    file: Arc<std::fs::File>,
+}
+
+#[cfg(unix)]
+impl PositionalFile {
+    pub fn new(file: std::fs::File) -> Self {
+        PositionalFile { file: Arc::new(file) }
+    }
+
+    /// Writes all of `data` at `offset` and returns it, so its allocation can be reused.
+    pub async fn write_all_at(&self, offset: u64, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        use std::os::unix::fs::FileExt;
+
+        let file = Arc::clone(&self.file);
+        tokio::task::spawn_blocking(move || {
+            file.write_all_at(&data, offset)?;
+            Ok(data)
+        })
+        .await
+        .map_err(io::Error::other)?
+    }
+
+    pub async fn sync_all(&self) -> io::Result<()> {
+        let file = Arc::clone(&self.file);
+        tokio::task::spawn_blocking(move || file.sync_all()).await.map_err(io::Error::other)?
+    }
+}
+
+#[cfg(all(feature = "io_uring", target_os = "linux"))]
+mod uring {
+    use std::io;
+    use tokio::sync::{mpsc, oneshot};
+
+    enum Command {
+        Read {
+            offset: u64,
+            buf: Vec<u8>,
+            reply: oneshot::Sender<io::Result<Vec<u8>>>,
+        },
+        Write {
+            offset: u64,
+            data: Vec<u8>,
+            reply: oneshot::Sender<io::Result<Vec<u8>>>,
+        },
+        Sync {
+            reply: oneshot::Sender<io::Result<()>>,
+        },
+    }
+
+    /// A file served by a dedicated thread running a tokio-uring runtime.
+    ///
+    /// tokio-uring can't run on the shared multi-threaded runtime, so operations are sent to the
+    /// thread over a channel. The thread closes the file and exits when the handle is dropped.
+    pub struct UringFile {
+        commands: mpsc::UnboundedSender<Command>,
+    }
+
+    fn worker_exited() -> io::Error {
+        io::Error::other("io_uring worker thread exited")
+    }
+
+    impl UringFile {
+        pub fn new(file: std::fs::File) -> io::Result<Self> {
+            let (commands, mut receiver) = mpsc::unbounded_channel();
+            std::thread::Builder::new().name("msc-io-uring".to_string()).spawn(move || {
+                tokio_uring::start(async move {
+                    let file = tokio_uring::fs::File::from_std(file);
+                    while let Some(command) = receiver.recv().await {
+                        match command {
+                            Command::Read { offset, mut buf, reply } => {
+                                buf.clear();
+                                let (result, buf) = file.read_at(buf, offset).await;
+                                let _ = reply.send(result.map(|_| buf));
+                            }
+                            Command::Write { offset, data, reply } => {
+                                let (result, data) = file.write_all_at(data, offset).await;
+                                let _ = reply.send(result.map(|_| data));
+                            }
+                            Command::Sync { reply } => {
+                                let _ = reply.send(file.sync_all().await);
+                            }
+                        }
+                    }
+                    let _ = file.close().await;
+                })
+            })?;
+            Ok(UringFile { commands })
+        }
+
+        async fn request<T>(&self, command: impl FnOnce(oneshot::Sender<io::Result<T>>) -> Command) -> io::Result<T> {
+            let (reply, response) = oneshot::channel();
+            self.commands.send(command(reply)).map_err(|_| worker_exited())?;
+            response.await.map_err(|_| worker_exited())?
+        }
+
+        pub async fn read_at(&self, offset: u64, buf: Vec<u8>) -> io::Result<Vec<u8>> {
+            self.request(|reply| Command::Read { offset, buf, reply }).await
+        }
+
+        pub async fn write_all_at(&self, offset: u64, data: Vec<u8>) -> io::Result<Vec<u8>> {
+            self.request(|reply| Command::Write { offset, data, reply }).await
+        }
+
+        pub async fn sync_all(&self) -> io::Result<()> {
+            self.request(|reply| Command::Sync { reply }).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn backends() -> Vec<LocalIo> {
+        let mut backends = vec![LocalIo::Tokio];
+        if uring_available() {
+            backends.push(LocalIo::Uring);
+        }
+        backends
+    }
+
+    /// Writes `data` in reverse chunk order, then reads it back sequentially.
+    async fn round_trip(local_io: LocalIo, data: &[u8], chunksize: usize) -> Vec<u8> {
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut file = LocalFile::new(temp_file.reopen().unwrap(), local_io).unwrap();
+        let chunks: Vec<(usize, &[u8])> = data.chunks(chunksize).enumerate().collect();
+        for (index, chunk) in chunks.into_iter().rev() {
+            file.write_all_at((index * chunksize) as u64, chunk.to_vec()).await.unwrap();
+        }
+        file.sync_all().await.unwrap();
+
+        let mut file = LocalFile::new(temp_file.reopen().unwrap(), local_io).unwrap();
+        let mut result = Vec::with_capacity(data.len());
+        let mut buffer = Vec::with_capacity(chunksize);
+        loop {
+            buffer = file.read_at(result.len() as u64, buffer).await.unwrap();
+            if buffer.is_empty() {
+                break;
+            }
+            result.extend_from_slice(&buffer);
+        }
+        result
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_positional_file_parallel_writes() {
+        let data: Vec<u8> = (0..1024 * 1024 + 123).map(|i| (i % 251) as u8).collect();
+        let chunksize = 64 * 1024;
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let file = PositionalFile::new(temp_file.reopen().unwrap());
+
+        let writes = data.chunks(chunksize).enumerate().map(|(index, chunk)| {
+            let file = file.clone();
+            let chunk = chunk.to_vec();
+            tokio::spawn(async move { file.write_all_at((index * chunksize) as u64, chunk).await })
+        });
+        for write in writes.collect::<Vec<_>>() {
+            write.await.unwrap().unwrap();
+        }
+        file.sync_all().await.unwrap();
+        assert_eq!(std::fs::read(temp_file.path()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(LocalIo::resolve("tokio").unwrap(), LocalIo::Tokio);
+        assert!(LocalIo::resolve("auto").is_ok());
+        assert_eq!(LocalIo::resolve("uring").is_ok(), uring_available());
+        assert!(LocalIo::resolve("mmap").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backends_round_trip() {
+        let data: Vec<u8> = (0..1024 * 1024 + 123).map(|i| (i % 251) as u8).collect();
+        for local_io in backends() {
+            assert_eq!(round_trip(local_io, &data, 64 * 1024).await, data, "{:?}", local_io);
+        }
+    }
+
+    /// Compares writing 1 GiB in 32 MiB chunks through one seeking writer and with 8 tasks writing
+    /// positionally. Run with `cargo test -- --ignored --nocapture`.
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore]
+    async fn bench_positional_writes() {
+        let chunksize = 32 * 1024 * 1024;
+        let chunks = 32;
+        let chunk = vec![7u8; chunksize];
+
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let mut file = LocalFile::new(temp_file.reopen().unwrap(), LocalIo::Tokio).unwrap();
+        let started = Instant::now();
+        for index in 0..chunks {
+            file.write_all_at((index * chunksize) as u64, chunk.clone()).await.unwrap();
+        }
+        file.sync_all().await.unwrap();
+        let sequential = started.elapsed();
+
+        let temp_file = tempfile::NamedTempFile::new().unwrap();
+        let file = PositionalFile::new(temp_file.reopen().unwrap());
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(8));
+        let started = Instant::now();
+        let mut writes = tokio::task::JoinSet::new();
+        for index in 0..chunks {
+            let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+            let (file, chunk) = (file.clone(), chunk.clone());
+            writes.spawn(async move {
+                file.write_all_at((index * chunksize) as u64, chunk).await.unwrap();
+                drop(permit);
+            });
+        }
+        while writes.join_next().await.is_some() {}
+        file.sync_all().await.unwrap();
+        let positional = started.elapsed();
+
+        for (name, elapsed) in [("seeking writer", sequential), ("positional x8", positional)] {
+            println!("{}: wrote 1 GiB in {:.2}s ({:.0} MiB/s)", name, elapsed.as_secs_f64(), 1024.0 / elapsed.as_secs_f64());
+        }
+    }
+
+    /// Compares the backends on a 1 GiB file. Run with `cargo test -- --ignored --nocapture`.
+    #[tokio::test]
+    #[ignore]
+    async fn bench_backends_large_file() {
+        let data = vec![7u8; 1024 * 1024 * 1024];
+        for local_io in backends() {
+            let started = Instant::now();
+            let result = round_trip(local_io, &data, 32 * 1024 * 1024).await;
+            let elapsed = started.elapsed();
+            assert_eq!(result.len(), data.len());
+            println!(
+                "{:?}: wrote and read 1 GiB in {:.2}s ({:.0} MiB/s)",
+                local_io,
+                elapsed.as_secs_f64(),
+                2048.0 / elapsed.as_secs_f64()
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::PyDict;
+use regex::Regex;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::LazyLock;
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::metrics::ErrorClass;
+
+/// Target of the events forwarded to Python, and the name of the logger they are forwarded to.
+/// Events of other targets, like those of the HTTP client, are never forwarded as they may carry
+/// credentials.
+pub const TARGET: &str = "multistorageclient.rust";
+
+/// Most verbose level forwarded, ranked like `rank`. Warnings and errors by default.
+static LEVEL: AtomicU8 = AtomicU8::new(2);
+
+static LOGGER: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+fn rank(level: &Level) -> u8 {
+    match *level {
+        Level::ERROR => 1,
+        Level::WARN => 2,
+        Level::INFO => 3,
+        Level::DEBUG => 4,
+        Level::TRACE => 5,
+    }
+}
+
+/// Whether events at `level` are forwarded, for skipping the work of preparing those that aren't.
+pub fn enabled(level: Level) -> bool {
+    rank(&level) <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Parses a Python logging level, by number or by name, into a rank. `None` and levels above
+/// `ERROR` turn forwarding off.
+fn parse_level(level: Option<&Bound<'_, PyAny>>) -> PyResult<u8> {
+    let Some(level) = level else {
+        return Ok(0);
+    };
+    if let Ok(number) = level.extract::<i64>() {
+        return Ok(match number {
+            ..=5 => 5,
+            6..=10 => 4,
+            11..=20 => 3,
+            21..=30 => 2,
+            31..=40 => 1,
+            _ => 0,
+        });
+    }
+    let name = level.extract::<String>()?;
+    match name.to_ascii_uppercase().as_str() {
+        "TRACE" => Ok(5),
+        "DEBUG" => Ok(4),
+        "INFO" => Ok(3),
+        "WARNING" | "WARN" => Ok(2),
+        "ERROR" => Ok(1),
+        "CRITICAL" | "OFF" => Ok(0),
+        _ => Err(PyValueError::new_err(format!("Unknown log level '{}'", name))),
+    }
+}
+
+/// Sets the most verbose level of the records forwarded to the `multistorageclient.rust` logger,
+/// e.g. `"DEBUG"` or `logging.DEBUG`, or turns them off with `None`. The logger's own level and
+/// handlers still apply to the records that are forwarded.
+#[pyfunction]
+#[pyo3(signature = (level))]
+pub fn set_log_level(level: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+    LEVEL.store(parse_level(level)?, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Formats `err` followed by its sources, e.g. the I/O error behind a failed request.
+pub fn chain(err: &dyn std::error::Error) -> String {
+    let mut chain = err.to_string();
+    let mut source = err.source();
+    while let Some(err) = source {
+        let message = err.to_string();
+        // Errors commonly repeat their source in their own message.
+        if !chain.ends_with(&message) {
+            chain.push_str(": ");
+            chain.push_str(&message);
+        }
+        source = err.source();
+    }
+    chain
+}
+
+/// Formats the Python exception `err` followed by its causes, redacted.
+pub fn describe(py: Python<'_>, err: &PyErr) -> String {
+    let mut description = err.to_string();
+    let mut cause = err.cause(py);
+    while let Some(err) = cause {
+        description.push_str(": ");
+        description.push_str(&err.to_string());
+        cause = err.cause(py);
+    }
+    redact(&description)
+}
+
+/// Logs a failed operation, at `WARNING` unless the object was merely not found.
+pub fn operation_failed(operation: &str, key: Option<&str>, duration: Duration, class: ErrorClass, error: &str) {
+    let (duration_ms, error_class) = (duration.as_secs_f64() * 1000.0, class.name());
+    if class == ErrorClass::NotFound {
+        tracing::debug!(target: TARGET, operation, key, duration_ms, error_class, error, "Operation failed");
+    } else {
+        tracing::warn!(target: TARGET, operation, key, duration_ms, error_class, error, "Operation failed");
+    }
+}
+
+/// Replaces the query of every URL in `message`, which holds the signature of presigned and SAS
+/// URLs.
+pub fn redact(message: &str) -> String {
+    static URL_QUERY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(https?://[^\s?]*)\?[^\s]*").unwrap());
+    URL_QUERY.replace_all(message, "$1?<redacted>").into_owned()
+}
+
+#[derive(Default)]
+struct Record {
+    message: String,
+    fields: Vec<(&'static str, FieldValue)>,
+}
+
+enum FieldValue {
+    Str(String),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Visit for Record {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let value = format!("{:?}", value);
+        self.record_str(field, &value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push((field.name(), FieldValue::Str(value.to_string())));
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.push((field.name(), FieldValue::Int(value)));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.push((field.name(), FieldValue::UInt(value)));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.fields.push((field.name(), FieldValue::Float(value)));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.push((field.name(), FieldValue::Bool(value)));
+    }
+}
+
+impl Record {
+    /// The message followed by the fields as `name=value`, redacted.
+    fn line(&self) -> String {
+        let mut line = self.message.clone();
+        for (name, value) in &self.fields {
+            let _ = match value {
+                FieldValue::Str(value) => write!(line, " {}={}", name, value),
+                FieldValue::Int(value) => write!(line, " {}={}", name, value),
+                FieldValue::UInt(value) => write!(line, " {}={}", name, value),
+                FieldValue::Float(value) => write!(line, " {}={:.3}", name, value),
+                FieldValue::Bool(value) => write!(line, " {}={}", name, value),
+            };
+        }
+        redact(&line)
+    }
+
+    /// The fields, set as attributes of the Python log record.
+    fn extra<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let extra = PyDict::new(py);
+        for (name, value) in &self.fields {
+            match value {
+                FieldValue::Str(value) => extra.set_item(name, redact(value))?,
+                FieldValue::Int(value) => extra.set_item(name, value)?,
+                FieldValue::UInt(value) => extra.set_item(name, value)?,
+                FieldValue::Float(value) => extra.set_item(name, value)?,
+                FieldValue::Bool(value) => extra.set_item(name, value)?,
+            }
+        }
+        Ok(extra)
+    }
+}
+
+/// Forwards the events of `TARGET` to Python's `logging`, with their fields as `extra`.
+pub struct PythonLogLayer;
+
+impl<S: Subscriber> Layer<S> for PythonLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if metadata.target() != TARGET || !enabled(*metadata.level()) {
+            return;
+        }
+        let mut record = Record::default();
+        event.record(&mut record);
+        let level = match *metadata.level() {
+            Level::ERROR => 40,
+            Level::WARN => 30,
+            Level::INFO => 20,
+            Level::DEBUG => 10,
+            Level::TRACE => 5,
+        };
+        // An interpreter that is shutting down has nowhere left to log to.
+        let _ = Python::try_attach(|py| {
+            let logger = LOGGER.get_or_try_init(py, || {
+                Ok::<_, PyErr>(py.import("logging")?.call_method1("getLogger", (TARGET,))?.unbind())
+            })?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("extra", record.extra(py)?)?;
+            logger
+                .bind(py)
+                .call_method("log", (level, record.line()), Some(&kwargs))?;
+            Ok::<_, PyErr>(())
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        assert_eq!(
+            redact("Error performing GET https://account.blob.core.windows.net/c/key?sv=2024&sig=secret in 1s"),
+            "Error performing GET https://account.blob.core.windows.net/c/key?<redacted> in 1s"
+        );
+        assert_eq!(redact("GET /bucket/key status=200"), "GET /bucket/key status=200");
+    }
+
+    #[test]
+    fn test_enabled() {
+        assert!(enabled(Level::WARN) && !enabled(Level::DEBUG));
+        LEVEL.store(4, Ordering::Relaxed);
+        assert!(enabled(Level::DEBUG) && !enabled(Level::TRACE));
+        LEVEL.store(0, Ordering::Relaxed);
+        assert!(!enabled(Level::ERROR));
+        LEVEL.store(2, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,278 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, Result, UploadPart,
+};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_NEGATIVE_CACHE_TTL_SECS: f64 = 5.0;
+
+/// Number of entries above which expired entries are swept on insert.
+const SWEEP_THRESHOLD: usize = 100_000;
+
+tokio::task_local! {
+    static FRESH: bool;
+}
+
+/// Runs `fut` with the negative cache bypassed for its lookups.
+pub async fn fresh<F: Future>(fut: F) -> F::Output {
+    FRESH.scope(true, fut).await
+}
+
+fn is_fresh() -> bool {
+    FRESH.try_with(|fresh| *fresh).unwrap_or(false)
+}
+
+/// Keys recently found not to exist, each remembered for `ttl`.
+#[derive(Debug)]
+pub struct NegativeCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<Path, Instant>>,
+    hits: AtomicU64,
+}
+
+impl NegativeCache {
+    pub fn new(ttl: Duration) -> Self {
+        NegativeCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Head lookups answered with a cached NotFound.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn is_missing(&self, location: &Path) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(location) {
+            Some(expires_at) if *expires_at > Instant::now() => true,
+            Some(_) => {
+                entries.remove(location);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&self, location: &Path) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= SWEEP_THRESHOLD {
+            entries.retain(|_, expires_at| *expires_at > now);
+        }
+        entries.insert(location.clone(), now + self.ttl);
+    }
+
+    fn invalidate(&self, location: &Path) {
+        self.entries.lock().unwrap().remove(location);
+    }
+}
+
+fn not_found(location: &Path) -> object_store::Error {
+    object_store::Error::NotFound {
+        path: location.to_string(),
+        source: "cached NotFound from a recent lookup".into(),
+    }
+}
+
+/// Store wrapper that remembers NotFound head results for a short time.
+///
+/// Writes and copies through this store invalidate the destination key, so a client always sees
+/// its own writes; objects created by other clients become visible once the entry expires.
+#[derive(Debug)]
+pub struct NegativeCacheStore {
+    inner: Arc<dyn ObjectStore>,
+    cache: Arc<NegativeCache>,
+}
+
+impl NegativeCacheStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, cache: Arc<NegativeCache>) -> Self {
+        NegativeCacheStore { inner, cache }
+    }
+
+    fn record<T>(&self, location: &Path, result: &Result<T>) {
+        match result {
+            Err(object_store::Error::NotFound { .. }) => self.cache.insert(location),
+            Ok(_) => self.cache.invalidate(location),
+            Err(_) => {}
+        }
+    }
+}
+
+impl fmt::Display for NegativeCacheStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NegativeCacheStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for NegativeCacheStore {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        let result = self.inner.put_opts(location, payload, opts).await;
+        self.cache.invalidate(location);
+        result
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        let upload = self.inner.put_multipart_opts(location, opts).await?;
+        Ok(Box::new(InvalidatingUpload {
+            inner: upload,
+            location: location.clone(),
+            cache: Arc::clone(&self.cache),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        // Heads of the latest version, e.g. by `info` for the response's attributes, are cached
+        // like `head`.
+        if !options.head || options.version.is_some() {
+            return self.inner.get_opts(location, options).await;
+        }
+        if !is_fresh() && self.cache.is_missing(location) {
+            self.cache.hits.fetch_add(1, Ordering::Relaxed);
+            return Err(not_found(location));
+        }
+        let result = self.inner.get_opts(location, options).await;
+        self.record(location, &result);
+        result
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        if !is_fresh() && self.cache.is_missing(location) {
+            self.cache.hits.fetch_add(1, Ordering::Relaxed);
+            return Err(not_found(location));
+        }
+        let result = self.inner.head(location).await;
+        self.record(location, &result);
+        result
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        let result = self.inner.copy(from, to).await;
+        self.cache.invalidate(to);
+        result
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        let result = self.inner.copy_if_not_exists(from, to).await;
+        self.cache.invalidate(to);
+        result
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let result = self.inner.rename(from, to).await;
+        self.cache.invalidate(to);
+        result
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        let result = self.inner.rename_if_not_exists(from, to).await;
+        self.cache.invalidate(to);
+        result
+    }
+}
+
+#[derive(Debug)]
+struct InvalidatingUpload {
+    inner: Box<dyn MultipartUpload>,
+    location: Path,
+    cache: Arc<NegativeCache>,
+}
+
+#[async_trait]
+impl MultipartUpload for InvalidatingUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        self.inner.put_part(data)
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        let result = self.inner.complete().await;
+        self.cache.invalidate(&self.location);
+        result
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use object_store::memory::InMemory;
+
+    #[tokio::test]
+    async fn test_negative_cache() {
+        let inner: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let cache = Arc::new(NegativeCache::new(Duration::from_millis(200)));
+        let store = NegativeCacheStore::new(Arc::clone(&inner), Arc::clone(&cache));
+        let sidecar = Path::from("shard-0000.idx");
+
+        assert!(store.head(&sidecar).await.is_err());
+        assert!(store.head(&sidecar).await.is_err());
+        assert_eq!(cache.hits(), 1);
+
+        // Another writer's object stays hidden until the entry expires, unless the lookup is fresh.
+        inner.put(&sidecar, Bytes::from_static(b"idx").into()).await.unwrap();
+        assert!(store.head(&sidecar).await.is_err());
+        assert!(fresh(store.head(&sidecar)).await.is_ok());
+        inner.delete(&sidecar).await.unwrap();
+        assert!(store.head(&sidecar).await.is_err());
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        inner.put(&sidecar, Bytes::from_static(b"idx").into()).await.unwrap();
+        assert!(store.head(&sidecar).await.is_ok());
+
+        // Writes through the store are visible immediately.
+        let meta = Path::from("shard-0000.meta");
+        assert!(store.head(&meta).await.is_err());
+        store.put(&meta, Bytes::from_static(b"meta").into()).await.unwrap();
+        assert!(store.head(&meta).await.is_ok());
+
+        let copied = Path::from("shard-0001.meta");
+        assert!(store.head(&copied).await.is_err());
+        store.copy(&meta, &copied).await.unwrap();
+        assert!(store.head(&copied).await.is_ok());
+    }
+}
@@ -0,0 +1,271 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::{RustClientError, RustRetryableError, RustThrottledError};
+
+/// Operations counted separately, by the name their methods pass to `request_tag`. Any other is
+/// counted as `other`.
+const OPERATIONS: [&str; 29] = [
+    "copy",
+    "delete",
+    "delete_many",
+    "download",
+    "download_directory",
+    "download_multipart_to_bytes",
+    "download_multipart_to_file",
+    "du",
+    "exists",
+    "get",
+    "get_many",
+    "get_ranges",
+    "glob",
+    "info",
+    "list_iter",
+    "list_recursive",
+    "open_read_stream",
+    "open_reader",
+    "open_write_stream",
+    "open_writer",
+    "put",
+    "read_samples",
+    "rename",
+    "sync",
+    "upload",
+    "upload_directory",
+    "upload_multipart_from_buffers",
+    "upload_multipart_from_bytes",
+    "upload_multipart_from_file",
+];
+
+/// Upper bounds of the latency buckets in microseconds. Slower operations fall in a last,
+/// unbounded bucket.
+const LATENCY_BOUNDS_US: [u64; 14] = [
+    1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000, 1_000_000, 2_500_000, 5_000_000,
+    10_000_000, 60_000_000,
+];
+
+/// What a failed operation raised, coarse enough to be counted with a fixed set of counters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorClass {
+    NotFound,
+    Timeout,
+    Throttled,
+    /// Other retryable errors, including retries exhausted and an open circuit breaker.
+    Retryable,
+    /// Other error responses, including failed preconditions and integrity checks.
+    Client,
+    Invalid,
+    Other,
+}
+
+const ERROR_CLASSES: [(ErrorClass, &str); 7] = [
+    (ErrorClass::NotFound, "not_found"),
+    (ErrorClass::Timeout, "timeout"),
+    (ErrorClass::Throttled, "throttled"),
+    (ErrorClass::Retryable, "retryable"),
+    (ErrorClass::Client, "client"),
+    (ErrorClass::Invalid, "invalid"),
+    (ErrorClass::Other, "other"),
+];
+
+impl ErrorClass {
+    pub fn of(py: Python<'_>, err: &PyErr) -> Self {
+        if err.is_instance_of::<pyo3::exceptions::PyFileNotFoundError>(py) {
+            ErrorClass::NotFound
+        } else if err.is_instance_of::<pyo3::exceptions::PyTimeoutError>(py) {
+            ErrorClass::Timeout
+        } else if err.is_instance_of::<RustThrottledError>(py) {
+            ErrorClass::Throttled
+        } else if err.is_instance_of::<RustRetryableError>(py) {
+            ErrorClass::Retryable
+        } else if err.is_instance_of::<RustClientError>(py) {
+            ErrorClass::Client
+        } else if err.is_instance_of::<pyo3::exceptions::PyValueError>(py) {
+            ErrorClass::Invalid
+        } else {
+            ErrorClass::Other
+        }
+    }
+
+    fn index(self) -> usize {
+        ERROR_CLASSES.iter().position(|(class, _)| *class == self).unwrap()
+    }
+
+    pub fn name(self) -> &'static str {
+        ERROR_CLASSES[self.index()].1
+    }
+}
+
+#[derive(Default)]
+struct OperationMetrics {
+    count: AtomicU64,
+    errors: [AtomicU64; ERROR_CLASSES.len()],
+    latency_buckets: [AtomicU64; LATENCY_BOUNDS_US.len() + 1],
+    latency_sum_us: AtomicU64,
+}
+
+impl OperationMetrics {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let errors = PyDict::new(py);
+        for ((_, name), count) in ERROR_CLASSES.iter().zip(&self.errors) {
+            errors.set_item(name, count.load(Ordering::Relaxed))?;
+        }
+        let buckets = PyDict::new(py);
+        let bounds = LATENCY_BOUNDS_US
+            .iter()
+            .map(|bound| *bound as f64 / 1e6)
+            .chain([f64::INFINITY]);
+        for (bound, count) in bounds.zip(&self.latency_buckets) {
+            buckets.set_item(bound, count.load(Ordering::Relaxed))?;
+        }
+        let metrics = PyDict::new(py);
+        metrics.set_item("count", self.count.load(Ordering::Relaxed))?;
+        metrics.set_item("errors", errors)?;
+        metrics.set_item("latency_buckets", buckets)?;
+        metrics.set_item("latency_sum", self.latency_sum_us.load(Ordering::Relaxed) as f64 / 1e6)?;
+        Ok(metrics)
+    }
+
+    fn reset(&self) {
+        let counters = [&self.count, &self.latency_sum_us].into_iter();
+        for counter in counters.chain(&self.errors).chain(&self.latency_buckets) {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Operation and byte counters of one client, updated without locks by the operations it runs.
+pub struct Metrics {
+    /// Indexed like `OPERATIONS`, followed by `other`.
+    operations: [OperationMetrics; OPERATIONS.len() + 1],
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics {
+            operations: std::array::from_fn(|_| OperationMetrics::default()),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+        }
+    }
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
+}
+
+/// Name of the operation at `index` in `Metrics::operations`.
+pub fn operation_name(index: usize) -> &'static str {
+    OPERATIONS.get(index).copied().unwrap_or("other")
+}
+
+/// Index of `operation` in `Metrics::operations`.
+pub fn operation_index(operation: &str) -> usize {
+    OPERATIONS
+        .iter()
+        .position(|name| *name == operation)
+        .unwrap_or(OPERATIONS.len())
+}
+
+impl Metrics {
+    pub fn record_operation(&self, operation: usize, latency: Duration, error: Option<ErrorClass>) {
+        let operation = &self.operations[operation];
+        let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        let bucket = LATENCY_BOUNDS_US.partition_point(|bound| *bound < latency_us);
+        operation.count.fetch_add(1, Ordering::Relaxed);
+        operation.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        operation.latency_sum_us.fetch_add(latency_us, Ordering::Relaxed);
+        if let Some(error) = error {
+            operation.errors[error.index()].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_bytes(&self, sent: u64, received: u64) {
+        self.bytes_sent.fetch_add(sent, Ordering::Relaxed);
+        self.bytes_received.fetch_add(received, Ordering::Relaxed);
+    }
+
+    /// The counters as a dict, listing only the operations that ran since the last reset.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let operations = PyDict::new(py);
+        let names = OPERATIONS.iter().chain(&["other"]);
+        for (name, operation) in names.zip(&self.operations) {
+            if operation.count.load(Ordering::Relaxed) > 0 {
+                operations.set_item(name, operation.to_dict(py)?)?;
+            }
+        }
+        let metrics = PyDict::new(py);
+        metrics.set_item("operations", operations)?;
+        metrics.set_item("bytes_sent", self.bytes_sent.load(Ordering::Relaxed))?;
+        metrics.set_item("bytes_received", self.bytes_received.load(Ordering::Relaxed))?;
+        Ok(metrics)
+    }
+
+    /// Zeroes the counters. Operations finishing meanwhile may be counted on either side.
+    pub fn reset(&self) {
+        for operation in &self.operations {
+            operation.reset();
+        }
+        self.bytes_sent.store(0, Ordering::Relaxed);
+        self.bytes_received.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_operation() {
+        let metrics = Metrics::default();
+        let get = operation_index("get");
+        metrics.record_operation(get, Duration::from_micros(800), None);
+        metrics.record_operation(get, Duration::from_millis(30), Some(ErrorClass::NotFound));
+        metrics.record_operation(get, Duration::from_secs(120), None);
+        metrics.record_operation(
+            operation_index("unknown"),
+            Duration::from_millis(1),
+            Some(ErrorClass::Other),
+        );
+
+        let get = &metrics.operations[get];
+        assert_eq!(get.count.load(Ordering::Relaxed), 3);
+        assert_eq!(get.errors[ErrorClass::NotFound.index()].load(Ordering::Relaxed), 1);
+        let buckets: Vec<u64> = get
+            .latency_buckets
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .collect();
+        assert_eq!(buckets, [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(get.latency_sum_us.load(Ordering::Relaxed), 120_030_800);
+        assert_eq!(metrics.operations[OPERATIONS.len()].count.load(Ordering::Relaxed), 1);
+
+        metrics.reset();
+        assert!(metrics
+            .operations
+            .iter()
+            .all(|operation| operation.count.load(Ordering::Relaxed) == 0));
+        assert_eq!(get.latency_buckets[14].load(Ordering::Relaxed), 0);
+    }
+}
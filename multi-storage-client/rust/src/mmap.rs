@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use memmap2::Mmap;
+use pyo3::exceptions::PyRuntimeWarning;
+use pyo3::prelude::*;
+use std::ffi::CString;
+use std::fs::File;
+use std::io;
+
+/// Maps the file at `path` read-only and returns its contents as `Bytes` that keep the map alive.
+///
+/// Fails if the file is no longer `expected_len` bytes long, or if the platform or filesystem
+/// doesn't support mapping it.
+pub fn map_file(path: &str, expected_len: u64) -> io::Result<Bytes> {
+    let file = File::open(path)?;
+    // SAFETY: the map is only read. Another process truncating the file while it is mapped would
+    // fault on access; callers document that the source file must not shrink during an upload.
+    let map = unsafe { Mmap::map(&file)? };
+    if map.len() as u64 != expected_len {
+        return Err(io::Error::other(format!(
+            "file is {} bytes, expected {}",
+            map.len(),
+            expected_len
+        )));
+    }
+    Ok(Bytes::from_owner(map))
+}
+
+/// Emits a `RuntimeWarning` that a `use_mmap` upload of `path` is falling back to buffered reads.
+pub fn warn_fallback(path: &str, reason: &str) {
+    let message = format!("use_mmap: {} ({}); falling back to buffered reads", path, reason);
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    Python::attach(|py| {
+        if let Err(err) = PyErr::warn(py, py.get_type::<PyRuntimeWarning>().as_any(), &message, 1) {
+            err.write_unraisable(py, None);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_map_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"0123456789").unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let mapped = map_file(path, 10).unwrap();
+        assert_eq!(mapped.slice(2..5).as_ref(), b"234");
+
+        assert!(map_file(path, 11).is_err());
+        assert!(map_file("/nonexistent/file", 10).is_err());
+    }
+}
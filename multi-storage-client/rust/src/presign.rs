@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use http::Method;
+#[cfg(feature = "aws")]
+use object_store::aws::{AmazonS3, AwsAuthorizer};
+use object_store::path::Path;
+#[cfg(any(feature = "aws", feature = "gcp"))]
+use object_store::signer::Signer;
+#[cfg(feature = "aws")]
+use object_store::CredentialProvider;
+use object_store::Result;
+use std::fmt;
+use std::time::Duration;
+
+/// The longest a presigned URL may be valid for, on both S3 and GCS.
+pub const MAX_EXPIRES_IN: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Generates presigned URLs for the objects of a store.
+#[async_trait]
+pub trait Presigner: fmt::Debug + Send + Sync {
+    /// Signs a `method` request for `path`, valid for `expires_in`. `query` is signed with the
+    /// URL, e.g. the `response-content-type` override of a GET.
+    async fn presign(
+        &self,
+        method: Method,
+        path: &Path,
+        expires_in: Duration,
+        query: &[(&str, String)],
+    ) -> Result<String>;
+}
+
+/// Signs URLs with a store's own signer, which signs no query parameters of its own.
+#[cfg(feature = "gcp")]
+#[derive(Debug)]
+pub struct StorePresigner<S> {
+    signer: S,
+    provider: &'static str,
+}
+
+#[cfg(feature = "gcp")]
+impl<S: Signer> StorePresigner<S> {
+    pub fn new(signer: S, provider: &'static str) -> Self {
+        StorePresigner { signer, provider }
+    }
+}
+
+#[cfg(feature = "gcp")]
+#[async_trait]
+impl<S: Signer + fmt::Debug> Presigner for StorePresigner<S> {
+    async fn presign(
+        &self,
+        method: Method,
+        path: &Path,
+        expires_in: Duration,
+        query: &[(&str, String)],
+    ) -> Result<String> {
+        if let Some((name, _)) = query.first() {
+            return Err(object_store::Error::NotSupported {
+                source: format!("{} is not supported by the '{}' provider", name, self.provider).into(),
+            });
+        }
+        Ok(self.signer.signed_url(method, path, expires_in).await?.to_string())
+    }
+}
+
+/// Signs URLs of S3 objects, including response overrides in the signature.
+#[cfg(feature = "aws")]
+#[derive(Debug)]
+pub struct S3Presigner {
+    store: AmazonS3,
+    region: String,
+    request_payer: bool,
+}
+
+#[cfg(feature = "aws")]
+impl S3Presigner {
+    pub fn new(store: AmazonS3, region: String, request_payer: bool) -> Self {
+        S3Presigner {
+            store,
+            region,
+            request_payer,
+        }
+    }
+}
+
+#[cfg(feature = "aws")]
+#[async_trait]
+impl Presigner for S3Presigner {
+    async fn presign(
+        &self,
+        method: Method,
+        path: &Path,
+        expires_in: Duration,
+        query: &[(&str, String)],
+    ) -> Result<String> {
+        let mut url = self.store.signed_url(method.clone(), path, expires_in).await?;
+        if query.is_empty() {
+            return Ok(url.to_string());
+        }
+        // The signer only signs the object's URL, so it's signed again with the query added.
+        url.set_query(None);
+        url.query_pairs_mut().extend_pairs(query);
+        let credential = self.store.credentials().get_credential().await?;
+        AwsAuthorizer::new(&credential, "s3", &self.region)
+            .with_request_payer(self.request_payer)
+            .sign(method, &mut url, expires_in);
+        Ok(url.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "aws"))]
+mod tests {
+    use super::*;
+    use object_store::aws::AmazonS3Builder;
+
+    #[tokio::test]
+    async fn test_s3_presigned_url() {
+        let store = AmazonS3Builder::new()
+            .with_bucket_name("bucket")
+            .with_region("us-west-2")
+            .with_access_key_id("AKIDEXAMPLE")
+            .with_secret_access_key("secret")
+            .build()
+            .unwrap();
+        let presigner = S3Presigner::new(store, "us-west-2".to_string(), false);
+        let path = Path::from("dir/object.bin");
+        let expires_in = Duration::from_secs(60);
+
+        let url = presigner.presign(Method::GET, &path, expires_in, &[]).await.unwrap();
+        assert!(url.starts_with("https://s3.us-west-2.amazonaws.com/bucket/dir/object.bin?"));
+        assert!(url.contains("X-Amz-Expires=60"));
+        assert!(url.contains("X-Amz-Signature="));
+
+        let query = [("response-content-type", "text/plain".to_string())];
+        let overridden = presigner.presign(Method::GET, &path, expires_in, &query).await.unwrap();
+        assert!(overridden.contains("response-content-type=text%2Fplain"));
+        assert_eq!(overridden.matches("X-Amz-Signature=").count(), 1);
+        let signature = |url: &str| url.split("X-Amz-Signature=").nth(1).unwrap().to_string();
+        assert_ne!(signature(&url), signature(&overridden));
+    }
+}
@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use object_store::{MultipartUpload, PutPayload, PutResult, Result, UploadPart};
+use pyo3::prelude::*;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::Mutex;
+
+use crate::StorageError;
+
+struct Inner {
+    callback: Py<PyAny>,
+    total: AtomicU64,
+    // Held while the callback runs, so calls don't overlap and `bytes_transferred` only grows.
+    transferred: Mutex<u64>,
+    error: StdMutex<Option<PyErr>>,
+}
+
+/// Reports the progress of a transfer to a Python `progress_callback(bytes_transferred, total_bytes)`.
+#[derive(Clone)]
+pub struct Progress {
+    inner: Arc<Inner>,
+}
+
+impl fmt::Debug for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Progress").field("total", &self.inner.total).finish()
+    }
+}
+
+impl Progress {
+    pub fn new(callback: Py<PyAny>) -> Self {
+        Progress {
+            inner: Arc::new(Inner {
+                callback,
+                total: AtomicU64::new(0),
+                transferred: Mutex::new(0),
+                error: StdMutex::new(None),
+            }),
+        }
+    }
+
+    /// Sets the size of the transfer, once it is known.
+    pub fn start(&self, total: u64) {
+        self.inner.total.store(total, Ordering::SeqCst);
+    }
+
+    /// Records `bytes` more transferred and calls the callback on a blocking thread.
+    ///
+    /// An exception raised by the callback is kept for [`Progress::take_error`] and returned as
+    /// an error, which aborts the transfer.
+    pub async fn advance(&self, bytes: u64) -> Result<(), StorageError> {
+        let mut transferred = self.inner.transferred.lock().await;
+        *transferred += bytes;
+        let args = (*transferred, self.inner.total.load(Ordering::SeqCst));
+        let inner = Arc::clone(&self.inner);
+        let result = tokio::task::spawn_blocking(move || {
+            Python::attach(|py| inner.callback.call1(py, args).map(drop))
+        })
+        .await
+        .map_err(|e| StorageError::ObjectStoreError(format!("Progress callback task failed: {}", e)))?;
+
+        result.map_err(|err| {
+            let message = format!("Progress callback raised: {}", err);
+            self.inner.error.lock().unwrap().get_or_insert(err);
+            StorageError::ObjectStoreError(message)
+        })
+    }
+
+    /// Returns the exception the callback raised, if it aborted the transfer.
+    pub fn take_error(&self) -> Option<PyErr> {
+        self.inner.error.lock().unwrap().take()
+    }
+}
+
+/// Converts the error of a transfer to a Python exception, re-raising the callback's own
+/// exception if that is what aborted it.
+pub fn into_py_err(progress: Option<&Progress>, err: StorageError) -> PyErr {
+    progress.and_then(Progress::take_error).unwrap_or_else(|| err.into())
+}
+
+/// Wraps `upload` so `progress` advances as each part finishes uploading.
+pub fn upload(upload: Box<dyn MultipartUpload>, progress: Option<&Progress>) -> Box<dyn MultipartUpload> {
+    match progress {
+        Some(progress) => Box::new(ProgressUpload {
+            inner: upload,
+            progress: progress.clone(),
+        }),
+        None => upload,
+    }
+}
+
+#[derive(Debug)]
+struct ProgressUpload {
+    inner: Box<dyn MultipartUpload>,
+    progress: Progress,
+}
+
+#[async_trait]
+impl MultipartUpload for ProgressUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let len = data.content_length() as u64;
+        let part = self.inner.put_part(data);
+        let progress = self.progress.clone();
+        Box::pin(async move {
+            part.await?;
+            progress.advance(len).await.map_err(|e| object_store::Error::Generic {
+                store: "progress_callback",
+                source: Box::new(e),
+            })
+        })
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        self.inner.complete().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, Result, UploadPart,
+};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::StorageError;
+
+/// Rate limit groups shared by every client in the process, by name.
+static GROUPS: LazyLock<Mutex<HashMap<String, Arc<RateLimiter>>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the limiter for `name`, creating an unlimited one on first use.
+pub fn group(name: &str) -> Arc<RateLimiter> {
+    let mut groups = GROUPS.lock().unwrap();
+    Arc::clone(groups.entry(name.to_string()).or_default())
+}
+
+/// Token bucket that allows bursts of up to one second's worth of its rate.
+///
+/// Callers reserve tokens up front and sleep off any deficit, so a request larger than the burst
+/// is admitted and delays the requests after it instead of waiting forever.
+#[derive(Debug, Default)]
+struct TokenBucket {
+    rate: Option<f64>,
+    tokens: f64,
+    updated: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn set_rate(&mut self, rate: Option<f64>) {
+        self.tokens = match (self.rate, rate) {
+            (Some(_), Some(rate)) => self.tokens.min(rate),
+            (_, rate) => rate.unwrap_or(0.0),
+        };
+        self.rate = rate;
+    }
+
+    fn reserve(&mut self, amount: f64, now: Instant) -> Duration {
+        let Some(rate) = self.rate else {
+            return Duration::ZERO;
+        };
+        if let Some(updated) = self.updated {
+            self.tokens = (self.tokens + rate * now.duration_since(updated).as_secs_f64()).min(rate);
+        }
+        self.updated = Some(now);
+        self.tokens -= amount;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / rate)
+        }
+    }
+}
+
+/// Byte and request rate limits shared by all clients in one `rate_limit_group`.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: Mutex<(TokenBucket, TokenBucket)>,
+    throttled_nanos: AtomicU64,
+}
+
+impl RateLimiter {
+    pub fn set_limits(&self, bytes_per_sec: Option<f64>, requests_per_sec: Option<f64>) {
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.0.set_rate(bytes_per_sec);
+        buckets.1.set_rate(requests_per_sec);
+    }
+
+    /// Waits until `requests` requests transferring `bytes` bytes fit within the group's limits.
+    pub async fn acquire(&self, bytes: u64, requests: u32) {
+        let wait = {
+            let now = Instant::now();
+            let mut buckets = self.buckets.lock().unwrap();
+            let bytes_wait = buckets.0.reserve(bytes as f64, now);
+            bytes_wait.max(buckets.1.reserve(requests as f64, now))
+        };
+        if !wait.is_zero() {
+            self.throttled_nanos.fetch_add(wait.as_nanos() as u64, Ordering::Relaxed);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let (bytes_per_sec, requests_per_sec) = {
+            let buckets = self.buckets.lock().unwrap();
+            (buckets.0.rate, buckets.1.rate)
+        };
+        let stats = PyDict::new(py);
+        stats.set_item("bytes_per_sec", bytes_per_sec)?;
+        stats.set_item("requests_per_sec", requests_per_sec)?;
+        stats.set_item(
+            "throttled_secs",
+            Duration::from_nanos(self.throttled_nanos.load(Ordering::Relaxed)).as_secs_f64(),
+        )?;
+        Ok(stats)
+    }
+}
+
+fn parse_rate(name: &str, rate: Option<f64>) -> Result<Option<f64>, StorageError> {
+    match rate {
+        Some(rate) if !rate.is_finite() || rate <= 0.0 => Err(StorageError::ConfigError(format!(
+            "{} must be a positive number or None, got {}",
+            name, rate
+        ))),
+        rate => Ok(rate),
+    }
+}
+
+/// Sets the limits of a process-wide rate limit group. `None` removes a limit.
+///
+/// The new limits apply immediately to every client configured with `rate_limit_group=group`.
+#[pyfunction]
+#[pyo3(signature = (group, bytes_per_sec=None, requests_per_sec=None))]
+pub fn set_rate_limit(group: &str, bytes_per_sec: Option<f64>, requests_per_sec: Option<f64>) -> PyResult<()> {
+    let bytes_per_sec = parse_rate("bytes_per_sec", bytes_per_sec)?;
+    let requests_per_sec = parse_rate("requests_per_sec", requests_per_sec)?;
+    self::group(group).set_limits(bytes_per_sec, requests_per_sec);
+    Ok(())
+}
+
+/// Returns each rate limit group's limits and the total time its requests have spent throttled.
+#[pyfunction]
+pub fn rate_limit_stats(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let groups: Vec<(String, Arc<RateLimiter>)> = GROUPS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(name, limiter)| (name.clone(), Arc::clone(limiter)))
+        .collect();
+    let stats = PyDict::new(py);
+    for (name, limiter) in groups {
+        stats.set_item(name, limiter.stats(py)?)?;
+    }
+    Ok(stats)
+}
+
+/// Store wrapper that charges every request, and the bytes it transfers, to a rate limit group.
+///
+/// Multipart parts and ranged gets are separate requests, so transfers are throttled per chunk.
+/// Get responses are charged once their size is known, before the body is read.
+#[derive(Debug)]
+pub struct RateLimitedStore {
+    inner: Arc<dyn ObjectStore>,
+    group: String,
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitedStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, group: &str) -> Self {
+        RateLimitedStore {
+            inner,
+            group: group.to_string(),
+            limiter: self::group(group),
+        }
+    }
+}
+
+impl fmt::Display for RateLimitedStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RateLimitedStore({}, {})", self.group, self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for RateLimitedStore {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        self.limiter.acquire(payload.content_length() as u64, 1).await;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        self.limiter.acquire(0, 1).await;
+        let upload = self.inner.put_multipart_opts(location, opts).await?;
+        Ok(Box::new(RateLimitedUpload {
+            inner: upload,
+            limiter: Arc::clone(&self.limiter),
+        }))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        self.limiter.acquire(0, 1).await;
+        let head = options.head;
+        let result = self.inner.get_opts(location, options).await?;
+        if !head {
+            self.limiter.acquire(result.range.end - result.range.start, 0).await;
+        }
+        Ok(result)
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.rename(from, to).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.rename_if_not_exists(from, to).await
+    }
+}
+
+#[derive(Debug)]
+struct RateLimitedUpload {
+    inner: Box<dyn MultipartUpload>,
+    limiter: Arc<RateLimiter>,
+}
+
+#[async_trait]
+impl MultipartUpload for RateLimitedUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        let limiter = Arc::clone(&self.limiter);
+        let bytes = data.content_length() as u64;
+        let part = self.inner.put_part(data);
+        Box::pin(async move {
+            limiter.acquire(bytes, 1).await;
+            part.await
+        })
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        self.limiter.acquire(0, 1).await;
+        self.inner.complete().await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        self.inner.abort().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use object_store::memory::InMemory;
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::default();
+        assert_eq!(bucket.reserve(1e9, start), Duration::ZERO);
+
+        bucket.set_rate(Some(100.0));
+        // The first second's worth is available as a burst; the rest is owed.
+        assert_eq!(bucket.reserve(100.0, start), Duration::ZERO);
+        assert_eq!(bucket.reserve(50.0, start), Duration::from_millis(500));
+        // Half a second later the deficit is repaid.
+        assert_eq!(bucket.reserve(0.0, start + Duration::from_millis(500)), Duration::ZERO);
+
+        bucket.set_rate(None);
+        assert_eq!(bucket.reserve(1e9, start), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_shared_group() {
+        let group_name = "test_shared_group";
+        let first: Arc<dyn ObjectStore> = Arc::new(RateLimitedStore::new(Arc::new(InMemory::new()), group_name));
+        let second: Arc<dyn ObjectStore> = Arc::new(RateLimitedStore::new(Arc::new(InMemory::new()), group_name));
+        set_rate_limit(group_name, Some(1000.0), None).unwrap();
+
+        // 3000 bytes across two clients in one group: 1000 bytes of burst, then two seconds owed.
+        let started = Instant::now();
+        let payload = Bytes::from(vec![0u8; 1000]);
+        for store in [&first, &second, &first] {
+            store.put(&Path::from("object"), payload.clone().into()).await.unwrap();
+        }
+        assert!(started.elapsed() >= Duration::from_millis(1900));
+        assert!(group(group_name).throttled_nanos.load(Ordering::Relaxed) >= 1_900_000_000);
+
+        assert!(set_rate_limit(group_name, Some(0.0), None).is_err());
+        set_rate_limit(group_name, None, None).unwrap();
+    }
+}
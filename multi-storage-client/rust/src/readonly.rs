@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, Result,
+};
+use std::fmt;
+use std::sync::Arc;
+
+/// Store wrapper that rejects every write before it is sent, for providers and clients that are
+/// read-only.
+#[derive(Debug)]
+pub struct ReadOnlyStore {
+    inner: Arc<dyn ObjectStore>,
+    /// Why writes are rejected, the start of their error message.
+    reason: String,
+}
+
+impl ReadOnlyStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, provider: &'static str) -> Self {
+        ReadOnlyStore {
+            inner,
+            reason: format!("The '{}' provider is read-only", provider),
+        }
+    }
+
+    /// Wraps the store of an `anonymous` client, whose unsigned writes would be denied anyway.
+    pub fn anonymous(inner: Arc<dyn ObjectStore>) -> Self {
+        ReadOnlyStore {
+            inner,
+            reason: "Anonymous clients are read-only, configure credentials instead of anonymous=True to write"
+                .to_string(),
+        }
+    }
+
+    fn rejected(&self, operation: &str, location: &Path) -> object_store::Error {
+        object_store::Error::PermissionDenied {
+            path: location.to_string(),
+            source: format!("{}; {} is not supported", self.reason, operation).into(),
+        }
+    }
+}
+
+impl fmt::Display for ReadOnlyStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReadOnlyStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for ReadOnlyStore {
+    async fn put_opts(&self, location: &Path, _payload: PutPayload, _opts: PutOptions) -> Result<PutResult> {
+        Err(self.rejected("put", location))
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, _opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        Err(self.rejected("put", location))
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.inner.head(location).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        Err(self.rejected("delete", location))
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, _from: &Path, to: &Path) -> Result<()> {
+        Err(self.rejected("copy", to))
+    }
+
+    async fn copy_if_not_exists(&self, _from: &Path, to: &Path) -> Result<()> {
+        Err(self.rejected("copy", to))
+    }
+
+    async fn rename(&self, _from: &Path, to: &Path) -> Result<()> {
+        Err(self.rejected("rename", to))
+    }
+
+    async fn rename_if_not_exists(&self, _from: &Path, to: &Path) -> Result<()> {
+        Err(self.rejected("rename", to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    #[tokio::test]
+    async fn test_read_only_store_rejects_writes() {
+        let inner: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("object");
+        inner.put(&path, PutPayload::from_static(b"data")).await.unwrap();
+        let store = ReadOnlyStore::new(inner, "http");
+
+        assert_eq!(store.get(&path).await.unwrap().bytes().await.unwrap().as_ref(), b"data");
+        match store.put(&path, PutPayload::from_static(b"other")).await {
+            Err(object_store::Error::PermissionDenied { source, .. }) => {
+                assert!(source.to_string().contains("'http' provider is read-only"))
+            }
+            other => panic!("Expected PermissionDenied, got {:?}", other),
+        }
+        assert!(store.put_multipart(&path).await.is_err());
+        assert!(store.delete(&path).await.is_err());
+        assert_eq!(store.head(&path).await.unwrap().size, 4);
+
+        let store = ReadOnlyStore::anonymous(store.inner);
+        match store.copy(&path, &Path::from("copy")).await {
+            Err(object_store::Error::PermissionDenied { source, .. }) => {
+                assert!(source.to_string().contains("anonymous=True"))
+            }
+            other => panic!("Expected PermissionDenied, got {:?}", other),
+        }
+    }
+}
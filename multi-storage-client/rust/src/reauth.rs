@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts, PutOptions,
+    PutPayload, PutResult, Result,
+};
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::credentials::ForceRefresh;
+use crate::logging;
+
+/// Error codes of S3 requests signed with a token that expired or was revoked, which come back as
+/// 400 rather than 403.
+const TOKEN_ERROR_CODES: [&str; 3] = ["ExpiredToken", "InvalidToken", "TokenRefreshRequired"];
+
+fn is_denied<T>(result: &Result<T>) -> bool {
+    match result {
+        Err(object_store::Error::PermissionDenied { .. } | object_store::Error::Unauthenticated { .. }) => true,
+        Err(err @ object_store::Error::Generic { .. }) => {
+            let chain = logging::chain(err);
+            TOKEN_ERROR_CODES.iter().any(|code| chain.contains(code))
+        }
+        _ => false,
+    }
+}
+
+/// Store wrapper that retries a request denied for its credentials once, after forcing their
+/// refresh. Expiry-based refreshes never replace a token revoked early.
+#[derive(Debug)]
+pub struct ReauthStore {
+    inner: Arc<dyn ObjectStore>,
+    credentials: Arc<dyn ForceRefresh>,
+}
+
+impl ReauthStore {
+    pub fn new(inner: Arc<dyn ObjectStore>, credentials: Arc<dyn ForceRefresh>) -> Self {
+        ReauthStore { inner, credentials }
+    }
+
+    async fn call<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let generation = self.credentials.generation();
+        let result = op().await;
+        if is_denied(&result) && self.credentials.force_refresh(generation).await {
+            return op().await;
+        }
+        result
+    }
+
+    /// Retries listings denied on their first page.
+    fn stream<F>(&self, list: F) -> BoxStream<'static, Result<ObjectMeta>>
+    where
+        F: Fn(&Arc<dyn ObjectStore>) -> BoxStream<'static, Result<ObjectMeta>> + Send + 'static,
+    {
+        let (inner, credentials) = (Arc::clone(&self.inner), Arc::clone(&self.credentials));
+        stream::once(async move {
+            let generation = credentials.generation();
+            let mut listing = list(&inner);
+            let first = listing.next().await;
+            if matches!(&first, Some(first) if is_denied(first)) && credentials.force_refresh(generation).await {
+                return list(&inner);
+            }
+            stream::iter(first).chain(listing).boxed()
+        })
+        .flatten()
+        .boxed()
+    }
+}
+
+impl fmt::Display for ReauthStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReauthStore({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl ObjectStore for ReauthStore {
+    async fn put_opts(&self, location: &Path, payload: PutPayload, opts: PutOptions) -> Result<PutResult> {
+        self.call(|| self.inner.put_opts(location, payload.clone(), opts.clone()))
+            .await
+    }
+
+    async fn put_multipart_opts(&self, location: &Path, opts: PutMultipartOpts) -> Result<Box<dyn MultipartUpload>> {
+        self.call(|| self.inner.put_multipart_opts(location, opts.clone()))
+            .await
+    }
+
+    async fn get_opts(&self, location: &Path, options: GetOptions) -> Result<GetResult> {
+        self.call(|| self.inner.get_opts(location, options.clone())).await
+    }
+
+    async fn head(&self, location: &Path) -> Result<ObjectMeta> {
+        self.call(|| self.inner.head(location)).await
+    }
+
+    async fn delete(&self, location: &Path) -> Result<()> {
+        self.call(|| self.inner.delete(location)).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, Result<ObjectMeta>> {
+        let prefix = prefix.cloned();
+        self.stream(move |inner| inner.list(prefix.as_ref()))
+    }
+
+    fn list_with_offset(&self, prefix: Option<&Path>, offset: &Path) -> BoxStream<'static, Result<ObjectMeta>> {
+        let (prefix, offset) = (prefix.cloned(), offset.clone());
+        self.stream(move |inner| inner.list_with_offset(prefix.as_ref(), &offset))
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        self.call(|| self.inner.list_with_delimiter(prefix)).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(|| self.inner.copy(from, to)).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(|| self.inner.copy_if_not_exists(from, to)).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(|| self.inner.rename(from, to)).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        self.call(|| self.inner.rename_if_not_exists(from, to)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Credentials that may be refreshed `refreshes` times.
+    #[derive(Debug)]
+    struct MockCredentials {
+        generation: AtomicU64,
+        refreshes: u64,
+    }
+
+    #[async_trait]
+    impl ForceRefresh for MockCredentials {
+        fn generation(&self) -> u64 {
+            self.generation.load(Ordering::SeqCst)
+        }
+
+        async fn force_refresh(&self, generation: u64) -> bool {
+            if self.generation() != generation {
+                return true;
+            }
+            self.generation.fetch_add(1, Ordering::SeqCst) < self.refreshes
+        }
+    }
+
+    fn reauth_store(refreshes: u64) -> ReauthStore {
+        let credentials = MockCredentials {
+            generation: AtomicU64::new(0),
+            refreshes,
+        };
+        ReauthStore::new(Arc::new(InMemory::new()), Arc::new(credentials))
+    }
+
+    fn denied() -> Result<()> {
+        Err(object_store::Error::PermissionDenied {
+            path: "key".to_string(),
+            source: "AccessDenied".into(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_denied_requests_are_retried_after_refresh() {
+        // The first credentials are denied, the refreshed ones accepted.
+        let store = reauth_store(1);
+        let attempts = AtomicU64::new(0);
+        let op = || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => denied(),
+                _ => Ok(()),
+            }
+        };
+        assert!(store.call(op).await.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // Without a refresh, the error is returned without a retry.
+        let store = reauth_store(0);
+        let attempts = AtomicU64::new(0);
+        let op = || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            denied()
+        };
+        assert!(matches!(
+            store.call(op).await,
+            Err(object_store::Error::PermissionDenied { .. })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        let expired = object_store::Error::Generic {
+            store: "S3",
+            source: "Client error with status 400 Bad Request: <Code>ExpiredToken</Code>".into(),
+        };
+        assert!(is_denied::<()>(&Err(expired)));
+        let not_found = object_store::Error::NotFound {
+            path: "key".to_string(),
+            source: "missing".into(),
+        };
+        assert!(!is_denied::<()>(&Err(not_found)));
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use http::header::{HeaderName, HeaderValue, CONTENT_LENGTH, RANGE};
+use object_store::client::{
+    HttpClient, HttpConnector, HttpError, HttpRequest, HttpResponse, HttpService, ReqwestConnector,
+};
+use object_store::{ClientOptions, MultipartUpload, PutPayload, PutResult, Result, UploadPart};
+use std::future::Future;
+use std::time::Instant;
+use tracing::Level;
+
+use crate::checksum;
+use crate::failed_response;
+use crate::integrity;
+use crate::logging;
+use crate::server_time;
+use crate::storage_class;
+use crate::telemetry;
+use crate::StorageError;
+
+pub const DEFAULT_REQUEST_TAG_HEADER: &str = "x-msc-request-tag";
+
+tokio::task_local! {
+    static TAG: HeaderValue;
+}
+
+/// Returns the tag of the operation running on the current task, if any.
+pub fn current() -> Option<HeaderValue> {
+    TAG.try_with(HeaderValue::clone).ok()
+}
+
+pub fn parse_tag(tag: &str) -> Result<HeaderValue, StorageError> {
+    HeaderValue::from_str(tag)
+        .map_err(|_| StorageError::ConfigError(format!("request_tag '{}' is not a valid header value", tag)))
+}
+
+pub fn parse_header(header: &str) -> Result<HeaderName, StorageError> {
+    HeaderName::from_bytes(header.as_bytes())
+        .map_err(|_| StorageError::ConfigError(format!("request_tag_header '{}' is not a valid header name", header)))
+}
+
+/// Runs `fut` with every request it sends stamped with `tag`.
+pub async fn scoped<F: Future>(tag: Option<HeaderValue>, fut: F) -> F::Output {
+    match tag {
+        Some(tag) => TAG.scope(tag, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Carries the current tag and trace into `fut`, for futures that will be spawned onto other tasks.
+pub fn propagate<F: Future>(fut: F) -> impl Future<Output = F::Output> {
+    failed_response::propagate(telemetry::scoped(telemetry::current(), scoped(current(), fut)))
+}
+
+/// Carries the current tag and trace into the part, complete and abort requests of `upload`,
+/// which `WriteMultipart` sends from its own tasks.
+pub fn upload(upload: Box<dyn MultipartUpload>) -> Box<dyn MultipartUpload> {
+    let (tag, trace) = (current(), telemetry::current());
+    if tag.is_none() && trace.is_none() {
+        return upload;
+    }
+    Box::new(TaggedUpload {
+        inner: upload,
+        tag,
+        trace,
+    })
+}
+
+#[derive(Debug)]
+struct TaggedUpload {
+    inner: Box<dyn MultipartUpload>,
+    tag: Option<HeaderValue>,
+    trace: Option<telemetry::Context>,
+}
+
+#[async_trait]
+impl MultipartUpload for TaggedUpload {
+    fn put_part(&mut self, data: PutPayload) -> UploadPart {
+        Box::pin(telemetry::scoped(
+            self.trace.clone(),
+            scoped(self.tag.clone(), telemetry::chunk(self.inner.put_part(data))),
+        ))
+    }
+
+    async fn complete(&mut self) -> Result<PutResult> {
+        telemetry::scoped(self.trace.clone(), scoped(self.tag.clone(), self.inner.complete())).await
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        telemetry::scoped(self.trace.clone(), scoped(self.tag.clone(), self.inner.abort())).await
+    }
+}
+
+/// HTTP connector that adds the current operation's tag as a header to each request, retries
+/// included, and records the server time and any error status of each response, and the request
+/// in the client's metrics, on the operation's span and in the log.
+#[derive(Debug)]
+pub struct TaggingConnector {
+    header: HeaderName,
+    inner: ReqwestConnector,
+}
+
+impl TaggingConnector {
+    pub fn new(header: HeaderName) -> Self {
+        TaggingConnector {
+            header,
+            inner: ReqwestConnector::default(),
+        }
+    }
+}
+
+impl HttpConnector for TaggingConnector {
+    fn connect(&self, options: &ClientOptions) -> Result<HttpClient> {
+        let inner = self.inner.connect(options)?;
+        Ok(HttpClient::new(TaggingService {
+            header: self.header.clone(),
+            inner,
+        }))
+    }
+}
+
+#[derive(Debug)]
+struct TaggingService {
+    header: HeaderName,
+    inner: HttpClient,
+}
+
+#[async_trait]
+impl HttpService for TaggingService {
+    async fn call(&self, mut request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        if let Some(tag) = current() {
+            request.headers_mut().insert(self.header.clone(), tag);
+        }
+        let method = request.method().clone();
+        let sent = request.body().content_length() as u64;
+        // Only the path is logged, as the query of a presigned URL holds its signature.
+        let uri = request.uri().clone();
+        let range = logging::enabled(Level::DEBUG)
+            .then(|| request.headers().get(RANGE)?.to_str().ok().map(str::to_string))
+            .flatten();
+        let retry_count = failed_response::failures();
+        let started = Instant::now();
+        let response = self.inner.execute(request).await.inspect_err(|err| {
+            failed_response::record_unanswered();
+            telemetry::record_request(&method, None, sent, 0);
+            tracing::warn!(
+                target: logging::TARGET,
+                method = %method,
+                path = uri.path(),
+                duration_ms = started.elapsed().as_secs_f64() * 1000.0,
+                retry_count,
+                error = %logging::chain(err),
+                "Request failed",
+            );
+        })?;
+        server_time::record(response.headers());
+        storage_class::record(response.headers());
+        checksum::record(response.headers());
+        integrity::record(response.headers());
+        failed_response::record(response.status(), response.headers());
+        let received = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|length| length.to_str().ok()?.parse::<u64>().ok());
+        telemetry::record_request(&method, Some(response.status()), sent, received.unwrap_or(0));
+        if logging::enabled(Level::DEBUG) {
+            tracing::debug!(
+                target: logging::TARGET,
+                method = %method,
+                path = uri.path(),
+                range = range.as_deref(),
+                status = response.status().as_u16(),
+                duration_ms = started.elapsed().as_secs_f64() * 1000.0,
+                retry_count,
+                "Request",
+            );
+        }
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tag_follows_spawned_work() {
+        assert!(current().is_none());
+        let tag = parse_tag("job-1234").unwrap();
+        let seen = scoped(Some(tag.clone()), async {
+            let spawned = tokio::spawn(propagate(async { current() }));
+            let unpropagated = tokio::spawn(async { current() });
+            (spawned.await.unwrap(), unpropagated.await.unwrap())
+        })
+        .await;
+        assert_eq!(seen, (Some(tag), None));
+
+        assert!(parse_tag("bad\ntag").is_err());
+        assert!(parse_header("x-job id").is_err());
+        assert!(parse_header(DEFAULT_REQUEST_TAG_HEADER).is_ok());
+    }
+}
@@ -0,0 +1,300 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use object_store::multipart::{MultipartStore, PartId};
+use object_store::path::Path;
+use object_store::{PutPayload, PutResult};
+use pyo3::exceptions::PyRuntimeWarning;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ffi::CString;
+use std::path::{Path as StdPath, PathBuf};
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::sync::{Mutex, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::progress::Progress;
+use crate::request_tag;
+use crate::telemetry;
+use crate::StorageError;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct CompletedPart {
+    size: u64,
+    /// Identifier the store returned for the part, needed to complete the upload.
+    content_id: String,
+}
+
+/// Progress of a multipart upload, saved after every part so a later call can continue it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct ResumeState {
+    remote_path: String,
+    upload_id: String,
+    file_size: u64,
+    /// Modification time of the local file in nanoseconds since the epoch.
+    file_mtime_ns: u64,
+    chunksize: u64,
+    /// Completed parts by zero-based index.
+    parts: BTreeMap<usize, CompletedPart>,
+}
+
+impl ResumeState {
+    /// Whether this state was saved by an upload of the same, unchanged file to `remote_path`.
+    fn continues(&self, remote_path: &Path, file_size: u64, file_mtime_ns: u64, chunksize: u64) -> bool {
+        self.remote_path == remote_path.as_ref()
+            && self.file_size == file_size
+            && self.file_mtime_ns == file_mtime_ns
+            && self.chunksize == chunksize
+    }
+
+    /// Writes the state to a temporary file next to `path` and renames it into place, so a crash
+    /// leaves either the previous or the new state.
+    async fn save(&self, path: &StdPath) -> Result<(), StorageError> {
+        let json = serde_json::to_vec(self)
+            .map_err(|e| StorageError::ObjectStoreError(format!("Failed to encode upload state: {}", e)))?;
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        tokio::fs::write(&temp_path, json).await?;
+        tokio::fs::rename(&temp_path, path).await?;
+        Ok(())
+    }
+}
+
+/// Loads the state at `path`. A missing file gives `Ok(None)`; an unreadable or corrupted one an
+/// error describing why it can't be used.
+async fn load_state(path: &StdPath) -> Result<Option<ResumeState>, String> {
+    match tokio::fs::read(path).await {
+        Ok(json) => serde_json::from_slice(&json).map(Some).map_err(|e| format!("corrupted state file: {}", e)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("unreadable state file: {}", err)),
+    }
+}
+
+/// Emits a `RuntimeWarning` that the state at `path` is discarded and the upload starts over.
+fn warn_restart(path: &StdPath, reason: &str) {
+    let message = format!("resume_state_path: {} ({}); starting a new upload", path.display(), reason);
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    Python::attach(|py| {
+        if let Err(err) = PyErr::warn(py, py.get_type::<PyRuntimeWarning>().as_any(), &message, 1) {
+            err.write_unraisable(py, None);
+        }
+    });
+}
+
+async fn read_part(local_path: &StdPath, offset: u64, size: u64) -> Result<PutPayload, StorageError> {
+    let mut file = tokio::fs::File::open(local_path).await?;
+    file.seek(std::io::SeekFrom::Start(offset)).await?;
+    let mut data = vec![0; size as usize];
+    file.read_exact(&mut data).await?;
+    Ok(data.into())
+}
+
+/// Uploads the file at `local_path` in parts of `chunksize` bytes, recording each completed part
+/// in the JSON file at `state_path`.
+///
+/// If `state_path` holds the state of an upload of the same file, by size and modification time,
+/// to `remote_path` with the same `chunksize`, its completed parts are skipped and the upload
+/// continues. A corrupted or mismatched state file is discarded with a warning. The state file is
+/// kept when the upload fails and removed once it completes. Parts completed by an earlier call
+/// count toward `progress`, which must already be started.
+#[allow(clippy::too_many_arguments)]
+pub async fn upload_file(
+    store: Arc<dyn MultipartStore>,
+    local_path: PathBuf,
+    remote_path: Path,
+    chunksize: usize,
+    concurrency: usize,
+    state_path: PathBuf,
+    progress: Option<&Progress>,
+) -> Result<(PutResult, u64), StorageError> {
+    let metadata = tokio::fs::metadata(&local_path).await?;
+    let file_size = metadata.len();
+    let file_mtime_ns = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |mtime| mtime.as_nanos() as u64);
+    let chunksize = chunksize as u64;
+
+    let state = match load_state(&state_path).await {
+        Ok(Some(state)) if state.continues(&remote_path, file_size, file_mtime_ns, chunksize) => Some(state),
+        Ok(Some(state)) => {
+            warn_restart(&state_path, "the local file, remote path or chunk size changed");
+            if state.remote_path == remote_path.as_ref() {
+                let _ = store.abort_multipart(&remote_path, &state.upload_id).await;
+            }
+            None
+        }
+        Ok(None) => None,
+        Err(reason) => {
+            warn_restart(&state_path, &reason);
+            None
+        }
+    };
+    let state = match state {
+        Some(state) => state,
+        None => {
+            let upload_id = store.create_multipart(&remote_path).await?;
+            let state = ResumeState {
+                remote_path: remote_path.to_string(),
+                upload_id,
+                file_size,
+                file_mtime_ns,
+                chunksize,
+                parts: BTreeMap::new(),
+            };
+            state.save(&state_path).await?;
+            state
+        }
+    };
+
+    let num_parts = file_size.div_ceil(chunksize).max(1) as usize;
+    let completed: u64 = state.parts.values().map(|part| part.size).sum();
+    if let Some(progress) = progress.filter(|_| completed > 0) {
+        progress.advance(completed).await?;
+    }
+
+    let upload_id = state.upload_id.clone();
+    let pending: Vec<usize> = (0..num_parts).filter(|index| !state.parts.contains_key(index)).collect();
+    let state = Arc::new(Mutex::new(state));
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut parts = JoinSet::new();
+    for index in pending {
+        let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+        let store = Arc::clone(&store);
+        let local_path = local_path.clone();
+        let remote_path = remote_path.clone();
+        let upload_id = upload_id.clone();
+        let state = Arc::clone(&state);
+        let state_path = state_path.clone();
+        let progress = progress.cloned();
+
+        parts.spawn(request_tag::propagate(async move {
+            let offset = index as u64 * chunksize;
+            let size = chunksize.min(file_size - offset);
+            let payload = read_part(&local_path, offset, size).await?;
+            let part = telemetry::chunk(store.put_part(&remote_path, &upload_id, index, payload)).await?;
+            drop(permit);
+
+            let mut state = state.lock().await;
+            let completed = CompletedPart {
+                size,
+                content_id: part.content_id,
+            };
+            state.parts.insert(index, completed);
+            state.save(&state_path).await?;
+            drop(state);
+            if let Some(progress) = progress {
+                progress.advance(size).await?;
+            }
+            Ok::<(), StorageError>(())
+        }));
+        while let Some(joined) = parts.try_join_next() {
+            joined.map_err(|e| StorageError::ObjectStoreError(format!("Upload part task failed: {}", e)))??;
+        }
+    }
+    while let Some(joined) = parts.join_next().await {
+        joined.map_err(|e| StorageError::ObjectStoreError(format!("Upload part task failed: {}", e)))??;
+    }
+
+    let state = state.lock().await;
+    let part_ids = state
+        .parts
+        .values()
+        .map(|part| PartId {
+            content_id: part.content_id.clone(),
+        })
+        .collect();
+    let put_result = store.complete_multipart(&remote_path, &upload_id, part_ids).await?;
+    tokio::fs::remove_file(&state_path).await?;
+    Ok((put_result, file_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+    use object_store::ObjectStore;
+
+    #[tokio::test]
+    async fn test_upload_file_resumes() {
+        let dir = tempfile::tempdir().unwrap();
+        let local_path = dir.path().join("source.bin");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&local_path, &data).unwrap();
+        let state_path = dir.path().join("source.bin.upload");
+        let remote_path = Path::from("object");
+        let store = Arc::new(InMemory::new());
+
+        // A previous attempt that uploaded the first two parts before dying.
+        let metadata = std::fs::metadata(&local_path).unwrap();
+        let mtime = metadata.modified().unwrap().duration_since(UNIX_EPOCH).unwrap();
+        let upload_id = store.create_multipart(&remote_path).await.unwrap();
+        let mut state = ResumeState {
+            remote_path: remote_path.to_string(),
+            upload_id: upload_id.clone(),
+            file_size: data.len() as u64,
+            file_mtime_ns: mtime.as_nanos() as u64,
+            chunksize: 4000,
+            parts: BTreeMap::new(),
+        };
+        for index in 0..2 {
+            let chunk = data[index * 4000..(index + 1) * 4000].to_vec();
+            let part = store.put_part(&remote_path, &upload_id, index, chunk.into()).await.unwrap();
+            let completed = CompletedPart {
+                size: 4000,
+                content_id: part.content_id,
+            };
+            state.parts.insert(index, completed);
+        }
+        state.save(&state_path).await.unwrap();
+
+        let multipart: Arc<dyn MultipartStore> = store.clone();
+        let (_, size) = upload_file(multipart, local_path, remote_path.clone(), 4000, 2, state_path.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(size, data.len() as u64);
+        let uploaded = store.get(&remote_path).await.unwrap().bytes().await.unwrap();
+        assert_eq!(uploaded.as_ref(), data.as_slice());
+        assert!(!state_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_load_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        assert_eq!(load_state(&path).await, Ok(None));
+
+        std::fs::write(&path, b"{\"upload_id\": ").unwrap();
+        assert!(load_state(&path).await.unwrap_err().contains("corrupted"));
+
+        let state = ResumeState {
+            remote_path: "object".to_string(),
+            upload_id: "1".to_string(),
+            file_size: 10,
+            file_mtime_ns: 5,
+            chunksize: 4,
+            parts: BTreeMap::from([(1, CompletedPart { size: 4, content_id: "b".to_string() })]),
+        };
+        state.save(&path).await.unwrap();
+        let loaded = load_state(&path).await.unwrap().unwrap();
+        assert_eq!(loaded, state);
+        assert!(loaded.continues(&Path::from("object"), 10, 5, 4));
+        assert!(!loaded.continues(&Path::from("object"), 10, 6, 4));
+        assert!(!loaded.continues(&Path::from("other"), 10, 5, 4));
+    }
+}
@@ -0,0 +1,302 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::Regex;
+use std::future::Future;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use crate::{ErrorDetails, StorageError};
+
+static INNER_RETRIES_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"after (\d+) retries").unwrap());
+
+/// Policy for the operation-level retry loop.
+///
+/// This sits on top of object_store's own per-request retries (configured by `RustRetryConfig`)
+/// and re-runs a whole operation when it still fails with a transient error. The client builds it
+/// from `retry_operation_attempts`, a single attempt by default, leaving further retries to the
+/// Python layer as before.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub init_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            init_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(15),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the retry following the `attempt`-th (1-based) failed attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        self.init_backoff.mul_f64(factor).min(self.max_backoff)
+    }
+}
+
+/// Overall time budget for one operation, shared by all of its attempts and chunk tasks.
+#[derive(Clone, Copy, Debug)]
+pub struct Deadline {
+    expires_at: Instant,
+    budget: Duration,
+}
+
+impl Deadline {
+    pub fn after(budget: Duration) -> Self {
+        Deadline {
+            expires_at: Instant::now() + budget,
+            budget,
+        }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+
+    fn exceeded(&self, attempts: Option<u32>) -> StorageError {
+        let mut message = format!("Operation did not complete within its {:.3}s deadline", self.budget.as_secs_f64());
+        if let Some(attempts) = attempts {
+            message.push_str(&format!(" after {} attempts", attempts));
+        }
+        StorageError::DeadlineExceeded(message)
+    }
+}
+
+/// Runs `fut` to completion unless `deadline` expires first, in which case it is dropped and
+/// any chunk tasks it owns are cancelled with it.
+pub async fn with_deadline<T, Fut>(deadline: Option<Deadline>, fut: Fut) -> Result<T, StorageError>
+where
+    Fut: Future<Output = Result<T, StorageError>>,
+{
+    match deadline {
+        Some(deadline) => tokio::time::timeout(deadline.remaining(), fut)
+            .await
+            .unwrap_or_else(|_| Err(deadline.exceeded(None))),
+        None => fut.await,
+    }
+}
+
+/// Number of retries object_store reports having made inside a failed request.
+pub fn inner_retries(message: &str) -> u32 {
+    INNER_RETRIES_PATTERN
+        .captures(message)
+        .and_then(|captures| captures[1].parse().ok())
+        .unwrap_or(0)
+}
+
+pub fn is_transient(err: &StorageError) -> bool {
+    matches!(err.root(), StorageError::RetryExhaustedError(_) | StorageError::Throttled(..))
+}
+
+/// The wait a throttled store asked for before the next attempt.
+fn retry_after(err: &StorageError) -> Duration {
+    match err.root() {
+        StorageError::Throttled(_, Some(retry_after)) => *retry_after,
+        _ => Duration::ZERO,
+    }
+}
+
+/// Runs `op` under `policy`, retrying transient failures with exponential backoff, waiting at
+/// least as long as a throttled store asked for.
+///
+/// When a `deadline` is given, each attempt is cut off when the budget runs out and no retry is
+/// scheduled whose backoff would overrun it. The returned error carries the attempt count
+/// (including object_store's internal retries), the time spent backing off, and the first and
+/// last error messages.
+pub async fn run_with_retry<T, F, Fut>(
+    policy: &RetryPolicy,
+    deadline: Option<Deadline>,
+    mut op: F,
+) -> Result<T, StorageError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, StorageError>>,
+{
+    let started = Instant::now();
+    let mut details = ErrorDetails::default();
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+        let result = match deadline {
+            Some(deadline) => tokio::time::timeout(deadline.remaining(), op()).await.ok(),
+            None => Some(op().await),
+        };
+        let err = match result {
+            Some(Ok(value)) => return Ok(value),
+            Some(Err(err)) => err,
+            None => {
+                let deadline = deadline.expect("attempts only time out under a deadline");
+                details.attempts += 1;
+                details.last_error = Some("attempt cut off by the deadline".to_string());
+                details.elapsed = started.elapsed();
+                return Err(deadline.exceeded(Some(details.attempts)).annotate(details));
+            }
+        };
+
+        let message = err.to_string();
+        details.attempts += 1 + inner_retries(&message);
+        details.first_error.get_or_insert_with(|| message.clone());
+        details.last_error = Some(message);
+
+        if !is_transient(&err) || attempt >= policy.max_attempts {
+            details.elapsed = started.elapsed();
+            return Err(err.annotate(details));
+        }
+
+        let delay = policy.backoff(attempt).max(retry_after(&err));
+        if let Some(deadline) = deadline.filter(|deadline| delay >= deadline.remaining()) {
+            details.elapsed = started.elapsed();
+            return Err(deadline.exceeded(Some(details.attempts)).annotate(details));
+        }
+        tokio::time::sleep(delay).await;
+        details.retry_delay += delay;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use object_store::memory::InMemory;
+    use object_store::{path::Path, ObjectStore};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            init_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            multiplier: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_backoff_and_inner_retries() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(20), Duration::from_secs(15));
+
+        assert_eq!(inner_retries("Error performing GET in 1.2s, after 7 retries, max_retries: 10"), 7);
+        assert_eq!(inner_retries("connection reset"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_retry_flaky_store() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("flaky");
+        store.put(&path, Bytes::from_static(b"data").into()).await.unwrap();
+
+        // Each simulated outage reports two internal object_store retries before giving up.
+        let failures = Arc::new(AtomicU32::new(0));
+        let flaky_get = |failures_before_success: u32| {
+            let store = Arc::clone(&store);
+            let path = path.clone();
+            let failures = Arc::clone(&failures);
+            move || {
+                let store = Arc::clone(&store);
+                let path = path.clone();
+                let failure = failures.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if failure < failures_before_success {
+                        return Err(StorageError::RetryExhaustedError(format!(
+                            "outage {}: after 2 retries",
+                            failure
+                        )));
+                    }
+                    let result = store.get(&path).await?;
+                    Ok(result.bytes().await?)
+                }
+            }
+        };
+
+        let data = run_with_retry(&fast_policy(3), None, flaky_get(2)).await.unwrap();
+        assert_eq!(data.as_ref(), b"data");
+
+        failures.store(0, Ordering::SeqCst);
+        let err = run_with_retry(&fast_policy(3), None, flaky_get(u32::MAX)).await.unwrap_err();
+        let StorageError::Annotated(inner, details) = err else {
+            panic!("expected an annotated error");
+        };
+        assert!(matches!(*inner, StorageError::RetryExhaustedError(_)));
+        assert_eq!(details.attempts, 9);
+        assert_eq!(details.retry_delay, Duration::from_millis(3));
+        assert!(details.first_error.unwrap().contains("outage 0"));
+        assert!(details.last_error.unwrap().contains("outage 2"));
+
+        // Permanent errors are not retried.
+        let err = run_with_retry(&fast_policy(3), None, || async {
+            Err::<(), _>(StorageError::HttpError("not found".to_string(), Some(404)))
+        })
+        .await
+        .unwrap_err();
+        let StorageError::Annotated(_, details) = err else {
+            panic!("expected an annotated error");
+        };
+        assert_eq!(details.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_retry_deadline() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            init_backoff: Duration::from_millis(400),
+            max_backoff: Duration::from_millis(400),
+            multiplier: 1.0,
+        };
+        let transient = || async { Err::<(), _>(StorageError::RetryExhaustedError("connection reset".to_string())) };
+
+        // The third backoff would overrun the budget, so the loop gives up after three attempts.
+        let err = run_with_retry(&policy, Some(Deadline::after(Duration::from_secs(1))), transient)
+            .await
+            .unwrap_err();
+        let StorageError::Annotated(inner, details) = err else {
+            panic!("expected an annotated error");
+        };
+        assert!(matches!(*inner, StorageError::DeadlineExceeded(ref msg) if msg.contains("1.000s") && msg.contains("3 attempts")));
+        assert_eq!(details.attempts, 3);
+        assert!(details.last_error.unwrap().contains("connection reset"));
+
+        // A hung attempt is cut off when the budget runs out.
+        let started = Instant::now();
+        let err = run_with_retry(&policy, Some(Deadline::after(Duration::from_millis(50))), || async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(matches!(err.root(), StorageError::DeadlineExceeded(_)));
+
+        let err = with_deadline(Some(Deadline::after(Duration::from_millis(10))), async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, StorageError::DeadlineExceeded(_)));
+    }
+}
@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use std::sync::Mutex;
+use tokio::runtime::{Builder, Runtime};
+
+/// Environment variable with the number of worker threads of the shared runtime.
+const WORKER_THREADS_ENV: &str = "MSC_RUST_WORKER_THREADS";
+
+struct RuntimeState {
+    worker_threads: Option<usize>,
+    /// The shared runtime and the process that created it.
+    current: Option<(u32, &'static Runtime)>,
+}
+
+static STATE: Mutex<RuntimeState> = Mutex::new(RuntimeState {
+    worker_threads: None,
+    current: None,
+});
+
+fn worker_threads_from_env() -> Option<usize> {
+    std::env::var(WORKER_THREADS_ENV)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|threads| *threads > 0)
+}
+
+fn build(worker_threads: Option<usize>) -> Runtime {
+    let mut builder = Builder::new_multi_thread();
+    builder.enable_all().thread_name("msc-rust-worker");
+    if let Some(threads) = worker_threads.or_else(worker_threads_from_env) {
+        builder.worker_threads(threads);
+    }
+    builder.build().expect("Failed to build the Tokio runtime")
+}
+
+/// Returns the process-wide runtime every client runs its operations on, creating it on first use.
+///
+/// A forked child gets a runtime of its own: the one it inherited has no worker threads left in
+/// it, so it is leaked rather than dropped, which would wait for them.
+pub fn get_runtime() -> &'static Runtime {
+    let mut state = STATE.lock().unwrap();
+    let pid = std::process::id();
+    match state.current {
+        Some((owner, runtime)) if owner == pid => runtime,
+        _ => {
+            let runtime: &'static Runtime = Box::leak(Box::new(build(state.worker_threads)));
+            state.current = Some((pid, runtime));
+            runtime
+        }
+    }
+}
+
+/// Forgets the runtime inherited from the parent, so that a forked child starts its own before
+/// its first operation. Registered with `os.register_at_fork`.
+#[pyfunction]
+pub fn reset_after_fork() {
+    if let Ok(mut state) = STATE.lock() {
+        state.current = None;
+    }
+}
+
+/// Sets the number of worker threads of the shared runtime, overriding `MSC_RUST_WORKER_THREADS`.
+///
+/// Must be called before the first operation of the process, as the runtime isn't resized once
+/// it is running.
+#[pyfunction]
+#[pyo3(signature = (threads))]
+pub fn configure_runtime(threads: usize) -> PyResult<()> {
+    if threads == 0 {
+        return Err(PyValueError::new_err("threads must be at least 1"));
+    }
+    let mut state = STATE.lock().unwrap();
+    if matches!(state.current, Some((owner, _)) if owner == std::process::id()) {
+        return Err(PyRuntimeError::new_err(
+            "configure_runtime must be called before the first operation, the runtime is already running",
+        ));
+    }
+    state.worker_threads = Some(threads);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_runtime() {
+        let runtime = get_runtime();
+        assert!(std::ptr::eq(runtime, get_runtime()));
+        assert_eq!(runtime.block_on(async { 1 + 1 }), 2);
+        assert!(configure_runtime(0).is_err());
+        assert!(configure_runtime(2).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use object_store::{path::Path, ObjectStore};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::{parse_path, request_tag, StorageError};
+
+// Samples in the same shard separated by less than this many bytes are fetched with a single
+// ranged get, matching object_store's own coalescing threshold for get_ranges.
+pub const SAMPLE_COALESCE_GAP: u64 = 1024 * 1024;
+
+/// A single ranged get covering one or more requested samples of the same object.
+#[derive(Debug, PartialEq)]
+pub struct CoalescedRead {
+    pub key: String,
+    pub start: u64,
+    pub end: u64,
+    /// `(request index, offset, length)` of every sample served by this read.
+    pub members: Vec<(usize, u64, u64)>,
+}
+
+/// Groups sample requests by key and merges ranges that overlap or lie within `max_gap` bytes
+/// of each other, as long as the merged read stays within `max_read_size` bytes.
+///
+/// Zero-length requests need no fetch and are left out of the plan.
+pub fn plan_reads(requests: &[(String, u64, u64)], max_gap: u64, max_read_size: u64) -> Vec<CoalescedRead> {
+    let mut by_key: BTreeMap<&str, Vec<(usize, u64, u64)>> = BTreeMap::new();
+    for (index, (key, offset, length)) in requests.iter().enumerate() {
+        if *length == 0 {
+            continue;
+        }
+        by_key.entry(key.as_str()).or_default().push((index, *offset, *length));
+    }
+
+    let mut reads = Vec::new();
+    for (key, mut members) in by_key {
+        members.sort_by_key(|&(_, offset, length)| (offset, length));
+
+        let mut current: Option<CoalescedRead> = None;
+        for (index, offset, length) in members {
+            let end = offset.saturating_add(length);
+            if let Some(read) = current.as_mut() {
+                let merged_end = read.end.max(end);
+                if offset <= read.end.saturating_add(max_gap) && merged_end - read.start <= max_read_size {
+                    read.end = merged_end;
+                    read.members.push((index, offset, length));
+                    continue;
+                }
+                reads.extend(current.take());
+            }
+            current = Some(CoalescedRead {
+                key: key.to_string(),
+                start: offset,
+                end,
+                members: vec![(index, offset, length)],
+            });
+        }
+        reads.extend(current);
+    }
+
+    reads
+}
+
+/// Fetches every `(key, offset, length)` request and returns the results in request order.
+///
+/// Failures are reported per entry: a bad range only fails its own sample and a missing
+/// shard only fails the samples that point at it.
+pub async fn read_samples(
+    store: &Arc<dyn ObjectStore>,
+    requests: Vec<(String, u64, u64)>,
+    concurrency: usize,
+    max_read_size: u64,
+) -> Vec<Result<Bytes, String>> {
+    let mut results: Vec<Option<Result<Bytes, String>>> = requests
+        .iter()
+        .map(|(_, _, length)| if *length == 0 { Some(Ok(Bytes::new())) } else { None })
+        .collect();
+
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let mut join_set = JoinSet::new();
+    for read in plan_reads(&requests, SAMPLE_COALESCE_GAP, max_read_size) {
+        let store = Arc::clone(store);
+        let semaphore = Arc::clone(&semaphore);
+        join_set.spawn(request_tag::propagate(async move { fetch_coalesced(store, semaphore, read).await }));
+    }
+
+    while let Some(joined) = join_set.join_next().await {
+        // A panicked task leaves its entries unset; they are reported as failures below.
+        if let Ok(entries) = joined {
+            for (index, result) in entries {
+                results[index] = Some(result);
+            }
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err("Sample read task failed unexpectedly".to_string())))
+        .collect()
+}
+
+async fn fetch_coalesced(
+    store: Arc<dyn ObjectStore>,
+    semaphore: Arc<Semaphore>,
+    read: CoalescedRead,
+) -> Vec<(usize, Result<Bytes, String>)> {
+    let path = match parse_path(&read.key) {
+        Ok(path) => path,
+        Err(e) => {
+            let message = e.to_string();
+            return read.members.iter().map(|(index, _, _)| (*index, Err(message.clone()))).collect();
+        }
+    };
+
+    let fetched = fetch_range(&store, &semaphore, &path, read.start, read.end).await;
+    match fetched {
+        Ok(data) => read
+            .members
+            .iter()
+            .map(|&(index, offset, length)| (index, slice_sample(&data, read.start, offset, length)))
+            .collect(),
+        // One out-of-range sample fails the whole coalesced get, so retry members individually
+        // to pin the error on the sample that caused it. A missing shard fails them all anyway.
+        Err(StorageError::HttpError(msg, Some(404))) => read
+            .members
+            .iter()
+            .map(|(index, _, _)| (*index, Err(msg.clone())))
+            .collect(),
+        Err(e) if read.members.len() == 1 => vec![(read.members[0].0, Err(e.to_string()))],
+        Err(_) => {
+            let mut entries = Vec::with_capacity(read.members.len());
+            for &(index, offset, length) in &read.members {
+                let result = fetch_range(&store, &semaphore, &path, offset, offset + length)
+                    .await
+                    .map_err(|e| e.to_string())
+                    .and_then(|data| slice_sample(&data, offset, offset, length));
+                entries.push((index, result));
+            }
+            entries
+        }
+    }
+}
+
+async fn fetch_range(
+    store: &Arc<dyn ObjectStore>,
+    semaphore: &Semaphore,
+    path: &Path,
+    start: u64,
+    end: u64,
+) -> Result<Bytes, StorageError> {
+    let _permit = semaphore.acquire().await.unwrap();
+    store.get_range(path, start..end).await.map_err(StorageError::from)
+}
+
+/// Cuts one sample out of a read that started at `read_start`, rejecting short responses.
+fn slice_sample(data: &Bytes, read_start: u64, offset: u64, length: u64) -> Result<Bytes, String> {
+    let start = (offset - read_start) as usize;
+    let end = start + length as usize;
+    if end > data.len() {
+        return Err(format!(
+            "Requested range {}..{} exceeds the object size {}",
+            offset,
+            offset + length,
+            read_start + data.len() as u64
+        ));
+    }
+    Ok(data.slice(start..end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    fn request(key: &str, offset: u64, length: u64) -> (String, u64, u64) {
+        (key.to_string(), offset, length)
+    }
+
+    #[test]
+    fn test_plan_reads_coalesces_nearby_ranges() {
+        let requests = vec![
+            request("b.tar", 0, 10),
+            request("a.tar", 500, 100),
+            request("a.tar", 0, 100),
+            request("a.tar", 50, 20),
+            request("a.tar", 10_000, 100),
+            request("a.tar", 7, 0),
+        ];
+
+        let reads = plan_reads(&requests, 1000, 1 << 20);
+        assert_eq!(
+            reads,
+            vec![
+                CoalescedRead {
+                    key: "a.tar".to_string(),
+                    start: 0,
+                    end: 600,
+                    members: vec![(2, 0, 100), (3, 50, 20), (1, 500, 100)],
+                },
+                CoalescedRead {
+                    key: "a.tar".to_string(),
+                    start: 10_000,
+                    end: 10_100,
+                    members: vec![(4, 10_000, 100)],
+                },
+                CoalescedRead {
+                    key: "b.tar".to_string(),
+                    start: 0,
+                    end: 10,
+                    members: vec![(0, 0, 10)],
+                },
+            ]
+        );
+
+        // The read size cap splits ranges that would otherwise be merged.
+        let reads = plan_reads(&requests[1..4], 1000, 200);
+        assert_eq!(reads.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_read_samples_reports_errors_per_entry() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let data: Vec<u8> = (0..=255).collect();
+        store.put(&Path::from("shard-0.tar"), Bytes::from(data.clone()).into()).await.unwrap();
+
+        let requests = vec![
+            request("shard-0.tar", 10, 5),
+            request("missing.tar", 0, 5),
+            request("shard-0.tar", 250, 10),
+            request("shard-0.tar", 0, 4),
+            request("shard-0.tar", 12, 0),
+        ];
+        let results = read_samples(&store, requests, 4, 1 << 20).await;
+
+        assert_eq!(results[0].as_ref().unwrap().as_ref(), &data[10..15]);
+        assert!(results[1].is_err());
+        assert!(results[2].as_ref().unwrap_err().contains("exceeds the object size"));
+        assert_eq!(results[3].as_ref().unwrap().as_ref(), &data[0..4]);
+        assert!(results[4].as_ref().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use http::header::{HeaderMap, DATE};
+use std::cell::Cell;
+use std::future::Future;
+
+tokio::task_local! {
+    static LATEST: Cell<Option<DateTime<Utc>>>;
+}
+
+/// Runs `fut` and returns the server time of the last response it received, from the `Date`
+/// header. Requests sent from other tasks, like multipart parts, aren't seen.
+pub async fn capture<F: Future>(fut: F) -> (F::Output, Option<DateTime<Utc>>) {
+    LATEST
+        .scope(Cell::new(None), async {
+            let output = fut.await;
+            (output, LATEST.with(Cell::get))
+        })
+        .await
+}
+
+/// Records the `Date` of a response for the enclosing `capture`, if any.
+pub fn record(headers: &HeaderMap) {
+    let date = headers
+        .get(DATE)
+        .and_then(|date| date.to_str().ok())
+        .and_then(|date| DateTime::parse_from_rfc2822(date).ok());
+    if let Some(date) = date {
+        let _ = LATEST.try_with(|latest| latest.set(Some(date.with_timezone(&Utc))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[tokio::test]
+    async fn test_capture_latest_date() {
+        let mut first = HeaderMap::new();
+        first.insert(DATE, HeaderValue::from_static("Tue, 13 Oct 2026 08:12:31 GMT"));
+        let mut last = HeaderMap::new();
+        last.insert(DATE, HeaderValue::from_static("Tue, 13 Oct 2026 08:12:35 GMT"));
+
+        let ((), date) = capture(async {
+            record(&first);
+            record(&HeaderMap::new());
+            record(&last);
+        })
+        .await;
+        assert_eq!(date.unwrap().to_rfc3339(), "2026-10-13T08:12:35+00:00");
+
+        // Outside of a capture there is nowhere to record to.
+        record(&last);
+        assert_eq!(capture(async {}).await.1, None);
+    }
+}
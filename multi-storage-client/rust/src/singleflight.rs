@@ -0,0 +1,141 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use object_store::path::Path;
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::StorageError;
+
+type Key = (Path, Option<Range<u64>>);
+type SharedFetch = Shared<BoxFuture<'static, Result<Bytes, Arc<StorageError>>>>;
+
+/// Coalesces concurrent identical reads so only one request reaches the store.
+///
+/// A read joins a fetch of the same path and range that is still in flight and receives a clone
+/// of its `Bytes` or its error. Entries are removed as soon as the fetch completes, so nothing is
+/// cached beyond the lifetime of the request.
+#[derive(Default)]
+pub struct SingleFlight {
+    in_flight: Mutex<HashMap<Key, SharedFetch>>,
+    deduplicated: AtomicU64,
+}
+
+impl SingleFlight {
+    /// Number of reads that were served by another read's request.
+    pub fn deduplicated(&self) -> u64 {
+        self.deduplicated.load(Ordering::Relaxed)
+    }
+
+    pub async fn run<F>(self: &Arc<Self>, path: &Path, range: Option<Range<u64>>, fetch: F) -> Result<Bytes, StorageError>
+    where
+        F: Future<Output = Result<Bytes, StorageError>> + Send + 'static,
+    {
+        let key = (path.clone(), range);
+        let shared = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            if let Some(shared) = in_flight.get(&key) {
+                self.deduplicated.fetch_add(1, Ordering::Relaxed);
+                shared.clone()
+            } else {
+                let this = Arc::clone(self);
+                let entry = key.clone();
+                let shared = async move {
+                    let result = fetch.await.map_err(Arc::new);
+                    this.in_flight.lock().unwrap().remove(&entry);
+                    result
+                }
+                .boxed()
+                .shared();
+                in_flight.insert(key, shared.clone());
+                shared
+            }
+        };
+        shared.await.map_err(|err| err.duplicate())
+    }
+}
+
+/// Runs `fetch` through `single_flight` when deduplication is enabled, or directly otherwise.
+pub async fn dedupe<F>(
+    single_flight: Option<&Arc<SingleFlight>>,
+    path: &Path,
+    range: Option<Range<u64>>,
+    fetch: F,
+) -> Result<Bytes, StorageError>
+where
+    F: Future<Output = Result<Bytes, StorageError>> + Send + 'static,
+{
+    match single_flight {
+        Some(single_flight) => single_flight.run(path, range, fetch).await,
+        None => fetch.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_reads_share_one_fetch() {
+        let single_flight = Arc::new(SingleFlight::default());
+        let fetches = Arc::new(AtomicU32::new(0));
+        let path = Path::from("hot/index");
+
+        let read = |range: Option<Range<u64>>, fail: bool| {
+            let single_flight = Arc::clone(&single_flight);
+            let fetches = Arc::clone(&fetches);
+            let path = path.clone();
+            async move {
+                single_flight
+                    .run(&path, range, async move {
+                        fetches.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        if fail {
+                            Err(StorageError::HttpError("not found".to_string(), Some(404)))
+                        } else {
+                            Ok(Bytes::from_static(b"index"))
+                        }
+                    })
+                    .await
+            }
+        };
+
+        let results = futures::future::join_all((0..8).map(|_| read(None, false))).await;
+        assert!(results.iter().all(|result| result.as_ref().unwrap().as_ref() == b"index"));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(single_flight.deduplicated(), 7);
+
+        // Different ranges are separate fetches, and completed fetches are not reused.
+        let results = futures::future::join_all([read(Some(0..2), false), read(Some(2..4), false)]).await;
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(fetches.load(Ordering::SeqCst), 3);
+        read(None, false).await.unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 4);
+        assert!(single_flight.in_flight.lock().unwrap().is_empty());
+
+        // Errors reach every waiter.
+        let results = futures::future::join_all((0..3).map(|_| read(None, true))).await;
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(StorageError::HttpError(_, Some(404))))));
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::header::HeaderMap;
+use std::cell::RefCell;
+use std::future::Future;
+
+use crate::StorageError;
+
+const S3_CLASSES: [&str; 9] = [
+    "STANDARD",
+    "STANDARD_IA",
+    "ONEZONE_IA",
+    "INTELLIGENT_TIERING",
+    "GLACIER_IR",
+    "GLACIER",
+    "DEEP_ARCHIVE",
+    "REDUCED_REDUNDANCY",
+    "EXPRESS_ONEZONE",
+];
+
+const GCS_CLASSES: [&str; 4] = ["STANDARD", "NEARLINE", "COLDLINE", "ARCHIVE"];
+
+/// Response headers S3 and GCS report the storage class of an object in.
+const HEADERS: [&str; 2] = ["x-amz-storage-class", "x-goog-storage-class"];
+
+tokio::task_local! {
+    static LATEST: RefCell<Option<String>>;
+}
+
+/// Checks that `name` is a storage class of `provider`, ignoring case, and returns it as the
+/// provider spells it.
+pub fn parse(provider: &str, name: &str) -> Result<String, StorageError> {
+    let accepted: &[&str] = match provider {
+        "s3" | "s8k" => &S3_CLASSES,
+        "gcs" | "gcs_s3" => &GCS_CLASSES,
+        _ => {
+            return Err(StorageError::ConfigError(format!(
+                "storage_class is not supported by the '{}' provider",
+                provider
+            )))
+        }
+    };
+    let class = name.to_ascii_uppercase();
+    if !accepted.contains(&class.as_str()) {
+        return Err(StorageError::ConfigError(format!(
+            "Unsupported storage_class '{}' for the '{}' provider. Accepted values: {}",
+            name,
+            provider,
+            accepted.join(", ")
+        )));
+    }
+    Ok(class)
+}
+
+/// Runs `fut` and returns the storage class reported by the last response it received that had
+/// one. S3 leaves it out for `STANDARD` objects.
+pub async fn capture<F: Future>(fut: F) -> (F::Output, Option<String>) {
+    LATEST
+        .scope(RefCell::new(None), async {
+            let output = fut.await;
+            (output, LATEST.with(|latest| latest.take()))
+        })
+        .await
+}
+
+/// Records the storage class of a response for the enclosing `capture`, if any.
+pub fn record(headers: &HeaderMap) {
+    let class = HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name))
+        .and_then(|class| class.to_str().ok());
+    if let Some(class) = class {
+        let _ = LATEST.try_with(|latest| *latest.borrow_mut() = Some(class.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("s3", "standard_ia").unwrap(), "STANDARD_IA");
+        assert_eq!(parse("gcs", "Nearline").unwrap(), "NEARLINE");
+        match parse("s3", "NEARLINE").unwrap_err() {
+            StorageError::ConfigError(msg) => assert!(msg.contains("GLACIER_IR"), "unexpected: {}", msg),
+            other => panic!("Expected ConfigError, got {:?}", other),
+        }
+        assert!(matches!(parse("file", "STANDARD"), Err(StorageError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_capture() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-storage-class", HeaderValue::from_static("GLACIER_IR"));
+        let ((), class) = capture(async {
+            record(&headers);
+            record(&HeaderMap::new());
+        })
+        .await;
+        assert_eq!(class.as_deref(), Some("GLACIER_IR"));
+        assert_eq!(capture(async {}).await.1, None);
+    }
+}
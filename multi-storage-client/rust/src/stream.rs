@@ -0,0 +1,909 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::{Bytes, BytesMut};
+use futures::StreamExt;
+use object_store::{path::Path, GetOptions, GetRange, ObjectStore, WriteMultipart};
+use http::HeaderValue;
+use pyo3::exceptions::{PyRuntimeError, PyStopAsyncIteration, PyValueError};
+use pyo3::prelude::*;
+use pyo3_bytes::PyBytes;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::task::{AbortHandle, JoinHandle};
+
+use crate::bridge::future_into_py;
+use crate::request_tag;
+use crate::runtime::get_runtime;
+use crate::singleflight::{dedupe, SingleFlight};
+use crate::telemetry;
+use crate::StorageError;
+
+/// Fetches `path` in `chunksize` ranged gets, up to `concurrency` at a time, and delivers the
+/// chunks in order. The producer stops after the first error or once the receiver is dropped.
+pub fn spawn_chunk_reader(
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    size: u64,
+    chunksize: usize,
+    concurrency: usize,
+    single_flight: Option<Arc<SingleFlight>>,
+) -> mpsc::Receiver<Result<Bytes, StorageError>> {
+    let concurrency = concurrency.max(1);
+    let (tx, rx) = mpsc::channel(concurrency);
+
+    tokio::spawn(request_tag::propagate(async move {
+        let ranges = (0..size)
+            .step_by(chunksize)
+            .map(move |start| start..std::cmp::min(start + chunksize as u64, size));
+        let mut chunks = futures::stream::iter(ranges)
+            .map(|range| {
+                let store = Arc::clone(&store);
+                let path = path.clone();
+                let single_flight = single_flight.clone();
+                async move {
+                    let fetch_path = path.clone();
+                    let fetch_range = range.clone();
+                    let fetch =
+                        async move { store.get_range(&fetch_path, fetch_range).await.map_err(StorageError::from) };
+                    dedupe(single_flight.as_ref(), &path, Some(range), fetch).await
+                }
+            })
+            .buffered(concurrency);
+
+        while let Some(chunk) = chunks.next().await {
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    }));
+
+    rx
+}
+
+/// Streams `path` with a single get, sending its bytes in `chunksize` pieces, the last possibly
+/// shorter. Stops after the first error or once the receiver is dropped.
+async fn send_stream_chunks(
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    range: Option<GetRange>,
+    chunksize: usize,
+    chunks: mpsc::Sender<Result<Bytes, StorageError>>,
+) {
+    let options = GetOptions {
+        range,
+        ..Default::default()
+    };
+    let mut body = match store.get_opts(&path, options).await {
+        Ok(result) => result.into_stream(),
+        Err(err) => {
+            let _ = chunks.send(Err(err.into())).await;
+            return;
+        }
+    };
+    let mut buffer = BytesMut::new();
+    while let Some(data) = body.next().await {
+        match data {
+            Ok(data) => buffer.extend_from_slice(&data),
+            Err(err) => {
+                let _ = chunks.send(Err(err.into())).await;
+                return;
+            }
+        }
+        while buffer.len() >= chunksize {
+            if chunks.send(Ok(buffer.split_to(chunksize).freeze())).await.is_err() {
+                return;
+            }
+        }
+    }
+    if !buffer.is_empty() {
+        let _ = chunks.send(Ok(buffer.freeze())).await;
+    }
+}
+
+/// Async iterator over the bytes of an object, streamed by a single get.
+///
+/// At most one chunk is read ahead of the consumer. Dropping the iterator cancels the request.
+#[pyclass]
+pub struct RustGetStream {
+    chunks: Arc<Mutex<mpsc::Receiver<Result<Bytes, StorageError>>>>,
+    task: AbortHandle,
+}
+
+impl RustGetStream {
+    pub fn spawn(
+        store: Arc<dyn ObjectStore>,
+        path: Path,
+        range: Option<GetRange>,
+        chunksize: usize,
+        tag: Option<HeaderValue>,
+    ) -> Self {
+        let (tx, rx) = mpsc::channel(1);
+        let body = send_stream_chunks(store, path, range, chunksize, tx);
+        let task = get_runtime().spawn(telemetry::instrument(request_tag::scoped(tag, body)));
+        RustGetStream {
+            chunks: Arc::new(Mutex::new(rx)),
+            task: task.abort_handle(),
+        }
+    }
+}
+
+impl Drop for RustGetStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[pymethods]
+impl RustGetStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let chunks = Arc::clone(&self.chunks);
+        future_into_py(py, async move {
+            match chunks.lock().await.recv().await {
+                Some(chunk) => Ok(PyBytes::new(chunk?)),
+                None => Err(PyStopAsyncIteration::new_err(())),
+            }
+        })
+    }
+}
+
+/// Buffered read side of a [`RustReadStream`].
+///
+/// Methods that can come up short return `Ok(Err(partial))` at end of stream.
+pub struct ReaderState {
+    chunks: mpsc::Receiver<Result<Bytes, StorageError>>,
+    buffer: BytesMut,
+    eof: bool,
+}
+
+impl ReaderState {
+    pub fn new(chunks: mpsc::Receiver<Result<Bytes, StorageError>>) -> Self {
+        ReaderState {
+            chunks,
+            buffer: BytesMut::new(),
+            eof: false,
+        }
+    }
+
+    pub fn at_eof(&self) -> bool {
+        self.eof && self.buffer.is_empty()
+    }
+
+    /// Appends the next chunk to the buffer, returning `false` at end of stream.
+    async fn fill(&mut self) -> Result<bool, StorageError> {
+        if self.eof {
+            return Ok(false);
+        }
+        match self.chunks.recv().await {
+            Some(chunk) => {
+                self.buffer.extend_from_slice(&chunk?);
+                Ok(true)
+            }
+            None => {
+                self.eof = true;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Reads up to `n` bytes, or everything up to end of stream when `n` is negative.
+    pub async fn read(&mut self, n: isize) -> Result<Bytes, StorageError> {
+        if n < 0 {
+            while self.fill().await? {}
+            return Ok(self.buffer.split().freeze());
+        }
+        if n > 0 && self.buffer.is_empty() {
+            self.fill().await?;
+        }
+        let len = std::cmp::min(n as usize, self.buffer.len());
+        Ok(self.buffer.split_to(len).freeze())
+    }
+
+    pub async fn read_exactly(&mut self, n: usize) -> Result<Result<Bytes, Bytes>, StorageError> {
+        while self.buffer.len() < n {
+            if !self.fill().await? {
+                return Ok(Err(self.buffer.split().freeze()));
+            }
+        }
+        Ok(Ok(self.buffer.split_to(n).freeze()))
+    }
+
+    /// Reads up to and including `separator`.
+    pub async fn read_until(&mut self, separator: &[u8]) -> Result<Result<Bytes, Bytes>, StorageError> {
+        if separator.is_empty() {
+            return Err(StorageError::ConfigError("Separator should be at least one-byte string".to_string()));
+        }
+        let mut search_from = 0;
+        loop {
+            if let Some(position) = self.buffer[search_from..]
+                .windows(separator.len())
+                .position(|window| window == separator)
+            {
+                let end = search_from + position + separator.len();
+                return Ok(Ok(self.buffer.split_to(end).freeze()));
+            }
+            search_from = self.buffer.len().saturating_sub(separator.len() - 1);
+            if !self.fill().await? {
+                return Ok(Err(self.buffer.split().freeze()));
+            }
+        }
+    }
+}
+
+/// Builds an `asyncio.IncompleteReadError` so stream consumers can handle short reads as usual.
+fn incomplete_read_error(partial: Bytes, expected: Option<usize>) -> PyErr {
+    Python::attach(|py| {
+        let partial = pyo3::types::PyBytes::new(py, &partial);
+        match py
+            .import("asyncio")
+            .and_then(|asyncio| asyncio.getattr("IncompleteReadError"))
+            .and_then(|error_type| error_type.call1((partial, expected)))
+        {
+            Ok(error) => PyErr::from_value(error),
+            Err(e) => e,
+        }
+    })
+}
+
+/// An object reader with `asyncio.StreamReader` semantics backed by chunked ranged gets.
+#[pyclass]
+pub struct RustReadStream {
+    state: Arc<Mutex<ReaderState>>,
+}
+
+impl RustReadStream {
+    pub fn new(chunks: mpsc::Receiver<Result<Bytes, StorageError>>) -> Self {
+        RustReadStream {
+            state: Arc::new(Mutex::new(ReaderState::new(chunks))),
+        }
+    }
+}
+
+#[pymethods]
+impl RustReadStream {
+    #[pyo3(signature = (n=-1))]
+    fn read<'p>(&self, py: Python<'p>, n: isize) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, async move {
+            let data = state.lock().await.read(n).await?;
+            Ok(PyBytes::new(data))
+        })
+    }
+
+    fn readexactly<'p>(&self, py: Python<'p>, n: usize) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, async move {
+            match state.lock().await.read_exactly(n).await? {
+                Ok(data) => Ok(PyBytes::new(data)),
+                Err(partial) => Err(incomplete_read_error(partial, Some(n))),
+            }
+        })
+    }
+
+    #[pyo3(signature = (separator=b"\n".to_vec()))]
+    fn readuntil<'p>(&self, py: Python<'p>, separator: Vec<u8>) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, async move {
+            match state.lock().await.read_until(&separator).await? {
+                Ok(data) => Ok(PyBytes::new(data)),
+                Err(partial) => Err(incomplete_read_error(partial, None)),
+            }
+        })
+    }
+
+    fn readline<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, async move {
+            let data = state.lock().await.read_until(b"\n").await?.unwrap_or_else(|partial| partial);
+            Ok(PyBytes::new(data))
+        })
+    }
+
+    fn at_eof(&self) -> bool {
+        // A read in progress holds the lock, which means the stream isn't known to be exhausted yet.
+        self.state.try_lock().map(|state| state.at_eof()).unwrap_or(false)
+    }
+}
+
+/// Position and read-ahead buffer of a [`RustObjectReader`].
+pub struct ObjectReaderState {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    size: u64,
+    buffer_size: usize,
+    position: u64,
+    buffer: Bytes,
+    buffer_start: u64,
+}
+
+impl ObjectReaderState {
+    pub fn new(store: Arc<dyn ObjectStore>, path: Path, size: u64, buffer_size: usize) -> Self {
+        ObjectReaderState {
+            store,
+            path,
+            size,
+            buffer_size: buffer_size.max(1),
+            position: 0,
+            buffer: Bytes::new(),
+            buffer_start: 0,
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Reads up to `n` bytes, or everything up to the end of the object when `n` is negative.
+    ///
+    /// Reads the buffer can't serve fetch at least `buffer_size` bytes from the current position.
+    pub async fn read(&mut self, n: isize) -> Result<Bytes, StorageError> {
+        let remaining = self.size.saturating_sub(self.position);
+        let len = if n < 0 { remaining } else { std::cmp::min(n as u64, remaining) };
+        if len == 0 {
+            return Ok(Bytes::new());
+        }
+        let end = self.position + len;
+        if self.position < self.buffer_start || end > self.buffer_start + self.buffer.len() as u64 {
+            let fetch_end = std::cmp::min(std::cmp::max(end, self.position + self.buffer_size as u64), self.size);
+            self.buffer = self.store.get_range(&self.path, self.position..fetch_end).await?;
+            self.buffer_start = self.position;
+        }
+        let offset = (self.position - self.buffer_start) as usize;
+        let data = self.buffer.slice(offset..offset + len as usize);
+        self.position = end;
+        Ok(data)
+    }
+
+    /// Moves to `offset` relative to the start (`whence=0`), the current position (`1`) or the
+    /// end of the object (`2`), which must land within the object.
+    pub fn seek(&mut self, offset: i64, whence: i32) -> Result<u64, String> {
+        let base = match whence {
+            0 => 0,
+            1 => self.position as i64,
+            2 => self.size as i64,
+            _ => return Err(format!("Invalid whence ({}, should be 0, 1 or 2)", whence)),
+        };
+        let position = base
+            .checked_add(offset)
+            .ok_or_else(|| format!("Seek offset {} is out of range", offset))?;
+        if position < 0 {
+            return Err(format!("Negative seek position {}", position));
+        }
+        if position as u64 > self.size {
+            return Err(format!(
+                "Seek position {} is past the end of the object ({} bytes)",
+                position, self.size
+            ));
+        }
+        self.position = position as u64;
+        Ok(self.position)
+    }
+}
+
+/// A seekable, file-like object reader backed by ranged gets.
+#[pyclass]
+pub struct RustObjectReader {
+    state: Arc<Mutex<ObjectReaderState>>,
+    closed: Arc<AtomicBool>,
+    tag: Option<HeaderValue>,
+}
+
+impl RustObjectReader {
+    pub fn new(state: ObjectReaderState, tag: Option<HeaderValue>) -> Self {
+        RustObjectReader {
+            state: Arc::new(Mutex::new(state)),
+            closed: Arc::new(AtomicBool::new(false)),
+            tag,
+        }
+    }
+
+    fn check_open(&self) -> PyResult<()> {
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(PyValueError::new_err("I/O operation on closed reader"));
+        }
+        Ok(())
+    }
+
+    fn try_state(&self) -> PyResult<tokio::sync::MutexGuard<'_, ObjectReaderState>> {
+        self.check_open()?;
+        self.state
+            .try_lock()
+            .map_err(|_| PyRuntimeError::new_err("Reader has a read in progress"))
+    }
+}
+
+#[pymethods]
+impl RustObjectReader {
+    #[pyo3(signature = (n=-1))]
+    fn read<'p>(&self, py: Python<'p>, n: isize) -> PyResult<Bound<'p, PyAny>> {
+        self.check_open()?;
+        let state = Arc::clone(&self.state);
+        let closed = Arc::clone(&self.closed);
+        future_into_py(py, request_tag::scoped(self.tag.clone(), async move {
+            let mut state = state.lock().await;
+            if closed.load(Ordering::SeqCst) {
+                return Err(PyValueError::new_err("I/O operation on closed reader"));
+            }
+            let data = state.read(n).await?;
+            Ok(PyBytes::new(data))
+        }))
+    }
+
+    #[pyo3(signature = (offset, whence=0))]
+    fn seek(&self, offset: i64, whence: i32) -> PyResult<u64> {
+        self.try_state()?.seek(offset, whence).map_err(PyValueError::new_err)
+    }
+
+    fn tell(&self) -> PyResult<u64> {
+        Ok(self.try_state()?.position())
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Closes the reader. Further reads and seeks raise `ValueError`; closing again does nothing.
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+pub enum WriterCommand {
+    Write(Bytes),
+    Drain(oneshot::Sender<Result<(), String>>),
+    Close,
+}
+
+/// Feeds queued writes into `writer`, keeping at most `concurrency` parts in flight.
+///
+/// The upload is completed only after an explicit `Close`; if the command channel is dropped
+/// first, or any part fails, the multipart upload is aborted.
+pub async fn run_writer(
+    mut writer: WriteMultipart,
+    mut commands: mpsc::UnboundedReceiver<WriterCommand>,
+    concurrency: usize,
+) -> Result<u64, StorageError> {
+    let mut written = 0u64;
+    let mut failure: Option<StorageError> = None;
+    let mut closed = false;
+
+    while let Some(command) = commands.recv().await {
+        match command {
+            WriterCommand::Write(data) => {
+                if failure.is_none() {
+                    match writer.wait_for_capacity(concurrency).await {
+                        Ok(()) => {
+                            written += data.len() as u64;
+                            writer.put(data);
+                        }
+                        Err(e) => failure = Some(StorageError::from(e)),
+                    }
+                }
+            }
+            WriterCommand::Drain(reply) => {
+                if failure.is_none() {
+                    if let Err(e) = writer.wait_for_capacity(concurrency).await {
+                        failure = Some(StorageError::from(e));
+                    }
+                }
+                let _ = reply.send(failure.as_ref().map_or(Ok(()), |e| Err(e.to_string())));
+            }
+            WriterCommand::Close => {
+                closed = true;
+                break;
+            }
+        }
+    }
+
+    if let Some(e) = failure {
+        let _ = writer.abort().await;
+        return Err(e);
+    }
+    if !closed {
+        let _ = writer.abort().await;
+        return Err(StorageError::ObjectStoreError(
+            "Write stream was dropped before close(); the upload was aborted".to_string(),
+        ));
+    }
+    writer.finish().await.map_err(StorageError::from)?;
+    Ok(written)
+}
+
+enum CloseState {
+    Pending(JoinHandle<Result<u64, StorageError>>),
+    Done(Result<u64, String>),
+}
+
+/// An object writer with `asyncio.StreamWriter` semantics backed by a multipart upload.
+#[pyclass]
+pub struct RustWriteStream {
+    commands: StdMutex<Option<mpsc::UnboundedSender<WriterCommand>>>,
+    closing: AtomicBool,
+    close_state: Arc<Mutex<CloseState>>,
+}
+
+impl RustWriteStream {
+    pub fn new(writer: WriteMultipart, concurrency: usize) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(run_writer(writer, rx, concurrency.max(1)));
+        RustWriteStream {
+            commands: StdMutex::new(Some(tx)),
+            closing: AtomicBool::new(false),
+            close_state: Arc::new(Mutex::new(CloseState::Pending(handle))),
+        }
+    }
+
+    fn send(&self, command: WriterCommand) -> PyResult<()> {
+        let commands = self.commands.lock().unwrap();
+        match commands.as_ref() {
+            Some(tx) if !self.closing.load(Ordering::SeqCst) => tx
+                .send(command)
+                .map_err(|_| PyRuntimeError::new_err("Write stream has failed")),
+            _ => Err(PyRuntimeError::new_err("Write stream is closed")),
+        }
+    }
+}
+
+#[pymethods]
+impl RustWriteStream {
+    fn write(&self, data: PyBytes) -> PyResult<()> {
+        self.send(WriterCommand::Write(data.into_inner()))
+    }
+
+    fn writelines(&self, data: Vec<PyBytes>) -> PyResult<()> {
+        for chunk in data {
+            self.write(chunk)?;
+        }
+        Ok(())
+    }
+
+    fn drain<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.send(WriterCommand::Drain(reply_tx))?;
+        future_into_py(py, async move {
+            match reply_rx.await {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(msg)) => Err(StorageError::ObjectStoreError(msg).into()),
+                Err(_) => Err(PyRuntimeError::new_err("Write stream has failed")),
+            }
+        })
+    }
+
+    fn can_write_eof(&self) -> bool {
+        false
+    }
+
+    fn is_closing(&self) -> bool {
+        self.closing.load(Ordering::SeqCst)
+    }
+
+    /// Starts completing the upload. Queued writes are flushed first; see `wait_closed`.
+    fn close(&self) {
+        let mut commands = self.commands.lock().unwrap();
+        if !self.closing.swap(true, Ordering::SeqCst) {
+            if let Some(tx) = commands.take() {
+                let _ = tx.send(WriterCommand::Close);
+            }
+        }
+    }
+
+    /// Waits for the upload to complete and returns the number of bytes written.
+    fn wait_closed<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let close_state = Arc::clone(&self.close_state);
+        future_into_py(py, async move {
+            let mut state = close_state.lock().await;
+            match &mut *state {
+                CloseState::Pending(handle) => {
+                    let result = handle
+                        .await
+                        .unwrap_or_else(|e| Err(StorageError::ObjectStoreError(e.to_string())));
+                    *state = CloseState::Done(result.as_ref().copied().map_err(|e| e.to_string()));
+                    Ok(result?)
+                }
+                CloseState::Done(Ok(written)) => Ok(*written),
+                CloseState::Done(Err(msg)) => Err(StorageError::ObjectStoreError(msg.clone()).into()),
+            }
+        })
+    }
+}
+
+/// Upload side of a [`RustObjectWriter`]. The writer is gone once the upload is finished or aborted.
+pub struct ObjectWriterState {
+    writer: Option<WriteMultipart>,
+    concurrency: usize,
+    written: u64,
+    failure: Option<String>,
+}
+
+impl ObjectWriterState {
+    pub fn new(writer: WriteMultipart, concurrency: usize) -> Self {
+        ObjectWriterState {
+            writer: Some(writer),
+            concurrency: concurrency.max(1),
+            written: 0,
+            failure: None,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.writer.is_none()
+    }
+
+    /// Aborts the upload after a failed part, so it doesn't leave parts behind.
+    async fn fail(&mut self, err: object_store::Error) -> StorageError {
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.abort().await;
+        }
+        self.failure = Some(err.to_string());
+        StorageError::from(err)
+    }
+
+    /// Buffers `data`, uploading each part once it fills while keeping at most `concurrency` in flight.
+    pub async fn write(&mut self, data: Bytes) -> Result<(), StorageError> {
+        let Some(writer) = self.writer.as_mut() else {
+            return Err(writer_closed());
+        };
+        if let Err(e) = writer.wait_for_capacity(self.concurrency).await {
+            return Err(self.fail(e).await);
+        }
+        self.written += data.len() as u64;
+        writer.put(data);
+        Ok(())
+    }
+
+    /// Waits for every part in flight. Data short of a full part stays buffered until `finish`.
+    pub async fn flush(&mut self) -> Result<(), StorageError> {
+        let Some(writer) = self.writer.as_mut() else {
+            return Err(writer_closed());
+        };
+        if let Err(e) = writer.wait_for_capacity(0).await {
+            return Err(self.fail(e).await);
+        }
+        Ok(())
+    }
+
+    /// Uploads the remaining data and completes the upload, returning the number of bytes written.
+    pub async fn finish(&mut self) -> Result<u64, StorageError> {
+        if let Some(msg) = &self.failure {
+            return Err(StorageError::ObjectStoreError(msg.clone()));
+        }
+        if let Some(writer) = self.writer.take() {
+            writer.finish().await?;
+        }
+        Ok(self.written)
+    }
+
+    pub async fn abort(&mut self) -> Result<(), StorageError> {
+        match self.writer.take() {
+            Some(writer) => Ok(writer.abort().await?),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for ObjectWriterState {
+    fn drop(&mut self) {
+        if let Some(writer) = self.writer.take() {
+            get_runtime().spawn(async move {
+                let _ = writer.abort().await;
+            });
+        }
+    }
+}
+
+fn writer_closed() -> StorageError {
+    StorageError::ObjectStoreError("Writer is closed".to_string())
+}
+
+/// An incremental object writer backed by a multipart upload.
+///
+/// The upload is only completed by `close()`; a writer that is aborted, fails or is dropped first
+/// aborts the multipart upload.
+#[pyclass]
+pub struct RustObjectWriter {
+    state: Arc<Mutex<ObjectWriterState>>,
+    tag: Option<HeaderValue>,
+}
+
+impl RustObjectWriter {
+    pub fn new(state: ObjectWriterState, tag: Option<HeaderValue>) -> Self {
+        RustObjectWriter {
+            state: Arc::new(Mutex::new(state)),
+            tag,
+        }
+    }
+}
+
+/// Locks `state`, raising `ValueError` if the writer is already closed.
+async fn lock_open(state: &Mutex<ObjectWriterState>) -> PyResult<tokio::sync::MutexGuard<'_, ObjectWriterState>> {
+    let state = state.lock().await;
+    if state.is_closed() {
+        return Err(PyValueError::new_err("I/O operation on closed writer"));
+    }
+    Ok(state)
+}
+
+#[pymethods]
+impl RustObjectWriter {
+    fn write<'p>(&self, py: Python<'p>, data: PyBytes) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, request_tag::scoped(self.tag.clone(), async move {
+            Ok(lock_open(&state).await?.write(data.into_inner()).await?)
+        }))
+    }
+
+    fn flush<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, request_tag::scoped(self.tag.clone(), async move {
+            Ok(lock_open(&state).await?.flush().await?)
+        }))
+    }
+
+    /// Completes the upload and returns the number of bytes written. Closing again has no effect.
+    fn close<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, request_tag::scoped(self.tag.clone(), async move {
+            Ok(state.lock().await.finish().await?)
+        }))
+    }
+
+    fn abort<'p>(&self, py: Python<'p>) -> PyResult<Bound<'p, PyAny>> {
+        let state = Arc::clone(&self.state);
+        future_into_py(py, request_tag::scoped(self.tag.clone(), async move {
+            Ok(state.lock().await.abort().await?)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    async fn reader_for(data: &[u8], chunksize: usize) -> ReaderState {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("stream");
+        store.put(&path, Bytes::copy_from_slice(data).into()).await.unwrap();
+        ReaderState::new(spawn_chunk_reader(store, path, data.len() as u64, chunksize, 2, None))
+    }
+
+    #[tokio::test]
+    async fn test_reader_state() {
+        let mut reader = reader_for(b"header\r\n\r\nline one\nline two\ntail", 3).await;
+
+        assert_eq!(reader.read_until(b"\r\n\r\n").await.unwrap().unwrap().as_ref(), b"header\r\n\r\n");
+        assert_eq!(reader.read_exactly(4).await.unwrap().unwrap().as_ref(), b"line");
+        assert_eq!(reader.read_until(b"\n").await.unwrap().unwrap().as_ref(), b" one\n");
+        assert_eq!(reader.read(-1).await.unwrap().as_ref(), b"line two\ntail");
+        assert!(reader.at_eof());
+        assert!(reader.read(10).await.unwrap().is_empty());
+
+        let mut reader = reader_for(b"abcdef", 4).await;
+        assert_eq!(reader.read_exactly(10).await.unwrap().unwrap_err().as_ref(), b"abcdef");
+
+        let mut reader = reader_for(b"no separator", 5).await;
+        assert_eq!(reader.read_until(b"\n").await.unwrap().unwrap_err().as_ref(), b"no separator");
+    }
+
+    async fn stream_chunks(store: &Arc<dyn ObjectStore>, range: Option<GetRange>, chunksize: usize) -> Vec<Bytes> {
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(send_stream_chunks(Arc::clone(store), Path::from("stream"), range, chunksize, tx));
+        let mut chunks = Vec::new();
+        while let Some(chunk) = rx.recv().await {
+            chunks.push(chunk.unwrap());
+        }
+        chunks
+    }
+
+    #[tokio::test]
+    async fn test_stream_chunks() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        store.put(&Path::from("stream"), Bytes::from_static(b"0123456789").into()).await.unwrap();
+
+        assert_eq!(stream_chunks(&store, None, 4).await, ["0123", "4567", "89"]);
+        assert_eq!(stream_chunks(&store, None, 64).await, ["0123456789"]);
+        assert_eq!(stream_chunks(&store, Some(GetRange::Bounded(2..9)), 3).await, ["234", "567", "8"]);
+        assert_eq!(stream_chunks(&store, Some(GetRange::Offset(7)), 3).await, ["789"]);
+
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(send_stream_chunks(store, Path::from("missing"), None, 4, tx));
+        assert!(matches!(rx.recv().await, Some(Err(StorageError::HttpError(_, Some(404))))));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_object_reader_state() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let path = Path::from("object");
+        store.put(&path, Bytes::from_static(b"0123456789").into()).await.unwrap();
+        let mut reader = ObjectReaderState::new(store, path, 10, 4);
+
+        assert_eq!(reader.read(3).await.unwrap().as_ref(), b"012");
+        assert_eq!(reader.read(3).await.unwrap().as_ref(), b"345");
+        assert_eq!(reader.position(), 6);
+        assert_eq!(reader.seek(-2, 2).unwrap(), 8);
+        assert_eq!(reader.read(-1).await.unwrap().as_ref(), b"89");
+        assert!(reader.read(1).await.unwrap().is_empty());
+        assert_eq!(reader.seek(1, 0).unwrap(), 1);
+        assert_eq!(reader.seek(2, 1).unwrap(), 3);
+        assert_eq!(reader.read(2).await.unwrap().as_ref(), b"34");
+        assert!(reader.seek(11, 0).is_err());
+        assert!(reader.seek(-1, 0).is_err());
+        assert!(reader.seek(0, 3).is_err());
+        assert_eq!(reader.position(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_object_writer_state() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+
+        let completed = Path::from("completed");
+        let upload = store.put_multipart(&completed).await.unwrap();
+        let mut writer = ObjectWriterState::new(WriteMultipart::new_with_chunk_size(upload, 4), 2);
+        for piece in [&b"ab"[..], b"cdef", b"g"] {
+            writer.write(Bytes::copy_from_slice(piece)).await.unwrap();
+        }
+        writer.flush().await.unwrap();
+        assert_eq!(writer.finish().await.unwrap(), 7);
+        assert!(writer.is_closed());
+        assert!(writer.write(Bytes::from_static(b"late")).await.is_err());
+        assert_eq!(writer.finish().await.unwrap(), 7);
+        let data = store.get(&completed).await.unwrap().bytes().await.unwrap();
+        assert_eq!(data.as_ref(), b"abcdefg");
+
+        let aborted = Path::from("aborted");
+        let upload = store.put_multipart(&aborted).await.unwrap();
+        let mut writer = ObjectWriterState::new(WriteMultipart::new_with_chunk_size(upload, 4), 2);
+        writer.write(Bytes::from_static(b"partial")).await.unwrap();
+        writer.abort().await.unwrap();
+        assert!(writer.is_closed());
+        assert!(store.head(&aborted).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_writer_completes_or_aborts() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+
+        let completed = Path::from("completed");
+        let upload = store.put_multipart(&completed).await.unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(run_writer(WriteMultipart::new_with_chunk_size(upload, 5), rx, 2));
+        tx.send(WriterCommand::Write(Bytes::from_static(b"hello "))).unwrap();
+        let (reply_tx, reply_rx) = oneshot::channel();
+        tx.send(WriterCommand::Drain(reply_tx)).unwrap();
+        assert!(reply_rx.await.unwrap().is_ok());
+        tx.send(WriterCommand::Write(Bytes::from_static(b"world"))).unwrap();
+        tx.send(WriterCommand::Close).unwrap();
+        assert_eq!(handle.await.unwrap().unwrap(), 11);
+        let data = store.get(&completed).await.unwrap().bytes().await.unwrap();
+        assert_eq!(data.as_ref(), b"hello world");
+
+        let dropped = Path::from("dropped");
+        let upload = store.put_multipart(&dropped).await.unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(run_writer(WriteMultipart::new_with_chunk_size(upload, 5), rx, 2));
+        tx.send(WriterCommand::Write(Bytes::from_static(b"partial"))).unwrap();
+        drop(tx);
+        assert!(handle.await.unwrap().is_err());
+        assert!(store.head(&dropped).await.is_err());
+    }
+}
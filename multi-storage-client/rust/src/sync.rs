@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use object_store::{path::Path, ObjectMeta, ObjectStore};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::fs;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::directory::DirectoryWalk;
+use crate::integrity;
+use crate::listing::{list_tree, ListOptions, ObjectFilter, Traversal};
+use crate::local_io::LocalIo;
+use crate::request_tag;
+use crate::retry::RetryPolicy;
+use crate::types::SyncResult;
+use crate::{download_local_file, StorageError, DEFAULT_POOL_CONNECTIONS};
+
+/// An object or local file on one side of a sync, keyed elsewhere by its path relative to the
+/// synced root.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyncEntry {
+    pub size: u64,
+    pub last_modified: DateTime<Utc>,
+    /// MD5 of the content when it is known without reading it, i.e. from a plain-MD5 ETag.
+    pub md5: Option<String>,
+}
+
+impl SyncEntry {
+    /// The entry of an object, taking its MD5 from its ETag only with `md5_etags`.
+    pub fn object(meta: &ObjectMeta, md5_etags: bool) -> Self {
+        SyncEntry {
+            size: meta.size,
+            last_modified: meta.last_modified,
+            md5: integrity::etag_md5(meta.e_tag.as_deref()).filter(|_| md5_etags),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Decision {
+    Skip,
+    Copy,
+    /// Same size and only one side's MD5 is known: the other side has to be hashed and compared.
+    CompareMd5(String),
+}
+
+/// Decides whether `source` has to be copied over `destination`: always when the sizes differ,
+/// by content when an MD5 is known, and otherwise when the destination is older than the source.
+pub fn compare(source: &SyncEntry, destination: Option<&SyncEntry>) -> Decision {
+    let Some(destination) = destination else {
+        return Decision::Copy;
+    };
+    if source.size != destination.size {
+        return Decision::Copy;
+    }
+    match (&source.md5, &destination.md5) {
+        (Some(source_md5), Some(destination_md5)) if source_md5 == destination_md5 => Decision::Skip,
+        (Some(_), Some(_)) => Decision::Copy,
+        (Some(md5), None) | (None, Some(md5)) => Decision::CompareMd5(md5.clone()),
+        (None, None) if destination.last_modified >= source.last_modified => Decision::Skip,
+        (None, None) => Decision::Copy,
+    }
+}
+
+pub struct SyncOptions {
+    pub delete_extraneous: bool,
+    pub dry_run: bool,
+    /// Objects in flight.
+    pub concurrency: usize,
+    pub chunksize: usize,
+    /// Chunks in flight for each object larger than `chunksize`.
+    pub chunk_concurrency: usize,
+    pub local_io: LocalIo,
+    pub retry_policy: RetryPolicy,
+    /// Whether ETags that look like an MD5 are the MD5 of the object.
+    pub md5_etags: bool,
+}
+
+/// Lists the regular files below `local_dir` with the errors of those that couldn't be read. A
+/// missing directory has no files.
+async fn list_local(local_dir: PathBuf) -> (BTreeMap<String, SyncEntry>, HashMap<String, String>) {
+    let mut files = BTreeMap::new();
+    let mut failed = HashMap::new();
+    if !fs::try_exists(&local_dir).await.unwrap_or(false) {
+        return (files, failed);
+    }
+    let mut walk = DirectoryWalk::new(local_dir, false);
+    while let Some(entry) = walk.next().await {
+        let (relative, metadata) = match entry {
+            Ok(entry) => (entry.relative, fs::metadata(&entry.path).await),
+            Err((relative, err)) => (relative, Err(err)),
+        };
+        match metadata.and_then(|metadata| Ok((metadata.len(), metadata.modified()?))) {
+            Ok((size, modified)) => {
+                let entry = SyncEntry {
+                    size,
+                    last_modified: modified.into(),
+                    md5: None,
+                };
+                files.insert(relative, entry);
+            }
+            Err(err) => {
+                failed.insert(relative, err.to_string());
+            }
+        }
+    }
+    (files, failed)
+}
+
+/// Downloads `meta` to `local_path` unless hashing the local file shows it is unchanged. Returns the
+/// bytes copied, or that would be with `dry_run`, and `None` if it is unchanged.
+async fn sync_object(
+    store: Arc<dyn ObjectStore>,
+    meta: ObjectMeta,
+    local_path: PathBuf,
+    decision: Decision,
+    options: Arc<SyncOptions>,
+) -> Result<Option<u64>, StorageError> {
+    if let Decision::CompareMd5(md5) = decision {
+        if integrity::file_md5(local_path.clone()).await? == md5 {
+            return Ok(None);
+        }
+    }
+    if options.dry_run {
+        return Ok(Some(meta.size));
+    }
+    let download = download_local_file(
+        &store,
+        &meta,
+        local_path,
+        options.chunksize,
+        options.chunk_concurrency,
+        options.local_io,
+        &options.retry_policy,
+        false,
+        options.md5_etags,
+    );
+    download.await
+}
+
+fn record_sync(
+    result: &mut SyncResult,
+    joined: Result<(String, Result<Option<u64>, StorageError>), tokio::task::JoinError>,
+) -> Result<(), StorageError> {
+    match joined.map_err(|e| StorageError::ObjectStoreError(format!("Sync task failed: {}", e)))? {
+        (relative, Ok(Some(size))) => {
+            result.copied.push(relative);
+            result.bytes_copied += size;
+        }
+        (_, Ok(None)) => result.unchanged += 1,
+        (relative, Err(err)) => {
+            result.failed.insert(relative, err.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Makes `local_dir` mirror the objects under `root`: new and changed objects are downloaded and,
+/// with `delete_extraneous`, local files that have no object are deleted. Both sides are listed
+/// concurrently before anything is copied. With `dry_run` the planned actions are returned and
+/// nothing is changed.
+pub async fn sync_to_local(
+    store: Arc<dyn ObjectStore>,
+    root: Path,
+    local_dir: PathBuf,
+    options: SyncOptions,
+) -> Result<SyncResult, StorageError> {
+    let list_options = ListOptions {
+        limit: None,
+        filter: ObjectFilter::default(),
+        max_depth: None,
+        max_concurrency: DEFAULT_POOL_CONNECTIONS,
+        traversal: Traversal::Bfs,
+        start_after: None,
+        descend: None,
+    };
+    let (listing, (mut local, failed)) =
+        tokio::join!(list_tree(&store, vec![root.clone()], &list_options), list_local(local_dir.clone()));
+    let listing = listing?;
+    let mut result = SyncResult {
+        failed,
+        dry_run: options.dry_run,
+        ..Default::default()
+    };
+
+    let options = Arc::new(options);
+    let semaphore = Arc::new(Semaphore::new(options.concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    for meta in listing.objects {
+        let key = meta.location.to_string();
+        let relative = key.strip_prefix(root.as_ref()).unwrap_or(&key).trim_start_matches('/').to_string();
+        if relative.is_empty() {
+            continue;
+        }
+        let decision = compare(&SyncEntry::object(&meta, options.md5_etags), local.remove(&relative).as_ref());
+        if decision == Decision::Skip {
+            result.unchanged += 1;
+            continue;
+        }
+
+        let local_path = local_dir.join(&relative);
+        let permit = Arc::clone(&semaphore).acquire_owned().await.unwrap();
+        let store = Arc::clone(&store);
+        let options = Arc::clone(&options);
+        tasks.spawn(request_tag::propagate(async move {
+            let outcome = sync_object(store, meta, local_path, decision, options).await;
+            drop(permit);
+            (relative, outcome)
+        }));
+        while let Some(joined) = tasks.try_join_next() {
+            record_sync(&mut result, joined)?;
+        }
+    }
+    while let Some(joined) = tasks.join_next().await {
+        record_sync(&mut result, joined)?;
+    }
+
+    // Whatever is left of the local listing has no object.
+    if options.delete_extraneous {
+        for relative in local.into_keys() {
+            if !options.dry_run {
+                if let Err(err) = fs::remove_file(local_dir.join(&relative)).await {
+                    result.failed.insert(relative, err.to_string());
+                    continue;
+                }
+            }
+            result.deleted.push(relative);
+        }
+    }
+    result.copied.sort();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use object_store::memory::InMemory;
+
+    fn entry(size: u64, age_secs: i64, md5: Option<&str>) -> SyncEntry {
+        SyncEntry {
+            size,
+            last_modified: DateTime::from_timestamp(1_700_000_000, 0).unwrap() - TimeDelta::seconds(age_secs),
+            md5: md5.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let source = entry(10, 0, None);
+        assert_eq!(compare(&source, None), Decision::Copy);
+        assert_eq!(compare(&source, Some(&entry(11, 0, None))), Decision::Copy);
+        assert_eq!(compare(&source, Some(&entry(10, 0, None))), Decision::Skip);
+        assert_eq!(compare(&source, Some(&entry(10, 60, None))), Decision::Copy);
+        assert_eq!(compare(&source, Some(&entry(10, 60, Some("a")))), Decision::CompareMd5("a".to_string()));
+
+        let source = entry(10, 0, Some("a"));
+        assert_eq!(compare(&source, Some(&entry(10, 60, None))), Decision::CompareMd5("a".to_string()));
+        assert_eq!(compare(&source, Some(&entry(10, 60, Some("a")))), Decision::Skip);
+        assert_eq!(compare(&source, Some(&entry(10, 0, Some("b")))), Decision::Copy);
+    }
+
+    fn options(delete_extraneous: bool, dry_run: bool) -> SyncOptions {
+        SyncOptions {
+            delete_extraneous,
+            dry_run,
+            concurrency: 4,
+            chunksize: 4,
+            chunk_concurrency: 2,
+            local_io: LocalIo::Tokio,
+            retry_policy: RetryPolicy::default(),
+            md5_etags: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_to_local() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        for (key, data) in [("data/a.bin", &b"aaaa"[..]), ("data/nested/b.bin", b"bbbbbbbbbb")] {
+            store.put(&Path::from(key), data.to_vec().into()).await.unwrap();
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let local_dir = dir.path().join("mirror");
+        std::fs::create_dir_all(&local_dir).unwrap();
+        std::fs::write(local_dir.join("stale.bin"), b"old").unwrap();
+
+        let root = Path::from("data");
+        let plan = sync_to_local(Arc::clone(&store), root.clone(), local_dir.clone(), options(true, true))
+            .await
+            .unwrap();
+        assert_eq!(plan.copied, ["a.bin", "nested/b.bin"]);
+        assert_eq!(plan.deleted, ["stale.bin"]);
+        assert_eq!(plan.bytes_copied, 14);
+        assert!(!local_dir.join("a.bin").exists());
+        assert!(local_dir.join("stale.bin").exists());
+
+        let result = sync_to_local(Arc::clone(&store), root.clone(), local_dir.clone(), options(true, false))
+            .await
+            .unwrap();
+        assert_eq!(result.copied, plan.copied);
+        assert_eq!(result.deleted, plan.deleted);
+        assert_eq!(std::fs::read(local_dir.join("nested/b.bin")).unwrap(), b"bbbbbbbbbb");
+        assert!(!local_dir.join("stale.bin").exists());
+
+        let result = sync_to_local(store, root, local_dir, options(true, false)).await.unwrap();
+        assert!(result.copied.is_empty() && result.deleted.is_empty());
+        assert_eq!(result.unchanged, 2);
+    }
+}
@@ -0,0 +1,469 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::{Method, StatusCode};
+use object_store::path::Path;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::PyDict;
+use std::cell::RefCell;
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::time::Instant;
+use tracing::field::Empty;
+use tracing::{Instrument, Span};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::failed_response;
+use crate::logging;
+use crate::metrics::{self, ErrorClass, Metrics};
+use crate::types::TransferStats;
+
+/// The `traceparent` context variable of the module, set by callers to parent the spans of the
+/// operations they start.
+static TRACEPARENT: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+/// Whether spans are exported: only when built with the `otel` feature and an OTLP endpoint is
+/// configured, decided on first use. Otherwise no spans are created.
+pub fn enabled() -> bool {
+    static ENABLED: LazyLock<bool> = LazyLock::new(install);
+    *ENABLED
+}
+
+/// Installs the process's subscriber, which forwards log events to Python and, if configured,
+/// exports spans. Returns whether spans will be exported.
+fn install() -> bool {
+    let registry = tracing_subscriber::registry().with(logging::PythonLogLayer);
+    #[cfg(feature = "otel")]
+    {
+        let tracer = otel::tracer();
+        let exported = tracer.is_some();
+        let layer = tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer));
+        registry.with(layer).try_init().is_ok() && exported
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        let _ = registry.try_init();
+        false
+    }
+}
+
+/// Adds the `traceparent` context variable and flushes the exported spans at exit.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("default", py.None())?;
+    let traceparent = py
+        .import("contextvars")?
+        .call_method("ContextVar", ("traceparent",), Some(&kwargs))?;
+    m.add("traceparent", &traceparent)?;
+    let _ = TRACEPARENT.set(py, traceparent.unbind());
+    m.add_function(wrap_pyfunction!(flush_traces, m)?)?;
+    py.import("atexit")?
+        .call_method1("register", (m.getattr("flush_traces")?,))?;
+    Ok(())
+}
+
+/// Requests an operation sent and chunks it transferred, counted from every task working on it.
+#[derive(Debug, Default)]
+struct Stats {
+    requests: AtomicU32,
+    bytes: AtomicU64,
+    chunks: AtomicU32,
+    chunks_in_flight: AtomicU32,
+    peak_chunks_in_flight: AtomicU32,
+    /// Total time the chunks took, in microseconds.
+    chunk_time_us: AtomicU64,
+    /// Microseconds from the start of the operation to its first response, plus one, or zero
+    /// before it.
+    first_byte_us: AtomicU64,
+}
+
+/// The span of a running operation, its request counts and the metrics of its client, carried
+/// into the tasks it spawns.
+#[derive(Clone, Debug)]
+pub struct Context {
+    span: Span,
+    stats: Arc<Stats>,
+    metrics: Arc<Metrics>,
+    started: Instant,
+}
+
+impl Context {
+    fn record_first_byte(&self) {
+        let elapsed = self.started.elapsed().as_micros() as u64 + 1;
+        let _ = self
+            .stats
+            .first_byte_us
+            .compare_exchange(0, elapsed, Ordering::Relaxed, Ordering::Relaxed);
+    }
+}
+
+tokio::task_local! {
+    static CONTEXT: Context;
+}
+
+/// An operation started by a Python method, with its span if traced, picked up by `instrument`
+/// when the method hands its future to the runtime.
+struct Pending {
+    id: u64,
+    span: Span,
+    key: Option<String>,
+    metrics: Arc<Metrics>,
+    operation: usize,
+    started: Instant,
+}
+
+thread_local! {
+    static PENDING: RefCell<Option<Pending>> = const { RefCell::new(None) };
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Held by the Python method that started an operation until it returns. If the method fails
+/// before handing its future to `instrument`, e.g. on an invalid argument, dropping it records the
+/// operation as rejected, so that it is counted and later futures on the thread don't pick it up.
+#[must_use]
+pub struct Started {
+    id: u64,
+}
+
+impl Drop for Started {
+    fn drop(&mut self) {
+        let pending = PENDING.with_borrow_mut(|pending| pending.take_if(|pending| pending.id == self.id));
+        if let Some(Pending {
+            span,
+            metrics,
+            operation,
+            started,
+            ..
+        }) = pending
+        {
+            metrics.record_operation(operation, started.elapsed(), Some(ErrorClass::Invalid));
+            span.record("otel.status_code", "error");
+        }
+    }
+}
+
+/// Starts an operation counted in `metrics`. When tracing is enabled, also opens its
+/// `msc.rust.<operation>` span, parented under the caller's trace: the `traceparent` context
+/// variable when set, or else the current span of the Python OpenTelemetry API, if installed.
+pub fn start(py: Python<'_>, operation: &str, provider: &str, bucket: Option<&str>, metrics: &Arc<Metrics>) -> Started {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    PENDING.set(Some(Pending {
+        id,
+        span: open_span(py, operation, provider, bucket),
+        key: None,
+        metrics: Arc::clone(metrics),
+        operation: metrics::operation_index(operation),
+        started: Instant::now(),
+    }));
+    Started { id }
+}
+
+#[cfg_attr(not(feature = "otel"), allow(unused_variables))]
+fn open_span(py: Python<'_>, operation: &str, provider: &str, bucket: Option<&str>) -> Span {
+    if !enabled() {
+        return Span::none();
+    }
+    #[cfg(feature = "otel")]
+    let _parent = otel::attach_parent(py);
+    let name = format!("msc.rust.{}", operation);
+    tracing::info_span!(
+        "msc.rust",
+        otel.name = name.as_str(),
+        otel.kind = "client",
+        otel.status_code = Empty,
+        msc.provider = provider,
+        msc.bucket = bucket,
+        msc.key = Empty,
+        msc.bytes = Empty,
+        msc.chunks = Empty,
+    )
+}
+
+/// Records the first key the pending operation parses as the one it works on.
+pub fn record_key(key: &Path) {
+    PENDING.with_borrow_mut(|pending| {
+        if let Some(pending) = pending.as_mut().filter(|pending| pending.key.is_none()) {
+            pending.span.record("msc.key", key.as_ref());
+            pending.key = Some(key.to_string());
+        }
+    });
+}
+
+/// Output of an operation, which tells whether it failed and how.
+pub trait Outcome {
+    /// The class and description of the error the operation failed with, if it did.
+    fn failure(&self) -> Option<(ErrorClass, String)>;
+}
+
+impl<T> Outcome for PyResult<T> {
+    fn failure(&self) -> Option<(ErrorClass, String)> {
+        let err = self.as_ref().err()?;
+        let failure = Python::try_attach(|py| (ErrorClass::of(py, err), logging::describe(py, err)));
+        Some(failure.unwrap_or((ErrorClass::Other, String::new())))
+    }
+}
+
+/// Operations that report their errors elsewhere, like a listing feeding an iterator.
+impl Outcome for () {
+    fn failure(&self) -> Option<(ErrorClass, String)> {
+        None
+    }
+}
+
+/// Runs `fut` as the operation begun by the last `start` on this thread, if any. Once `fut`
+/// completes, its latency and outcome are added to the client's metrics and, with its byte and
+/// request counts, recorded on its span. A failure is also logged.
+pub fn instrument<F>(fut: F) -> impl Future<Output = F::Output>
+where
+    F: Future,
+    F::Output: Outcome,
+{
+    let pending = PENDING.take();
+    async move {
+        let Some(Pending {
+            id: _,
+            span,
+            key,
+            metrics,
+            operation,
+            started,
+        }) = pending
+        else {
+            return fut.await;
+        };
+        let context = Context {
+            span: span.clone(),
+            stats: Arc::default(),
+            metrics: Arc::clone(&metrics),
+            started,
+        };
+        let stats = Arc::clone(&context.stats);
+        let result = CONTEXT.scope(context, fut.instrument(span.clone())).await;
+        let failure = result.failure();
+        let duration = started.elapsed();
+        metrics.record_operation(operation, duration, failure.as_ref().map(|(class, _)| *class));
+        span.record("msc.chunks", stats.requests.load(Ordering::Relaxed));
+        span.record("msc.bytes", stats.bytes.load(Ordering::Relaxed));
+        span.record("otel.status_code", if failure.is_none() { "ok" } else { "error" });
+        if let Some((class, error)) = failure {
+            let operation = metrics::operation_name(operation);
+            logging::operation_failed(operation, key.as_deref(), duration, class, &error);
+        }
+        result
+    }
+}
+
+/// Returns the context of the operation running on the current task, if any.
+pub fn current() -> Option<Context> {
+    CONTEXT.try_with(Context::clone).ok()
+}
+
+/// Runs `fut` as part of the operation `context`.
+pub async fn scoped<F: Future>(context: Option<Context>, fut: F) -> F::Output {
+    match context {
+        Some(context) => {
+            let span = context.span.clone();
+            CONTEXT.scope(context, fut.instrument(span)).await
+        }
+        None => fut.await,
+    }
+}
+
+/// Records a request of the current operation in its client's metrics, and an event for it on the
+/// operation's span. `status` is `None` for a request that got no response.
+pub fn record_request(method: &Method, status: Option<StatusCode>, sent: u64, received: u64) {
+    let _ = CONTEXT.try_with(|context| {
+        let bytes = sent + received;
+        context.metrics.record_bytes(sent, received);
+        context.stats.requests.fetch_add(1, Ordering::Relaxed);
+        context.stats.bytes.fetch_add(bytes, Ordering::Relaxed);
+        if status.is_some() {
+            context.record_first_byte();
+        }
+        tracing::info!(
+            name: "msc.rust.request",
+            parent: &context.span,
+            http.request.method = %method,
+            http.response.status_code = status.map(|status| status.as_u16()),
+            msc.bytes = bytes,
+        );
+    });
+}
+
+/// Runs `fut`, one part or range of the current operation's transfer, counting it in the stats
+/// returned with `return_stats`.
+pub fn chunk<F: Future>(fut: F) -> impl Future<Output = F::Output> {
+    async move {
+        let Some(context) = current() else {
+            return fut.await;
+        };
+        let stats = &context.stats;
+        let in_flight = stats.chunks_in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+        stats.peak_chunks_in_flight.fetch_max(in_flight, Ordering::Relaxed);
+        let started = Instant::now();
+        let output = fut.await;
+        stats.chunks_in_flight.fetch_sub(1, Ordering::Relaxed);
+        stats.chunks.fetch_add(1, Ordering::Relaxed);
+        stats
+            .chunk_time_us
+            .fetch_add(started.elapsed().as_micros() as u64, Ordering::Relaxed);
+        context.record_first_byte();
+        output
+    }
+}
+
+/// Stats of the transfer the current operation made so far. One that transferred no chunks sent
+/// the object in a single request, counted as one chunk.
+pub fn transfer_stats(bytes_transferred: u64) -> TransferStats {
+    let Some(context) = current() else {
+        return TransferStats {
+            bytes_transferred,
+            ..Default::default()
+        };
+    };
+    let stats = &context.stats;
+    let wall_time = context.started.elapsed().as_secs_f64();
+    let chunks = stats.chunks.load(Ordering::Relaxed);
+    let (chunks, peak_concurrency, effective_concurrency) = match chunks {
+        0 => (1, 1, 1.0),
+        chunks => {
+            let chunk_time = stats.chunk_time_us.load(Ordering::Relaxed) as f64 / 1e6;
+            let effective_concurrency = if wall_time > 0.0 { chunk_time / wall_time } else { 1.0 };
+            (
+                chunks,
+                stats.peak_chunks_in_flight.load(Ordering::Relaxed),
+                effective_concurrency,
+            )
+        }
+    };
+    let first_byte_us = stats.first_byte_us.load(Ordering::Relaxed);
+    TransferStats {
+        bytes_transferred,
+        chunks,
+        peak_concurrency,
+        effective_concurrency,
+        wall_time,
+        time_to_first_byte: (first_byte_us > 0).then(|| (first_byte_us - 1) as f64 / 1e6),
+        retry_count: failed_response::failures(),
+        uncompressed_size: None,
+    }
+}
+
+/// Exports the spans that are still buffered.
+#[pyfunction]
+#[cfg_attr(not(feature = "otel"), allow(unused_variables))]
+pub fn flush_traces(py: Python<'_>) {
+    #[cfg(feature = "otel")]
+    py.detach(otel::flush);
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use opentelemetry::propagation::TextMapPropagator;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::ContextGuard;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+    use std::collections::HashMap;
+    use std::sync::OnceLock;
+
+    const ENDPOINT_VARIABLES: [&str; 2] = ["OTEL_EXPORTER_OTLP_ENDPOINT", "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT"];
+
+    static PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+    /// Sets up an OTLP exporter, configured by the standard `OTEL_*` variables, when an endpoint
+    /// is set and `OTEL_SDK_DISABLED` isn't, and returns the tracer exporting through it.
+    pub fn tracer() -> Option<SdkTracer> {
+        let configured = ENDPOINT_VARIABLES
+            .iter()
+            .any(|name| std::env::var_os(name).is_some_and(|value| !value.is_empty()));
+        let disabled = std::env::var("OTEL_SDK_DISABLED").is_ok_and(|value| value.eq_ignore_ascii_case("true"));
+        if !configured || disabled {
+            return None;
+        }
+        let exporter = opentelemetry_otlp::SpanExporter::builder().with_http().build().ok()?;
+        let provider = SdkTracerProvider::builder().with_batch_exporter(exporter).build();
+        let tracer = provider.tracer("multistorageclient_rust");
+        PROVIDER.set(provider).ok()?;
+        Some(tracer)
+    }
+
+    pub fn flush() {
+        if let Some(provider) = PROVIDER.get() {
+            let _ = provider.force_flush();
+        }
+    }
+
+    /// Makes the caller's trace context current while the operation's span is created.
+    pub fn attach_parent(py: Python<'_>) -> Option<ContextGuard> {
+        let traceparent = traceparent(py).ok().flatten()?;
+        let carrier = HashMap::from([("traceparent".to_string(), traceparent)]);
+        Some(TraceContextPropagator::new().extract(&carrier).attach())
+    }
+
+    fn traceparent(py: Python<'_>) -> PyResult<Option<String>> {
+        if let Some(traceparent) = super::TRACEPARENT.get(py) {
+            if let Some(traceparent) = traceparent.bind(py).call_method0("get")?.extract::<Option<String>>()? {
+                return Ok(Some(traceparent));
+            }
+        }
+        let Ok(propagate) = py.import("opentelemetry.propagate") else {
+            return Ok(None);
+        };
+        let carrier = PyDict::new(py);
+        propagate.call_method1("inject", (&carrier,))?;
+        carrier
+            .get_item("traceparent")?
+            .map(|value| value.extract())
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_requests_counted_across_tasks() {
+        let context = Context {
+            span: Span::none(),
+            stats: Arc::default(),
+            metrics: Arc::default(),
+            started: Instant::now(),
+        };
+        let stats = Arc::clone(&context.stats);
+        scoped(Some(context), async {
+            record_request(&Method::GET, Some(StatusCode::OK), 0, 10);
+            tokio::spawn(scoped(current(), async { record_request(&Method::PUT, None, 5, 0) }))
+                .await
+                .unwrap();
+        })
+        .await;
+        record_request(&Method::GET, Some(StatusCode::OK), 0, 1);
+        assert_eq!(stats.requests.load(Ordering::Relaxed), 2);
+        assert_eq!(stats.bytes.load(Ordering::Relaxed), 15);
+
+        // Without an operation from `start`, futures run as they are.
+        assert_eq!(instrument(async { PyResult::Ok(1) }).await.unwrap(), 1);
+        assert!(current().is_none());
+    }
+}
@@ -13,49 +13,519 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::{DateTime, Utc};
+use object_store::{Attribute, Attributes, ObjectMeta};
+use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PySlice, PyTuple};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 
-#[pyclass(from_py_object, get_all, set_all)]
-#[derive(Clone, Debug, Default)]
+use crate::checksum;
+use crate::failed_response;
+use crate::listing::Usage;
+use crate::telemetry;
+
+#[pyclass(module = "multistorageclient_rust", from_py_object, get_all, set_all)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ObjectMetadata {
     pub key: String,
     pub content_length: u64,
-    pub last_modified: String,
+    pub last_modified: DateTime<Utc>,
     pub object_type: String,
     pub etag: Option<String>,
+    pub version: Option<String>,
+    /// Storage class reported by `info`, where the provider reports one.
+    pub storage_class: Option<String>,
+    /// Content type reported by `info`.
+    pub content_type: Option<String>,
+    /// User-defined metadata reported by `info`.
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl ObjectMetadata {
+    /// Adds the content type and user-defined metadata of the object's `attributes`.
+    pub fn with_attributes(mut self, attributes: &Attributes) -> Self {
+        self.content_type = attributes.get(&Attribute::ContentType).map(|value| value.to_string());
+        let metadata: HashMap<String, String> = attributes
+            .iter()
+            .filter_map(|(attribute, value)| match attribute {
+                Attribute::Metadata(key) => Some((key.to_string(), value.to_string())),
+                _ => None,
+            })
+            .collect();
+        self.metadata = (!metadata.is_empty()).then_some(metadata);
+        self
+    }
 }
 
+#[pymethods]
 impl ObjectMetadata {
-    pub fn new(
+    #[new]
+    #[pyo3(signature = (key, content_length, last_modified, object_type="file", etag=None, version=None, storage_class=None, content_type=None, metadata=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
         key: String,
         content_length: u64,
-        last_modified: String,
-        object_type: String,
+        last_modified: DateTime<Utc>,
+        object_type: &str,
         etag: Option<String>,
+        version: Option<String>,
+        storage_class: Option<String>,
+        content_type: Option<String>,
+        metadata: Option<HashMap<String, String>>,
     ) -> Self {
         Self {
             key,
             content_length,
             last_modified,
-            object_type,
+            object_type: object_type.to_string(),
             etag,
+            version,
+            storage_class,
+            content_type,
+            metadata,
+        }
+    }
+
+    /// `last_modified` in RFC 3339 format, as it was returned before it became a datetime.
+    #[getter]
+    fn last_modified_str(&self) -> String {
+        self.last_modified.to_rfc3339()
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let metadata = PyDict::new(py);
+        metadata.set_item("key", &self.key)?;
+        metadata.set_item("content_length", self.content_length)?;
+        metadata.set_item("last_modified", self.last_modified)?;
+        metadata.set_item("object_type", &self.object_type)?;
+        metadata.set_item("etag", &self.etag)?;
+        metadata.set_item("version", &self.version)?;
+        metadata.set_item("storage_class", &self.storage_class)?;
+        metadata.set_item("content_type", &self.content_type)?;
+        metadata.set_item("metadata", &self.metadata)?;
+        Ok(metadata)
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let fields = self
+            .to_dict(py)?
+            .iter()
+            .map(|(name, value)| Ok(format!("{}={}", name, value.repr()?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(format!("ObjectMetadata({})", fields.join(", ")))
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Hashes the fields identifying the object's content, which equal objects share.
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (&self.key, &self.etag, &self.version).hash(&mut hasher);
+        (self.content_length, self.last_modified).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyTuple>)> {
+        let metadata = slf.borrow();
+        let args = (
+            metadata.key.clone(),
+            metadata.content_length,
+            metadata.last_modified,
+            metadata.object_type.clone(),
+            metadata.etag.clone(),
+            metadata.version.clone(),
+            metadata.storage_class.clone(),
+            metadata.content_type.clone(),
+            metadata.metadata.clone(),
+        );
+        Ok((slf.get_type().into_any(), args.into_pyobject(slf.py())?))
+    }
+}
+
+impl From<ObjectMeta> for ObjectMetadata {
+    fn from(meta: ObjectMeta) -> Self {
+        ObjectMetadata {
+            key: meta.location.to_string(),
+            content_length: meta.size,
+            last_modified: meta.last_modified,
+            object_type: "file".to_string(),
+            etag: meta.e_tag,
+            version: meta.version,
+            ..Default::default()
         }
     }
 }
 
+/// Traversal statistics of a recursive listing.
+#[pyclass(from_py_object, get_all, set_all)]
+#[derive(Clone, Debug, Default)]
+pub struct ListStats {
+    pub directories_listed: u64,
+    /// Largest number of directories waiting to be listed at any one time.
+    pub max_pending_directories: u64,
+}
+
 #[pyclass(from_py_object, get_all, set_all)]
 #[derive(Clone, Debug, Default)]
 pub struct ListResult {
     pub objects: Vec<ObjectMetadata>,
     pub prefixes: Vec<ObjectMetadata>,
+    pub stats: Option<ListStats>,
+    /// Key of the last object returned under a `limit`, to pass as `start_after` to resume.
+    pub last_key: Option<String>,
 }
 
 impl ListResult {
     pub fn new(objects: Vec<ObjectMetadata>, prefixes: Vec<ObjectMetadata>) -> Self {
-        Self { objects, prefixes }
+        Self {
+            objects,
+            prefixes,
+            stats: None,
+            last_key: None,
+        }
+    }
+
+    pub fn with_stats(mut self, stats: ListStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// The `index`th entry, counting the objects before the prefixes.
+    fn entry(&self, index: usize) -> Option<&ObjectMetadata> {
+        match index.checked_sub(self.objects.len()) {
+            None => self.objects.get(index),
+            Some(index) => self.prefixes.get(index),
+        }
+    }
+}
+
+#[pymethods]
+impl ListResult {
+    fn __len__(&self) -> usize {
+        self.objects.len() + self.prefixes.len()
+    }
+
+    fn __iter__(slf: Bound<'_, Self>) -> ListResultIterator {
+        ListResultIterator {
+            result: slf.unbind(),
+            index: 0,
+        }
+    }
+
+    /// Returns the entry at an index, or a list of the entries of a slice.
+    fn __getitem__<'py>(&self, index: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = index.py();
+        if let Ok(slice) = index.cast::<PySlice>() {
+            let indices = slice.indices(self.__len__() as isize)?;
+            let entries: Vec<ObjectMetadata> = (0..indices.slicelength)
+                .map(|i| indices.start + i as isize * indices.step)
+                .filter_map(|index| self.entry(index as usize).cloned())
+                .collect();
+            return Ok(entries.into_pyobject(py)?.into_any());
+        }
+        let index: isize = index.extract()?;
+        let index = if index < 0 {
+            index + self.__len__() as isize
+        } else {
+            index
+        };
+        let entry = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.entry(index))
+            .ok_or_else(|| PyIndexError::new_err("ListResult index out of range"))?;
+        Ok(Py::new(py, entry.clone())?.into_bound(py).into_any())
+    }
+
+    /// Keys of the objects, without the metadata or the prefixes.
+    fn keys(&self) -> Vec<String> {
+        self.objects.iter().map(|object| object.key.clone()).collect()
+    }
+
+    /// The entries as dicts, in the order they are iterated in, e.g. to serialize as JSON.
+    fn to_dicts<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let entries = self.objects.iter().chain(&self.prefixes);
+        entries.map(|entry| entry.to_dict(py)).collect()
+    }
+}
+
+/// Iterator over the entries of a `ListResult`, which converts each entry as it is reached.
+#[pyclass(module = "multistorageclient_rust")]
+pub struct ListResultIterator {
+    result: Py<ListResult>,
+    index: usize,
+}
+
+#[pymethods]
+impl ListResultIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<ObjectMetadata> {
+        let entry = self.result.borrow(py).entry(self.index).cloned();
+        self.index += 1;
+        entry
+    }
+}
+
+/// Totals of the objects under a prefix, as returned by `du`.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct UsageSummary {
+    pub object_count: u64,
+    pub total_bytes: u64,
+    pub largest_object: Option<ObjectMetadata>,
+    /// Most recent `last_modified` of the objects, in RFC 3339 format.
+    pub latest_modified: Option<String>,
+    pub stats: Option<ListStats>,
+}
+
+impl From<Usage> for UsageSummary {
+    fn from(usage: Usage) -> Self {
+        Self {
+            object_count: usage.object_count,
+            total_bytes: usage.total_bytes,
+            largest_object: usage.largest_object.map(ObjectMetadata::from),
+            latest_modified: usage.latest_modified.map(|latest| latest.to_rfc3339()),
+            stats: None,
+        }
+    }
+}
+
+/// One part of a multipart upload.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct PartInfo {
+    pub part_number: u32,
+    pub size: u64,
+    /// Not reported by the multipart writer, so currently always `None`.
+    pub etag: Option<String>,
+}
+
+/// Bytes written by an upload and what the store reported for the resulting object.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct TransferResult {
+    pub bytes_transferred: u64,
+    pub etag: Option<String>,
+    pub version_id: Option<String>,
+    /// Parts of a multipart upload in order, or `None` when the object was written in one request.
+    pub parts: Option<Vec<PartInfo>>,
+    /// Server time of the response that completed the upload, in RFC 3339 format, or `None` if the
+    /// store didn't report one.
+    pub last_modified: Option<String>,
+    /// How a verified download was checked, `"md5"` or `"size"`, or `None` if it wasn't.
+    pub verification: Option<String>,
+    /// Requests of the transfer that failed and were retried.
+    pub retry_count: u32,
+    /// Algorithm of `checksum`, from the `checksum_algorithm` config, or `None` if none is configured.
+    pub checksum_algorithm: Option<String>,
+    /// Verified checksum of the uploaded object: base64 for crc32c and sha256, hex for md5. For
+    /// multipart sha256 and md5 uploads it's the composite `<digest of part digests>-<parts>`.
+    pub checksum: Option<String>,
+    /// The object as reported by the `head` of a verified upload, or `None` if it wasn't verified.
+    pub metadata: Option<ObjectMetadata>,
+    /// Size of the data before it was compressed with `compress`, in which case `bytes_transferred`
+    /// is the compressed size, or `None` if it wasn't compressed.
+    pub uncompressed_size: Option<u64>,
+}
+
+impl TransferResult {
+    pub fn new(bytes_transferred: u64, put_result: object_store::PutResult) -> Self {
+        Self {
+            bytes_transferred,
+            etag: put_result.e_tag.filter(|etag| !etag.is_empty()),
+            version_id: put_result.version.filter(|version| !version.is_empty()),
+            parts: None,
+            last_modified: None,
+            verification: None,
+            retry_count: 0,
+            checksum_algorithm: None,
+            checksum: None,
+            metadata: None,
+            uncompressed_size: None,
+        }
+    }
+
+    pub fn with_last_modified(mut self, server_time: Option<DateTime<Utc>>) -> Self {
+        self.last_modified = server_time.map(|server_time| server_time.to_rfc3339());
+        self
+    }
+
+    pub fn with_uncompressed_size(mut self, uncompressed_size: u64) -> Self {
+        self.uncompressed_size = Some(uncompressed_size);
+        self
+    }
+
+    pub fn with_metadata(mut self, meta: ObjectMeta) -> Self {
+        self.metadata = Some(meta.into());
+        self
+    }
+
+    /// Records the `chunksize` parts that `bytes_transferred` bytes were uploaded in.
+    pub fn with_parts(mut self, chunksize: usize) -> Self {
+        let chunksize = chunksize as u64;
+        let parts = (0..self.bytes_transferred.div_ceil(chunksize))
+            .map(|index| PartInfo {
+                part_number: index as u32 + 1,
+                size: chunksize.min(self.bytes_transferred - index * chunksize),
+                etag: None,
+            })
+            .collect();
+        self.parts = Some(parts);
+        self
+    }
+
+    /// Converts to this result when `detailed`, to the transfer's stats with `return_stats`, or to
+    /// the plain byte count returned by default. Called from the operation's task, which is where
+    /// its retries and chunks are counted.
+    pub fn into_py_result(mut self, py: Python<'_>, detailed: bool, return_stats: bool) -> PyResult<Py<PyAny>> {
+        if return_stats {
+            let mut stats = telemetry::transfer_stats(self.bytes_transferred);
+            stats.uncompressed_size = self.uncompressed_size;
+            Ok(Py::new(py, stats)?.into_any())
+        } else if detailed {
+            self.retry_count = failed_response::failures();
+            if let Some(checksum) = checksum::latest() {
+                self.checksum_algorithm = Some(checksum.algorithm.to_string());
+                self.checksum = Some(checksum.value);
+            }
+            Ok(Py::new(py, self)?.into_any())
+        } else {
+            Ok(self.bytes_transferred.into_pyobject(py)?.into_any().unbind())
+        }
     }
 }
 
+/// How a multipart transfer went, returned instead of the byte count with `return_stats`.
+#[pyclass(module = "multistorageclient_rust", from_py_object, get_all, eq)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferStats {
+    pub bytes_transferred: u64,
+    /// Parts or ranges transferred, or 1 when the object was transferred in a single request.
+    pub chunks: u32,
+    /// Most chunks in flight at once.
+    pub peak_concurrency: u32,
+    /// Chunks in flight on average: the time they took in total divided by `wall_time`.
+    pub effective_concurrency: f64,
+    /// Seconds from the call until the transfer finished.
+    pub wall_time: f64,
+    /// Seconds from the call until the store first answered, or `None` if no request was answered.
+    pub time_to_first_byte: Option<f64>,
+    /// Requests of the transfer that failed and were retried.
+    pub retry_count: u32,
+    /// Size of the data before it was compressed with `compress`, in which case `bytes_transferred`
+    /// is the compressed size, or `None` if it wasn't compressed.
+    pub uncompressed_size: Option<u64>,
+}
+
+#[pymethods]
+impl TransferStats {
+    #[new]
+    #[pyo3(signature = (bytes_transferred=0, chunks=0, peak_concurrency=0, effective_concurrency=0.0, wall_time=0.0, time_to_first_byte=None, retry_count=0, uncompressed_size=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        bytes_transferred: u64,
+        chunks: u32,
+        peak_concurrency: u32,
+        effective_concurrency: f64,
+        wall_time: f64,
+        time_to_first_byte: Option<f64>,
+        retry_count: u32,
+        uncompressed_size: Option<u64>,
+    ) -> Self {
+        Self {
+            bytes_transferred,
+            chunks,
+            peak_concurrency,
+            effective_concurrency,
+            wall_time,
+            time_to_first_byte,
+            retry_count,
+            uncompressed_size,
+        }
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = PyDict::new(py);
+        stats.set_item("bytes_transferred", self.bytes_transferred)?;
+        stats.set_item("chunks", self.chunks)?;
+        stats.set_item("peak_concurrency", self.peak_concurrency)?;
+        stats.set_item("effective_concurrency", self.effective_concurrency)?;
+        stats.set_item("wall_time", self.wall_time)?;
+        stats.set_item("time_to_first_byte", self.time_to_first_byte)?;
+        stats.set_item("retry_count", self.retry_count)?;
+        stats.set_item("uncompressed_size", self.uncompressed_size)?;
+        Ok(stats)
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyTuple>)> {
+        let stats = slf.borrow();
+        let args = (
+            stats.bytes_transferred,
+            stats.chunks,
+            stats.peak_concurrency,
+            stats.effective_concurrency,
+            stats.wall_time,
+            stats.time_to_first_byte,
+            stats.retry_count,
+            stats.uncompressed_size,
+        );
+        Ok((slf.get_type().into_any(), args.into_pyobject(slf.py())?))
+    }
+}
+
+/// Per-key outcome of a `delete_many` batch.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct DeleteResult {
+    pub deleted: Vec<String>,
+    /// Error message of each key that could not be deleted.
+    pub failed: HashMap<String, String>,
+}
+
+/// Outcome of an `upload_directory` call.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct DirectoryUploadResult {
+    pub files_uploaded: u64,
+    pub bytes_uploaded: u64,
+    /// Error message of each file, by path relative to the directory, that could not be uploaded.
+    pub failed: HashMap<String, String>,
+}
+
+/// Outcome of a `download_directory` call.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct DirectoryDownloadResult {
+    pub files_downloaded: u64,
+    pub bytes_downloaded: u64,
+    /// Objects left alone because `skip_existing` found a matching local copy.
+    pub files_skipped: u64,
+    /// Error message of each key that could not be downloaded.
+    pub failed: HashMap<String, String>,
+}
+
+/// Outcome of a `sync` call, or with `dry_run` the actions it would take.
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct SyncResult {
+    /// Relative paths of the new or changed objects copied, sorted.
+    pub copied: Vec<String>,
+    /// Relative paths of the extraneous files deleted.
+    pub deleted: Vec<String>,
+    pub unchanged: u64,
+    pub bytes_copied: u64,
+    /// Error message of each relative path that could not be compared, copied or deleted.
+    pub failed: HashMap<String, String>,
+    pub dry_run: bool,
+}
+
 #[derive(FromPyObject)]
 pub struct ByteRangeLike {
     #[pyo3(attribute)]
@@ -94,3 +564,30 @@ impl RustRetryConfig {
         }
     }
 }
+
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct BenchmarkPhaseStats {
+    pub num_objects: usize,
+    pub total_bytes: u64,
+    pub elapsed_secs: f64,
+    pub throughput_mb_per_sec: f64,
+    pub objects_per_sec: f64,
+    pub mean_object_throughput_mb_per_sec: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p90_ms: f64,
+    pub latency_p99_ms: f64,
+    pub latency_max_ms: f64,
+}
+
+#[pyclass(from_py_object, get_all)]
+#[derive(Clone, Debug, Default)]
+pub struct BenchmarkReport {
+    pub prefix: String,
+    pub direction: String,
+    pub read_only: bool,
+    pub object_size: u64,
+    pub concurrency: usize,
+    pub write: Option<BenchmarkPhaseStats>,
+    pub read: Option<BenchmarkPhaseStats>,
+}
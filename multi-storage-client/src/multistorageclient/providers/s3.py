@@ -26,7 +26,6 @@ from boto3.s3.transfer import TransferConfig
 from botocore.credentials import RefreshableCredentials
 from botocore.exceptions import ClientError, IncompleteReadError, ReadTimeoutError, ResponseStreamingError
 from botocore.session import get_session
-from dateutil.parser import parse as dateutil_parse
 
 from multistorageclient_rust import RustClient, RustClientError, RustRetryableError
 
@@ -234,6 +233,7 @@ class S3StorageProvider(BaseStorageProvider):
         self._checksum_algorithm: Optional[str] = self._validate_checksum_algorithm(kwargs.get("checksum_algorithm"))
 
         self._rust_client = None
+        self._rust_request_payer = False
         if "rust_client" in kwargs:
             # Inherit the rust client options from the kwargs
             rust_client_options = kwargs["rust_client"]
@@ -252,16 +252,17 @@ class S3StorageProvider(BaseStorageProvider):
             if self._signature_version == "UNSIGNED":
                 rust_client_options["skip_signature"] = True
 
-            # Rust client only supports SHA256 today (object_store limitation).
+            # Rust client sends SHA256 checksums (object_store limitation) and checks MD5 against the ETag.
             rust_checksum = rust_client_options.get("checksum_algorithm")
             if rust_checksum is not None:
-                if str(rust_checksum).upper() != "SHA256":
+                if str(rust_checksum).upper() not in ("SHA256", "MD5"):
                     raise ValueError(
-                        f"Rust client only supports checksum_algorithm='SHA256' today "
+                        f"Rust client only supports checksum_algorithm='SHA256' or 'MD5' today "
                         f"(object_store limitation), got '{rust_checksum!r}'. "
-                        f"Disable rust_client or use SHA256."
+                        f"Disable rust_client or use SHA256 or MD5."
                     )
-                rust_client_options["checksum_algorithm"] = "sha256"
+                rust_client_options["checksum_algorithm"] = str(rust_checksum).lower()
+            self._rust_request_payer = str(rust_client_options.get("request_payer", False)).lower() == "true"
             self._rust_client = self._create_rust_client(rust_client_options)
 
     @staticmethod
@@ -478,8 +479,11 @@ class S3StorageProvider(BaseStorageProvider):
             if status_code == 404:
                 raise FileNotFoundError(f"Object {bucket}/{key} does not exist. {message}") from error
             elif status_code == 403:
+                hint = ""
+                if self._rust_client and not self._rust_request_payer:
+                    hint = " If the bucket is requester-pays, set request_payer=True."
                 raise PermissionError(
-                    f"Permission denied to {operation} object(s) at {bucket}/{key}. {message}"
+                    f"Permission denied to {operation} object(s) at {bucket}/{key}. {message}{hint}"
                 ) from error
             else:
                 raise RetryableError(
@@ -886,7 +890,7 @@ class S3StorageProvider(BaseStorageProvider):
                 yield ObjectMetadata(
                     key=relative_key,
                     content_length=obj.content_length,
-                    last_modified=dateutil_parse(obj.last_modified),
+                    last_modified=obj.last_modified,
                     type="file" if obj.object_type == "object" else obj.object_type,
                     etag=obj.etag,
                 )
@@ -13,13 +13,19 @@
 # See the License for the specific language governing permissions and
 # limitations under the License.
 
-from typing import Any
+from collections.abc import AsyncIterable, Awaitable, Callable, Iterator
+from contextvars import ContextVar
+from datetime import datetime
+from typing import Any, Generator, Literal, overload
 
 from multistorageclient.types import Range
 
 class RustClient:
     """
     RustClient provides asynchronous methods for interacting with an object storage backend (e.g., S3).
+
+    A client can be used in a forked child, such as a ``DataLoader`` worker: its first call there
+    reconnects instead of reusing the parent's connections.
     """
     def __init__(
         self,
@@ -27,59 +33,548 @@ class RustClient:
         configs: dict | None = ...,
         credentials_provider: Any | None = ...,
         retry: RustRetryConfig | None = ...,
+        request_tag_generator: Callable[[str], str | None] | None = ...,
     ) -> None:
         """
         Initialize a RustClient instance.
-        :param provider: The storage provider type (default: 's3').
+        :param provider: The storage provider type (default: 's3'). ``file`` stores objects as files under the ``base_path`` config;
+            ``http`` reads objects relative to the ``url`` config and rejects writes.
         :param configs: Configuration dictionary for the provider (e.g., bucket, endpoint_url).
             Supported config keys:
-            - bucket: Bucket name for the storage provider
-            - endpoint_url: Custom endpoint URL
+            - base_path: Directory holding the objects of the ``file`` provider, created if missing
+            - bucket: Bucket name for the storage provider, or for S3 a regional access point ARN (``arn:aws:s3:<region>:<account>:accesspoint/<name>``); Multi-Region Access Points are not supported
+            - endpoint_url: Custom endpoint URL. For S3, a list or comma-separated string of endpoints of the same bucket fails
+              over to the next endpoint on connection errors and exhausted retries, and sticks to the last one that answered
+            - url: Base URL of the ``http`` provider
             - region_name: AWS region name (S3 only)
             - allow_http: Allow HTTP connections (default: False)
+            - ca_certificate_path: PEM bundle of CA certificates trusted in addition to the system roots, e.g. an internal CA;
+              read when the client is created (default: None)
+            - ca_certificate_pem: Same as ``ca_certificate_path``, given as the PEM text (default: None)
+            - default_headers: Dict of headers sent with every request, e.g. for routing or auditing; visible ASCII only, and
+              ``authorization``, ``content-length``, ``host``, ``user-agent`` and ``x-amz-*``/``x-goog-*`` headers are rejected (default: None)
+            - user_agent: Appended to the ``User-Agent``, which always names object_store and the multi-storage-client version (default: None)
+            - allow_invalid_certificates: Skip TLS certificate and host name verification, for test environments only;
+              logs a warning when enabled (default: False)
             - skip_signature: Skip request signing for public buckets (default: False)
+            - anonymous: Skip request signing and reject writes before they are sent, S3 only; can't be combined with
+              credentials (default: False). Without either, an S3 client that finds no credentials raises ``ValueError``
+            - addressing_style: "path" or "virtual"; with "virtual" a custom ``endpoint_url`` must already name the bucket
+              (default: "path", "virtual" for access points)
+            - force_path_style: Alias of ``addressing_style="path"`` when True (default: False)
+            - use_default_credentials: Resolve S3 credentials from the ``AWS_*`` environment variables, web identity or the instance profile,
+              refreshed internally; can't be combined with ``credentials_provider`` (default: False)
             - max_concurrency: Maximum concurrent operations (default: 8)
             - multipart_chunksize: Chunk size for multipart operations (default: 32MB)
             - connect_timeout: Connection timeout in seconds (default: 60)
             - read_timeout: Read timeout in seconds (default: 120)
-            - checksum_algorithm: Upload-only object integrity checksum, S3 only (default: None, only "sha256" is supported)
-        :param credentials_provider: Credentials provider for the provider (e.g., StaticS3CredentialsProvider).
+            - pool_idle_timeout: Seconds an idle pooled connection is kept (default: 30)
+            - pool_max_idle_per_host: Maximum idle pooled connections per host (default: None, unlimited)
+            - http2: True to only use HTTP/2, False to only use HTTP/1.1 (default: None, HTTP/2 when negotiated over TLS)
+            - http2_keep_alive_secs: Interval of HTTP/2 keep-alive pings (default: None, no pings)
+            - tcp_keepalive_secs: Not supported; the HTTP client doesn't expose TCP keepalive, so it raises ``ValueError``
+              pointing to ``http2_keep_alive_secs``
+            - checksum_algorithm: Checksum computed and verified on upload, ``"sha256"`` or ``"md5"`` on S3 and ``"crc32c"`` on GCS, reported in :py:attr:`TransferResult.checksum`;
+              ``"md5"`` can't be combined with ``sse="aws:kms"`` or ``sse_c_key`` (default: None)
+            - unsigned_payload: Send ``UNSIGNED-PAYLOAD`` instead of hashing each payload, S3 only; conflicts with ``checksum_algorithm`` (default: False)
+            - request_payer: Send ``x-amz-request-payer: requester`` with every request, required by requester-pays buckets, S3 only;
+              conflicts with ``skip_signature`` (default: False)
+            - trailing_checksum: Send every put and multipart part in the aws-chunked encoding with a ``"sha256"`` or ``"crc32c"``
+              checksum in its trailer, S3 only; conflicts with ``checksum_algorithm``. Stores that reject the encoding raise an error
+              suggesting ``checksum_algorithm`` instead (default: None)
+            - sse: Server-side encryption of written objects, S3 only, "aws:kms" or "AES256" (default: None)
+            - sse_kms_key_id: KMS key of ``sse="aws:kms"``, which it implies when ``sse`` is unset (default: None, the bucket's key)
+            - storage_class: Storage class of the objects written, S3 (e.g. "STANDARD_IA", "GLACIER_IR", "INTELLIGENT_TIERING") and GCS
+              ("NEARLINE", "COLDLINE", "ARCHIVE") only; can't be combined with ``resume_state_path`` (default: None, the bucket's default)
+            - sse_c_key: Base64-encoded 256-bit customer key, S3 only, sent with every write, part, get and head; can't be combined
+              with ``sse`` or ``sse_kms_key_id`` (default: None)
+            - deadline: Default time budget in seconds for each operation, including retries (default: None)
+            - local_io: Local file I/O backend for multipart file transfers, "tokio", "uring" or "auto" (default: "tokio").
+              With "tokio" on Linux and macOS, downloaded chunks are written to the file in parallel with positional writes
+            - rate_limit_group: Name of a process-wide rate limit group shared with other clients, see :py:func:`set_rate_limit` (default: None)
+            - dedupe_gets: Serve concurrent identical ``get`` calls and read stream chunks from one request (default: False)
+            - negative_cache_ttl: Seconds to remember that an object was not found; 0 disables (default: 5)
+            - circuit_breaker: Dict enabling a client-wide circuit breaker that fails requests fast with :py:class:`RustCircuitOpenError` once
+              failure_rate (default: 0.5) of the last window (default: 50) requests failed, after at least min_requests (default: 20),
+              then lets a probe request through after cooldown seconds (default: 30). NotFound and permission errors don't count (default: None)
+            - validate_on_init: List at most one object while constructing the client and raise ``FileNotFoundError``, ``PermissionError``
+              or ``ConnectionError`` naming the endpoint and region if the bucket can't be reached; with ``skip_signature``, a denied listing passes (default: False)
+            - verify_upload: Check every ``upload``, ``upload_multipart_from_file`` and ``upload_multipart_from_bytes`` with a ``head`` once complete, unless the call passes ``verify_upload`` (default: False)
+            - max_append_rewrite_size: Largest object in bytes that :py:meth:`append` rewrites on providers without native appends (default: 64 MiB)
+            - retry_max_attempts: Maximum number of retries of each request, overriding ``retry`` (default: 10)
+            - retry_init_backoff_ms: Backoff before the first retry in milliseconds, overriding ``retry`` (default: 100)
+            - retry_max_backoff_ms: Upper bound of the backoff between retries in milliseconds, overriding ``retry`` (default: 15000)
+            - retry_timeout_secs: Time after which a failing request is no longer retried, overriding ``retry`` (default: 180)
+            - retry_operation_attempts: Attempts of a whole operation that still fails with a transient error once its requests
+              are out of retries, backing off like them; the error then reports every attempt (default: 1)
+            - request_tag_header: Header that carries each operation's ``request_tag`` (default: "x-msc-request-tag")
+            - benchmark_prefix_pattern: Regex a prefix must match before benchmark() writes to it (default: "(^|/)msc-benchmark(/|$)")
+        :param credentials_provider: Credentials provider for the provider (e.g., StaticS3CredentialsProvider). The ``expiration``
+            of its credentials may be an RFC3339 string, a ``datetime`` or epoch seconds; credentials are refreshed before it.
+            A request denied for its credentials, e.g. revoked early, is retried once after ``refresh_credentials()``, forced at most every 30 seconds.
         :param retry: Retry configuration for the Rust client.
+        :param request_tag_generator: Called with the method name to tag each operation that has no ``request_tag``; may return ``None``.
         """
         ...
 
-    async def put(self, path: str, data: bytes | memoryview | bytearray) -> int:
+    async def put(
+        self,
+        path: str,
+        data: bytes | memoryview | bytearray,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        if_not_exists: bool = ...,
+        if_match: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        storage_class: str | None = ...,
+        compress: Literal["gzip", "zstd"] | None = ...,
+        compression_level: int | None = ...,
+    ) -> int | TransferResult:
         """
         Upload data to the object store at the specified path.
         :param path: The remote object path in the storage backend.
         :param data: The data to upload as bytes, memoryview, or bytearray (buffer protocol).
-        :return: The number of bytes uploaded.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param write_mode: ``"atomic"`` (default) or ``"streaming"``, which writes through to the destination file so its progress is visible. Only the ``file`` provider supports streaming.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag, version id and parts of the new object instead of the byte count.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :param if_not_exists: Alias of ``mode="create"``: only create the object, atomically on the store (``If-None-Match: *``). Raises :py:class:`RustAlreadyExistsError` if it already exists.
+        :param if_match: Only replace the object if its current ETag matches. Raises :py:class:`RustPreconditionFailedError` otherwise.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param storage_class: Storage class of the new object instead of the client's ``storage_class``, e.g. ``"STANDARD_IA"``
+            on S3 or ``"NEARLINE"`` on GCS. Raises ``ValueError`` listing the accepted classes if the provider has no such class.
+        :param compress: Compress the data with ``"gzip"`` or ``"zstd"`` before it is uploaded and set the object's
+            Content-Encoding accordingly. The compressed size is returned, and with ``detailed`` the original one is in
+            :py:attr:`TransferResult.uncompressed_size`. Raises ``ValueError`` if ``metadata`` names a Content-Encoding or on
+            the ``file`` provider.
+        :param compression_level: Level of ``compress``, 0 to 9 for gzip (default: 6) and up to 22 for zstd (default: 3).
+        :return: The number of bytes uploaded, or a :py:class:`TransferResult` when ``detailed``.
+        """
+        ...
+
+    async def append(
+        self,
+        path: str,
+        data: bytes | memoryview | bytearray,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+    ) -> int:
+        """
+        Append data to the object at the specified path, creating it if it doesn't exist.
+
+        The ``file`` provider appends to the file in place. Other providers, GCS included as objects aren't composed, read
+        the object and write it back followed by ``data``, on condition that it hasn't changed in between, so the whole
+        object is transferred twice. If it was written
+        concurrently, :py:class:`RustPreconditionFailedError` is raised without anything appended, and the call can be
+        repeated. Objects larger than the client's ``max_append_rewrite_size`` are refused with ``ValueError``.
+
+        :param path: The remote object path in the storage backend.
+        :param data: The data to append as bytes, memoryview, or bytearray (buffer protocol).
+        :param deadline: Time budget in seconds for the whole operation. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation.
+        :return: The new size of the object in bytes.
         """
         ...
 
-    async def get(self, path: str, range: Range | None = ...) -> bytes:
+    async def get(
+        self,
+        path: str,
+        range: Range | None = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        as_memoryview: bool = ...,
+    ) -> bytes | memoryview:
         """
         Download data from the object store at the specified path.
         :param path: The remote object path in the storage backend.
         :param range: Optional byte range for download.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param version_id: Read this version of the object instead of the latest one. Raises ``ValueError`` on providers without object versions.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param as_memoryview: Return a read-only :py:class:`memoryview` of the downloaded data instead, which
+            ``np.frombuffer`` and ``torch.frombuffer`` wrap without a copy. The data stays valid for as long as any
+            view of it, including arrays made from it, is alive, even after the client is dropped.
         :return: The downloaded data as bytes.
         """
         ...
 
-    async def upload(self, local_path: str, remote_path: str) -> int:
+    async def get_ranges(
+        self,
+        path: str,
+        ranges: list[tuple[int, int]],
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+    ) -> list[bytes]:
+        """
+        Download several byte ranges of one object, coalescing nearby ranges into fewer requests.
+        :param path: The remote object path in the storage backend.
+        :param ranges: ``(start, end)`` byte ranges, end exclusive. They may overlap or be out of order. A range past
+            the end of the object fails like the same :py:meth:`get` would. Raises ``ValueError`` if a start is after its end.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :return: The data of each range, in request order.
+        """
+        ...
+
+    async def get_many(
+        self,
+        paths: list[str],
+        max_concurrency: int | None = ...,
+        total_size_limit: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> tuple[dict[str, bytes], dict[str, str]]:
+        """
+        Download the objects at the specified paths concurrently. A failed get doesn't stop the rest of the batch.
+        :param paths: The remote object paths in the storage backend.
+        :param max_concurrency: Maximum number of requests in flight. Defaults to ``max_pool_connections``.
+        :param total_size_limit: Check the size of every object with a HEAD request first and raise ``ValueError``,
+            without downloading anything, if they add up to more than this many bytes.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The data of each path that was downloaded, and the error message of each path that failed.
+        """
+        ...
+
+    async def delete(
+        self,
+        path: str,
+        missing_ok: bool = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> None:
+        """
+        Delete the object at the specified path.
+        :param path: The remote object path in the storage backend.
+        :param missing_ok: Don't raise if the object doesn't exist. S3-compatible stores report success for a missing key either way.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        """
+        ...
+
+    async def info(
+        self,
+        path: str,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> ObjectMetadata:
+        """
+        Get the metadata of the object at the specified path without reading it.
+        :param path: The remote object path in the storage backend.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param version_id: Get the metadata of this version of the object instead of the latest one. Raises ``ValueError`` on providers without object versions.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :return: The object's metadata.
+        :raises FileNotFoundError: If the object doesn't exist.
+        """
+        ...
+
+    async def exists(
+        self,
+        path: str,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> bool:
+        """
+        Check whether an object exists at the specified path. Only a missing object returns ``False``; other errors,
+        such as a denied permission, are raised.
+        :param path: The remote object path in the storage backend.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        """
+        ...
+
+    def generate_presigned_url(
+        self,
+        path: str,
+        method: str = ...,
+        expires_in: int = ...,
+        response_content_disposition: str | None = ...,
+        response_content_type: str | None = ...,
+    ) -> str:
+        """
+        Generate a presigned URL granting a ``GET`` or ``PUT`` of an object without credentials, on the ``s3``, ``s8k``,
+        ``gcs_s3`` and ``gcs`` providers.
+        :param path: The remote object path in the storage backend.
+        :param method: ``"GET"`` or ``"PUT"``.
+        :param expires_in: Seconds the URL is valid for, at most 7 days.
+        :param response_content_disposition: ``Content-Disposition`` header of the response to a ``GET`` URL. Not
+            supported by the ``gcs`` provider.
+        :param response_content_type: ``Content-Type`` header of the response to a ``GET`` URL. Not supported by the
+            ``gcs`` provider.
+        :raises ValueError: If the provider can't sign URLs, or the client doesn't sign requests, e.g. with
+            ``skip_signature``.
+        """
+        ...
+
+    async def copy(
+        self,
+        src_path: str,
+        dst_path: str,
+        overwrite: bool = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> int:
+        """
+        Copy an object within the storage backend without downloading it.
+        :param src_path: The remote path of the object to copy.
+        :param dst_path: The remote path to copy the object to.
+        :param overwrite: Replace an existing object at ``dst_path``. When ``False``, raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :return: The number of bytes copied.
+        :raises FileNotFoundError: If the source object doesn't exist.
+        """
+        ...
+
+    async def rename(
+        self,
+        src: str,
+        dst: str,
+        overwrite: bool = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> int:
+        """
+        Move an object to a new path. On object stores this is a server-side copy followed by a delete of the
+        source, which only happens once the copy is confirmed.
+        :param src: The remote path of the object to move.
+        :param dst: The remote path to move the object to.
+        :param overwrite: Replace an existing object at ``dst``.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :return: The number of bytes moved.
+        :raises FileNotFoundError: If the source object doesn't exist.
+        :raises FileExistsError: If ``overwrite`` is ``False`` and the destination exists.
+        """
+        ...
+
+    async def delete_many(
+        self,
+        paths: list[str],
+        max_concurrency: int | None = ...,
+        missing_ok: bool = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> DeleteResult:
+        """
+        Delete the objects at the specified paths concurrently. A failed delete doesn't stop the rest of the batch.
+        :param paths: The remote object paths in the storage backend.
+        :param max_concurrency: Maximum number of deletes in flight. Defaults to ``max_pool_connections``.
+        :param missing_ok: Report objects that don't exist as deleted.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The deleted paths and the error of each path that failed.
+        """
+        ...
+
+    def get_config(self) -> dict[str, Any]:
+        """
+        Return the settings this client resolved from its configs and defaults, such as the ``retry_*`` values, for debugging.
+        """
+        ...
+
+    @property
+    def deduplicated_requests(self) -> int:
+        """
+        Number of reads served by an identical read already in flight, when ``dedupe_gets`` is enabled.
+        """
+        ...
+
+    @property
+    def negative_cache_hits(self) -> int:
+        """
+        Number of metadata lookups answered with a cached "not found" instead of a request.
+        """
+        ...
+
+    def circuit_breaker_stats(self) -> dict[str, Any] | None:
+        """
+        Return the circuit breaker's state ("closed", "open" or "half_open"), the number of
+        trips and the number of rejected requests, or None if circuit_breaker isn't configured.
+        """
+        ...
+
+    def get_metrics(self) -> dict[str, Any]:
+        """
+        Return the client's metrics since it was created or :py:meth:`reset_metrics` was last called.
+
+        ``operations`` maps each operation that ran, e.g. ``get``, to its ``count``, its ``errors`` by class
+        (``not_found``, ``timeout``, ``throttled``, ``retryable``, ``client``, ``invalid`` and ``other``), its
+        ``latency_buckets`` mapping each upper bound in seconds, the last being ``inf``, to the number of calls
+        that took longer than the previous bound, and its ``latency_sum`` in seconds. The blocking variants are
+        counted with their async methods, and calls rejected before any request, e.g. for an invalid argument,
+        are counted as ``invalid`` errors. ``bytes_sent`` and ``bytes_received`` total the HTTP requests of all
+        operations, so they stay at zero for the ``file`` provider.
+        """
+        ...
+
+    def reset_metrics(self) -> None:
+        """
+        Reset the counters returned by :py:meth:`get_metrics` to zero.
+        """
+        ...
+
+    def refresh(self) -> None:
+        """
+        Rebuild the client's stores, fetching new credentials from the credentials provider and opening a new
+        connection pool. Operations in flight complete on the previous stores.
+
+        :raises RuntimeError: If the client is closed.
+        """
+        ...
+
+    def close(self) -> None:
+        """
+        Drop the client's stores. Operations in flight complete, while later calls raise :py:exc:`RuntimeError`.
+        Closing a closed client does nothing.
+        """
+        ...
+
+    async def get_into(
+        self,
+        path: str,
+        buffer: Any,
+        start: int | None = ...,
+        end: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> int:
+        """
+        Download an object, or the ``start:end`` byte range of it, directly into a preallocated buffer such as pinned
+        memory, fetching chunks concurrently like :py:meth:`download_multipart_to_bytes`.
+
+        ``buffer`` can be any writable, C-contiguous buffer protocol object. The requested range must be exactly as long
+        as the buffer in bytes; when ``end`` is given, a mismatch raises ``ValueError`` before any request is made.
+        A buffer cannot be the target of two downloads at the same time.
+
+        :param path: The remote object path in the storage backend.
+        :param buffer: The writable, C-contiguous buffer to fill.
+        :param start: The first byte of the object to download (default: 0).
+        :param end: The byte after the last byte to download (default: the object size).
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The number of bytes written.
+        """
+        ...
+
+    async def get_into_array(
+        self,
+        path: str,
+        array: Any,
+        start: int | None = ...,
+        end: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> int:
+        """
+        Download an object, or the ``start:end`` byte range of it, directly into a preallocated array.
+
+        ``array`` can be any writable, C-contiguous buffer protocol object such as a numpy array.
+        The requested range must be exactly ``array.nbytes`` long. Bytes are copied as-is, so converting
+        the byte order of the stored data is the caller's responsibility. An array cannot be the target
+        of two downloads at the same time.
+
+        :param path: The remote object path in the storage backend.
+        :param array: The writable, C-contiguous array to fill.
+        :param start: The first byte of the object to download (default: 0).
+        :param end: The byte after the last byte to download (default: the object size).
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The number of bytes downloaded.
+        """
+        ...
+
+    async def upload(
+        self,
+        local_path: str,
+        remote_path: str,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        retry: RustRetryConfig | None = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+    ) -> int | TransferResult:
         """
         Upload a local file to the object store.
         :param local_path: Path to the local file to upload.
         :param remote_path: The destination path in the storage backend.
-        :return: The number of bytes uploaded.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param write_mode: ``"atomic"`` (default) or ``"streaming"``, which writes through to the destination file so its progress is visible. Only the ``file`` provider supports streaming.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag, version id and parts of the new object instead of the byte count.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param storage_class: Storage class of the new object instead of the client's ``storage_class``, e.g. ``"STANDARD_IA"``
+            on S3 or ``"NEARLINE"`` on GCS. Raises ``ValueError`` listing the accepted classes if the provider has no such class.
+        :param verify_upload: ``head`` the object once uploaded and check its size against the bytes sent, and its ETag
+            against the MD5 of the data when the ETag is a plain MD5, as for single-part uploads not encrypted with SSE-KMS or
+            SSE-C. An object that doesn't match is deleted and :py:class:`RustIntegrityError` raised. With ``detailed``, the
+            head result is returned in :py:attr:`TransferResult.metadata`. Defaults to the client's ``verify_upload``; pass
+            ``False`` to skip the check on stores that aren't consistent on a head right after a put.
+        :return: The number of bytes uploaded, or a :py:class:`TransferResult` when ``detailed``.
         """
         ...
 
-    async def download(self, remote_path: str, local_path: str) -> int:
+    async def download(
+        self,
+        remote_path: str,
+        local_path: str,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> int:
         """
         Download an object from the store and save it to a local file.
         :param remote_path: The remote object path in the storage backend.
         :param local_path: Path to the local file to save the downloaded data.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param version_id: Read this version of the object instead of the latest one. Raises ``ValueError`` on providers without object versions.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
         :return: The number of bytes downloaded.
         """
         ...
@@ -90,7 +585,22 @@ class RustClient:
         remote_path: str,
         multipart_chunksize: int | None = ...,
         max_concurrency: int | None = ...,
-    ) -> int:
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        use_mmap: bool = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        resume_state_path: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+        compress: Literal["gzip", "zstd"] | None = ...,
+        compression_level: int | None = ...,
+    ) -> int | TransferResult | TransferStats:
         """
         Upload a local file to the object store using multipart upload.
 
@@ -102,17 +612,63 @@ class RustClient:
         :param remote_path: The destination path in the storage backend.
         :param multipart_chunksize: The size of the multipart chunks.
         :param max_concurrency: The maximum number of concurrent operations.
-        :return: The number of bytes uploaded.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param use_mmap: Memory-map the file and upload parts directly from the map instead of reading them into buffers.
+            Falls back to buffered reads with a ``RuntimeWarning`` if the file can't be mapped or changes size during the
+            upload. The file must not be truncated while it is mapped.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag, version id and parts of the new object instead of the byte count.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param progress_callback: Called with ``(bytes_transferred, total_bytes)`` after each chunk completes. An exception raised by the callback aborts the transfer and is re-raised.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :param resume_state_path: Local JSON file recording the upload id and completed parts as they finish, so a call
+            that failed or whose process died can be repeated and continue where it stopped. The upload continues only if
+            the state matches ``remote_path``, ``multipart_chunksize`` and the local file's size and modification time;
+            otherwise, or if the file is corrupted, a new upload starts with a ``RuntimeWarning``. The file is removed
+            once the upload completes. Supported by the ``s3``, ``s8k``, ``gcs_s3`` and ``gcs`` providers, and not
+            combinable with ``content_type``, ``metadata`` or ``storage_class``. ``use_mmap`` is ignored. Without it, a
+            multipart upload that fails or is cancelled is aborted so its parts aren't left in the bucket.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param return_stats: Return a :py:class:`TransferStats` describing how the parts were uploaded instead of the byte
+            count. Can't be combined with ``detailed``.
+        :param storage_class: Storage class of the new object instead of the client's ``storage_class``, e.g. ``"STANDARD_IA"``
+            on S3 or ``"NEARLINE"`` on GCS. Raises ``ValueError`` listing the accepted classes if the provider has no such class.
+        :param verify_upload: ``head`` the object once uploaded and check its size against the bytes sent, and its ETag
+            against the MD5 of the data when the ETag is a plain MD5, as for single-part uploads not encrypted with SSE-KMS or
+            SSE-C. An object that doesn't match is deleted and :py:class:`RustIntegrityError` raised. With ``detailed``, the
+            head result is returned in :py:attr:`TransferResult.metadata`. Defaults to the client's ``verify_upload``; pass
+            ``False`` to skip the check on stores that aren't consistent on a head right after a put.
+        :param compress: Stream the file through ``"gzip"`` or ``"zstd"`` compression as it is uploaded and set the object's
+            Content-Encoding accordingly. The compressed size is returned, and with ``detailed`` or ``return_stats`` the file's
+            size is in ``uncompressed_size``. ``progress_callback`` counts the bytes of the file compressed. Raises ``ValueError`` if ``metadata`` names a Content-Encoding, with
+            ``resume_state_path`` or on the ``file`` provider; ``use_mmap`` is ignored.
+        :param compression_level: Level of ``compress``, 0 to 9 for gzip (default: 6) and up to 22 for zstd (default: 3).
+        :return: The number of bytes uploaded, a :py:class:`TransferResult` when ``detailed``, or a
+            :py:class:`TransferStats` when ``return_stats``.
         """
         ...
 
     async def upload_multipart_from_bytes(
         self,
         remote_path: str,
-        data: bytes | memoryview | bytearray,
+        data: Any,
         multipart_chunksize: int | None = ...,
         max_concurrency: int | None = ...,
-    ) -> int:
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        if_not_exists: bool = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+    ) -> int | TransferResult | TransferStats:
         """
         Upload data to the object store at the specified remote_path using multipart upload.
 
@@ -121,20 +677,110 @@ class RustClient:
         compared to put() method.
 
         :param remote_path: The remote object path in the storage backend.
-        :param data: The data to upload as bytes, memoryview, or bytearray (buffer protocol).
+        :param data: The data to upload: any C-contiguous buffer protocol object, such as bytes, bytearray, memoryview or a
+            numpy array, read as raw bytes. Chunks are sliced from its memory without copying, so it must not be modified
+            until the upload completes.
         :param multipart_chunksize: The size of the multipart chunks.
         :param max_concurrency: The maximum number of concurrent operations.
-        :return: The number of bytes uploaded.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag, version id and parts of the new object instead of the byte count.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param progress_callback: Called with ``(bytes_transferred, total_bytes)`` after each chunk completes. An exception raised by the callback aborts the transfer and is re-raised.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :param if_not_exists: Alias of ``mode="create"``: raise :py:class:`RustAlreadyExistsError` if the object already exists. Checked before the upload starts.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param return_stats: Return a :py:class:`TransferStats` describing how the parts were uploaded instead of the byte
+            count. Can't be combined with ``detailed``.
+        :param storage_class: Storage class of the new object instead of the client's ``storage_class``, e.g. ``"STANDARD_IA"``
+            on S3 or ``"NEARLINE"`` on GCS. Raises ``ValueError`` listing the accepted classes if the provider has no such class.
+        :param verify_upload: ``head`` the object once uploaded and check its size against the bytes sent, and its ETag
+            against the MD5 of the data when the ETag is a plain MD5, as for single-part uploads not encrypted with SSE-KMS or
+            SSE-C. An object that doesn't match is deleted and :py:class:`RustIntegrityError` raised. With ``detailed``, the
+            head result is returned in :py:attr:`TransferResult.metadata`. Defaults to the client's ``verify_upload``; pass
+            ``False`` to skip the check on stores that aren't consistent on a head right after a put.
+        :return: The number of bytes uploaded, a :py:class:`TransferResult` when ``detailed``, or a
+            :py:class:`TransferStats` when ``return_stats``.
         """
         ...
 
-    async def download_multipart_to_file(
+    @overload
+    def download_multipart_to_file(
         self,
         remote_path: str,
         local_path: str,
         multipart_chunksize: int | None = ...,
         max_concurrency: int | None = ...,
-    ) -> int:
+        deadline: float | None = ...,
+        detachable: Literal[False] = ...,
+        request_tag: str | None = ...,
+        atomic: bool = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        verify_checksum: bool = ...,
+        detailed: Literal[False] = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: Literal[False] = ...,
+    ) -> Awaitable[int]: ...
+    @overload
+    def download_multipart_to_file(
+        self,
+        remote_path: str,
+        local_path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        detachable: Literal[False] = ...,
+        request_tag: str | None = ...,
+        atomic: bool = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        verify_checksum: bool = ...,
+        *,
+        detailed: Literal[True],
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: Literal[False] = ...,
+    ) -> Awaitable[TransferResult]: ...
+    @overload
+    def download_multipart_to_file(
+        self,
+        remote_path: str,
+        local_path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        detachable: Literal[False] = ...,
+        request_tag: str | None = ...,
+        atomic: bool = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        verify_checksum: bool = ...,
+        detailed: Literal[False] = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        *,
+        return_stats: Literal[True],
+    ) -> Awaitable[TransferStats]: ...
+    @overload
+    def download_multipart_to_file(
+        self,
+        remote_path: str,
+        local_path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        *,
+        detachable: Literal[True],
+        request_tag: str | None = ...,
+        atomic: bool = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        verify_checksum: bool = ...,
+        detailed: bool = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: Literal[False] = ...,
+    ) -> RustDownloadHandle:
         """
         Download an object from the store and save it to a local file using multipart download.
 
@@ -146,7 +792,180 @@ class RustClient:
         :param local_path: Path to the local file to upload.
         :param multipart_chunksize: The size of the multipart chunks.
         :param max_concurrency: The maximum number of concurrent operations.
-        :return: The number of bytes downloaded.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param detachable: Start the download in the background and return a :py:class:`RustDownloadHandle` that can abandon it.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param atomic: If ``True`` (default), download into a temporary file next to ``local_path`` and rename it into place at the end. If ``False``, write into ``local_path`` directly, which needs no extra free space but leaves a partial file visible while downloading; the partial file is deleted if the download fails.
+        :param progress_callback: Called with ``(bytes_transferred, total_bytes)`` after each chunk completes. An exception raised by the callback aborts the transfer and is re-raised.
+        :param verify_checksum: Check the downloaded file against the object before putting it in place: by MD5 when the object's ETag is a plain MD5, and by size otherwise (e.g. for multipart ETags or objects encrypted with SSE-KMS or SSE-C). Raises :py:class:`RustIntegrityError` on a mismatch, and the file is discarded.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag and version id of the object and the ``verification`` method used instead of the byte count. Ignored when ``detachable`` is set.
+        :param version_id: Read this version of the object instead of the latest one. Raises ``ValueError`` on providers without object versions.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param return_stats: Return a :py:class:`TransferStats` describing how the chunks were downloaded instead of the
+            byte count. Can't be combined with ``detailed`` or ``detachable``.
+        :return: The number of bytes downloaded, a :py:class:`TransferResult` when ``detailed``, a :py:class:`TransferStats`
+            when ``return_stats``, or the handle if ``detachable`` is set.
+        """
+        ...
+
+    async def upload_multipart_from_buffers(
+        self,
+        remote_path: str,
+        buffers: list[bytes | memoryview | bytearray],
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        return_stats: bool = ...,
+        storage_class: str | None = ...,
+    ) -> int | TransferResult | TransferStats:
+        """
+        Upload a list of buffers to the store as a single object using multipart upload.
+
+        The buffers are treated as one contiguous stream. Parts are sliced across buffer boundaries
+        without copying the buffers into a single allocation, so any buffer protocol object
+        (``bytes``, ``memoryview``, numpy arrays, tensor storage) can be passed directly.
+
+        :param remote_path: The destination path in the storage backend.
+        :param buffers: The buffers to upload, in order.
+        :param multipart_chunksize: The size of the multipart chunks.
+        :param max_concurrency: The maximum number of concurrent operations.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param detailed: Return a :py:class:`TransferResult` with the ETag, version id and parts of the new object instead of the byte count.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :param return_stats: Return a :py:class:`TransferStats` describing how the parts were uploaded instead of the byte
+            count. Can't be combined with ``detailed``.
+        :param storage_class: Storage class of the new object instead of the client's ``storage_class``, e.g. ``"STANDARD_IA"``
+            on S3 or ``"NEARLINE"`` on GCS. Raises ``ValueError`` listing the accepted classes if the provider has no such class.
+        :return: The number of bytes uploaded, a :py:class:`TransferResult` when ``detailed``, or a
+            :py:class:`TransferStats` when ``return_stats``.
+        """
+        ...
+
+    async def upload_from_async_iter(
+        self,
+        remote_path: str,
+        aiter: AsyncIterable[bytes | memoryview | bytearray],
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+    ) -> int:
+        """
+        Upload the chunks yielded by an async iterator to the store as a single object.
+
+        Chunks are re-chunked into parts of ``multipart_chunksize`` bytes. The next chunk is only
+        awaited once fewer than ``max_concurrency`` parts are uploading, so a fast producer never
+        gets more than a few parts ahead of the upload. Chunks must not be modified after they are
+        yielded. Objects no larger than one part are uploaded with a single request. If the iterator
+        raises, the multipart upload is aborted and the exception propagated.
+
+        :param remote_path: The destination path in the storage backend.
+        :param aiter: The async iterable, e.g. an async generator, yielding buffer protocol objects.
+        :param multipart_chunksize: The size of the multipart chunks.
+        :param max_concurrency: The maximum number of concurrent operations.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param content_type: Content-Type of the new object.
+        :param metadata: User metadata of the new object. Raises ``ValueError`` naming any key or value that isn't a valid HTTP header.
+        :return: The number of bytes uploaded.
+        """
+        ...
+
+    async def upload_directory(
+        self,
+        local_dir: str,
+        remote_prefix: str,
+        max_concurrency: int | None = ...,
+        include_pattern: str | None = ...,
+        follow_symlinks: bool = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> DirectoryUploadResult:
+        """
+        Upload the files below a local directory concurrently, keeping their paths relative to it under ``remote_prefix``.
+        Files larger than ``multipart_chunksize`` use multipart upload. Empty directories are ignored, and a failed file
+        doesn't stop the rest.
+
+        :param local_dir: The local directory to upload.
+        :param remote_prefix: The destination prefix in the storage backend.
+        :param max_concurrency: Maximum number of files in flight. Defaults to ``max_pool_connections``.
+        :param include_pattern: Glob, as in :py:meth:`glob`, that a file's relative path must match to be uploaded.
+        :param follow_symlinks: Upload the targets of symbolic links instead of skipping them. Links to a directory
+            already being walked are skipped.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The number of files and bytes uploaded and the error of each relative path that failed.
+        """
+        ...
+
+    async def download_directory(
+        self,
+        remote_prefix: str,
+        local_dir: str,
+        max_concurrency: int | None = ...,
+        suffix: str | None = ...,
+        skip_existing: bool = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> DirectoryDownloadResult:
+        """
+        Download the objects under ``remote_prefix`` concurrently into ``local_dir``, creating subdirectories for their
+        keys relative to the prefix. Objects larger than ``multipart_chunksize`` are downloaded in parallel chunks. A
+        failed object doesn't stop the rest.
+
+        :param remote_prefix: The prefix in the storage backend to download.
+        :param local_dir: The local directory to download into.
+        :param max_concurrency: Maximum number of objects in flight. Defaults to ``max_pool_connections``.
+        :param suffix: Only download objects whose keys end with this suffix.
+        :param skip_existing: Skip objects whose local copy has the same size and, when the ETag is a plain MD5, the
+            same MD5.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The number of files and bytes downloaded, the files skipped and the error of each key that failed.
+        """
+        ...
+
+    async def sync(
+        self,
+        remote_prefix: str,
+        local_dir: str,
+        delete_extraneous: bool = ...,
+        dry_run: bool = ...,
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> SyncResult:
+        """
+        Mirror the objects under ``remote_prefix`` into ``local_dir``, downloading only new and changed objects.
+
+        Both sides are listed concurrently. A local file is unchanged if it has the object's size and, when the ETag is a
+        plain MD5, its MD5; otherwise if it isn't older than the object. Objects larger than ``multipart_chunksize`` are
+        downloaded in parallel chunks. A failed object doesn't stop the rest.
+
+        :param remote_prefix: The prefix in the storage backend to mirror.
+        :param local_dir: The local directory to mirror into. Created if missing.
+        :param delete_extraneous: Delete local files that have no object under ``remote_prefix``. Empty directories are kept.
+        :param dry_run: Return the planned copies and deletions without changing anything.
+        :param max_concurrency: Maximum number of objects in flight. Defaults to ``max_pool_connections``.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The copied, deleted, unchanged and failed paths relative to ``remote_prefix``.
         """
         ...
 
@@ -156,7 +975,13 @@ class RustClient:
         range: Range | None = ...,
         multipart_chunksize: int | None = ...,
         max_concurrency: int | None = ...,
-    ) -> bytes:
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+    ) -> bytes | tuple[bytes, TransferStats]:
         """
         Download an object from the store and return it as bytes using multipart download.
 
@@ -168,65 +993,1112 @@ class RustClient:
         :param range: Optional byte range for download.
         :param multipart_chunksize: The size of the multipart chunks.
         :param max_concurrency: The maximum number of concurrent operations.
+        :param deadline: Time budget in seconds for the whole operation, including retries. Raises ``TimeoutError`` when exceeded.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param progress_callback: Called with ``(bytes_transferred, total_bytes)`` after each chunk completes. An exception raised by the callback aborts the transfer and is re-raised.
+        :param version_id: Read this version of the object instead of the latest one. Raises ``ValueError`` on providers without object versions.
+        :param retry: Per-request retries for this call instead of the client's, e.g. fewer for a large upload.
+            Calls with the same override share a store built for it.
+        :param return_stats: Return the data together with a :py:class:`TransferStats` describing how the chunks were
+            downloaded, as a ``(data, stats)`` tuple.
         """
         ...
 
-    async def list_recursive(
+    def get_stream(
         self,
-        prefixes: list[str],
-        limit: int | None = ...,
-        suffix: str | None = ...,
-        max_depth: int | None = ...,
-        max_concurrency: int | None = ...,
-    ) -> ListResult:
+        path: str,
+        chunk_size: int = ...,
+        start: int | None = ...,
+        end: int | None = ...,
+        request_tag: str | None = ...,
+    ) -> RustGetStream:
         """
-        List objects and directories recursively from the object store for the given prefixes input list.
+        Stream an object as successive chunks, without holding the whole object in memory.
 
-        This method lists objects and directories recursively from the object store for the given prefixes.
-        It supports filtering by suffix, limiting the number of objects returned,
-        and setting the maximum depth of the directory tree to traverse.
-        The method uses concurrent operations to improve performance. The default max_concurrency is 32.
+        The object is read with a single request, sent as soon as the iterator is created. Dropping
+        the iterator before it is exhausted cancels the request.
 
-        :param prefixes: List of prefixes to list objects from.
-        :param limit: Maximum number of objects to return.
-        :param suffix: Filter objects by suffix.
-        :param max_depth: Maximum depth of the directory tree to traverse.
-        :param max_concurrency: Maximum number of concurrent operations.
+        :param path: The path of the object to read.
+        :param chunk_size: Size of each chunk but the last. Defaults to 8 MiB.
+        :param start: Offset of the first byte to read. Defaults to the start of the object.
+        :param end: Offset after the last byte to read. Defaults to the end of the object.
+        :param request_tag: Correlation tag sent as a header on the request.
+        :return: An async iterator over ``bytes`` chunks.
+        :raises ValueError: If ``chunk_size`` is 0 or ``end`` isn't after ``start``.
         """
         ...
 
-class ObjectMetadata:
-    """
-    ObjectMetadata contains metadata about an object or a directory in the object store.
-    """
-
-    key: str
-    content_length: int
-    last_modified: str  # in RFC 3339 format
-    object_type: str  # "object" or "directory"
-    etag: str | None
+    async def open_read_stream(
+        self,
+        path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+    ) -> RustReadStream:
+        """
+        Open an object for reading with :py:class:`asyncio.StreamReader` semantics.
 
-class ListResult:
-    """
-    ListResult contains the result of a list operation.
-    """
+        The object is fetched in ``multipart_chunksize`` ranged reads, up to ``max_concurrency`` ahead of the reader.
 
-    objects: list[ObjectMetadata]
+        :param path: The remote object path in the storage backend.
+        :param multipart_chunksize: The size of each ranged read.
+        :param max_concurrency: The maximum number of chunks fetched ahead.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :return: The read stream.
+        """
+        ...
+
+    async def open_reader(
+        self,
+        path: str,
+        buffer_size: int | None = ...,
+        request_tag: str | None = ...,
+    ) -> RustObjectReader:
+        """
+        Open an object as a seekable, file-like reader.
+
+        :param path: The remote object path in the storage backend.
+        :param buffer_size: The minimum size of each ranged read. Defaults to ``multipart_chunksize``.
+        :param request_tag: Correlation tag sent as a header on every request of this reader.
+        :return: The reader.
+        :raises FileNotFoundError: If the object does not exist.
+        """
+        ...
+
+    async def open_writer(
+        self,
+        path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+    ) -> RustObjectWriter:
+        """
+        Open an object for incremental writing, backed by a multipart upload that is aborted if the writer is dropped before it is closed.
+
+        :param path: The remote object path in the storage backend.
+        :param multipart_chunksize: The size of the multipart chunks. Smaller writes are buffered until a part fills.
+        :param max_concurrency: The maximum number of parts uploading at once before ``write()`` waits.
+        :param request_tag: Correlation tag sent as a header on every request of this writer.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param write_mode: ``"atomic"`` (default) or ``"streaming"``, which writes through to the destination file so its progress is visible. Only the ``file`` provider supports streaming.
+        :return: The writer.
+        """
+        ...
+
+    async def open_write_stream(
+        self,
+        path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+        request_tag: str | None = ...,
+    ) -> RustWriteStream:
+        """
+        Open an object for writing with :py:class:`asyncio.StreamWriter` semantics, backed by a multipart upload.
+
+        :param path: The remote object path in the storage backend.
+        :param multipart_chunksize: The size of the multipart chunks.
+        :param max_concurrency: The maximum number of parts uploading at once before ``drain()`` waits.
+        :param mode: ``"overwrite"`` (default) replaces an existing object; ``"create"`` raises :py:class:`RustAlreadyExistsError` if it exists.
+        :param write_mode: ``"atomic"`` (default) or ``"streaming"``, which writes through to the destination file so its progress is visible. Only the ``file`` provider supports streaming.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :return: The write stream.
+        """
+        ...
+
+    async def read_samples(
+        self,
+        requests: list[tuple[str, int, int]],
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> tuple[list[bytes | None], dict[int, str]]:
+        """
+        Read many small byte ranges, such as samples packed into shards, in a single batch.
+
+        Requests against the same object are coalesced into larger ranged reads when they are close together,
+        and all reads are fetched concurrently. A failed sample does not fail the batch.
+
+        :param requests: ``(remote_path, offset, length)`` tuples.
+        :param max_concurrency: The maximum number of concurrent reads.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The sample bytes aligned with ``requests`` (``None`` for failed samples), and a mapping from the index of each failed request to its error message.
+        """
+        ...
+
+    async def benchmark(
+        self,
+        prefix: str,
+        object_size: int,
+        num_objects: int,
+        concurrency: int,
+        direction: str = "both",
+        read_only: bool = False,
+    ) -> BenchmarkReport:
+        """
+        Measure storage throughput and latency from this client.
+
+        Writes ``num_objects`` synthetic objects of ``object_size`` bytes under ``prefix`` using the multipart
+        machinery, optionally reads them back, and deletes them again, even when the benchmark fails.
+        Write benchmarks refuse to run unless ``prefix`` matches the ``benchmark_prefix_pattern`` config.
+
+        :param prefix: Scratch prefix to write synthetic objects under, or to read existing objects from.
+        :param object_size: Size of each synthetic object in bytes.
+        :param num_objects: Number of objects to write or read.
+        :param concurrency: Maximum number of objects in flight at once.
+        :param direction: ``"write"``, ``"read"``, or ``"both"``. Only the requested phases are reported.
+        :param read_only: Read up to ``num_objects`` pre-existing objects under ``prefix`` without writing anything.
+        :return: The benchmark report.
+        """
+        ...
+
+    async def glob(
+        self,
+        pattern: str,
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> ListResult:
+        """
+        List the objects whose keys match a glob pattern such as ``datasets/*/shard-*.tar``.
+
+        ``*`` and ``?`` match within one path segment, ``[...]`` matches a character class (``[!...]`` negates it) and
+        ``**`` matches any number of directories. Listing starts at the directory before the first wildcard and skips
+        directories that can't hold a match.
+
+        :param pattern: The glob pattern to match keys against.
+        :param max_concurrency: Maximum number of directories listed at once.
+        :param request_tag: Correlation tag sent as a header on every request of this operation.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The matching objects sorted by key. ``prefixes`` is empty.
+        """
+        ...
+
+    async def du(
+        self,
+        prefix: str,
+        max_depth: int | None = ...,
+        suffix: str | None = ...,
+        max_concurrency: int | None = ...,
+        request_tag: str | None = ...,
+        deadline: float | None = ...,
+    ) -> UsageSummary:
+        """
+        Summarize the objects under a prefix: how many there are, their total size, the largest and the most recent
+        modification time.
+
+        The prefix is walked like :py:meth:`list_recursive`, but the objects are counted as each directory is listed
+        rather than collected, so memory use doesn't grow with the number of objects.
+
+        :param prefix: Prefix to summarize.
+        :param max_depth: Maximum depth of the directory tree to traverse.
+        :param suffix: Only count objects whose keys end with this suffix.
+        :param max_concurrency: Maximum number of directories listed at once.
+        :param request_tag: Correlation tag sent as a header on every request of this operation.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        :return: The totals of the matching objects.
+        """
+        ...
+
+    def list_iter(
+        self,
+        prefix: str,
+        batch_size: int = ...,
+        suffix: str | None = ...,
+        request_tag: str | None = ...,
+        start_after: str | None = ...,
+    ) -> RustListIterator:
+        """
+        List the objects under a prefix as they arrive from the store, without collecting them first.
+
+        :param prefix: Prefix to list objects from.
+        :param batch_size: Maximum number of objects in each batch. Defaults to 1000.
+        :param suffix: Filter objects by suffix.
+        :param request_tag: Correlation tag sent as a header on every request of this operation.
+        :param start_after: Only list keys that sort after this one.
+        :return: An async iterator over batches of :py:class:`ObjectMetadata`.
+        """
+        ...
+
+    async def list_recursive(
+        self,
+        prefixes: list[str],
+        limit: int | None = ...,
+        suffix: str | None = ...,
+        max_depth: int | None = ...,
+        max_concurrency: int | None = ...,
+        traversal: Literal["bfs", "dfs"] = ...,
+        request_tag: str | None = ...,
+        start_after: str | None = ...,
+        modified_before: str | datetime | None = ...,
+        modified_after: str | datetime | None = ...,
+        min_size: int | None = ...,
+        max_size: int | None = ...,
+        deadline: float | None = ...,
+    ) -> ListResult:
+        """
+        List objects and directories recursively from the object store for the given prefixes input list.
+
+        This method lists objects and directories recursively from the object store for the given prefixes.
+        It supports filtering by suffix, limiting the number of objects returned,
+        and setting the maximum depth of the directory tree to traverse.
+        The method uses concurrent operations to improve performance. The default max_concurrency is 32.
+
+        :param prefixes: List of prefixes to list objects from.
+        :param limit: Maximum number of objects to return. These are the first ``limit`` objects in key order, and
+            :py:attr:`ListResult.last_key` is set to the last of them.
+        :param suffix: Filter objects by suffix.
+        :param max_depth: Maximum depth of the directory tree to traverse.
+        :param max_concurrency: Maximum number of concurrent operations.
+        :param traversal: ``"bfs"`` (default) lists level by level; ``"dfs"`` lists the deepest pending
+            directory first, which keeps the number of pending directories small for very wide trees.
+            Both return the same entries when no ``limit`` is set.
+        :param request_tag: Correlation tag sent as a header on every request of this operation, retries and chunks included.
+        :param start_after: Only list keys that sort after this one, e.g. the ``last_key`` of a previous call to resume from.
+            Directories whose keys all sort before it are skipped, even below ``max_depth``.
+        :param modified_before: Only list objects last modified before this time, an RFC 3339 string or a ``datetime``.
+            Raises ``ValueError`` for an invalid string.
+        :param modified_after: Only list objects last modified after this time, like ``modified_before``.
+        :param min_size: Only list objects of at least this many bytes.
+        :param max_size: Only list objects of at most this many bytes.
+        :param deadline: Time budget in seconds for the whole call. Raises ``TimeoutError`` when exceeded.
+            The client's default deadline doesn't apply.
+        """
+        ...
+
+    def list_recursive_blocking(
+        self,
+        prefixes: list[str],
+        limit: int | None = ...,
+        suffix: str | None = ...,
+        max_depth: int | None = ...,
+        max_concurrency: int | None = ...,
+        traversal: Literal["bfs", "dfs"] = ...,
+        request_tag: str | None = ...,
+        start_after: str | None = ...,
+        modified_before: str | datetime | None = ...,
+        modified_after: str | datetime | None = ...,
+        min_size: int | None = ...,
+        max_size: int | None = ...,
+        deadline: float | None = ...,
+    ) -> ListResult:
+        """
+        Blocking variant of :py:meth:`list_recursive` for synchronous callers, which takes the same parameters.
+
+        The GIL is released while listing. Don't call it from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def put_sync(
+        self,
+        path: str,
+        data: bytes | memoryview | bytearray,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        if_not_exists: bool = ...,
+        if_match: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        storage_class: str | None = ...,
+        compress: Literal["gzip", "zstd"] | None = ...,
+        compression_level: int | None = ...,
+    ) -> int | TransferResult:
+        """
+        Blocking variant of :py:meth:`put`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def get_sync(
+        self,
+        path: str,
+        range: Range | None = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        as_memoryview: bool = ...,
+    ) -> bytes | memoryview:
+        """
+        Blocking variant of :py:meth:`get`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def upload_sync(
+        self,
+        local_path: str,
+        remote_path: str,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        write_mode: Literal["atomic", "streaming"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        retry: RustRetryConfig | None = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+    ) -> int | TransferResult:
+        """
+        Blocking variant of :py:meth:`upload`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def download_sync(
+        self,
+        remote_path: str,
+        local_path: str,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+    ) -> int:
+        """
+        Blocking variant of :py:meth:`download`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def upload_multipart_from_file_sync(
+        self,
+        local_path: str,
+        remote_path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        use_mmap: bool = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        resume_state_path: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+        compress: Literal["gzip", "zstd"] | None = ...,
+        compression_level: int | None = ...,
+    ) -> int | TransferResult | TransferStats:
+        """
+        Blocking variant of :py:meth:`upload_multipart_from_file`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def upload_multipart_from_bytes_sync(
+        self,
+        remote_path: str,
+        data: Any,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        mode: Literal["overwrite", "create"] = ...,
+        detailed: bool = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        content_type: str | None = ...,
+        metadata: dict[str, str] | None = ...,
+        if_not_exists: bool = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+        storage_class: str | None = ...,
+        verify_upload: bool | None = ...,
+    ) -> int | TransferResult | TransferStats:
+        """
+        Blocking variant of :py:meth:`upload_multipart_from_bytes`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def download_multipart_to_file_sync(
+        self,
+        remote_path: str,
+        local_path: str,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        detachable: Literal[False] = ...,
+        request_tag: str | None = ...,
+        atomic: bool = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        verify_checksum: bool = ...,
+        detailed: bool = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+    ) -> int | TransferResult | TransferStats:
+        """
+        Blocking variant of :py:meth:`download_multipart_to_file`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+    def download_multipart_to_bytes_sync(
+        self,
+        remote_path: str,
+        range: Range | None = ...,
+        multipart_chunksize: int | None = ...,
+        max_concurrency: int | None = ...,
+        deadline: float | None = ...,
+        request_tag: str | None = ...,
+        progress_callback: Callable[[int, int], None] | None = ...,
+        version_id: str | None = ...,
+        retry: RustRetryConfig | None = ...,
+        return_stats: bool = ...,
+    ) -> bytes | tuple[bytes, TransferStats]:
+        """
+        Blocking variant of :py:meth:`download_multipart_to_bytes`, with the same parameters and result.
+
+        The GIL is released while it runs. It can be called from any thread, including the workers of an executor
+        used by an async application, but not from a coroutine, as it blocks the event loop.
+        """
+        ...
+
+class ObjectMetadata:
+    """
+    ObjectMetadata contains metadata about an object or a directory in the object store.
+
+    Comparable, hashable and pickleable, and :py:meth:`to_dict` gives the fields as a dict.
+    """
+
+    key: str
+    content_length: int
+    #: Timezone-aware, in UTC. The Unix epoch for directories.
+    last_modified: datetime
+    object_type: str  # "object" or "directory"
+    etag: str | None
+    #: Version id of the object, on stores with versioning enabled.
+    version: str | None
+    #: Storage class reported by :py:meth:`RustClient.info`, e.g. ``"GLACIER_IR"``. ``None`` in listings, and for
+    #: ``STANDARD`` objects on S3, which doesn't report the default class.
+    storage_class: str | None
+    #: Content type reported by :py:meth:`RustClient.info`. ``None`` in listings.
+    content_type: str | None
+    #: User-defined metadata reported by :py:meth:`RustClient.info`, ``None`` in listings and for objects without any.
+    metadata: dict[str, str] | None
+
+    def __init__(
+        self,
+        key: str,
+        content_length: int,
+        last_modified: datetime,
+        object_type: str = "file",
+        etag: str | None = None,
+        version: str | None = None,
+        storage_class: str | None = None,
+        content_type: str | None = None,
+        metadata: dict[str, str] | None = None,
+    ) -> None: ...
+    @property
+    def last_modified_str(self) -> str:
+        """
+        ``last_modified`` in RFC 3339 format, as it was returned before it became a datetime.
+        """
+        ...
+
+    def to_dict(self) -> dict[str, Any]:
+        """
+        The fields as a dict, e.g. to pass as ``extra`` to a log call.
+        """
+        ...
+
+class ListResult:
+    """
+    ListResult contains the result of a list operation.
+
+    A sequence of the objects followed by the prefixes, which are converted to Python as they are iterated over
+    or indexed.
+    """
+
+    objects: list[ObjectMetadata]
     prefixes: list[ObjectMetadata]
+    #: Traversal statistics, set by :py:meth:`RustClient.list_recursive`.
+    stats: ListStats | None
+    #: Key of the last object returned under a ``limit``. Pass it as ``start_after`` to resume the listing.
+    last_key: str | None
+
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[ObjectMetadata]: ...
+    @overload
+    def __getitem__(self, index: int) -> ObjectMetadata: ...
+    @overload
+    def __getitem__(self, index: slice) -> list[ObjectMetadata]: ...
+    def keys(self) -> list[str]:
+        """
+        The keys of the objects, without converting their metadata. The prefixes are left out.
+        """
+        ...
+
+    def to_dicts(self) -> list[dict[str, Any]]:
+        """
+        The objects and then the prefixes as dicts, as returned by :py:meth:`ObjectMetadata.to_dict`, e.g. to
+        serialize as JSON.
+        """
+        ...
+
+class ListStats:
+    """
+    Traversal statistics of a recursive listing.
+    """
+
+    directories_listed: int
+    #: Largest number of directories waiting to be listed at any one time.
+    max_pending_directories: int
+
+class UsageSummary:
+    """
+    Totals of the objects under a prefix, returned by :py:meth:`RustClient.du`.
+    """
+
+    object_count: int
+    total_bytes: int
+    #: The largest object, or ``None`` if there are no objects.
+    largest_object: ObjectMetadata | None
+    #: Most recent ``last_modified`` of the objects, in RFC 3339 format.
+    latest_modified: str | None
+    stats: ListStats | None
+
+class PartInfo:
+    """
+    One part of a multipart upload.
+    """
+
+    part_number: int
+    size: int
+    #: Not reported by the multipart writer, so currently always ``None``.
+    etag: str | None
+
+class TransferResult:
+    """
+    Bytes transferred by an upload or download and what the store reported for the object.
+    """
+
+    bytes_transferred: int
+    #: ETag of the object, or ``None`` if the provider returned none. For multipart uploads this is the composed ETag.
+    etag: str | None
+    version_id: str | None
+    #: Parts of a multipart upload in order, or ``None`` when the object was written in one request.
+    parts: list[PartInfo] | None
+    #: Server time of the response that completed the upload, as an RFC 3339 string, or ``None`` if the provider sent none.
+    #: Multipart objects may report an earlier ``last_modified`` on ``head``, since S3 dates them from the start of the upload.
+    last_modified: str | None
+    #: How a download with ``verify_checksum`` was checked, ``"md5"`` or ``"size"``, or ``None`` if it wasn't.
+    verification: str | None
+    #: Requests of the transfer that failed and were retried, e.g. after a throttling response or a connection reset.
+    retry_count: int
+    #: Algorithm of ``checksum``, from the ``checksum_algorithm`` config, or ``None`` if none is configured.
+    checksum_algorithm: str | None
+    #: Verified checksum of the uploaded object: base64 for crc32c and sha256, hex for md5. Multipart sha256 and md5
+    #: uploads report the composite ``<digest of part digests>-<parts>``.
+    checksum: str | None
+    #: The object as reported by the ``head`` of an upload with ``verify_upload``, or ``None`` if it wasn't verified.
+    metadata: ObjectMetadata | None
+    #: Size of the data before it was compressed with ``compress``, when ``bytes_transferred`` is the compressed size,
+    #: or ``None`` if it wasn't compressed.
+    uncompressed_size: int | None
+
+class TransferStats:
+    """
+    How a multipart upload or download went, returned with ``return_stats=True``.
+
+    Pickleable, and :py:meth:`to_dict` gives the fields as a dict for logging.
+    """
+
+    bytes_transferred: int
+    #: Parts or ranges transferred, or 1 when the object was transferred in a single request.
+    chunks: int
+    #: Most chunks in flight at once.
+    peak_concurrency: int
+    #: Chunks in flight on average: the time they took in total divided by ``wall_time``.
+    effective_concurrency: float
+    #: Seconds from the call until the transfer finished.
+    wall_time: float
+    #: Seconds from the call until the store first answered, or ``None`` if no request was answered.
+    time_to_first_byte: float | None
+    #: Requests of the transfer that failed and were retried.
+    retry_count: int
+    #: Size of the data before it was compressed with ``compress``, when ``bytes_transferred`` is the compressed size,
+    #: or ``None`` if it wasn't compressed.
+    uncompressed_size: int | None
+
+    def __init__(
+        self,
+        bytes_transferred: int = 0,
+        chunks: int = 0,
+        peak_concurrency: int = 0,
+        effective_concurrency: float = 0.0,
+        wall_time: float = 0.0,
+        time_to_first_byte: float | None = None,
+        retry_count: int = 0,
+        uncompressed_size: int | None = None,
+    ) -> None: ...
+    def to_dict(self) -> dict[str, Any]:
+        """
+        The fields as a dict, e.g. to pass as ``extra`` to a log call or to serialize as JSON.
+        """
+        ...
+
+class DeleteResult:
+    """
+    DeleteResult contains the result of :py:meth:`RustClient.delete_many`.
+    """
+
+    deleted: list[str]
+    #: Error message of each path that could not be deleted.
+    failed: dict[str, str]
+
+class DirectoryUploadResult:
+    """
+    DirectoryUploadResult contains the result of :py:meth:`RustClient.upload_directory`.
+    """
+
+    files_uploaded: int
+    bytes_uploaded: int
+    #: Error message of each path, relative to the directory, that could not be uploaded.
+    failed: dict[str, str]
+
+class DirectoryDownloadResult:
+    """
+    DirectoryDownloadResult contains the result of :py:meth:`RustClient.download_directory`.
+    """
+
+    files_downloaded: int
+    bytes_downloaded: int
+    #: Objects skipped because ``skip_existing`` found a matching local copy.
+    files_skipped: int
+    #: Error message of each key that could not be downloaded.
+    failed: dict[str, str]
+
+class SyncResult:
+    """
+    SyncResult contains the result of :py:meth:`RustClient.sync`, or with ``dry_run`` the actions it would take.
+    """
+
+    #: Relative paths of the new or changed objects copied, sorted.
+    copied: list[str]
+    #: Relative paths of the extraneous local files deleted.
+    deleted: list[str]
+    unchanged: int
+    bytes_copied: int
+    #: Error message of each relative path that could not be compared, copied or deleted.
+    failed: dict[str, str]
+    dry_run: bool
+
+class BenchmarkPhaseStats:
+    """
+    Throughput and latency statistics for one phase (write or read) of a benchmark.
+    """
+
+    num_objects: int
+    total_bytes: int
+    elapsed_secs: float
+    throughput_mb_per_sec: float
+    objects_per_sec: float
+    mean_object_throughput_mb_per_sec: float
+    latency_p50_ms: float
+    latency_p90_ms: float
+    latency_p99_ms: float
+    latency_max_ms: float
+
+class BenchmarkReport:
+    """
+    BenchmarkReport contains the result of :py:meth:`RustClient.benchmark`.
+    """
+
+    prefix: str
+    direction: str
+    read_only: bool
+    object_size: int
+    concurrency: int
+    write: BenchmarkPhaseStats | None
+    read: BenchmarkPhaseStats | None
+
+class RustReadStream:
+    """
+    Object reader compatible with :py:class:`asyncio.StreamReader`. Returned by :py:meth:`RustClient.open_read_stream`.
+    """
+
+    async def read(self, n: int = -1) -> bytes:
+        """
+        Read up to ``n`` bytes, or until end of stream if ``n`` is negative.
+        """
+        ...
+
+    async def readexactly(self, n: int) -> bytes:
+        """
+        Read exactly ``n`` bytes. Raises :py:class:`asyncio.IncompleteReadError` if the stream ends first.
+        """
+        ...
+
+    async def readuntil(self, separator: bytes = b"\n") -> bytes:
+        """
+        Read up to and including ``separator``. Raises :py:class:`asyncio.IncompleteReadError` if the stream ends first.
+        """
+        ...
+
+    async def readline(self) -> bytes:
+        """
+        Read one line, including the trailing newline unless the stream ends first.
+        """
+        ...
+
+    def at_eof(self) -> bool:
+        """
+        Return ``True`` if the stream is exhausted and all buffered data was read.
+        """
+        ...
+
+class RustObjectReader:
+    """
+    Seekable object reader backed by ranged reads. Returned by :py:meth:`RustClient.open_reader`.
+    """
+
+    closed: bool
+
+    async def read(self, n: int = -1) -> bytes:
+        """
+        Read up to ``n`` bytes, or until the end of the object if ``n`` is negative.
+
+        :raises ValueError: If the reader is closed.
+        """
+        ...
+
+    def seek(self, offset: int, whence: int = 0) -> int:
+        """
+        Move to ``offset`` relative to the start (``os.SEEK_SET``), current position (``os.SEEK_CUR``) or end (``os.SEEK_END``) of the object.
+
+        :return: The new position.
+        :raises ValueError: If the position is negative or past the end of the object, or the reader is closed.
+        """
+        ...
+
+    def tell(self) -> int:
+        """
+        Return the current position.
+        """
+        ...
+
+    def close(self) -> None:
+        """
+        Close the reader. Closing a closed reader has no effect.
+        """
+        ...
+
+class RustObjectWriter:
+    """
+    Incremental object writer backed by a multipart upload. Returned by :py:meth:`RustClient.open_writer`.
+
+    The upload is only completed by :py:meth:`close`. If the writer is aborted, a part fails to upload, or the
+    writer is garbage collected first, the multipart upload is aborted.
+    """
+
+    async def write(self, data: bytes | memoryview | bytearray) -> None:
+        """
+        Buffer ``data``, uploading each part as it fills.
+
+        :raises ValueError: If the writer is closed or aborted.
+        """
+        ...
+
+    async def flush(self) -> None:
+        """
+        Wait for the parts in flight to upload. Data short of a full part stays buffered until :py:meth:`close`.
+
+        :raises ValueError: If the writer is closed or aborted.
+        """
+        ...
+
+    async def close(self) -> int:
+        """
+        Upload the buffered data and complete the upload. Closing a closed writer has no effect.
+
+        :return: The number of bytes written.
+        """
+        ...
+
+    async def abort(self) -> None:
+        """
+        Abort the multipart upload, discarding the data written so far.
+        """
+        ...
+
+class RustWriteStream:
+    """
+    Object writer compatible with :py:class:`asyncio.StreamWriter`. Returned by :py:meth:`RustClient.open_write_stream`.
+
+    The upload is only completed by :py:meth:`close`. If the stream is garbage collected before that, or a part
+    fails to upload, the multipart upload is aborted.
+    """
+
+    def write(self, data: bytes | memoryview | bytearray) -> None:
+        """
+        Queue ``data`` for upload.
+        """
+        ...
+
+    def writelines(self, data: list[bytes | memoryview | bytearray]) -> None:
+        """
+        Queue each buffer in ``data`` for upload.
+        """
+        ...
+
+    async def drain(self) -> None:
+        """
+        Wait until the queued data has been handed to the uploader and part uploads are below ``max_concurrency``.
+        """
+        ...
+
+    def can_write_eof(self) -> bool:
+        """
+        Always ``False``; half-closing is not supported.
+        """
+        ...
+
+    def is_closing(self) -> bool:
+        """
+        Return ``True`` once :py:meth:`close` has been called.
+        """
+        ...
+
+    def close(self) -> None:
+        """
+        Flush queued data and complete the upload in the background.
+        """
+        ...
+
+    async def wait_closed(self) -> int:
+        """
+        Wait for the upload started by :py:meth:`close` to complete.
+
+        :return: The number of bytes written.
+        """
+        ...
+
+class RustListIterator:
+    """
+    Async iterator over batches of :py:class:`ObjectMetadata`. Returned by :py:meth:`RustClient.list_iter`.
+
+    The listing runs in the background at most one batch ahead. Dropping the iterator stops it.
+    """
+
+    def __aiter__(self) -> RustListIterator: ...
+    async def __anext__(self) -> list[ObjectMetadata]: ...
+
+class RustGetStream:
+    """
+    Async iterator over the ``bytes`` chunks of an object. Returned by :py:meth:`RustClient.get_stream`.
+
+    At most one chunk is read ahead of the consumer. Dropping the iterator cancels the request.
+    """
+
+    def __aiter__(self) -> RustGetStream: ...
+    async def __anext__(self) -> bytes: ...
+
+class RustDownloadHandle:
+    """
+    A download started with ``detachable=True``. Await the handle for the number of bytes downloaded.
+    """
+
+    def abandon(self) -> None:
+        """
+        Stop the download without persisting it. Chunks are no longer scheduled, those in flight are
+        cancelled and the temporary file is deleted; awaiting the handle then returns a
+        :py:class:`RustDownloadCancelled`. Has no effect once the download has finished.
+        """
+        ...
+
+    def done(self) -> bool:
+        """
+        Return ``True`` once the download has finished, failed or been abandoned.
+        """
+        ...
+
+    def __await__(self) -> Generator[Any, None, int | RustDownloadCancelled]: ...
+
+class RustDownloadCancelled:
+    """
+    Result of awaiting a :py:class:`RustDownloadHandle` that was abandoned. It is falsy.
+    """
+
+    def __bool__(self) -> bool: ...
+
+def set_rate_limit(group: str, bytes_per_sec: float | None = None, requests_per_sec: float | None = None) -> None:
+    """
+    Set the limits of a process-wide rate limit group.
+
+    Every :py:class:`RustClient` configured with ``rate_limit_group=group`` shares one token bucket
+    for bytes and one for requests, charged per request (each multipart part or ranged read is one
+    request). Changes apply immediately to clients that already exist.
+
+    :param group: The rate limit group name.
+    :param bytes_per_sec: Maximum transfer rate across the group, or ``None`` for no limit.
+    :param requests_per_sec: Maximum request rate across the group, or ``None`` for no limit.
+    """
+    ...
+
+def rate_limit_stats() -> dict[str, dict[str, float | None]]:
+    """
+    Return each rate limit group's ``bytes_per_sec`` and ``requests_per_sec`` limits and the
+    ``throttled_secs`` its requests have spent waiting in total.
+    """
+    ...
+
+def configure_runtime(threads: int) -> None:
+    """
+    Set the number of worker threads of the process-wide Tokio runtime every :py:class:`RustClient`
+    runs its operations on, overriding the ``MSC_RUST_WORKER_THREADS`` environment variable.
+
+    The runtime starts with the first operation, after which it can't be resized. A forked child
+    starts a runtime of its own, which this can still configure.
+
+    :param threads: Number of worker threads, defaulting to one per CPU core.
+    :raises RuntimeError: If the runtime is already running in this process.
+    """
+    ...
+
+def set_log_level(level: int | str | None) -> None:
+    """
+    Set the most verbose level of the records the Rust client sends to the ``multistorageclient.rust`` logger,
+    ``WARNING`` by default. The logger's own level and handlers still apply.
+
+    At ``DEBUG``, each HTTP request is logged with its ``method``, ``path``, ``range``, ``status``, ``duration_ms``
+    and the ``retry_count`` of its operation so far, and operations that fail because the object wasn't found are
+    logged. Other failed operations and requests that got no response are logged at ``WARNING`` with the full error
+    chain. These fields are also set as attributes of the log records. Credentials, request headers and URL queries
+    are never logged.
+
+    :param level: A :py:mod:`logging` level or its name, e.g. ``logging.DEBUG`` or ``"DEBUG"``, or ``None`` to turn
+        the records off.
+    :raises ValueError: If the level name is unknown.
+    """
+    ...
+
+#: W3C ``traceparent`` of the span that :py:class:`RustClient` operations started in this context are children of.
+#: When unset, the current span of the OpenTelemetry API is used, if it is installed.
+#:
+#: Operations are only traced when the extension is built with the ``otel`` feature and ``OTEL_EXPORTER_OTLP_ENDPOINT``
+#: (or ``OTEL_EXPORTER_OTLP_TRACES_ENDPOINT``) is set. Each runs in a ``msc.rust.<operation>`` span with the provider,
+#: bucket, key, bytes, request count and status as attributes, and an event for every request it sends.
+traceparent: ContextVar[str | None]
+
+def flush_traces() -> None:
+    """
+    Export the operation spans that are still buffered. Called at interpreter exit.
+    """
+    ...
 
 class RustRetryableError(Exception):
     """
     RustRetryableError is raised when a retryable error occurs.
     """
 
-    ...
+    #: Total attempts made, including object_store's internal retries, if the error came from a store operation.
+    attempts: int | None
+    #: Seconds spent backing off between operation-level attempts.
+    retry_delay_secs: float | None
+    #: Seconds from the first attempt until the error was raised.
+    elapsed_secs: float | None
+    #: Message of the first failed attempt.
+    first_error: str | None
+    #: Message of the last failed attempt.
+    last_error: str | None
+    #: ``request_tag`` of the operation that raised the error.
+    request_tag: str | None
+    #: HTTP status of the failed response, if the error came from one.
+    status_code: int | None
+    #: Request ID the store returned with the failed response (e.g. ``x-amz-request-id``), for support cases.
+    request_id: str | None
+    #: Retries made before the error was raised.
+    retry_count: int | None
 
 class RustClientError(Exception):
     """
     RustClientError is raised when a client error occurs.
     """
 
-    ...
+    #: Total attempts made, including object_store's internal retries, if the error came from a store operation.
+    attempts: int | None
+    #: Seconds spent backing off between operation-level attempts.
+    retry_delay_secs: float | None
+    #: Seconds from the first attempt until the error was raised.
+    elapsed_secs: float | None
+    #: Message of the first failed attempt.
+    first_error: str | None
+    #: Message of the last failed attempt.
+    last_error: str | None
+    #: ``request_tag`` of the operation that raised the error.
+    request_tag: str | None
+    #: HTTP status of the failed response, if the error came from one.
+    status_code: int | None
+    #: Request ID the store returned with the failed response (e.g. ``x-amz-request-id``), for support cases.
+    request_id: str | None
+    #: Retries made before the error was raised.
+    retry_count: int | None
+
+class RustAlreadyExistsError(FileExistsError):
+    """
+    RustAlreadyExistsError is raised when a write with ``mode="create"`` or ``if_not_exists``, or a copy or rename
+    without ``overwrite``, finds an existing object.
+
+    Single-request writes use a conditional put. Multipart uploads check for the object before
+    starting, so a concurrent writer can still create it in between.
+    """
+
+class RustCircuitOpenError(RustRetryableError):
+    """
+    RustCircuitOpenError is raised without sending a request while the client's circuit breaker is open.
+    """
+
+class RustIntegrityError(RustClientError):
+    """
+    RustIntegrityError is raised when a download with ``verify_checksum`` doesn't match the object it was read from,
+    or when an uploaded object's ``checksum_algorithm`` checksum or, with ``verify_upload``, its size or MD5 doesn't match
+    what the store reports.
+    """
+
+class RustPreconditionFailedError(RustClientError):
+    """
+    RustPreconditionFailedError is raised with status code 412 when a conditional request's precondition, such as
+    ``if_match``, doesn't hold.
+    """
+
+class RustThrottledError(RustRetryableError):
+    """
+    RustThrottledError is raised when the store keeps throttling requests, with a 429 or a 503 such as S3's
+    ``SlowDown``, after object_store's own retries.
+    """
+
+    #: Seconds the store asked to wait before retrying, from its ``Retry-After`` header.
+    retry_after_secs: float | None
 
 class RustRetryConfig:
     """
@@ -223,9 +223,20 @@ def test_nested_only_checksum_drives_rust_path_only(rust_client_cls: MagicMock):
     assert call_kwargs["configs"].get("checksum_algorithm") == "sha256"
 
 
+@patch("multistorageclient.providers.s3.RustClient")
+def test_nested_only_md5_reaches_rust_client(rust_client_cls: MagicMock):
+    """MD5 is accepted for the Rust client, which checks it against the ETag."""
+    rust_client_cls.return_value = MagicMock()
+
+    _make_provider(rust_client={"checksum_algorithm": "MD5"})
+
+    _, call_kwargs = rust_client_cls.call_args
+    assert call_kwargs["configs"].get("checksum_algorithm") == "md5"
+
+
 @patch("multistorageclient.providers.s3.RustClient")
 def test_nested_only_non_sha256_rejected_when_rust_enabled(rust_client_cls: MagicMock):
-    """SHA256-only Rust constraint applies to nested-only values too."""
+    """The Rust checksum constraint applies to nested-only values too."""
     rust_client_cls.return_value = MagicMock()
     with pytest.raises(ValueError, match="Rust client only supports checksum_algorithm='SHA256'"):
         _make_provider(rust_client={"checksum_algorithm": "crc32"})
@@ -13,10 +13,29 @@
 # See the License for the specific language governing permissions and
 # limitations under the License.
 
+import array
+import asyncio
+import base64
+import functools
+import gc
+import gzip
+import hashlib
+import http.client
+import http.server
 import io
+import logging
+import multiprocessing
 import os
+import pathlib
+import pickle
+import subprocess
+import sys
 import tempfile
+import textwrap
+import threading
 import time
+import urllib.parse
+import urllib.request
 import uuid
 from datetime import datetime, timedelta, timezone
 from typing import Type
@@ -24,18 +43,27 @@ from typing import Type
 import pytest
 import test_multistorageclient.unit.utils.tempdatastore as tempdatastore
 
-from multistorageclient import StorageClient, StorageClientConfig
+from multistorageclient import StorageClient, StorageClientConfig, __version__
 from multistorageclient.constants import MEMORY_LOAD_LIMIT
 from multistorageclient.providers.s3 import StaticS3CredentialsProvider
 from multistorageclient.types import Range
 from multistorageclient_rust import (  # pyright: ignore[reportAttributeAccessIssue]
+    RustAlreadyExistsError,
+    RustCircuitOpenError,
+    ObjectMetadata,
     RustClient,
     RustClientError,
+    RustDownloadCancelled,
+    RustPreconditionFailedError,
     RustRetryableError,
     RustRetryConfig,
+    RustThrottledError,
+    TransferResult,
+    TransferStats,
+    set_log_level,
 )
 
-from .utils import RefreshableTestCredentialsProvider
+from .utils import RefreshableTestCredentialsProvider, RevokedTestCredentialsProvider
 
 
 async def run_rust_client_operations(rust_client: RustClient, storage_client: StorageClient):
@@ -61,6 +89,40 @@ async def run_rust_client_operations(rust_client: RustClient, storage_client: St
     result = await rust_client.get(file_path, range=Range(0, len(file_body_bytes)))
     assert result == file_body_bytes
 
+    # Test info
+    metadata = await rust_client.info(file_path)
+    assert metadata.key == file_path
+    assert metadata.content_length == len(file_body_bytes)
+    assert metadata.object_type == "file"
+    assert metadata.etag is not None
+    with pytest.raises(FileNotFoundError):
+        await rust_client.info(f"{file_path}.missing")
+
+    # Test exists
+    assert await rust_client.exists(file_path)
+    assert not await rust_client.exists(f"{file_path}.missing")
+
+    # Test copy
+    copy_path = f"{file_path}.copy"
+    assert await rust_client.copy(file_path, copy_path) == len(file_body_bytes)
+    assert await rust_client.get(copy_path) == file_body_bytes
+    with pytest.raises(RustAlreadyExistsError):
+        await rust_client.copy(file_path, copy_path, overwrite=False)
+    with pytest.raises(FileNotFoundError):
+        await rust_client.copy(f"{file_path}.missing", copy_path)
+
+    # Test rename
+    renamed_path = f"{file_path}.renamed"
+    assert await rust_client.rename(copy_path, renamed_path) == len(file_body_bytes)
+    assert await rust_client.get(renamed_path) == file_body_bytes
+    assert not storage_client.is_file(path=copy_path)
+    with pytest.raises(FileNotFoundError):
+        await rust_client.rename(copy_path, renamed_path)
+    with pytest.raises(RustAlreadyExistsError):
+        await rust_client.rename(file_path, renamed_path, overwrite=False)
+    assert await rust_client.get(file_path) == file_body_bytes
+    await rust_client.delete(renamed_path)
+
     # Test upload the file.
     with tempfile.NamedTemporaryFile(delete=False) as temp_file:
         temp_file.write(file_body_bytes)
@@ -142,7 +204,10 @@ async def run_rust_client_operations(rust_client: RustClient, storage_client: St
     result = await rust_client.get(special_chars_path)
     assert result == special_chars_body
 
-    storage_client.delete(path=special_chars_path)
+    # Test delete
+    assert await rust_client.delete(special_chars_path) is None
+    assert not storage_client.is_file(path=special_chars_path)
+    assert await rust_client.delete(special_chars_path, missing_ok=True) is None
 
 
 @pytest.mark.parametrize(
@@ -229,21 +294,6 @@ async def test_rustclient_basic_operations_with_sha256_checksum(
         await run_rust_client_operations(rust_client, storage_client)
 
 
-def test_rustclient_invalid_checksum_algorithm_raises():
-    with pytest.raises(ValueError, match="checksum_algorithm"):
-        RustClient(
-            provider="s3",
-            configs={
-                "bucket": "test-bucket",
-                "endpoint_url": "http://localhost:7070",
-                "region_name": "us-east-1",
-                "allow_http": True,
-                "checksum_algorithm": "md5",
-            },
-            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
-        )
-
-
 @pytest.mark.parametrize(
     argnames=["temp_data_store_type"],
     argvalues=[
@@ -251,83 +301,68 @@ def test_rustclient_invalid_checksum_algorithm_raises():
     ],
 )
 @pytest.mark.asyncio
-async def test_rustclient_with_refreshable_credentials(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+async def test_rustclient_presigned_urls(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
     with temp_data_store_type() as temp_data_store:
         config_dict = temp_data_store.profile_config_dict()
-        # The credentials are valid for 605 seconds before the refresh, refresh threshold is 10 minutes for Rust Client.
-        # After refresh, the credentials are invalid.
-        credentials_provider = RefreshableTestCredentialsProvider(
+        credentials_provider = StaticS3CredentialsProvider(
             access_key=config_dict["credentials_provider"]["options"]["access_key"],
             secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
-            expiration=(datetime.now(timezone.utc) + timedelta(seconds=605)).strftime("%Y-%m-%dT%H:%M:%SZ"),
         )
-
         rust_client = RustClient(
             provider="s3",
             configs={
                 "bucket": config_dict["storage_provider"]["options"]["base_path"],
                 "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
                 "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
-                "max_concurrency": 16,
-                "multipart_chunksize": 10 * 1024 * 1024,
             },
             credentials_provider=credentials_provider,
         )
 
-        # Create a storage client as well for operations that are not supported by the Rust client
-        profile = "data"
-        py_client_config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
-        py_storage_client = StorageClient(
-            config=StorageClientConfig.from_dict(config_dict=py_client_config_dict, profile=profile)
-        )
-
-        file_extension = ".txt"
-        # add a random string to the file path below so concurrent tests don't conflict
-        file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"suffix{file_extension}"]
-        file_path = os.path.join(*file_path_fragments)
-        file_body_bytes = b"\x00\x01\x02" * 3
-
-        # Test before valid credentials expire
-        await rust_client.put(file_path, file_body_bytes)
-        result = await rust_client.get(file_path)
-        assert result == file_body_bytes
-        assert credentials_provider.refresh_count == 0
-
-        # Test Rust client proactively refreshes credentials 10 minutes before expiration, should call refresh_credentials and fail
-        time.sleep(6)
-        with pytest.raises(RustClientError) as exc_info:
-            await rust_client.get(file_path)
-        assert exc_info.value.args[1] == 403
-        assert credentials_provider.refresh_count == 1
+        put_url = rust_client.generate_presigned_url("presigned/data.txt", method="PUT", expires_in=60)
+        with urllib.request.urlopen(urllib.request.Request(put_url, data=b"hello", method="PUT")) as response:
+            assert response.status == 200
+        assert await rust_client.get("presigned/data.txt") == b"hello"
 
-        error_message = str(exc_info.value)
-        assert "The operation lacked the necessary privileges" in error_message or "403 Forbidden" in error_message, (
-            f"Expected access error in message, but got: {error_message}"
+        get_url = rust_client.generate_presigned_url(
+            "presigned/data.txt",
+            response_content_disposition='attachment; filename="hello.txt"',
+            response_content_type="text/plain",
         )
-
-        # Delete the file.
-        py_storage_client.delete(path=file_path)
+        with urllib.request.urlopen(get_url) as response:
+            assert response.read() == b"hello"
+            assert response.headers["Content-Disposition"] == 'attachment; filename="hello.txt"'
+            assert response.headers["Content-Type"] == "text/plain"
+
+        with pytest.raises(ValueError, match="only supported for GET"):
+            rust_client.generate_presigned_url("presigned/data.txt", method="PUT", response_content_type="text/plain")
+        with pytest.raises(ValueError, match="expires_in"):
+            rust_client.generate_presigned_url("presigned/data.txt", expires_in=8 * 24 * 60 * 60)
+        with pytest.raises(ValueError, match="method"):
+            rust_client.generate_presigned_url("presigned/data.txt", method="DELETE")
+
+    anonymous_client = RustClient(provider="s3", configs={"bucket": "bucket", "skip_signature": True})
+    with pytest.raises(ValueError, match="without credentials"):
+        anonymous_client.generate_presigned_url("data.txt")
+    file_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    with pytest.raises(ValueError, match="not supported by the 'file' provider"):
+        file_client.generate_presigned_url("data.txt")
 
 
 @pytest.mark.parametrize(
     argnames=["temp_data_store_type"],
     argvalues=[
-        [tempdatastore.TemporarySwiftStackBucket],
+        [tempdatastore.TemporaryAWSS3Bucket],
     ],
 )
 @pytest.mark.asyncio
-async def test_rustclient_with_refreshable_credentials_expect_error(
+async def test_rustclient_basic_operations_with_unsigned_payload(
     temp_data_store_type: Type[tempdatastore.TemporaryDataStore],
 ):
     with temp_data_store_type() as temp_data_store:
         config_dict = temp_data_store.profile_config_dict()
-
-        # The credentials are valid for 599 seconds before the refresh, refresh threshold is 10 minutes for Rust Client.
-        credentials_provider = RefreshableTestCredentialsProvider(
+        credentials_provider = StaticS3CredentialsProvider(
             access_key=config_dict["credentials_provider"]["options"]["access_key"],
             secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
-            expiration=(datetime.now(timezone.utc) + timedelta(seconds=599)).strftime("%Y-%m-%dT%H:%M:%SZ"),
-            refresh_error=True,
         )
 
         rust_client = RustClient(
@@ -336,225 +371,2960 @@ async def test_rustclient_with_refreshable_credentials_expect_error(
                 "bucket": config_dict["storage_provider"]["options"]["base_path"],
                 "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
                 "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "max_concurrency": 16,
+                "multipart_chunksize": 10 * 1024 * 1024,
+                "unsigned_payload": True,
             },
             credentials_provider=credentials_provider,
         )
 
-        # Create a file
-        file_extension = ".txt"
-        file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"suffix{file_extension}"]
-        file_path = os.path.join(*file_path_fragments)
-        file_body_bytes = b"\x00\x01\x02" * 3
+        profile = "data"
+        config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
+        storage_client = StorageClient(config=StorageClientConfig.from_dict(config_dict=config_dict, profile=profile))
 
-        # Test Rust client proactively refreshes credentials 10 minutes before expiration, should call refresh_credentials and fail
-        with pytest.raises(RustRetryableError) as exc_info:
-            await rust_client.put(file_path, file_body_bytes)
-        assert credentials_provider.refresh_count == 1
+        await run_rust_client_operations(rust_client, storage_client)
 
-        # Verify the error message indicates refresh failure (Python-side exception)
-        error_message = str(exc_info.value)
-        assert "Failed to refresh credentials" in error_message, (
-            f"Expected refresh failure error in message, but got: {error_message}"
+
+def test_rustclient_unsigned_payload_with_checksum_raises():
+    with pytest.raises(ValueError, match="unsigned_payload"):
+        RustClient(
+            provider="s3",
+            configs={
+                "bucket": "test-bucket",
+                "endpoint_url": "http://localhost:7070",
+                "region_name": "us-east-1",
+                "allow_http": True,
+                "unsigned_payload": True,
+                "checksum_algorithm": "sha256",
+            },
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        )
+
+
+def test_rustclient_invalid_checksum_algorithm_raises():
+    with pytest.raises(ValueError, match="checksum_algorithm"):
+        RustClient(
+            provider="s3",
+            configs={
+                "bucket": "test-bucket",
+                "endpoint_url": "http://localhost:7070",
+                "region_name": "us-east-1",
+                "allow_http": True,
+                "checksum_algorithm": "crc32c",
+            },
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
         )
+    with pytest.raises(ValueError, match="checksum_algorithm"):
+        RustClient(provider="file", configs={"base_path": "/tmp", "checksum_algorithm": "sha256"})
 
 
 @pytest.mark.parametrize(
     argnames=["temp_data_store_type"],
     argvalues=[
         [tempdatastore.TemporaryAWSS3Bucket],
-        [tempdatastore.TemporarySwiftStackBucket],
     ],
 )
 @pytest.mark.asyncio
-async def test_rustclient_list_recursive(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+async def test_rustclient_md5_checksum(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
     with temp_data_store_type() as temp_data_store:
         config_dict = temp_data_store.profile_config_dict()
-        credentials_provider = StaticS3CredentialsProvider(
-            access_key=config_dict["credentials_provider"]["options"]["access_key"],
-            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
-        )
         rust_client = RustClient(
             provider="s3",
             configs={
                 "bucket": config_dict["storage_provider"]["options"]["base_path"],
                 "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
                 "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
-                "max_concurrency": 16,
-                "multipart_chunksize": 10 * 1024 * 1024,
+                "checksum_algorithm": "md5",
             },
-            credentials_provider=credentials_provider,
+            credentials_provider=StaticS3CredentialsProvider(
+                access_key=config_dict["credentials_provider"]["options"]["access_key"],
+                secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            ),
         )
 
-        profile = "data"
-        config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
-        storage_client = StorageClient(config=StorageClientConfig.from_dict(config_dict=config_dict, profile=profile))
+        data = b"checksum" * 1024
+        result = await rust_client.put("small.bin", data, detailed=True)
+        assert result.checksum_algorithm == "md5"
+        assert result.checksum == hashlib.md5(data).hexdigest()
 
-        test_prefix = f"test-list-recursive-{uuid.uuid4().hex}"
+        chunksize = 5 * 1024 * 1024
+        data = os.urandom(chunksize + 1024)
+        result = await rust_client.upload_multipart_from_bytes(
+            "large.bin", data, multipart_chunksize=chunksize, detailed=True
+        )
+        digests = hashlib.md5(data[:chunksize]).digest() + hashlib.md5(data[chunksize:]).digest()
+        assert result.checksum == f"{hashlib.md5(digests).hexdigest()}-2"
+        assert result.checksum == result.etag.strip('"')
 
-        test_files = [
-            f"{test_prefix}/file1.txt",
-            f"{test_prefix}/file2.txt",
-            f"{test_prefix}/subdir1/file3.txt",
-            f"{test_prefix}/subdir1/file4.txt",
-            f"{test_prefix}/subdir2/file5.txt",
-            f"{test_prefix}/subdir1/nested/file6.txt",
-        ]
+        assert await rust_client.put("plain.bin", data) == len(data)
 
-        file_content = b"test content for list_recursive"
 
-        for file_path in test_files:
-            await rust_client.put(file_path, file_content)
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_trailing_checksum(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        bucket = config_dict["storage_provider"]["options"]["base_path"]
+        configs = {
+            "bucket": bucket,
+            "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+            "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+        }
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
 
-        result = await rust_client.list_recursive([test_prefix])
+        rust_client = RustClient(
+            provider="s3",
+            configs={**configs, "trailing_checksum": "sha256"},
+            credentials_provider=credentials_provider,
+        )
+        data = b"trailing" * 1024
+        assert await rust_client.put("small.bin", data) == len(data)
+        assert await rust_client.get("small.bin") == data
+        head = temp_data_store._client.head_object(Bucket=bucket, Key="small.bin", ChecksumMode="ENABLED")
+        assert head["ChecksumSHA256"] == base64.b64encode(hashlib.sha256(data).digest()).decode()
+
+        chunksize = 5 * 1024 * 1024
+        data = os.urandom(chunksize + 1024)
+        await rust_client.upload_multipart_from_bytes("large.bin", data, multipart_chunksize=chunksize)
+        assert await rust_client.get("large.bin") == data
+        local_path = tmp_path / "large.bin"
+        local_path.write_bytes(data)
+        await rust_client.upload_multipart_from_file(str(local_path), "from_file.bin", multipart_chunksize=chunksize)
+        assert await rust_client.get("from_file.bin") == data
 
-        assert hasattr(result, "objects")
-        assert hasattr(result, "prefixes")
-        assert isinstance(result.objects, list)
-        assert isinstance(result.prefixes, list)
+        rust_client = RustClient(
+            provider="s3",
+            configs={**configs, "trailing_checksum": "crc32c"},
+            credentials_provider=credentials_provider,
+        )
+        assert await rust_client.put("crc32c.bin", b"hello world") == 11
+        assert await rust_client.get("crc32c.bin") == b"hello world"
 
-        assert len(result.objects) == 6
+        with pytest.raises(ValueError, match="sha256, crc32c"):
+            RustClient(provider="s3", configs={**configs, "trailing_checksum": "md5"})
+        with pytest.raises(ValueError, match="can't be combined"):
+            RustClient(
+                provider="s3",
+                configs={**configs, "trailing_checksum": "sha256", "checksum_algorithm": "sha256"},
+                credentials_provider=credentials_provider,
+            )
 
-        for obj in result.objects:
-            assert hasattr(obj, "key")
-            assert hasattr(obj, "content_length")
-            assert hasattr(obj, "last_modified")
-            assert hasattr(obj, "object_type")
-            assert hasattr(obj, "etag")
-            assert obj.object_type == "file"
-            assert obj.content_length == len(file_content)
-            assert obj.key in test_files
 
-        expected_dirs = [
-            f"{test_prefix}/subdir1",
-            f"{test_prefix}/subdir2",
-            f"{test_prefix}/subdir1/nested",
-        ]
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_verify_upload(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "verify_upload": True,
+            },
+            credentials_provider=StaticS3CredentialsProvider(
+                access_key=config_dict["credentials_provider"]["options"]["access_key"],
+                secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            ),
+        )
 
-        dir_keys = [obj.key for obj in result.prefixes]
-        assert len(dir_keys) == len(expected_dirs)
-        for expected_dir in expected_dirs:
-            assert any(dir_key == expected_dir for dir_key in dir_keys), (
-                f"Expected directory {expected_dir} not found in {dir_keys}"
-            )
+        local_path = tmp_path / "small.bin"
+        local_path.write_bytes(b"verify" * 1024)
+        result = await rust_client.upload(str(local_path), "small.bin", detailed=True)
+        assert result.metadata.key == "small.bin"
+        assert result.metadata.content_length == 6 * 1024
+        assert result.metadata.etag == result.etag
 
-        limited_result = await rust_client.list_recursive([test_prefix], limit=3)
-        assert len(limited_result.objects) == 3
+        chunksize = 5 * 1024 * 1024
+        data = os.urandom(chunksize + 1024)
+        result = await rust_client.upload_multipart_from_bytes(
+            "large.bin", data, multipart_chunksize=chunksize, detailed=True
+        )
+        assert result.metadata.content_length == len(data)
 
-        txt_result = await rust_client.list_recursive([test_prefix], suffix=".txt")
-        assert all(obj.key.endswith(".txt") for obj in txt_result.objects)
+        local_path.write_bytes(data)
+        result = await rust_client.upload_multipart_from_file(
+            str(local_path), "large.bin", multipart_chunksize=chunksize, detailed=True, verify_upload=False
+        )
+        assert result.metadata is None
+        assert await rust_client.upload(str(local_path), "large.bin") == len(data)
 
-        depth_1_result = await rust_client.list_recursive([test_prefix], max_depth=1)
-        for obj in depth_1_result.objects:
-            path_parts = obj.key.replace(test_prefix + "/", "").split("/")
-            assert len(path_parts) <= 2, f"File {obj.key} exceeds max_depth=1"
 
-        multi_prefix_result = await rust_client.list_recursive([f"{test_prefix}/subdir1", f"{test_prefix}/subdir2"])
-        assert len(multi_prefix_result.objects) == 4
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_compress(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=StaticS3CredentialsProvider(
+                access_key=config_dict["credentials_provider"]["options"]["access_key"],
+                secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            ),
+        )
 
-        concurrency_result = await rust_client.list_recursive([test_prefix], max_concurrency=4)
-        assert len(concurrency_result.objects) == 6
+        data = b"compressible record\n" * 100_000
+        result = await rust_client.put("small.gz", data, detailed=True, compress="gzip", compression_level=9)
+        assert result.uncompressed_size == len(data)
+        assert result.bytes_transferred < len(data) // 10
+        compressed = await rust_client.get("small.gz")
+        assert len(compressed) == result.bytes_transferred
+        assert gzip.decompress(compressed) == data
+
+        chunksize = 5 * 1024 * 1024
+        local_path = tmp_path / "large.bin"
+        local_path.write_bytes(os.urandom(chunksize) + data)
+        progress = []
+        result = await rust_client.upload_multipart_from_file(
+            str(local_path),
+            "large.gz",
+            multipart_chunksize=chunksize,
+            detailed=True,
+            progress_callback=lambda transferred, total: progress.append((transferred, total)),
+            compress="gzip",
+        )
+        assert result.uncompressed_size == local_path.stat().st_size
+        assert result.parts is not None and len(result.parts) == 2
+        assert progress[-1] == (result.uncompressed_size, result.uncompressed_size)
+        assert gzip.decompress(await rust_client.get("large.gz")) == local_path.read_bytes()
+
+        # Without detailed the compressed size is returned as is.
+        compressed_size = await rust_client.upload_multipart_from_file(str(local_path), "large.zst", compress="zstd")
+        assert compressed_size == len(await rust_client.get("large.zst"))
+        stats = await rust_client.upload_multipart_from_file(
+            str(local_path), "large.zst", return_stats=True, compress="zstd"
+        )
+        assert stats.bytes_transferred < stats.uncompressed_size == local_path.stat().st_size
 
-        for file_path in test_files:
-            storage_client.delete(path=file_path)
+        with pytest.raises(ValueError, match="compress"):
+            await rust_client.put("bad.gz", data, compress="brotli")
+        with pytest.raises(ValueError, match="compression_level"):
+            await rust_client.put("bad.gz", data, compress="gzip", compression_level=10)
+        with pytest.raises(ValueError, match="Content-Encoding"):
+            await rust_client.put("bad.gz", data, metadata={"content-encoding": "gzip"}, compress="gzip")
+
+    file_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    with pytest.raises(ValueError, match="compress"):
+        await file_client.put("data.gz", b"data", compress="gzip")
 
 
 @pytest.mark.parametrize(
     argnames=["temp_data_store_type"],
     argvalues=[
         [tempdatastore.TemporaryAWSS3Bucket],
-        [tempdatastore.TemporarySwiftStackBucket],
     ],
 )
 @pytest.mark.asyncio
-async def test_rustclient_explicit_multipart_chunksize(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+async def test_rustclient_append(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
+    file_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    assert await file_client.append("logs/app.log", b"one\n") == 4
+    assert await file_client.append("logs/app.log", b"two\n") == 8
+    assert (tmp_path / "logs" / "app.log").read_bytes() == b"one\ntwo\n"
+
     with temp_data_store_type() as temp_data_store:
         config_dict = temp_data_store.profile_config_dict()
-        credentials_provider = StaticS3CredentialsProvider(
-            access_key=config_dict["credentials_provider"]["options"]["access_key"],
-            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
-        )
         rust_client = RustClient(
             provider="s3",
             configs={
                 "bucket": config_dict["storage_provider"]["options"]["base_path"],
                 "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
                 "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
-                "max_concurrency": 16,
-                "multipart_chunksize": 10 * 1024 * 1024,
+                "max_append_rewrite_size": 8,
             },
-            credentials_provider=credentials_provider,
+            credentials_provider=StaticS3CredentialsProvider(
+                access_key=config_dict["credentials_provider"]["options"]["access_key"],
+                secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            ),
         )
 
-        profile = "data"
-        config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
-        storage_client = StorageClient(config=StorageClientConfig.from_dict(config_dict=config_dict, profile=profile))
+        await rust_client.put("app.log", b"one\n", content_type="text/plain")
+        assert await rust_client.append("app.log", b"two\n") == 8
+        assert await rust_client.get("app.log") == b"one\ntwo\n"
+        assert (await rust_client.info("app.log")).content_type == "text/plain"
+        with pytest.raises(ValueError, match="max_append_rewrite_size"):
+            await rust_client.append("app.log", b"three\n")
+
+        assert await rust_client.append("new.log", b"first\n") == 6
+
+
+def test_rustclient_server_side_encryption_configs():
+    configs = {
+        "bucket": "test-bucket",
+        "endpoint_url": "http://localhost:7070",
+        "region_name": "us-east-1",
+        "allow_http": True,
+    }
+    credentials_provider = StaticS3CredentialsProvider(access_key="a", secret_key="b")
+    customer_key = base64.b64encode(os.urandom(32)).decode()
+
+    for sse_configs in [
+        {"sse": "AES256"},
+        {"sse": "aws:kms", "sse_kms_key_id": "alias/data"},
+        {"sse_c_key": customer_key},
+        {"sse": "AES256", "checksum_algorithm": "md5"},
+    ]:
+        RustClient(provider="s3", configs={**configs, **sse_configs}, credentials_provider=credentials_provider)
+
+    for sse_configs, match in [
+        ({"sse": "aws:kms", "sse_c_key": customer_key}, "sse_c_key"),
+        ({"sse": "AES256", "sse_kms_key_id": "alias/data"}, "sse_kms_key_id"),
+        ({"sse": "none"}, "sse"),
+        ({"sse_c_key": "c2VjcmV0"}, "sse_c_key"),
+        # The ETags of objects encrypted with KMS or a customer key aren't their MD5.
+        ({"sse": "aws:kms", "checksum_algorithm": "md5"}, "md5"),
+        ({"sse_c_key": customer_key, "checksum_algorithm": "md5"}, "md5"),
+    ]:
+        with pytest.raises(ValueError, match=match):
+            RustClient(provider="s3", configs={**configs, **sse_configs}, credentials_provider=credentials_provider)
+
+
+def test_rustclient_tls_configs(tmp_path):
+    configs = {
+        "bucket": "test-bucket",
+        "endpoint_url": "https://localhost:7070",
+        "region_name": "us-east-1",
+    }
+    credentials_provider = StaticS3CredentialsProvider(access_key="a", secret_key="b")
+
+    RustClient(
+        provider="s3",
+        configs={**configs, "allow_invalid_certificates": True},
+        credentials_provider=credentials_provider,
+    )
+
+    malformed_path = tmp_path / "malformed.pem"
+    malformed_path.write_text("-----BEGIN CERTIFICATE-----\n!!!\n-----END CERTIFICATE-----\n")
+    for tls_configs, match in [
+        ({"ca_certificate_path": str(tmp_path / "missing.pem")}, "missing.pem"),
+        ({"ca_certificate_path": str(malformed_path)}, "malformed.pem"),
+        ({"ca_certificate_pem": "not a certificate"}, "ca_certificate_pem"),
+        ({"ca_certificate_path": str(malformed_path), "ca_certificate_pem": "pem"}, "cannot be combined"),
+    ]:
+        with pytest.raises(ValueError, match=match):
+            RustClient(provider="s3", configs={**configs, **tls_configs}, credentials_provider=credentials_provider)
+        with pytest.raises(ValueError, match=match):
+            RustClient(provider="gcs", configs={"bucket": "test-bucket", "skip_signature": True, **tls_configs})
+
+
+def test_rustclient_request_payer_configs():
+    configs = {
+        "bucket": "test-bucket",
+        "endpoint_url": "http://localhost:7070",
+        "region_name": "us-east-1",
+        "allow_http": True,
+    }
+    credentials_provider = StaticS3CredentialsProvider(access_key="a", secret_key="b")
+
+    for request_payer in [True, "true", False]:
+        RustClient(
+            provider="s3",
+            configs={**configs, "request_payer": request_payer},
+            credentials_provider=credentials_provider,
+        )
+
+    with pytest.raises(ValueError, match="request_payer"):
+        RustClient(provider="s3", configs={**configs, "request_payer": 1}, credentials_provider=credentials_provider)
+
+    with pytest.raises(ValueError, match="skip_signature"):
+        RustClient(provider="s3", configs={**configs, "request_payer": True, "skip_signature": True})
+
+
+def test_rustclient_retry_configs():
+    rust_client = RustClient(
+        provider="s3",
+        configs={
+            "bucket": "test-bucket",
+            "endpoint_url": "http://localhost:7070",
+            "region_name": "us-east-1",
+            "allow_http": True,
+            "retry_max_attempts": 2,
+            "retry_max_backoff_ms": 500,
+            "retry_timeout_secs": 5,
+            "retry_operation_attempts": 3,
+        },
+        credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        retry=RustRetryConfig(init_backoff_ms=50),
+    )
+    config = rust_client.get_config()
+    assert config["retry_max_attempts"] == 2
+    assert config["retry_init_backoff_ms"] == 50
+    assert config["retry_max_backoff_ms"] == 500
+    assert config["retry_timeout_secs"] == 5
+    assert config["retry_operation_attempts"] == 3
+    assert config["provider"] == "s3"
+
+
+@pytest.mark.asyncio
+async def test_rustclient_circuit_breaker():
+    rust_client = RustClient(
+        provider="s3",
+        configs={
+            "bucket": "test-bucket",
+            "endpoint_url": "http://127.0.0.1:1",
+            "region_name": "us-east-1",
+            "allow_http": True,
+            "circuit_breaker": {"failure_rate": 1.0, "window": 2, "min_requests": 2, "cooldown": 60},
+        },
+        credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        retry=RustRetryConfig(attempts=0, timeout=5, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+    )
+    assert rust_client.circuit_breaker_stats() == {"state": "closed", "trips": 0, "rejected": 0}
+
+    for _ in range(2):
+        with pytest.raises(RustRetryableError) as exc_info:
+            await rust_client.get("object")
+        assert not isinstance(exc_info.value, RustCircuitOpenError)
+
+    # The endpoint is down, so the next request fails fast without being sent.
+    with pytest.raises(RustCircuitOpenError):
+        await rust_client.get("object")
+    assert rust_client.circuit_breaker_stats() == {"state": "open", "trips": 1, "rejected": 1}
+
+    with pytest.raises(ValueError, match="failure_rate"):
+        RustClient(
+            provider="s3",
+            configs={"bucket": "test-bucket", "region_name": "us-east-1", "circuit_breaker": {"failure_rate": 2}},
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        )
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+def test_rustclient_validate_on_init(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        options = config_dict["storage_provider"]["options"]
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+
+        def create_client(bucket: str, endpoint_url: str = options["endpoint_url"], credentials=credentials_provider):
+            return RustClient(
+                provider="s3",
+                configs={
+                    "bucket": bucket,
+                    "endpoint_url": endpoint_url,
+                    "allow_http": endpoint_url.startswith("http://"),
+                    "validate_on_init": True,
+                },
+                credentials_provider=credentials,
+                retry=RustRetryConfig(attempts=0, timeout=5, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+            )
+
+        create_client(options["base_path"])
+
+        with pytest.raises(FileNotFoundError, match=options["endpoint_url"]):
+            create_client(f"{options['base_path']}-{uuid.uuid4().hex[:8]}")
+
+        with pytest.raises(PermissionError, match="request_payer=True"):
+            create_client(options["base_path"], credentials=StaticS3CredentialsProvider(access_key="a", secret_key="b"))
+
+        with pytest.raises(ConnectionError, match="us-east-1"):
+            create_client(options["base_path"], endpoint_url="http://127.0.0.1:1")
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_with_refreshable_credentials(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        # The credentials are valid for 605 seconds before the refresh, refresh threshold is 10 minutes for Rust Client.
+        # After refresh, the credentials are invalid.
+        credentials_provider = RefreshableTestCredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            expiration=(datetime.now(timezone.utc) + timedelta(seconds=605)).strftime("%Y-%m-%dT%H:%M:%SZ"),
+        )
+
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "max_concurrency": 16,
+                "multipart_chunksize": 10 * 1024 * 1024,
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        # Create a storage client as well for operations that are not supported by the Rust client
+        profile = "data"
+        py_client_config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
+        py_storage_client = StorageClient(
+            config=StorageClientConfig.from_dict(config_dict=py_client_config_dict, profile=profile)
+        )
 
-        large_file_size = MEMORY_LOAD_LIMIT + 1
-        large_data_bytes = os.urandom(large_file_size)
         file_extension = ".txt"
-        large_file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"multipart_suffix{file_extension}"]
-        large_file_path = os.path.join(*large_file_path_fragments)
+        # add a random string to the file path below so concurrent tests don't conflict
+        file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"suffix{file_extension}"]
+        file_path = os.path.join(*file_path_fragments)
+        file_body_bytes = b"\x00\x01\x02" * 3
+
+        # Test before valid credentials expire
+        await rust_client.put(file_path, file_body_bytes)
+        result = await rust_client.get(file_path)
+        assert result == file_body_bytes
+        assert credentials_provider.refresh_count == 0
+
+        # Test Rust client proactively refreshes credentials 10 minutes before expiration, should call refresh_credentials and fail
+        time.sleep(6)
+        with pytest.raises(RustClientError) as exc_info:
+            await rust_client.get(file_path)
+        assert exc_info.value.args[1] == 403
+        # The denied request forced another refresh before failing.
+        assert credentials_provider.refresh_count == 2
+
+        error_message = str(exc_info.value)
+        assert "The operation lacked the necessary privileges" in error_message or "403 Forbidden" in error_message, (
+            f"Expected access error in message, but got: {error_message}"
+        )
+
+        # Delete the file.
+        py_storage_client.delete(path=file_path)
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_with_refreshable_credentials_expect_error(
+    temp_data_store_type: Type[tempdatastore.TemporaryDataStore],
+):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+
+        # The credentials are valid for 599 seconds before the refresh, refresh threshold is 10 minutes for Rust Client.
+        credentials_provider = RefreshableTestCredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+            expiration=(datetime.now(timezone.utc) + timedelta(seconds=599)).strftime("%Y-%m-%dT%H:%M:%SZ"),
+            refresh_error=True,
+        )
+
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        # Create a file
+        file_extension = ".txt"
+        file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"suffix{file_extension}"]
+        file_path = os.path.join(*file_path_fragments)
+        file_body_bytes = b"\x00\x01\x02" * 3
+
+        # Test Rust client proactively refreshes credentials 10 minutes before expiration, should call refresh_credentials and fail
+        with pytest.raises(RustRetryableError) as exc_info:
+            await rust_client.put(file_path, file_body_bytes)
+        assert credentials_provider.refresh_count == 1
+
+        # Verify the error message indicates refresh failure (Python-side exception)
+        error_message = str(exc_info.value)
+        assert "Failed to refresh credentials" in error_message, (
+            f"Expected refresh failure error in message, but got: {error_message}"
+        )
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_list_recursive(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "max_concurrency": 16,
+                "multipart_chunksize": 10 * 1024 * 1024,
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        profile = "data"
+        config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
+        storage_client = StorageClient(config=StorageClientConfig.from_dict(config_dict=config_dict, profile=profile))
+
+        test_prefix = f"test-list-recursive-{uuid.uuid4().hex}"
+
+        test_files = [
+            f"{test_prefix}/file1.txt",
+            f"{test_prefix}/file2.txt",
+            f"{test_prefix}/subdir1/file3.txt",
+            f"{test_prefix}/subdir1/file4.txt",
+            f"{test_prefix}/subdir2/file5.txt",
+            f"{test_prefix}/subdir1/nested/file6.txt",
+        ]
+
+        file_content = b"test content for list_recursive"
+
+        for file_path in test_files:
+            await rust_client.put(file_path, file_content)
+
+        result = await rust_client.list_recursive([test_prefix])
+
+        assert hasattr(result, "objects")
+        assert hasattr(result, "prefixes")
+        assert isinstance(result.objects, list)
+        assert isinstance(result.prefixes, list)
+
+        assert len(result.objects) == 6
+
+        for obj in result.objects:
+            assert hasattr(obj, "key")
+            assert hasattr(obj, "content_length")
+            assert hasattr(obj, "last_modified")
+            assert hasattr(obj, "object_type")
+            assert hasattr(obj, "etag")
+            assert obj.object_type == "file"
+            assert obj.content_length == len(file_content)
+            assert obj.key in test_files
+
+        expected_dirs = [
+            f"{test_prefix}/subdir1",
+            f"{test_prefix}/subdir2",
+            f"{test_prefix}/subdir1/nested",
+        ]
+
+        dir_keys = [obj.key for obj in result.prefixes]
+        assert len(dir_keys) == len(expected_dirs)
+        for expected_dir in expected_dirs:
+            assert any(dir_key == expected_dir for dir_key in dir_keys), (
+                f"Expected directory {expected_dir} not found in {dir_keys}"
+            )
+
+        limited_result = await rust_client.list_recursive([test_prefix], limit=3)
+        assert len(limited_result.objects) == 3
+
+        txt_result = await rust_client.list_recursive([test_prefix], suffix=".txt")
+        assert all(obj.key.endswith(".txt") for obj in txt_result.objects)
+
+        depth_1_result = await rust_client.list_recursive([test_prefix], max_depth=1)
+        for obj in depth_1_result.objects:
+            path_parts = obj.key.replace(test_prefix + "/", "").split("/")
+            assert len(path_parts) <= 2, f"File {obj.key} exceeds max_depth=1"
+
+        multi_prefix_result = await rust_client.list_recursive([f"{test_prefix}/subdir1", f"{test_prefix}/subdir2"])
+        assert len(multi_prefix_result.objects) == 4
+
+        concurrency_result = await rust_client.list_recursive([test_prefix], max_concurrency=4)
+        assert len(concurrency_result.objects) == 6
+
+        for file_path in test_files:
+            storage_client.delete(path=file_path)
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_explicit_multipart_chunksize(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "max_concurrency": 16,
+                "multipart_chunksize": 10 * 1024 * 1024,
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        profile = "data"
+        config_dict = {"profiles": {profile: temp_data_store.profile_config_dict()}}
+        storage_client = StorageClient(config=StorageClientConfig.from_dict(config_dict=config_dict, profile=profile))
+
+        large_file_size = MEMORY_LOAD_LIMIT + 1
+        large_data_bytes = os.urandom(large_file_size)
+        file_extension = ".txt"
+        large_file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"multipart_suffix{file_extension}"]
+        large_file_path = os.path.join(*large_file_path_fragments)
+
+        # Test upload_multipart_from_bytes with large data bytes
+        chunk_size = 10 * 1024 * 1024
+        max_concurrency = 4
+        result = await rust_client.upload_multipart_from_bytes(
+            large_file_path, large_data_bytes, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+        )
+        assert result == large_file_size
+        # Test upload_multipart_from_bytes with BytesIO object
+        with io.BytesIO(large_data_bytes) as bytes_io:
+            result = await rust_client.upload_multipart_from_bytes(large_file_path, bytes_io.getbuffer())
+        assert result == large_file_size
+
+        # Test download_multipart_to_bytes with large data bytes
+        result = await rust_client.download_multipart_to_bytes(
+            large_file_path, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+        )
+        assert result == large_data_bytes
+
+        # Test download_multipart_to_bytes with range
+        result = await rust_client.download_multipart_to_bytes(
+            large_file_path,
+            range=Range(10, chunk_size * max_concurrency + 1),
+            multipart_chunksize=chunk_size,
+            max_concurrency=max_concurrency,
+        )
+        assert result == large_data_bytes[10 : 10 + chunk_size * max_concurrency + 1]
+
+        # Delete the file.
+        storage_client.delete(path=large_file_path)
+
+        # Test upload_multipart_from_file with explicit chunk size and concurrency
+        large_file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"multipart_suffix{file_extension}"]
+        large_file_path = os.path.join(*large_file_path_fragments)
+        chunk_size = 10 * 1024 * 1024
+        max_concurrency = 4
+        with tempfile.NamedTemporaryFile(delete=False) as temp_file:
+            temp_file.write(large_data_bytes)
+            temp_file.close()
+            result = await rust_client.upload_multipart_from_file(
+                temp_file.name, large_file_path, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+            )
+            assert result == large_file_size
+        os.unlink(temp_file.name)
+
+        # Test download_multipart_to_file with explicit chunk size and concurrency
+        with tempfile.NamedTemporaryFile(delete=False) as temp_file:
+            temp_file.close()
+            result = await rust_client.download_multipart_to_file(
+                large_file_path, temp_file.name, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+            )
+            assert result == large_file_size
+            assert os.path.getsize(temp_file.name) == large_file_size
+            # Assert file content is the same
+            with open(temp_file.name, "rb") as f:
+                downloaded = f.read()
+            assert downloaded == large_data_bytes
+        os.unlink(temp_file.name)
+
+        # Delete the file.
+        storage_client.delete(path=large_file_path)
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_write_mode(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        small_path = f"{uuid.uuid4().hex}/small.bin"
+        await rust_client.put(small_path, b"first", mode="create")
+        with pytest.raises(RustAlreadyExistsError) as exc_info:
+            await rust_client.put(small_path, b"second", mode="create")
+        assert isinstance(exc_info.value, FileExistsError)
+        await rust_client.put(small_path, b"second")
+        assert await rust_client.get(small_path) == b"second"
+
+        large_path = f"{uuid.uuid4().hex}/large.bin"
+        large_data = os.urandom(12 * 1024 * 1024)
+        await rust_client.upload_multipart_from_bytes(
+            large_path, large_data, multipart_chunksize=5 * 1024 * 1024, mode="create"
+        )
+        with pytest.raises(RustAlreadyExistsError):
+            await rust_client.upload_multipart_from_bytes(
+                large_path, large_data, multipart_chunksize=5 * 1024 * 1024, mode="create"
+            )
+        await rust_client.upload_multipart_from_bytes(
+            large_path, large_data, multipart_chunksize=5 * 1024 * 1024, mode="overwrite"
+        )
+
+        with tempfile.NamedTemporaryFile() as temp_file:
+            temp_file.write(b"local")
+            temp_file.flush()
+            with pytest.raises(RustAlreadyExistsError):
+                await rust_client.upload(temp_file.name, small_path, mode="create")
+
+        with pytest.raises(ValueError):
+            await rust_client.put(small_path, b"data", mode="append")
+
+        # Object store writes only become visible when complete, so streaming is rejected.
+        with pytest.raises(ValueError):
+            await rust_client.put(small_path, b"data", write_mode="streaming")
+
+
+class _RangeRequestHandler(http.server.SimpleHTTPRequestHandler):
+    """
+    Static file handler that also answers ``Range`` requests, which :py:class:`http.server.SimpleHTTPRequestHandler` ignores.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        range_header = self.headers.get("Range")
+        if range_header is None:
+            return super().do_GET()
+        with open(self.translate_path(self.path), "rb") as f:
+            data = f.read()
+        start, end = range_header.removeprefix("bytes=").split("-")
+        body = data[int(start) : int(end) + 1 if end else len(data)]
+        self.send_response(206)
+        self.send_header("Content-Range", f"bytes {start}-{int(start) + len(body) - 1}/{len(data)}")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+
+@pytest.mark.asyncio
+async def test_rustclient_http_provider(tmp_path):
+    data = os.urandom(3 * 1024 * 1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_RangeRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10}
+        )
+        assert await rust_client.get("data.bin") == data
+        assert await rust_client.get("data.bin", range=Range(10, 100)) == data[10:110]
+        assert (
+            await rust_client.download_multipart_to_bytes("data.bin", multipart_chunksize=1024 * 1024, max_concurrency=2)
+            == data
+        )
+        assert (await rust_client.info("data.bin")).content_length == len(data)
+
+        with pytest.raises(RustClientError, match="read-only") as exc_info:
+            await rust_client.put("new.bin", b"data")
+        assert exc_info.value.args[1] == 403
+        with pytest.raises(RustClientError, match="read-only"):
+            await rust_client.delete("data.bin")
+        assert (tmp_path / "data.bin").read_bytes() == data
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+class _StorageClassRequestHandler(_RangeRequestHandler):
+    def end_headers(self):
+        self.send_header("x-amz-storage-class", "GLACIER_IR")
+        super().end_headers()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_storage_class(tmp_path):
+    (tmp_path / "data.bin").write_bytes(b"data")
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_StorageClassRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}"})
+        assert (await rust_client.info("data.bin")).storage_class == "GLACIER_IR"
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    s3_configs = {"bucket": "test-bucket", "endpoint_url": "http://localhost:7070", "region_name": "us-east-1"}
+    credentials_provider = StaticS3CredentialsProvider(access_key="a", secret_key="b")
+    rust_client = RustClient(
+        provider="s3", configs={**s3_configs, "storage_class": "standard_ia"}, credentials_provider=credentials_provider
+    )
+    with pytest.raises(ValueError, match="GLACIER_IR"):
+        await rust_client.put("data.bin", b"data", storage_class="NEARLINE")
+    with pytest.raises(ValueError, match="Accepted values"):
+        RustClient(
+            provider="s3", configs={**s3_configs, "storage_class": "COLD"}, credentials_provider=credentials_provider
+        )
+
+    file_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    with pytest.raises(ValueError, match="storage_class"):
+        await file_client.put("data.bin", b"data", storage_class="STANDARD")
+    await file_client.put("data.bin", b"data")
+    assert (await file_client.info("data.bin")).storage_class is None
+
+
+class _PublicBucketRequestHandler(_RangeRequestHandler):
+    """
+    Path-style S3 endpoint serving the directory as a public bucket: signed requests are denied, like they are for a
+    bucket that only grants anonymous reads to callers without an account.
+    """
+
+    def end_headers(self):
+        self.send_header("ETag", '"public"')
+        super().end_headers()
+
+    def do_HEAD(self):
+        if self.headers.get("Authorization") is None:
+            return super().do_HEAD()
+        self.send_error(403)
+
+    def do_GET(self):
+        if self.headers.get("Authorization") is not None:
+            return self.send_error(403)
+        path, _, query = self.path.partition("?")
+        if "list-type=2" not in query:
+            return super().do_GET()
+        params = urllib.parse.parse_qs(query)
+        prefix = params.get("prefix", [""])[0]
+        delimiter = params.get("delimiter", [""])[0]
+        bucket_dir = pathlib.Path(self.translate_path(path))
+        contents, common_prefixes = [], set()
+        for file in sorted(f for f in bucket_dir.rglob("*") if f.is_file()):
+            key = file.relative_to(bucket_dir).as_posix()
+            if not key.startswith(prefix):
+                continue
+            if delimiter and delimiter in key[len(prefix) :]:
+                common_prefixes.add(key[: key.index(delimiter, len(prefix)) + 1])
+                continue
+            contents.append(
+                f"<Contents><Key>{key}</Key><LastModified>2026-01-01T00:00:00.000Z</LastModified>"
+                f"<Size>{file.stat().st_size}</Size></Contents>"
+            )
+        prefixes = "".join(f"<CommonPrefixes><Prefix>{p}</Prefix></CommonPrefixes>" for p in sorted(common_prefixes))
+        body = (
+            '<?xml version="1.0" encoding="UTF-8"?><ListBucketResult><IsTruncated>false</IsTruncated>'
+            f"{''.join(contents)}{prefixes}</ListBucketResult>"
+        ).encode()
+        self.send_response(200)
+        self.send_header("Content-Type", "application/xml")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+
+@pytest.mark.asyncio
+async def test_rustclient_anonymous(tmp_path):
+    (tmp_path / "public-bucket" / "data" / "nested").mkdir(parents=True)
+    (tmp_path / "public-bucket" / "data" / "a.bin").write_bytes(b"aaaa")
+    (tmp_path / "public-bucket" / "data" / "nested" / "b.bin").write_bytes(b"bbbbbbbb")
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_PublicBucketRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    configs = {
+        "bucket": "public-bucket",
+        "endpoint_url": f"http://127.0.0.1:{server.server_address[1]}",
+        "region_name": "us-east-1",
+        "allow_http": True,
+    }
+    try:
+        rust_client = RustClient(provider="s3", configs={**configs, "anonymous": True})
+        result = await rust_client.list_recursive(["data"])
+        assert sorted(obj.key for obj in result.objects) == ["data/a.bin", "data/nested/b.bin"]
+        assert await rust_client.get("data/nested/b.bin") == b"bbbbbbbb"
+        assert (await rust_client.info("data/a.bin")).content_length == 4
+
+        for write in [
+            lambda: rust_client.put("data/c.bin", b"c"),
+            lambda: rust_client.delete("data/a.bin"),
+            lambda: rust_client.upload_multipart_from_bytes("data/c.bin", b"c"),
+        ]:
+            with pytest.raises(RustClientError, match="anonymous=True") as exc_info:
+                await write()
+            assert exc_info.value.status_code == 403
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    with pytest.raises(ValueError, match="credentials_provider"):
+        RustClient(
+            provider="s3",
+            configs={**configs, "anonymous": True},
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        )
+
+
+def test_rustclient_missing_credentials(monkeypatch: pytest.MonkeyPatch, tmp_path):
+    for name in [
+        "AWS_ACCESS_KEY_ID",
+        "AWS_SECRET_ACCESS_KEY",
+        "AWS_SESSION_TOKEN",
+        "AWS_PROFILE",
+        "AWS_WEB_IDENTITY_TOKEN_FILE",
+        "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+        "AWS_CONTAINER_CREDENTIALS_FULL_URI",
+    ]:
+        monkeypatch.delenv(name, raising=False)
+    monkeypatch.setenv("AWS_SHARED_CREDENTIALS_FILE", str(tmp_path / "credentials"))
+    monkeypatch.setenv("AWS_CONFIG_FILE", str(tmp_path / "config"))
+    monkeypatch.setenv("AWS_EC2_METADATA_DISABLED", "true")
+    configs = {"bucket": "public-bucket", "endpoint_url": "http://localhost:7070", "region_name": "us-east-1"}
+
+    with pytest.raises(ValueError, match="anonymous=True"):
+        RustClient(provider="s3", configs=configs)
+    RustClient(provider="s3", configs={**configs, "skip_signature": True})
+
+
+@pytest.mark.asyncio
+async def test_rustclient_metrics(tmp_path):
+    data = os.urandom(1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_RangeRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}"})
+        assert rust_client.get_metrics() == {"operations": {}, "bytes_sent": 0, "bytes_received": 0}
+
+        assert await rust_client.get("data.bin") == data
+        assert rust_client.get_sync("data.bin", range=Range(0, 100)) == data[:100]
+        with pytest.raises(FileNotFoundError):
+            await rust_client.get("missing.bin")
+        assert await rust_client.exists("data.bin")
+
+        metrics = rust_client.get_metrics()
+        assert metrics["operations"].keys() == {"get", "exists"}
+        get = metrics["operations"]["get"]
+        assert get["count"] == 3
+        assert get["errors"]["not_found"] == 1
+        assert sum(get["errors"].values()) == 1
+        assert sum(get["latency_buckets"].values()) == 3
+        assert float("inf") in get["latency_buckets"]
+        assert 0 < get["latency_sum"] < 60
+        assert metrics["bytes_received"] >= len(data) + 100
+
+        with pytest.raises(ValueError, match="chunk_size"):
+            rust_client.get_stream("data.bin", chunk_size=0)
+        get_stream = rust_client.get_metrics()["operations"]["get_stream"]
+        assert get_stream["count"] == 1
+        assert get_stream["errors"]["invalid"] == 1
+
+        rust_client.reset_metrics()
+        assert rust_client.get_metrics() == {"operations": {}, "bytes_sent": 0, "bytes_received": 0}
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+class _FlakyRequestHandler(_RangeRequestHandler):
+    """
+    Static file handler that answers the next ``failures`` GET requests with a 503.
+    """
+
+    failures = 0
+
+    def do_GET(self):
+        if _FlakyRequestHandler.failures > 0:
+            _FlakyRequestHandler.failures -= 1
+            self.send_response(503)
+            self.send_header("Content-Length", "0")
+            self.end_headers()
+            return
+        super().do_GET()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_per_call_retry(tmp_path):
+    data = os.urandom(1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_FlakyRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http",
+            configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10},
+            retry=RustRetryConfig(attempts=0, timeout=10, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+        )
+        retry = RustRetryConfig(attempts=3, timeout=10, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0)
+
+        _FlakyRequestHandler.failures = 1
+        with pytest.raises(RustRetryableError):
+            await rust_client.get("data.bin")
+
+        _FlakyRequestHandler.failures = 1
+        assert await rust_client.get("data.bin", retry=retry) == data
+
+        _FlakyRequestHandler.failures = 2
+        result = await rust_client.download_multipart_to_file(
+            "data.bin", str(tmp_path / "copy.bin"), detailed=True, retry=retry
+        )
+        assert result.retry_count == 2
+        assert (tmp_path / "copy.bin").read_bytes() == data
+
+        result = await rust_client.download_multipart_to_file("data.bin", str(tmp_path / "copy.bin"), detailed=True)
+        assert result.retry_count == 0
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_logging(tmp_path, caplog: pytest.LogCaptureFixture):
+    data = os.urandom(1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_FlakyRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    caplog.set_level(logging.DEBUG, logger="multistorageclient.rust")
+    try:
+        rust_client = RustClient(
+            provider="http",
+            configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10},
+            retry=RustRetryConfig(attempts=0, timeout=10, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+        )
+        set_log_level("DEBUG")
+        assert await rust_client.get("data.bin", range=Range(0, 100)) == data[:100]
+        request = next(record for record in caplog.records if getattr(record, "status", None) == 206)
+        assert request.levelno == logging.DEBUG
+        assert (request.method, request.path, request.range) == ("GET", "/data.bin", "bytes=0-99")
+        assert request.retry_count == 0
+
+        caplog.clear()
+        _FlakyRequestHandler.failures = 1
+        with pytest.raises(RustRetryableError):
+            await rust_client.get("data.bin")
+        failure = next(record for record in caplog.records if record.levelno == logging.WARNING)
+        assert (failure.operation, failure.key, failure.error_class) == ("get", "data.bin", "retryable")
+        assert "RustRetryableError" in failure.error
+
+        # Missing objects are only logged at DEBUG.
+        caplog.clear()
+        set_log_level(logging.WARNING)
+        with pytest.raises(FileNotFoundError):
+            await rust_client.get("missing.bin")
+        assert not caplog.records
+
+        with pytest.raises(ValueError):
+            set_log_level("LOUD")
+    finally:
+        set_log_level("WARNING")
+        server.shutdown()
+        server.server_close()
+
+
+class _DeniedRequestHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that denies every request with a request ID, like an S3 endpoint would.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        self.send_response(403)
+        self.send_header("x-amz-request-id", "4442587FB7D0A2F9")
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_error_response_attributes():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _DeniedRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10}
+        )
+        with pytest.raises(RustClientError) as exc_info:
+            await rust_client.get("data.bin")
+        assert exc_info.value.status_code == 403
+        assert exc_info.value.request_id == "4442587FB7D0A2F9"
+        assert exc_info.value.retry_count == 0
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+class _PathRecordingRequestHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that records the path and headers of every request and reports the object as missing.
+    """
+
+    paths: list[str] = []
+    headers_seen: list[http.client.HTTPMessage] = []
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        self.paths.append(self.path)
+        self.headers_seen.append(self.headers)
+        self.send_response(404)
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    argnames=["addressing_configs", "expected_path"],
+    argvalues=[
+        [{}, "/test-bucket/data.bin"],
+        [{"addressing_style": "path"}, "/test-bucket/data.bin"],
+        [{"force_path_style": True}, "/test-bucket/data.bin"],
+        [{"force_path_style": "false"}, "/test-bucket/data.bin"],
+        [{"addressing_style": "virtual"}, "/data.bin"],
+    ],
+)
+async def test_rustclient_addressing_style(addressing_configs: dict, expected_path: str):
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _PathRecordingRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    _PathRecordingRequestHandler.paths = []
+    try:
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": "test-bucket",
+                "endpoint_url": f"http://127.0.0.1:{server.server_address[1]}",
+                "region_name": "us-east-1",
+                "allow_http": True,
+                **addressing_configs,
+            },
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        )
+        with pytest.raises(RustClientError):
+            await rust_client.get("data.bin")
+        assert _PathRecordingRequestHandler.paths == [expected_path]
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_default_headers():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _PathRecordingRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    _PathRecordingRequestHandler.headers_seen = []
+    try:
+        rust_client = RustClient(
+            provider="http",
+            configs={
+                "url": f"http://127.0.0.1:{server.server_address[1]}",
+                "default_headers": {"x-nv-team": "storage"},
+                "user_agent": "team-a/2.0",
+            },
+        )
+        with pytest.raises(RustClientError):
+            await rust_client.get("data.bin")
+        headers = _PathRecordingRequestHandler.headers_seen[0]
+        assert headers["x-nv-team"] == "storage"
+        assert headers["User-Agent"].startswith("object_store/")
+        assert f"multi-storage-client/{__version__}" in headers["User-Agent"]
+        assert headers["User-Agent"].endswith(" team-a/2.0")
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    for header_configs in [
+        {"default_headers": {"Authorization": "Bearer token"}},
+        {"default_headers": {"x-amz-acl": "private"}},
+        {"default_headers": {"x-nv-team": "caf\u00e9"}},
+        {"default_headers": {"x-nv-team": 1}},
+        {"user_agent": "team\n"},
+    ]:
+        with pytest.raises(ValueError):
+            RustClient(provider="http", configs={"url": "http://127.0.0.1:1", **header_configs})
+
+
+@pytest.mark.asyncio
+async def test_rustclient_connection_configs():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _PathRecordingRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    _PathRecordingRequestHandler.paths = []
+    try:
+        rust_client = RustClient(
+            provider="http",
+            configs={
+                "url": f"http://127.0.0.1:{server.server_address[1]}",
+                "http2": False,
+                "pool_max_idle_per_host": "4",
+                "pool_idle_timeout": 5,
+            },
+        )
+        with pytest.raises(RustClientError):
+            await rust_client.get("data.bin")
+        assert _PathRecordingRequestHandler.paths == ["/data.bin"]
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    for connection_configs, match in [
+        ({"http2": "h2"}, "http2"),
+        ({"tcp_keepalive_secs": 30}, "http2_keep_alive_secs"),
+    ]:
+        with pytest.raises(ValueError, match=match):
+            RustClient(provider="http", configs={"url": "http://127.0.0.1:1", **connection_configs})
+
+
+def test_rustclient_invalid_addressing_style():
+    configs = {"bucket": "test-bucket", "endpoint_url": "http://localhost:7070", "region_name": "us-east-1"}
+    credentials_provider = StaticS3CredentialsProvider(access_key="a", secret_key="b")
+    for addressing_configs in [
+        {"addressing_style": "auto"},
+        {"addressing_style": "virtual", "force_path_style": True},
+        {"bucket": "arn:aws:s3:us-east-1:123456789012:accesspoint/data", "addressing_style": "path"},
+    ]:
+        with pytest.raises(ValueError):
+            RustClient(
+                provider="s3", configs={**configs, **addressing_configs}, credentials_provider=credentials_provider
+            )
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(argnames=["as_list"], argvalues=[[True], [False]])
+async def test_rustclient_endpoint_failover(as_list: bool):
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _PathRecordingRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    _PathRecordingRequestHandler.paths = []
+    try:
+        endpoints = ["http://127.0.0.1:1", f"http://127.0.0.1:{server.server_address[1]}"]
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": "test-bucket",
+                "endpoint_url": endpoints if as_list else ",".join(endpoints),
+                "region_name": "us-east-1",
+                "allow_http": True,
+            },
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+            retry=RustRetryConfig(attempts=0, timeout=5, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+        )
+        # The dead endpoint fails over to the live one, which answers that the object is missing.
+        for _ in range(2):
+            with pytest.raises(RustClientError) as exc_info:
+                await rust_client.get("data.bin")
+            assert not isinstance(exc_info.value, RustRetryableError)
+        assert _PathRecordingRequestHandler.paths == ["/test-bucket/data.bin"] * 2
+    finally:
+        server.shutdown()
+        server.server_close()
+
+    with pytest.raises(ValueError, match="endpoint_url"):
+        RustClient(
+            provider="s3",
+            configs={"bucket": "test-bucket", "endpoint_url": [1, 2], "region_name": "us-east-1"},
+            credentials_provider=StaticS3CredentialsProvider(access_key="a", secret_key="b"),
+        )
+
+
+class _SignatureCheckingRequestHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that denies requests not signed with the ``valid`` access key, and reports the object as missing.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        status = 404 if "Credential=valid/" in self.headers.get("Authorization", "") else 403
+        self.send_response(status)
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_revoked_credentials():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _SignatureCheckingRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    configs = {
+        "bucket": "test-bucket",
+        "endpoint_url": f"http://127.0.0.1:{server.server_address[1]}",
+        "region_name": "us-east-1",
+        "allow_http": True,
+    }
+    try:
+        # The denied request forces a refresh and is retried with the new credentials.
+        credentials_provider = RevokedTestCredentialsProvider()
+        rust_client = RustClient(provider="s3", configs=configs, credentials_provider=credentials_provider)
+        with pytest.raises(RustClientError) as exc_info:
+            await rust_client.get("data.bin")
+        assert exc_info.value.args[1] == 404
+        assert credentials_provider.refresh_count == 1
+
+        # Refreshes aren't forced more than once every 30 seconds.
+        credentials_provider = RevokedTestCredentialsProvider(revoked_again=True)
+        rust_client = RustClient(provider="s3", configs=configs, credentials_provider=credentials_provider)
+        for _ in range(3):
+            with pytest.raises(RustClientError) as exc_info:
+                await rust_client.get("data.bin")
+            assert exc_info.value.args[1] == 403
+        assert credentials_provider.refresh_count == 1
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+class _SlowDownRequestHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that throttles every request the way S3 does, with a 503 ``SlowDown`` and a ``Retry-After`` hint.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        self.send_response(503)
+        self.send_header("Retry-After", "2")
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_throttled_error():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _SlowDownRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http",
+            configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10},
+            retry=RustRetryConfig(attempts=0, timeout=5, init_backoff_ms=10, max_backoff=1, backoff_multiplier=2.0),
+        )
+        with pytest.raises(RustThrottledError) as exc_info:
+            await rust_client.get("data.bin")
+        assert isinstance(exc_info.value, RustRetryableError)
+        assert exc_info.value.retry_after_secs == 2.0
+        assert exc_info.value.status_code == 503
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+class _StalledRequestHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that takes longer to respond than any deadline the tests set.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        time.sleep(3)
+        self.send_response(404)
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_batch_deadline():
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _StalledRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10}
+        )
+        start = time.monotonic()
+        with pytest.raises(TimeoutError):
+            await rust_client.get_many(["a.bin", "b.bin"], deadline=0.2)
+        with pytest.raises(TimeoutError):
+            await rust_client.get_into("a.bin", bytearray(4), start=0, end=4, deadline=0.2)
+        assert time.monotonic() - start < 2
+        with pytest.raises(ValueError):
+            await rust_client.get_many(["a.bin"], deadline=0)
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+
+@pytest.mark.asyncio
+async def test_rustclient_refresh_and_close(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    await rust_client.put("data.bin", b"data")
+    rust_client.refresh()
+    assert await rust_client.get("data.bin") == b"data"
+
+    rust_client.close()
+    with pytest.raises(RuntimeError, match="closed"):
+        await rust_client.get("data.bin")
+    with pytest.raises(RuntimeError, match="closed"):
+        rust_client.refresh()
+    rust_client.close()
+
+    # Operations in flight complete on the stores that were closed or replaced.
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _StalledRequestHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10}
+        )
+        refreshed = asyncio.create_task(rust_client.get("a.bin"))
+        await asyncio.sleep(0.2)
+        rust_client.refresh()
+        closed = asyncio.create_task(rust_client.get("b.bin"))
+        await asyncio.sleep(0.2)
+        rust_client.close()
+        for task in (refreshed, closed):
+            with pytest.raises(FileNotFoundError):
+                await task
+    finally:
+        server.shutdown()
+        server.server_close()
+
+def _get_in_child(rust_client, key, queue):
+    queue.put(asyncio.run(rust_client.get(key)))
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="requires the fork start method")
+def test_rustclient_get_after_fork(tmp_path):
+    data = os.urandom(1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    server = http.server.ThreadingHTTPServer(
+        ("127.0.0.1", 0), functools.partial(_RangeRequestHandler, directory=str(tmp_path))
+    )
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        rust_client = RustClient(
+            provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}", "read_timeout": 10}
+        )
+        # The parent's runtime and pooled connection are inherited by the child, which has to replace them.
+        assert asyncio.run(rust_client.get("data.bin")) == data
+        context = multiprocessing.get_context("fork")
+        queue = context.Queue()
+        process = context.Process(target=_get_in_child, args=(rust_client, "data.bin", queue))
+        process.start()
+        assert queue.get(timeout=30) == data
+        process.join(timeout=30)
+        assert process.exitcode == 0
+        assert asyncio.run(rust_client.get("data.bin")) == data
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_file_provider(tmp_path):
+    base_path = tmp_path / "base"
+    rust_client = RustClient(provider="file", configs={"base_path": str(base_path)})
+
+    await rust_client.put("dir/small.bin", b"small")
+    assert (base_path / "dir" / "small.bin").read_bytes() == b"small"
+    assert await rust_client.get("dir/small.bin") == b"small"
+
+    large_data = os.urandom(12 * 1024 * 1024)
+    local_source = tmp_path / "source.bin"
+    local_source.write_bytes(large_data)
+    await rust_client.upload_multipart_from_file(
+        str(local_source), "dir/large.bin", multipart_chunksize=5 * 1024 * 1024
+    )
+    local_target = tmp_path / "target.bin"
+    assert await rust_client.download_multipart_to_file("dir/large.bin", str(local_target)) == len(large_data)
+    assert local_target.read_bytes() == large_data
+
+    result = await rust_client.list_recursive(["dir/"])
+    assert sorted(obj.key for obj in result.objects) == ["dir/large.bin", "dir/small.bin"]
+
+    with pytest.raises(ValueError):
+        await rust_client.get("../outside.bin")
+
+    # Streaming writes go straight to the destination, so finished parts are visible before close.
+    await rust_client.put("dir/streamed.bin", b"streamed", write_mode="streaming")
+    assert (base_path / "dir" / "streamed.bin").read_bytes() == b"streamed"
+    writer = await rust_client.open_write_stream("dir/stream.bin", max_concurrency=1, write_mode="streaming")
+    writer.write(large_data[: 5 * 1024 * 1024])
+    await writer.drain()
+    for _ in range(100):
+        if (base_path / "dir" / "stream.bin").stat().st_size == 5 * 1024 * 1024:
+            break
+        await asyncio.sleep(0.05)
+    assert (base_path / "dir" / "stream.bin").stat().st_size == 5 * 1024 * 1024
+    writer.write(large_data[5 * 1024 * 1024 :])
+    writer.close()
+    assert await writer.wait_closed() == len(large_data)
+    assert (base_path / "dir" / "stream.bin").read_bytes() == large_data
+
+    with pytest.raises(ValueError):
+        RustClient(provider="file", configs={})
+
+
+@pytest.mark.asyncio
+async def test_rustclient_object_metadata(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    await rust_client.put("dir/data.bin", b"data")
+
+    info = await rust_client.info("dir/data.bin")
+    assert isinstance(info.last_modified, datetime)
+    assert info.last_modified.tzinfo is not None
+    assert abs((datetime.now(timezone.utc) - info.last_modified).total_seconds()) < 60
+    assert datetime.fromisoformat(info.last_modified_str) == info.last_modified
+    assert info.content_type is None and info.metadata is None
+
+    listed = (await rust_client.list_recursive(["dir/"])).objects[0]
+    assert listed == info and hash(listed) == hash(info)
+    assert len({listed, info}) == 1
+    assert pickle.loads(pickle.dumps(info)) == info
+    assert info.to_dict() == {
+        "key": "dir/data.bin",
+        "content_length": 4,
+        "last_modified": info.last_modified,
+        "object_type": "file",
+        "etag": info.etag,
+        "version": None,
+        "storage_class": None,
+        "content_type": None,
+        "metadata": None,
+    }
+    assert repr(info).startswith("ObjectMetadata(key='dir/data.bin', content_length=4, last_modified=datetime.")
+
+    directory = ObjectMetadata("dir", 0, datetime.fromtimestamp(0, timezone.utc), object_type="directory")
+    assert directory != info
+    assert directory.last_modified_str == "1970-01-01T00:00:00+00:00"
+
+
+@pytest.mark.asyncio
+async def test_rustclient_list_result_sequence(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    for key in ["dir/a.bin", "dir/b.bin", "dir/sub/c.bin"]:
+        await rust_client.put(key, b"data")
+
+    result = await rust_client.list_recursive(["dir/"])
+    entries = result.objects + result.prefixes
+    assert len(result) == len(entries) >= 3
+    assert list(result) == entries
+    assert result[0] == entries[0] and result[-1] == entries[-1]
+    assert result[1:] == entries[1:] and result[::-2] == entries[::-2]
+    with pytest.raises(IndexError):
+        result[len(entries)]
+    with pytest.raises(IndexError):
+        result[-len(entries) - 1]
+
+    assert result.keys() == ["dir/a.bin", "dir/b.bin", "dir/sub/c.bin"]
+    assert result.to_dicts() == [entry.to_dict() for entry in entries]
+
+
+@pytest.mark.asyncio
+async def test_rustclient_get_as_memoryview(tmp_path):
+    data = os.urandom(4096)
+    (tmp_path / "data.bin").write_bytes(data)
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+
+    view = await rust_client.get("data.bin", as_memoryview=True)
+    assert isinstance(view, memoryview)
+    assert view.readonly
+    assert view.tobytes() == data
+    with pytest.raises(TypeError):
+        view[0] = 0
+    assert rust_client.get_sync("data.bin", range=Range(16, 32), as_memoryview=True).tobytes() == data[16:48]
+
+    # Arrays wrapping the view keep the data alive once the view and the client are gone.
+    np = pytest.importorskip("numpy")
+    values = np.frombuffer(view, dtype=np.uint8)
+    assert not values.flags.writeable
+    del view, rust_client
+    gc.collect()
+    assert values.tobytes() == data
+
+
+@pytest.mark.asyncio
+async def test_rustclient_upload_from_async_iter(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    data = os.urandom(10 * 1024)
+
+    async def chunks(size: int):
+        for offset in range(0, len(data), size):
+            yield data[offset : offset + size]
+
+    # Chunks smaller and larger than a part are re-chunked into 4 KiB parts.
+    for size in (100, 5000):
+        path = f"async-{size}.bin"
+        assert await rust_client.upload_from_async_iter(path, chunks(size), multipart_chunksize=4096) == len(data)
+        assert (tmp_path / path).read_bytes() == data
+
+    assert await rust_client.upload_from_async_iter("small.bin", chunks(100)) == len(data)
+    assert (tmp_path / "small.bin").read_bytes() == data
+
+    with pytest.raises(RustAlreadyExistsError):
+        await rust_client.upload_from_async_iter("small.bin", chunks(100), multipart_chunksize=4096, mode="create")
+    created = await rust_client.upload_from_async_iter(
+        "created.bin", chunks(100), multipart_chunksize=4096, mode="create", deadline=30, request_tag="async-iter"
+    )
+    assert created == len(data)
+    assert (tmp_path / "created.bin").read_bytes() == data
+
+    async def failing():
+        yield data
+        yield memoryview(data)
+        raise ValueError("producer failed")
+
+    with pytest.raises(ValueError, match="producer failed"):
+        await rust_client.upload_from_async_iter("failed.bin", failing(), multipart_chunksize=4096)
+    # The aborted upload leaves neither the object nor its staged parts behind.
+    assert not any(name.startswith("failed.bin") for name in os.listdir(tmp_path))
+
+
+class _EndlessBodyHandler(http.server.BaseHTTPRequestHandler):
+    """
+    Handler that streams a body until the client disconnects, then sets ``server.disconnected``.
+    """
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_GET(self):
+        self.send_response(200)
+        self.send_header("Content-Length", str(1 << 40))
+        self.end_headers()
+        try:
+            while True:
+                self.wfile.write(b"x" * 65536)
+                time.sleep(0.01)
+        except OSError:
+            self.server.disconnected.set()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_get_stream(tmp_path):
+    data = os.urandom(10 * 1024)
+    (tmp_path / "data.bin").write_bytes(data)
+    (tmp_path / "empty.bin").write_bytes(b"")
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+
+    chunks = [chunk async for chunk in rust_client.get_stream("data.bin", chunk_size=4096)]
+    assert [len(chunk) for chunk in chunks] == [4096, 4096, 2048]
+    assert b"".join(chunks) == data
+    assert [chunk async for chunk in rust_client.get_stream("data.bin")] == [data]
+    assert [chunk async for chunk in rust_client.get_stream("empty.bin")] == []
+
+    chunks = [chunk async for chunk in rust_client.get_stream("data.bin", chunk_size=1000, start=100, end=2600)]
+    assert [len(chunk) for chunk in chunks] == [1000, 1000, 500]
+    assert b"".join(chunks) == data[100:2600]
+    assert b"".join([chunk async for chunk in rust_client.get_stream("data.bin", start=9000)]) == data[9000:]
+
+    with pytest.raises(FileNotFoundError):
+        async for _ in rust_client.get_stream("missing.bin"):
+            pass
+    with pytest.raises(ValueError):
+        rust_client.get_stream("data.bin", chunk_size=0)
+    with pytest.raises(ValueError):
+        rust_client.get_stream("data.bin", start=10, end=10)
+
+    # Dropping a stream that was only partly read cancels its request.
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _EndlessBodyHandler)
+    server.disconnected = threading.Event()
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    try:
+        http_client = RustClient(provider="http", configs={"url": f"http://127.0.0.1:{server.server_address[1]}"})
+        stream = http_client.get_stream("endless.bin", chunk_size=65536)
+        assert len(await stream.__anext__()) == 65536
+        del stream
+        gc.collect()
+        assert await asyncio.to_thread(server.disconnected.wait, 5)
+    finally:
+        server.shutdown()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_transfer_stats(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    data = os.urandom(12 * 1024 * 1024)
+    local_source = tmp_path / "source.bin"
+    local_source.write_bytes(data)
+    chunksize = 5 * 1024 * 1024
+
+    stats = await rust_client.upload_multipart_from_file(
+        str(local_source), "large.bin", multipart_chunksize=chunksize, max_concurrency=2, return_stats=True
+    )
+    assert isinstance(stats, TransferStats)
+    assert stats.bytes_transferred == len(data)
+    assert stats.chunks == 3
+    assert 1 <= stats.peak_concurrency <= 2
+    assert stats.wall_time > 0
+    assert stats.retry_count == 0
+
+    stats = await rust_client.download_multipart_to_file(
+        "large.bin", str(tmp_path / "target.bin"), multipart_chunksize=chunksize, max_concurrency=4, return_stats=True
+    )
+    assert stats.chunks == 3
+    assert 1 <= stats.peak_concurrency <= 3
+    assert (tmp_path / "target.bin").read_bytes() == data
+
+    downloaded, stats = await rust_client.download_multipart_to_bytes(
+        "large.bin", multipart_chunksize=chunksize, return_stats=True
+    )
+    assert downloaded == data
+    assert stats.bytes_transferred == len(data) and stats.chunks == 3
+
+    assert pickle.loads(pickle.dumps(stats)) == stats
+    assert stats.to_dict() == {
+        "bytes_transferred": len(data),
+        "chunks": 3,
+        "peak_concurrency": stats.peak_concurrency,
+        "effective_concurrency": stats.effective_concurrency,
+        "wall_time": stats.wall_time,
+        "time_to_first_byte": stats.time_to_first_byte,
+        "retry_count": 0,
+        "uncompressed_size": None,
+    }
+
+    # Without return_stats the byte count is returned as before.
+    assert await rust_client.upload_multipart_from_bytes("small.bin", b"small") == 5
+    with pytest.raises(ValueError):
+        await rust_client.upload_multipart_from_bytes("small.bin", b"small", detailed=True, return_stats=True)
+    with pytest.raises(ValueError):
+        rust_client.download_multipart_to_file("large.bin", str(tmp_path / "t.bin"), detachable=True, return_stats=True)
+
+
+@pytest.mark.asyncio
+async def test_rustclient_list_recursive_from_event_loop(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    for i in range(20):
+        await rust_client.put(f"tree/{i % 4}/{i}.bin", b"x")
+
+    ticks = 0
+
+    async def tick():
+        nonlocal ticks
+        while True:
+            ticks += 1
+            await asyncio.sleep(0)
+
+    ticker = asyncio.create_task(tick())
+    try:
+        results = await asyncio.gather(*(rust_client.list_recursive(["tree/"]) for _ in range(8)))
+    finally:
+        ticker.cancel()
+    assert all(len(result.objects) == 20 for result in results)
+    assert ticks > 0
+
+    # The blocking variant serves synchronous callers, here a worker thread.
+    result = await asyncio.to_thread(rust_client.list_recursive_blocking, ["tree/"], suffix="0.bin")
+    assert sorted(obj.key for obj in result.objects) == ["tree/0/0.bin", "tree/2/10.bin"]
+
+
+@pytest.mark.asyncio
+async def test_rustclient_upload_directory(tmp_path):
+    base_path = tmp_path / "base"
+    rust_client = RustClient(
+        provider="file",
+        configs={"base_path": str(base_path), "multipart_chunksize": 5 * 1024 * 1024},
+    )
+    source = tmp_path / "source"
+    (source / "nested" / "deeper").mkdir(parents=True)
+    (source / "empty").mkdir()
+    files = {f"file{i}.bin": os.urandom(100) for i in range(10)}
+    files["nested/index.json"] = b"{}"
+    files["nested/deeper/large.bin"] = os.urandom(6 * 1024 * 1024)
+    for relative, data in files.items():
+        (source / relative).write_bytes(data)
+    os.symlink(source / "file0.bin", source / "link.bin")
+
+    result = await rust_client.upload_directory(str(source), "uploaded/", max_concurrency=4)
+    assert result.files_uploaded == len(files)
+    assert result.bytes_uploaded == sum(len(data) for data in files.values())
+    assert result.failed == {}
+    for relative, data in files.items():
+        assert (base_path / "uploaded" / relative).read_bytes() == data
+    assert not (base_path / "uploaded" / "link.bin").exists()
+    assert not (base_path / "uploaded" / "empty").exists()
+
+    result = await rust_client.upload_directory(
+        str(source), "filtered", include_pattern="**/*.json", follow_symlinks=True
+    )
+    assert result.files_uploaded == 1
+    assert (base_path / "filtered" / "nested" / "index.json").read_bytes() == b"{}"
+
+    result = await rust_client.upload_directory(str(source), "linked", include_pattern="link.bin", follow_symlinks=True)
+    assert result.files_uploaded == 1
+    assert (base_path / "linked" / "link.bin").read_bytes() == files["file0.bin"]
+
+    with pytest.raises(ValueError):
+        await rust_client.upload_directory(str(source / "file0.bin"), "uploaded")
+
+
+@pytest.mark.asyncio
+async def test_rustclient_download_directory(tmp_path):
+    base_path = tmp_path / "base"
+    rust_client = RustClient(
+        provider="file",
+        configs={"base_path": str(base_path), "multipart_chunksize": 5 * 1024 * 1024},
+    )
+    objects = {f"file{i}.bin": os.urandom(100) for i in range(10)}
+    objects["nested/index.json"] = b"{}"
+    objects["nested/deeper/large.bin"] = os.urandom(6 * 1024 * 1024)
+    for relative, data in objects.items():
+        await rust_client.put(f"prefix/{relative}", data)
+
+    target = tmp_path / "target"
+    result = await rust_client.download_directory("prefix", str(target), max_concurrency=4)
+    assert result.files_downloaded == len(objects)
+    assert result.bytes_downloaded == sum(len(data) for data in objects.values())
+    assert result.files_skipped == 0
+    assert result.failed == {}
+    for relative, data in objects.items():
+        assert (target / relative).read_bytes() == data
+
+    (target / "file0.bin").write_bytes(b"changed")
+    result = await rust_client.download_directory("prefix/", str(target), skip_existing=True)
+    assert result.files_downloaded == 1
+    assert result.files_skipped == len(objects) - 1
+    assert (target / "file0.bin").read_bytes() == objects["file0.bin"]
+
+    result = await rust_client.download_directory("prefix", str(tmp_path / "json"), suffix=".json")
+    assert result.files_downloaded == 1
+    assert (tmp_path / "json" / "nested" / "index.json").read_bytes() == b"{}"
+
+
+@pytest.mark.asyncio
+async def test_rustclient_sync(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    for i in range(5):
+        await rust_client.put(f"dataset/shard-{i}.bin", os.urandom(100))
+    await rust_client.put("dataset/meta/index.json", b"{}")
+
+    mirror = tmp_path / "mirror"
+    plan = await rust_client.sync("dataset", str(mirror), dry_run=True)
+    assert plan.dry_run
+    assert plan.copied == ["meta/index.json"] + [f"shard-{i}.bin" for i in range(5)]
+    assert not mirror.exists()
+
+    result = await rust_client.sync("dataset", str(mirror), max_concurrency=2)
+    assert result.copied == plan.copied
+    assert result.bytes_copied == plan.bytes_copied
+    assert (mirror / "meta" / "index.json").read_bytes() == b"{}"
+
+    await rust_client.put("dataset/shard-5.bin", b"new")
+    (mirror / "extra.bin").write_bytes(b"extra")
+    result = await rust_client.sync("dataset", str(mirror), delete_extraneous=True)
+    assert result.copied == ["shard-5.bin"]
+    assert result.deleted == ["extra.bin"]
+    assert result.unchanged == 6
+    assert result.failed == {}
+    assert not (mirror / "extra.bin").exists()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_get_ranges(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    data = os.urandom(4096)
+    await rust_client.put("shard.tar", data)
+
+    ranges = [(3000, 3100), (0, 10), (5, 50), (4000, 4096), (20, 30)]
+    result = await rust_client.get_ranges("shard.tar", ranges)
+    assert [bytes(part) for part in result] == [data[start:end] for start, end in ranges]
+    assert await rust_client.get_ranges("shard.tar", []) == []
+
+    with pytest.raises(ValueError):
+        await rust_client.get_ranges("shard.tar", [(10, 5)])
+    with pytest.raises(Exception) as single:
+        await rust_client.get("shard.tar", range=Range(offset=5000, size=10))
+    with pytest.raises(type(single.value)):
+        await rust_client.get_ranges("shard.tar", [(0, 10), (5000, 5010)])
+
+
+@pytest.mark.asyncio
+async def test_rustclient_get_many(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    objects = {f"features/{i}.bin": os.urandom(100 + i) for i in range(50)}
+    for path, data in objects.items():
+        await rust_client.put(path, data)
+
+    data, failed = await rust_client.get_many(list(objects) + ["features/missing.bin"], max_concurrency=8)
+    assert data == objects
+    assert list(failed) == ["features/missing.bin"]
+
+    data, failed = await rust_client.get_many(list(objects)[:2], total_size_limit=201)
+    assert data == {path: objects[path] for path in list(objects)[:2]}
+    assert failed == {}
+    with pytest.raises(ValueError, match="total_size_limit"):
+        await rust_client.get_many(list(objects)[:2], total_size_limit=200)
+
+
+def test_rustclient_sync_methods(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    data = os.urandom(3 * 1024 * 1024)
+
+    assert rust_client.put_sync("small.bin", b"small") == 5
+    assert rust_client.get_sync("small.bin") == b"small"
+    assert rust_client.get_sync("small.bin", range=Range(1, 3)) == b"mal"
+    result = rust_client.upload_multipart_from_bytes_sync(
+        "large.bin", data, multipart_chunksize=1024 * 1024, detailed=True
+    )
+    assert isinstance(result, TransferResult) and result.bytes_transferred == len(data)
+    assert rust_client.download_multipart_to_bytes_sync("large.bin", multipart_chunksize=1024 * 1024) == data
+
+    local_path = tmp_path / "local.bin"
+    assert rust_client.download_multipart_to_file_sync("large.bin", str(local_path)) == len(data)
+    assert local_path.read_bytes() == data
+    assert rust_client.upload_multipart_from_file_sync(str(local_path), "copy.bin") == len(data)
+    assert rust_client.upload_sync(str(local_path), "uploaded.bin") == len(data)
+    assert rust_client.download_sync("uploaded.bin", str(tmp_path / "downloaded.bin")) == len(data)
+    assert (tmp_path / "downloaded.bin").read_bytes() == data
+    with pytest.raises(FileNotFoundError):
+        rust_client.get_sync("missing.bin")
+
+    # From the executor of an async application, whose event loop keeps running meanwhile.
+    async def from_executor() -> list[bytes]:
+        loop = asyncio.get_running_loop()
+        reads = [loop.run_in_executor(None, rust_client.get_sync, "small.bin") for _ in range(8)]
+        return await asyncio.gather(*reads, rust_client.get("small.bin"))
+
+    assert asyncio.run(from_executor()) == [b"small"] * 9
+
+
+def test_rustclient_configure_runtime(tmp_path):
+    (tmp_path / "data.bin").write_bytes(b"data")
+    # Runs in a fresh interpreter, as the runtime can only be configured before it starts.
+    script = textwrap.dedent(
+        f"""
+        import asyncio, os
+        from multistorageclient_rust import RustClient, configure_runtime
+
+        configure_runtime(threads=2)
+        client = RustClient(provider="file", configs={{"base_path": {str(tmp_path)!r}}})
+        assert asyncio.run(client.get("data.bin")) == b"data"
+        try:
+            configure_runtime(threads=4)
+            raise AssertionError("configure_runtime succeeded on a running runtime")
+        except RuntimeError:
+            pass
+
+        # The child can't use the parent's worker threads and starts a runtime of its own.
+        pid = os.fork()
+        if pid == 0:
+            os._exit(0 if asyncio.run(client.get("data.bin")) == b"data" else 1)
+        assert os.waitstatus_to_exitcode(os.waitpid(pid, 0)[1]) == 0
+        """
+    )
+    subprocess.run([sys.executable, "-c", script], check=True, env={**os.environ, "MSC_RUST_WORKER_THREADS": "1"})
+
+
+class _TraceCollectorHandler(http.server.BaseHTTPRequestHandler):
+    """
+    OTLP/HTTP endpoint that keeps the body of every export request it receives.
+    """
+
+    exports: list[bytes] = []
+
+    def log_message(self, format, *args):
+        pass
+
+    def do_POST(self):
+        _TraceCollectorHandler.exports.append(self.rfile.read(int(self.headers["Content-Length"])))
+        self.send_response(200)
+        self.send_header("Content-Type", "application/x-protobuf")
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+
+def test_rustclient_tracing(tmp_path):
+    server = http.server.ThreadingHTTPServer(("127.0.0.1", 0), _TraceCollectorHandler)
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    trace_id = uuid.uuid4().hex
+    # Runs in a fresh interpreter, as whether to export spans is decided on first use.
+    script = textwrap.dedent(
+        f"""
+        import asyncio
+        import multistorageclient_rust
+        from multistorageclient_rust import RustClient
+
+        multistorageclient_rust.traceparent.set("00-{trace_id}-0123456789abcdef-01")
+        client = RustClient(provider="file", configs={{"base_path": {str(tmp_path)!r}}})
+        asyncio.run(client.put("data.bin", b"data"))
+        multistorageclient_rust.flush_traces()
+        """
+    )
+    try:
+        env = {**os.environ, "OTEL_EXPORTER_OTLP_ENDPOINT": f"http://127.0.0.1:{server.server_address[1]}"}
+        subprocess.run([sys.executable, "-c", script], check=True, env=env)
+        exported = b"".join(_TraceCollectorHandler.exports)
+        assert b"msc.rust.put" in exported
+        assert bytes.fromhex(trace_id) in exported
+    finally:
+        server.shutdown()
+        server.server_close()
+
+
+@pytest.mark.asyncio
+async def test_rustclient_list_iter(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    for i in range(7):
+        await rust_client.put(f"data/{i}.bin", b"x")
+    await rust_client.put("data/index.json", b"{}")
+
+    batches = [batch async for batch in rust_client.list_iter("data", batch_size=3, suffix=".bin")]
+    assert [len(batch) for batch in batches] == [3, 3, 1]
+    assert sorted(obj.key for batch in batches for obj in batch) == [f"data/{i}.bin" for i in range(7)]
+
+    iterator = rust_client.list_iter("data", batch_size=1)
+    assert len(await iterator.__anext__()) == 1
+    del iterator
+
+
+@pytest.mark.asyncio
+async def test_rustclient_list_start_after(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    keys = sorted(f"tree/{i % 3}/{i}.bin" for i in range(10))
+    for key in keys:
+        await rust_client.put(key, b"x")
+
+    listed = []
+    start_after = None
+    while True:
+        page = await rust_client.list_recursive(["tree/"], limit=4, start_after=start_after)
+        listed.extend(obj.key for obj in page.objects)
+        if page.last_key is None:
+            break
+        start_after = page.last_key
+    assert listed == keys
+
+    batches = [batch async for batch in rust_client.list_iter("tree", start_after=keys[4])]
+    assert sorted(obj.key for batch in batches for obj in batch) == keys[5:]
+
+
+@pytest.mark.asyncio
+async def test_rustclient_list_filters(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    for size in [10, 100, 1000]:
+        await rust_client.put(f"filtered/{size}.bin", b"x" * size)
+
+    result = await rust_client.list_recursive(["filtered/"], min_size=50, max_size=500)
+    assert [obj.key for obj in result.objects] == ["filtered/100.bin"]
+    result = await rust_client.list_recursive(["filtered/"], min_size=50, limit=1)
+    assert [obj.key for obj in result.objects] == ["filtered/100.bin"]
+
+    now = datetime.now(timezone.utc)
+    result = await rust_client.list_recursive(["filtered/"], modified_before=now + timedelta(hours=1))
+    assert len(result.objects) == 3
+    result = await rust_client.list_recursive(["filtered/"], modified_after=(now + timedelta(hours=1)).isoformat())
+    assert result.objects == []
+
+    with pytest.raises(ValueError, match="modified_before"):
+        await rust_client.list_recursive(["filtered/"], modified_before="last tuesday")
+
+
+@pytest.mark.asyncio
+async def test_rustclient_glob(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    for key in [
+        "datasets/train/shard-000.tar",
+        "datasets/train/shard-001.tar",
+        "datasets/train/shard-000.idx",
+        "datasets/eval/shard-000.tar",
+        "datasets/eval/nested/shard-002.tar",
+        "other/shard-000.tar",
+    ]:
+        await rust_client.put(key, b"x")
+
+    result = await rust_client.glob("datasets/*/shard-*.tar")
+    assert [obj.key for obj in result.objects] == [
+        "datasets/eval/shard-000.tar",
+        "datasets/train/shard-000.tar",
+        "datasets/train/shard-001.tar",
+    ]
+    # The nested directory can't hold a match, so it isn't listed.
+    assert result.stats.directories_listed == 3
+
+    result = await rust_client.glob("datasets/**/shard-00[12].tar")
+    assert [obj.key for obj in result.objects] == [
+        "datasets/eval/nested/shard-002.tar",
+        "datasets/train/shard-001.tar",
+    ]
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_resumable_upload(temp_data_store_type: Type[tempdatastore.TemporaryDataStore], tmp_path):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        chunksize = 5 * 1024 * 1024
+        data = os.urandom(3 * chunksize + 17)
+        local_path = tmp_path / "archive.bin"
+        local_path.write_bytes(data)
+        state_path = tmp_path / "archive.bin.upload"
+        remote_path = f"{uuid.uuid4().hex}/archive.bin"
+
+        class _Interrupted(Exception):
+            pass
+
+        def interrupt(transferred: int, total: int) -> None:
+            raise _Interrupted()
+
+        # The first attempt dies after a part completes, leaving its state behind.
+        with pytest.raises(_Interrupted):
+            await rust_client.upload_multipart_from_file(
+                str(local_path),
+                remote_path,
+                multipart_chunksize=chunksize,
+                max_concurrency=1,
+                progress_callback=interrupt,
+                resume_state_path=str(state_path),
+            )
+        assert state_path.exists()
+
+        calls = []
+        await rust_client.upload_multipart_from_file(
+            str(local_path),
+            remote_path,
+            multipart_chunksize=chunksize,
+            progress_callback=lambda *args: calls.append(args),
+            resume_state_path=str(state_path),
+        )
+        assert await rust_client.get(remote_path) == data
+        assert not state_path.exists()
+        # Parts from the first attempt are reported up front rather than uploaded again.
+        assert calls[0][0] >= chunksize
+        assert len(calls) <= 4
+
+        state_path.write_text("{not json")
+        with pytest.warns(RuntimeWarning, match="resume_state_path"):
+            await rust_client.upload_multipart_from_file(
+                str(local_path), remote_path, multipart_chunksize=chunksize, resume_state_path=str(state_path)
+            )
+        assert await rust_client.get(remote_path) == data
+
+
+@pytest.mark.asyncio
+async def test_rustclient_resumable_upload_unsupported(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    local_path = tmp_path / "source.bin"
+    local_path.write_bytes(b"data")
+    with pytest.raises(ValueError, match="resume_state_path"):
+        await rust_client.upload_multipart_from_file(
+            str(local_path), "object.bin", resume_state_path=str(tmp_path / "state.json")
+        )
+
+
+@pytest.mark.asyncio
+async def test_rustclient_du(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    await rust_client.put("data/a/one.bin", b"x" * 10)
+    await rust_client.put("data/a/one.idx", b"x" * 3)
+    await rust_client.put("data/b/c/two.bin", b"x" * 25)
+    await rust_client.put("data/three.bin", b"x" * 5)
+
+    summary = await rust_client.du("data")
+    assert summary.object_count == 4
+    assert summary.total_bytes == 43
+    assert summary.largest_object.key == "data/b/c/two.bin"
+    assert summary.latest_modified is not None
+    assert summary.stats.directories_listed == 4
+
+    summary = await rust_client.du("data", suffix=".bin", max_depth=2)
+    assert summary.object_count == 2
+    assert summary.total_bytes == 15
+
+    summary = await rust_client.du("missing")
+    assert summary.object_count == 0
+    assert summary.largest_object is None
+
+
+@pytest.mark.asyncio
+async def test_rustclient_version_id_unsupported(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    await rust_client.put("object.bin", b"data")
+    assert (await rust_client.info("object.bin")).version is None
+
+    with pytest.raises(ValueError, match="version_id"):
+        await rust_client.get("object.bin", version_id="1")
+    with pytest.raises(ValueError, match="version_id"):
+        await rust_client.info("object.bin", version_id="1")
+    with pytest.raises(ValueError, match="version_id"):
+        await rust_client.download("object.bin", str(tmp_path / "copy.bin"), version_id="1")
+    with pytest.raises(ValueError, match="version_id"):
+        await rust_client.download_multipart_to_file("object.bin", str(tmp_path / "copy.bin"), version_id="1")
+    with pytest.raises(ValueError, match="version_id"):
+        await rust_client.download_multipart_to_bytes("object.bin", version_id="1")
+
+
+@pytest.mark.asyncio
+async def test_rustclient_object_reader(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    data = os.urandom(1024)
+    await rust_client.put("reader.bin", data)
+
+    reader = await rust_client.open_reader("reader.bin", buffer_size=100)
+    assert await reader.read(10) == data[:10]
+    assert reader.tell() == 10
+    assert reader.seek(-24, os.SEEK_END) == 1000
+    assert await reader.read() == data[1000:]
+    assert await reader.read(1) == b""
+    assert reader.seek(500) == 500
+    assert reader.seek(10, os.SEEK_CUR) == 510
+    assert await reader.read(300) == data[510:810]
+
+    with pytest.raises(ValueError):
+        reader.seek(len(data) + 1)
+    with pytest.raises(ValueError):
+        reader.seek(-1)
+
+    reader.close()
+    assert reader.closed
+    with pytest.raises(ValueError):
+        await reader.read(1)
+    with pytest.raises(ValueError):
+        reader.tell()
+
+    with pytest.raises(FileNotFoundError):
+        await rust_client.open_reader("missing.bin")
+
+
+@pytest.mark.asyncio
+async def test_rustclient_object_writer(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    data = os.urandom(12 * 1024 * 1024)
+
+    writer = await rust_client.open_writer("writer.bin", multipart_chunksize=5 * 1024 * 1024)
+    for offset in range(0, len(data), 1024 * 1024):
+        await writer.write(data[offset : offset + 1024 * 1024])
+    await writer.flush()
+    assert await writer.close() == len(data)
+    assert (tmp_path / "writer.bin").read_bytes() == data
+    with pytest.raises(ValueError):
+        await writer.write(b"late")
+
+    writer = await rust_client.open_writer("aborted.bin")
+    await writer.write(b"partial")
+    await writer.abort()
+    assert not (tmp_path / "aborted.bin").exists()
+    with pytest.raises(ValueError):
+        await writer.write(b"late")
+
+    with pytest.raises(RustAlreadyExistsError):
+        await rust_client.open_writer("writer.bin", mode="create")
+
+    writer = await rust_client.open_writer("streamed.bin", write_mode="streaming")
+    await writer.write(b"streamed")
+    await writer.flush()
+    assert await writer.close() == len(b"streamed")
+    assert (tmp_path / "streamed.bin").read_bytes() == b"streamed"
+
+
+@pytest.mark.serial
+@pytest.mark.skipif(sys.platform != "linux", reason="ru_maxrss is reported in KiB on Linux only")
+def test_rustclient_download_multipart_to_bytes_peak_memory(tmp_path):
+    size = 256 * 1024 * 1024
+    with open(tmp_path / "large.bin", "wb") as f:
+        f.truncate(size)
+
+    # Runs in a fresh interpreter so the peak RSS reflects this download alone.
+    script = textwrap.dedent(
+        f"""
+        import asyncio, resource
+        from multistorageclient_rust import RustClient
+
+        client = RustClient(provider="file", configs={{"base_path": {str(tmp_path)!r}}})
+        baseline = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+        data = asyncio.run(client.download_multipart_to_bytes("large.bin", multipart_chunksize=8 * 1024 * 1024))
+        assert len(data) == {size}
+        print((resource.getrusage(resource.RUSAGE_SELF).ru_maxrss - baseline) * 1024)
+        """
+    )
+    start = time.perf_counter()
+    output = subprocess.run([sys.executable, "-c", script], check=True, capture_output=True, text=True).stdout
+    elapsed = time.perf_counter() - start
+    peak_growth = int(output.strip())
+    print(f"download_multipart_to_bytes: {size} bytes in {elapsed:.2f}s, peak RSS growth {peak_growth} bytes")
+
+    # Chunks land directly in the returned buffer, so the peak stays near one copy of the object
+    # plus the chunks in flight, well below the two copies of concatenating them.
+    assert peak_growth < 1.5 * size
+
+
+@pytest.mark.asyncio
+async def test_rustclient_get_into(tmp_path):
+    rust_client = RustClient(
+        provider="file", configs={"base_path": str(tmp_path), "multipart_chunksize": 5 * 1024 * 1024}
+    )
+    data = os.urandom(12 * 1024 * 1024 + 3)
+    (tmp_path / "object.bin").write_bytes(data)
+
+    buffer = bytearray(len(data))
+    assert await rust_client.get_into("object.bin", buffer) == len(data)
+    assert buffer == data
+
+    window = bytearray(100)
+    assert await rust_client.get_into("object.bin", memoryview(window), start=50, end=150) == 100
+    assert window == data[50:150]
+
+    # The range is checked against the buffer before the object is looked up.
+    with pytest.raises(ValueError, match="target buffer"):
+        await rust_client.get_into("missing.bin", bytearray(10), start=0, end=20)
+    with pytest.raises(ValueError, match="target buffer"):
+        await rust_client.get_into("object.bin", bytearray(10))
+    with pytest.raises(ValueError, match="writable"):
+        await rust_client.get_into("object.bin", bytes(len(data)))
+
+
+@pytest.mark.asyncio
+async def test_rustclient_upload_multipart_from_buffer(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path)})
+    chunksize = 5 * 1024 * 1024
+    data = bytearray(os.urandom(2 * chunksize + 17))
+
+    await rust_client.upload_multipart_from_bytes("view.bin", memoryview(data), multipart_chunksize=chunksize)
+    assert (tmp_path / "view.bin").read_bytes() == data
+
+    # Buffers of any item format are uploaded as their raw bytes.
+    floats = array.array("d", [0.5, 1.5, 2.5])
+    await rust_client.upload_multipart_from_bytes("floats.bin", floats)
+    assert (tmp_path / "floats.bin").read_bytes() == floats.tobytes()
+
+    with pytest.raises(ValueError, match="contiguous"):
+        await rust_client.upload_multipart_from_bytes("strided.bin", memoryview(data)[::2])
+
+
+@pytest.mark.asyncio
+async def test_rustclient_progress_callback(tmp_path):
+    rust_client = RustClient(provider="file", configs={"base_path": str(tmp_path / "base")})
+    chunksize = 5 * 1024 * 1024
+    data = os.urandom(2 * chunksize + 17)
+    local_source = tmp_path / "source.bin"
+    local_source.write_bytes(data)
+
+    def assert_progress(calls: list[tuple[int, int]]) -> None:
+        assert len(calls) == 3
+        assert [total for _, total in calls] == [len(data)] * 3
+        assert [transferred for transferred, _ in calls] == sorted(transferred for transferred, _ in calls)
+        assert calls[-1][0] == len(data)
+
+    calls = []
+    await rust_client.upload_multipart_from_bytes(
+        "bytes.bin", data, multipart_chunksize=chunksize, progress_callback=lambda *args: calls.append(args)
+    )
+    assert_progress(calls)
 
-        # Test upload_multipart_from_bytes with large data bytes
-        chunk_size = 10 * 1024 * 1024
-        max_concurrency = 4
-        result = await rust_client.upload_multipart_from_bytes(
-            large_file_path, large_data_bytes, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+    calls = []
+    await rust_client.upload_multipart_from_file(
+        str(local_source), "file.bin", multipart_chunksize=chunksize, progress_callback=lambda *args: calls.append(args)
+    )
+    assert_progress(calls)
+
+    calls = []
+    downloaded = await rust_client.download_multipart_to_bytes(
+        "file.bin", multipart_chunksize=chunksize, progress_callback=lambda *args: calls.append(args)
+    )
+    assert downloaded == data
+    assert_progress(calls)
+
+    calls = []
+    local_target = tmp_path / "target.bin"
+    await rust_client.download_multipart_to_file(
+        "file.bin", str(local_target), multipart_chunksize=chunksize, progress_callback=lambda *args: calls.append(args)
+    )
+    assert local_target.read_bytes() == data
+    assert_progress(calls)
+
+    class _Stop(Exception):
+        pass
+
+    def stop(transferred: int, total: int) -> None:
+        raise _Stop()
+
+    aborted_target = tmp_path / "aborted.bin"
+    with pytest.raises(_Stop):
+        await rust_client.download_multipart_to_file(
+            "file.bin", str(aborted_target), multipart_chunksize=chunksize, progress_callback=stop
+        )
+    assert not aborted_target.exists()
+    with pytest.raises(_Stop):
+        await rust_client.upload_multipart_from_bytes(
+            "aborted.bin", data, multipart_chunksize=chunksize, progress_callback=stop
         )
-        assert result == large_file_size
-        # Test upload_multipart_from_bytes with BytesIO object
-        with io.BytesIO(large_data_bytes) as bytes_io:
-            result = await rust_client.upload_multipart_from_bytes(large_file_path, bytes_io.getbuffer())
-        assert result == large_file_size
 
-        # Test download_multipart_to_bytes with large data bytes
-        result = await rust_client.download_multipart_to_bytes(
-            large_file_path, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_delete_many(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
         )
-        assert result == large_data_bytes
 
-        # Test download_multipart_to_bytes with range
-        result = await rust_client.download_multipart_to_bytes(
-            large_file_path,
-            range=Range(10, chunk_size * max_concurrency + 1),
-            multipart_chunksize=chunk_size,
-            max_concurrency=max_concurrency,
+        prefix = uuid.uuid4().hex
+        paths = [f"{prefix}/file{i}.bin" for i in range(20)]
+        for path in paths:
+            await rust_client.put(path, b"data")
+
+        # An invalid path fails on its own without aborting the rest of the batch.
+        invalid_path = f"{prefix}//invalid.bin"
+        result = await rust_client.delete_many(paths + [invalid_path], max_concurrency=4)
+        assert sorted(result.deleted) == sorted(paths)
+        assert list(result.failed) == [invalid_path]
+        for path in paths:
+            with pytest.raises(RustClientError):
+                await rust_client.get(path)
+
+        result = await rust_client.delete_many(paths[:2], missing_ok=True)
+        assert sorted(result.deleted) == sorted(paths[:2])
+        assert result.failed == {}
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_content_type_and_metadata(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
         )
-        assert result == large_data_bytes[10 : 10 + chunk_size * max_concurrency + 1]
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+        profile = "data"
+        storage_client = StorageClient(
+            config=StorageClientConfig.from_dict(config_dict={"profiles": {profile: config_dict}}, profile=profile)
+        )
+        metadata = {"owner": "training", "step": "100"}
+
+        small_path = f"{uuid.uuid4().hex}/small.json"
+        await rust_client.put(small_path, b"{}", content_type="application/json", metadata=metadata)
+        large_path = f"{uuid.uuid4().hex}/large.bin"
+        await rust_client.upload_multipart_from_bytes(
+            large_path,
+            os.urandom(6 * 1024 * 1024),
+            multipart_chunksize=5 * 1024 * 1024,
+            content_type="application/x-tar",
+            metadata=metadata,
+        )
+        for path, content_type in [(small_path, "application/json"), (large_path, "application/x-tar")]:
+            info = storage_client.info(path)
+            assert info.content_type == content_type
+            assert info.metadata is not None
+            assert {key: info.metadata.get(key) for key in metadata} == metadata
+            rust_info = await rust_client.info(path)
+            assert rust_info.content_type == content_type
+            assert rust_info.metadata is not None
+            assert {key: rust_info.metadata.get(key) for key in metadata} == metadata
 
-        # Delete the file.
-        storage_client.delete(path=large_file_path)
+        with pytest.raises(ValueError, match="bad key"):
+            await rust_client.put(small_path, b"{}", metadata={"bad key": "value"})
 
-        # Test upload_multipart_from_file with explicit chunk size and concurrency
-        large_file_path_fragments = [f"{uuid.uuid4().hex}-prefix", "infix", f"multipart_suffix{file_extension}"]
-        large_file_path = os.path.join(*large_file_path_fragments)
-        chunk_size = 10 * 1024 * 1024
-        max_concurrency = 4
-        with tempfile.NamedTemporaryFile(delete=False) as temp_file:
-            temp_file.write(large_data_bytes)
-            temp_file.close()
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_conditional_put(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        path = f"{uuid.uuid4().hex}/object.bin"
+        first = await rust_client.put(path, b"first", if_not_exists=True, detailed=True)
+        with pytest.raises(RustAlreadyExistsError):
+            await rust_client.put(path, b"second", if_not_exists=True)
+        assert await rust_client.get(path) == b"first"
+
+        second = await rust_client.put(path, b"second", if_match=first.etag, detailed=True)
+        assert await rust_client.get(path) == b"second"
+        with pytest.raises(RustPreconditionFailedError) as exc_info:
+            await rust_client.put(path, b"third", if_match=first.etag)
+        assert isinstance(exc_info.value, RustClientError)
+        assert exc_info.value.args[1] == 412
+        await rust_client.put(path, b"third", if_match=second.etag)
+        assert await rust_client.get(path) == b"third"
+
+        with pytest.raises(ValueError):
+            await rust_client.put(path, b"fourth", if_not_exists=True, if_match=second.etag)
+
+        with pytest.raises(RustAlreadyExistsError):
+            await rust_client.upload_multipart_from_bytes(
+                path, os.urandom(6 * 1024 * 1024), multipart_chunksize=5 * 1024 * 1024, if_not_exists=True
+            )
+
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+        [tempdatastore.TemporarySwiftStackBucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_transfer_result(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+        profile = "data"
+        storage_client = StorageClient(
+            config=StorageClientConfig.from_dict(config_dict={"profiles": {profile: config_dict}}, profile=profile)
+        )
+
+        small_path = f"{uuid.uuid4().hex}/small.bin"
+        assert await rust_client.put(small_path, b"small") == 5
+        result = await rust_client.put(small_path, b"small", detailed=True)
+        assert isinstance(result, TransferResult)
+        assert result.bytes_transferred == 5
+        assert result.parts is None
+        assert result.etag is not None
+        small_info = storage_client.info(small_path)
+        assert result.etag.strip('"') == small_info.etag
+        assert result.last_modified is not None
+        server_time = datetime.fromisoformat(result.last_modified)
+        assert abs((server_time - small_info.last_modified.astimezone(timezone.utc)).total_seconds()) <= 2
+
+        chunksize = 5 * 1024 * 1024
+        with tempfile.NamedTemporaryFile() as temp_file:
+            temp_file.write(os.urandom(2 * chunksize + 17))
+            temp_file.flush()
+            large_path = f"{uuid.uuid4().hex}/large.bin"
             result = await rust_client.upload_multipart_from_file(
-                temp_file.name, large_file_path, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+                temp_file.name, large_path, multipart_chunksize=chunksize, detailed=True
             )
-            assert result == large_file_size
-        os.unlink(temp_file.name)
+        assert result.bytes_transferred == 2 * chunksize + 17
+        assert [(part.part_number, part.size) for part in result.parts] == [
+            (1, chunksize),
+            (2, chunksize),
+            (3, 17),
+        ]
+        assert result.etag is not None
+        assert result.etag.strip('"') == storage_client.info(large_path).etag
+        assert result.last_modified is not None
 
-        # Test download_multipart_to_file with explicit chunk size and concurrency
-        with tempfile.NamedTemporaryFile(delete=False) as temp_file:
-            temp_file.close()
+        with tempfile.TemporaryDirectory() as temp_dir:
+            small_target = os.path.join(temp_dir, "small.bin")
             result = await rust_client.download_multipart_to_file(
-                large_file_path, temp_file.name, multipart_chunksize=chunk_size, max_concurrency=max_concurrency
+                small_path, small_target, verify_checksum=True, detailed=True
             )
-            assert result == large_file_size
-            assert os.path.getsize(temp_file.name) == large_file_size
-            # Assert file content is the same
-            with open(temp_file.name, "rb") as f:
-                downloaded = f.read()
-            assert downloaded == large_data_bytes
-        os.unlink(temp_file.name)
+            assert result.bytes_transferred == 5
+            assert result.verification == "md5"
+            large_target = os.path.join(temp_dir, "large.bin")
+            result = await rust_client.download_multipart_to_file(
+                large_path, large_target, multipart_chunksize=chunksize, verify_checksum=True, detailed=True
+            )
+            assert result.bytes_transferred == 2 * chunksize + 17
+            assert result.verification == "size"
+            assert await rust_client.download_multipart_to_file(large_path, large_target) == 2 * chunksize + 17
 
-        # Delete the file.
-        storage_client.delete(path=large_file_path)
+
+@pytest.mark.parametrize(
+    argnames=["temp_data_store_type"],
+    argvalues=[
+        [tempdatastore.TemporaryAWSS3Bucket],
+    ],
+)
+@pytest.mark.asyncio
+async def test_rustclient_request_tag(temp_data_store_type: Type[tempdatastore.TemporaryDataStore]):
+    with temp_data_store_type() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        operations = []
+
+        def generate_tag(operation: str) -> str:
+            operations.append(operation)
+            return f"generated-{operation}"
+
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+                "request_tag_header": "x-job-id",
+            },
+            credentials_provider=credentials_provider,
+            request_tag_generator=generate_tag,
+        )
+
+        path = f"{uuid.uuid4().hex}/tagged.bin"
+        await rust_client.upload_multipart_from_bytes(
+            path, os.urandom(12 * 1024 * 1024), multipart_chunksize=5 * 1024 * 1024, request_tag="job-1234"
+        )
+        assert operations == []
+
+        with pytest.raises(RustClientError) as exc_info:
+            await rust_client.get(f"{path}.missing", request_tag="job-1234")
+        assert exc_info.value.request_tag == "job-1234"
+
+        with pytest.raises(RustClientError) as exc_info:
+            await rust_client.get(f"{path}.missing")
+        assert exc_info.value.request_tag == "generated-get"
+        assert operations == ["get"]
+
+        with pytest.raises(ValueError):
+            await rust_client.get(path, request_tag="bad\ntag")
+
+
+@pytest.mark.asyncio
+async def test_rustclient_detachable_download():
+    with tempdatastore.TemporaryAWSS3Bucket() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        remote_path = f"{uuid.uuid4().hex}/detachable.bin"
+        data = os.urandom(32 * 1024 * 1024)
+        await rust_client.put(remote_path, data)
+
+        with tempfile.TemporaryDirectory() as temp_dir:
+            local_path = os.path.join(temp_dir, "abandoned.bin")
+            handle = rust_client.download_multipart_to_file(
+                remote_path, local_path, multipart_chunksize=5 * 1024 * 1024, max_concurrency=1, detachable=True
+            )
+            handle.abandon()
+            assert isinstance(await handle, RustDownloadCancelled)
+            assert handle.done()
+            # Neither the destination nor the temporary file is left behind.
+            assert os.listdir(temp_dir) == []
+
+            local_path = os.path.join(temp_dir, "completed.bin")
+            handle = rust_client.download_multipart_to_file(remote_path, local_path, detachable=True)
+            assert await handle == len(data)
+            handle.abandon()
+            with open(local_path, "rb") as f:
+                assert f.read() == data
+
+
+def test_rustclient_event_loop_closed_mid_download(capfd: pytest.CaptureFixture[str]):
+    with tempdatastore.TemporaryAWSS3Bucket() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": config_dict["storage_provider"]["options"]["base_path"],
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        remote_path = f"{uuid.uuid4().hex}/loop-closed.bin"
+        asyncio.run(rust_client.put(remote_path, os.urandom(64 * 1024 * 1024)))
+
+        async def start_download(local_path: str) -> asyncio.Future:
+            return rust_client.download_multipart_to_file(
+                remote_path, local_path, multipart_chunksize=5 * 1024 * 1024, max_concurrency=1
+            )
+
+        with tempfile.TemporaryDirectory() as temp_dir:
+            loop = asyncio.new_event_loop()
+            loop.run_until_complete(start_download(os.path.join(temp_dir, "interrupted.bin")))
+            loop.run_until_complete(asyncio.sleep(0.01))
+            # Close the loop without cancelling the download, as an exiting script or test teardown does.
+            loop.close()
+            time.sleep(1)
+            assert [name for name in os.listdir(temp_dir) if name != "interrupted.bin"] == []
+
+        stderr = capfd.readouterr().err
+        assert "panicked" not in stderr
+        assert "Event loop is closed" not in stderr
+
+
+@pytest.mark.asyncio
+async def test_rustclient_cancel_mid_transfer():
+    with tempdatastore.TemporaryAWSS3Bucket() as temp_data_store:
+        config_dict = temp_data_store.profile_config_dict()
+        bucket = config_dict["storage_provider"]["options"]["base_path"]
+        credentials_provider = StaticS3CredentialsProvider(
+            access_key=config_dict["credentials_provider"]["options"]["access_key"],
+            secret_key=config_dict["credentials_provider"]["options"]["secret_key"],
+        )
+        rust_client = RustClient(
+            provider="s3",
+            configs={
+                "bucket": bucket,
+                "endpoint_url": config_dict["storage_provider"]["options"]["endpoint_url"],
+                "allow_http": config_dict["storage_provider"]["options"]["endpoint_url"].startswith("http://"),
+            },
+            credentials_provider=credentials_provider,
+        )
+
+        remote_path = f"{uuid.uuid4().hex}/cancelled.bin"
+        data = os.urandom(64 * 1024 * 1024)
+        await rust_client.put(remote_path, data)
+
+        with tempfile.TemporaryDirectory() as temp_dir:
+            download = asyncio.ensure_future(
+                rust_client.download_multipart_to_file(
+                    remote_path,
+                    os.path.join(temp_dir, "cancelled.bin"),
+                    multipart_chunksize=5 * 1024 * 1024,
+                    max_concurrency=1,
+                )
+            )
+            await asyncio.sleep(0.05)
+            download.cancel()
+            with pytest.raises(asyncio.CancelledError):
+                await download
+            await asyncio.sleep(0.5)
+            assert os.listdir(temp_dir) == []
+
+            local_path = os.path.join(temp_dir, "source.bin")
+            with open(local_path, "wb") as f:
+                f.write(data)
+            upload = asyncio.ensure_future(
+                rust_client.upload_multipart_from_file(
+                    local_path, f"{remote_path}.upload", multipart_chunksize=5 * 1024 * 1024, max_concurrency=1
+                )
+            )
+            await asyncio.sleep(0.05)
+            upload.cancel()
+            with pytest.raises(asyncio.CancelledError):
+                await upload
+
+        # The abandoned multipart upload is aborted in the background.
+        for _ in range(50):
+            if not temp_data_store._client.list_multipart_uploads(Bucket=bucket).get("Uploads"):
+                break
+            await asyncio.sleep(0.1)
+        assert not temp_data_store._client.list_multipart_uploads(Bucket=bucket).get("Uploads")
+        with pytest.raises(FileNotFoundError):
+            await rust_client.info(f"{remote_path}.upload")
 
 
 @pytest.mark.asyncio
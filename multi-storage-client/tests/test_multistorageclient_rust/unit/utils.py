@@ -53,3 +53,28 @@ class RefreshableTestCredentialsProvider(CredentialsProvider):
     @property
     def refresh_count(self) -> int:
         return self._refresh_count
+
+
+class RevokedTestCredentialsProvider(CredentialsProvider):
+    """
+    A test credentials provider whose first credentials were revoked before they expire.
+    The credentials use the ``revoked`` access key until the refresh_credentials method is called,
+    which switches them to the ``valid`` access key unless ``revoked_again`` is set.
+    """
+
+    def __init__(self, revoked_again: bool = False):
+        self._access_key = "revoked"
+        self._revoked_again = revoked_again
+        self._refresh_count = 0
+
+    def get_credentials(self) -> Credentials:
+        return Credentials(access_key=self._access_key, secret_key="secret", token=None, expiration=None)
+
+    def refresh_credentials(self) -> None:
+        self._refresh_count += 1
+        if not self._revoked_again:
+            self._access_key = "valid"
+
+    @property
+    def refresh_count(self) -> int:
+        return self._refresh_count